@@ -40,9 +40,9 @@ pub fn transfer_approval_create<'a>(
     );
 
     let no_approval = match input_account_reader.version() {
-        4 => {
-            let reader = input_account_reader.try_into_latest().unwrap();
-            util::is_reader_eq(reader.approval(), AccountApproval::default().as_reader())
+        4 | 5 => {
+            let approval = get_approval(input_account_reader.as_ref(), input_account_index)?;
+            util::is_reader_eq(approval, AccountApproval::default().as_reader())
         }
         _ => true,
     };
@@ -163,17 +163,7 @@ pub fn transfer_approval_delay<'a>(
 ) -> Result<(), Box<dyn ScriptError>> {
     debug!("Parsing the AccountCellData into the latest version ...");
 
-    let input_account_reader = match input_account_reader.try_into_latest() {
-        Ok(reader) => reader,
-        Err(_) => {
-            warn!(
-                "{:?}[{}] The witness should be the latest version.",
-                Source::Input,
-                input_account_index
-            );
-            return Err(code_to_error!(AccountCellErrorCode::WitnessParsingError));
-        }
-    };
+    let input_approval = get_approval(input_account_reader.as_ref(), input_account_index)?;
     let output_account_reader = match output_account_reader.try_into_latest() {
         Ok(reader) => reader,
         Err(_) => {
@@ -189,28 +179,23 @@ pub fn transfer_approval_delay<'a>(
     debug!("Verify if the AccountApprovalTransfer.params is consistent ...");
 
     das_assert!(
-        util::is_reader_eq(
-            input_account_reader.approval().action(),
-            output_account_reader.approval().action()
-        ),
+        util::is_reader_eq(input_approval.action(), output_account_reader.approval().action()),
         AccountCellErrorCode::ApprovalParamsCanNotBeChanged,
         "The AccountCell.witness.approval.action can not be changed.(input: {:?}, output: {:?})",
-        String::from_utf8(input_account_reader.approval().action().raw_data().to_vec()),
+        String::from_utf8(input_approval.action().raw_data().to_vec()),
         String::from_utf8(output_account_reader.approval().action().raw_data().to_vec())
     );
 
-    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(
-        input_account_reader.approval().params().raw_data(),
-    )
-    .map_err(|e| {
-        warn!(
-            "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
-            Source::Input,
-            input_account_index,
-            e.to_string()
-        );
-        return code_to_error!(AccountCellErrorCode::WitnessParsingError);
-    })?;
+    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(input_approval.params().raw_data())
+        .map_err(|e| {
+            warn!(
+                "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
+                Source::Input,
+                input_account_index,
+                e.to_string()
+            );
+            return code_to_error!(AccountCellErrorCode::WitnessParsingError);
+        })?;
     let input_approval_reader = input_approval_params.as_reader();
     let output_approval_params = AccountApprovalTransfer::from_compatible_slice(
         output_account_reader.approval().params().raw_data(),
@@ -298,17 +283,7 @@ pub fn transfer_approval_revoke<'a>(
 ) -> Result<(), Box<dyn ScriptError>> {
     debug!("Parsing the AccountCellData into the latest version ...");
 
-    let input_account_reader = match input_account_reader.try_into_latest() {
-        Ok(reader) => reader,
-        Err(_) => {
-            warn!(
-                "{:?}[{}] The witness should be the latest version.",
-                Source::Input,
-                input_account_index
-            );
-            return Err(code_to_error!(AccountCellErrorCode::WitnessParsingError));
-        }
-    };
+    let input_approval = get_approval(input_account_reader.as_ref(), input_account_index)?;
     let output_account_reader = match output_account_reader.try_into_latest() {
         Ok(reader) => reader,
         Err(_) => {
@@ -323,18 +298,16 @@ pub fn transfer_approval_revoke<'a>(
 
     debug!("Verify if the approval can be revoked ...");
 
-    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(
-        input_account_reader.approval().params().raw_data(),
-    )
-    .map_err(|e| {
-        warn!(
-            "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
-            Source::Input,
-            input_account_index,
-            e.to_string()
-        );
-        return code_to_error!(AccountCellErrorCode::WitnessParsingError);
-    })?;
+    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(input_approval.params().raw_data())
+        .map_err(|e| {
+            warn!(
+                "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
+                Source::Input,
+                input_account_index,
+                e.to_string()
+            );
+            return code_to_error!(AccountCellErrorCode::WitnessParsingError);
+        })?;
     let input_approval_reader = input_approval_params.as_reader();
 
     let input_protected_until = u64::from(input_approval_reader.protected_until());
@@ -378,17 +351,7 @@ pub fn transfer_approval_fulfill<'a>(
 ) -> Result<u64, Box<dyn ScriptError>> {
     debug!("Parsing the AccountCellData into the latest version ...");
 
-    let input_account_reader = match input_account_reader.try_into_latest() {
-        Ok(reader) => reader,
-        Err(_) => {
-            warn!(
-                "{:?}[{}] The witness should be the latest version.",
-                Source::Input,
-                input_account_index
-            );
-            return Err(code_to_error!(AccountCellErrorCode::WitnessParsingError));
-        }
-    };
+    let input_approval = get_approval(input_account_reader.as_ref(), input_account_index)?;
     let output_account_reader = match output_account_reader.try_into_latest() {
         Ok(reader) => reader,
         Err(_) => {
@@ -403,18 +366,16 @@ pub fn transfer_approval_fulfill<'a>(
 
     debug!("Parsing the approval params ...");
 
-    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(
-        input_account_reader.approval().params().raw_data(),
-    )
-    .map_err(|e| {
-        warn!(
-            "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
-            Source::Input,
-            input_account_index,
-            e.to_string()
-        );
-        return code_to_error!(AccountCellErrorCode::WitnessParsingError);
-    })?;
+    let input_approval_params = AccountApprovalTransfer::from_compatible_slice(input_approval.params().raw_data())
+        .map_err(|e| {
+            warn!(
+                "{:?}[{}] Decoding AccountCell.witness.approval.params failed: {}",
+                Source::Input,
+                input_account_index,
+                e.to_string()
+            );
+            return code_to_error!(AccountCellErrorCode::WitnessParsingError);
+        })?;
     let input_approval_reader = input_approval_params.as_reader();
     let to_lock = input_approval_reader.to_lock();
 
@@ -465,3 +426,27 @@ pub fn transfer_approval_fulfill<'a>(
 
     Ok(input_approval_reader.sealed_until().into())
 }
+
+/// The AccountCellData in inputs may still be the version before cross_chain was added, so the approval is
+/// loaded from both versions.
+fn get_approval<'r>(
+    input_account_reader: &dyn AccountCellDataReaderMixer<'r>,
+    input_account_index: usize,
+) -> Result<AccountApprovalReader<'r>, Box<dyn ScriptError>> {
+    let approval = match input_account_reader.version() {
+        4 => input_account_reader.try_into_v4().map(|reader| reader.approval()),
+        _ => input_account_reader.try_into_latest().map(|reader| reader.approval()),
+    };
+
+    match approval {
+        Ok(approval) => Ok(approval),
+        Err(_) => {
+            warn!(
+                "{:?}[{}] The witness should be the latest version.",
+                Source::Input,
+                input_account_index
+            );
+            Err(code_to_error!(AccountCellErrorCode::WitnessParsingError))
+        }
+    }
+}
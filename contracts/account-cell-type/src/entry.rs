@@ -42,7 +42,27 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
             let timestamp = util::load_oracle_data(OracleCellType::Time)?;
 
             let (input_account_cells, output_account_cells) = util::load_self_cells_in_inputs_and_outputs()?;
-            verifiers::common::verify_cell_number("AccountCell", &input_account_cells, 1, &output_account_cells, 1)?;
+            if parser.action == Action::EditRecords {
+                // The records of multiple AccountCells can be edited in one transaction, the AccountCells in inputs and
+                // outputs are paired by their order.
+                das_assert!(
+                    input_account_cells.len() > 0 && input_account_cells.len() == output_account_cells.len(),
+                    ErrorCode::InvalidTransactionStructure,
+                    "There should be the same number of AccountCells in inputs and outputs. (inputs: {}, outputs: {})",
+                    input_account_cells.len(),
+                    output_account_cells.len()
+                );
+
+                verify_accounts_managed_by_same_lock(&input_account_cells)?;
+            } else {
+                verifiers::common::verify_cell_number(
+                    "AccountCell",
+                    &input_account_cells,
+                    1,
+                    &output_account_cells,
+                    1,
+                )?;
+            }
 
             debug!("Verify if there is no redundant cells in inputs.");
 
@@ -53,136 +73,96 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                 Source::Input,
             )?;
 
-            let input_cell_witness = util::parse_account_cell_witness(input_account_cells[0], Source::Input)?;
-            let input_cell_witness_reader = input_cell_witness.as_reader();
-            let output_cell_witness = util::parse_account_cell_witness(output_account_cells[0], Source::Output)?;
-            let output_cell_witness_reader = output_cell_witness.as_reader();
-
             let config_account = Config::get_instance().account()?;
 
-            verify_transaction_fee_spent_correctly(
-                parser.action,
-                config_account,
-                input_account_cells[0],
-                output_account_cells[0],
-            )?;
-            if parser.action != Action::LockAccountForCrossChain {
-                verify_action_throttle(
+            for (&input_account_cell, &output_account_cell) in
+                input_account_cells.iter().zip(output_account_cells.iter())
+            {
+                let input_cell_witness = util::parse_account_cell_witness(input_account_cell, Source::Input)?;
+                let input_cell_witness_reader = input_cell_witness.as_reader();
+                let output_cell_witness = util::parse_account_cell_witness(output_account_cell, Source::Output)?;
+                let output_cell_witness_reader = output_cell_witness.as_reader();
+
+                verify_transaction_fee_spent_correctly(
                     parser.action,
                     config_account,
-                    &input_cell_witness_reader,
-                    &output_cell_witness_reader,
-                    timestamp,
+                    input_account_cell,
+                    output_account_cell,
                 )?;
-            }
-
-            verifiers::account_cell::verify_account_expiration(
-                config_account,
-                input_account_cells[0],
-                Source::Input,
-                timestamp,
-            )?;
+                if parser.action != Action::LockAccountForCrossChain {
+                    verify_action_throttle(
+                        parser.action,
+                        config_account,
+                        &input_cell_witness_reader,
+                        &output_cell_witness_reader,
+                        timestamp,
+                    )?;
+                }
 
-            match parser.action {
-                Action::TransferAccount => action_transfer_account(
-                    &input_account_cells,
-                    &output_account_cells,
-                    &input_cell_witness_reader,
-                    &output_cell_witness_reader,
-                )?,
-                Action::EditManager => action_edit_manager(
-                    &input_account_cells,
-                    &output_account_cells,
-                    &input_cell_witness_reader,
-                    &output_cell_witness_reader,
-                )?,
-                Action::EditRecords => action_edit_records(
-                    &input_account_cells,
-                    &output_account_cells,
-                    &input_cell_witness_reader,
-                    &output_cell_witness_reader,
-                )?,
-                Action::LockAccountForCrossChain => action_lock_account_for_cross_chain(
-                    &input_account_cells,
-                    &output_account_cells,
-                    &input_cell_witness_reader,
-                    &output_cell_witness_reader,
+                verifiers::account_cell::verify_account_expiration(
+                    config_account,
+                    input_account_cell,
+                    Source::Input,
                     timestamp,
-                )?,
-                _ => unreachable!(),
+                )?;
+
+                match parser.action {
+                    Action::TransferAccount => action_transfer_account(
+                        &[input_account_cell],
+                        &[output_account_cell],
+                        &input_cell_witness_reader,
+                        &output_cell_witness_reader,
+                    )?,
+                    Action::EditManager => action_edit_manager(
+                        &[input_account_cell],
+                        &[output_account_cell],
+                        &input_cell_witness_reader,
+                        &output_cell_witness_reader,
+                    )?,
+                    Action::EditRecords => action_edit_records(
+                        &[input_account_cell],
+                        &[output_account_cell],
+                        &input_cell_witness_reader,
+                        &output_cell_witness_reader,
+                    )?,
+                    Action::LockAccountForCrossChain => action_lock_account_for_cross_chain(
+                        &[input_account_cell],
+                        &[output_account_cell],
+                        &input_cell_witness_reader,
+                        &output_cell_witness_reader,
+                        timestamp,
+                    )?,
+                    _ => unreachable!(),
+                }
             }
             //WARNING: migrate it to das-lock
             //util::exec_by_type_id(&parser, TypeScript::EIP712Lib, &[])?;
         }
         Action::RenewAccount => {
-            let timestamp = util::load_oracle_data(OracleCellType::Time)?;
+            let timestamp = util::load_oracle_data_by_median(OracleCellType::Time)?;
 
-            let prices = Config::get_instance().price()?.prices();
+            let config_price = Config::get_instance().price()?;
             let config_main = Config::get_instance().main()?;
             let config_account = Config::get_instance().account()?;
 
             let (input_account_cells, output_account_cells) = util::load_self_cells_in_inputs_and_outputs()?;
 
-            verifiers::common::verify_cell_number("AccountCell", &input_account_cells, 1, &output_account_cells, 1)?;
+            debug!("Verify if the number of AccountCells is correct.");
 
-            let input_cell_witness = util::parse_account_cell_witness(input_account_cells[0], Source::Input)?;
-            let input_cell_witness_reader = input_cell_witness.as_reader();
-            let output_cell_witness = util::parse_account_cell_witness(output_account_cells[0], Source::Output)?;
-            let output_cell_witness_reader = output_cell_witness.as_reader();
-
-            verifiers::account_cell::verify_account_capacity_not_decrease(
-                input_account_cells[0],
-                output_account_cells[0],
-            )?;
-            verifiers::account_cell::verify_account_cell_consistent_with_exception(
-                input_account_cells[0],
-                output_account_cells[0],
-                &input_cell_witness_reader,
-                &output_cell_witness_reader,
-                None,
-                vec!["expired_at"],
-                vec![],
-            )?;
-
-            debug!("Verify if the AccountCell is locked for cross chain.");
-
-            let status = u8::from(input_cell_witness_reader.status());
+            // Multiple AccountCells can be renewed in one transaction, the AccountCells in inputs and outputs are paired by
+            // their order.
             das_assert!(
-                status != (AccountStatus::LockedForCrossChain as u8),
-                AccountCellErrorCode::AccountCellStatusLocked,
-                "inputs[{}] The AccountCell has been locked for cross chain, it is required to unlock first for renew.",
-                input_account_cells[0]
-            );
-
-            debug!("Verify if the AccountCell has been expired.");
-
-            let ret = verifiers::account_cell::verify_account_expiration(
-                config_account,
-                input_account_cells[0],
-                Source::Input,
-                timestamp,
+                input_account_cells.len() > 0 && input_account_cells.len() == output_account_cells.len(),
+                ErrorCode::InvalidTransactionStructure,
+                "There should be the same number of AccountCells in inputs and outputs. (inputs: {}, outputs: {})",
+                input_account_cells.len(),
+                output_account_cells.len()
             );
-            if let Err(err) = ret {
-                das_assert!(
-                    err.as_i8() == AccountCellErrorCode::AccountCellInExpirationGracePeriod as i8,
-                    AccountCellErrorCode::AccountCellHasExpired,
-                    "The AccountCell has been expired."
-                );
-            } else {
-                // Ok
-            }
-
-            debug!("Verify if there is no redundant cells in inputs.");
 
             let sender_lock = util::derive_owner_lock_from_cell(input_account_cells[0], Source::Input)?;
             let balance_cells = util::find_balance_cells(config_main, sender_lock.as_reader(), Source::Input)?;
-            let all_cells = [input_account_cells.clone(), balance_cells.clone()].concat();
-            verifiers::misc::verify_no_more_cells_with_same_lock(sender_lock.as_reader(), &all_cells, Source::Input)?;
+            let mut all_cells = [input_account_cells.clone(), balance_cells.clone()].concat();
 
-            debug!("Verify if the profit is distribute correctly.");
-            // TODO Unify the following codes to calculate profit from duration.
-
-            let mut profit_map = Map::new();
             let das_wallet_lock = wallet_lock().clone();
 
             let (input_income_cells, output_income_cells) = util::find_cells_by_type_id_in_inputs_and_outputs(
@@ -190,101 +170,171 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                 config_main.type_id_table().income_cell(),
             )?;
 
-            let mut exist_capacity = 0;
-            if input_income_cells.len() == 1 {
-                let input_income_cell_witness = util::parse_income_cell_witness(input_income_cells[0], Source::Input)?;
-                let input_income_cell_witness_reader = input_income_cell_witness.as_reader();
+            let paid;
+            let quote;
+            let mut udt_payment_opt = None;
+            let mut is_paid_in_dpoint = false;
+            let dpoint_paid = util::load_dpoint_paid_to_whitelist()?;
+            if output_income_cells.is_empty() && dpoint_paid > 0 {
+                debug!("There is no IncomeCell in outputs but some DPoint paid to the transfer whitelist, the renewal is paid in DPoint.");
+
+                verifiers::income_cell::verify_income_cells(Map::new())?;
+
+                paid = dpoint_paid;
+                quote = DPOINT_QUOTE;
+                is_paid_in_dpoint = true;
+            } else if output_income_cells.is_empty() {
+                debug!("There is no IncomeCell in outputs, verify if the renewal is paid in UDT.");
+
+                let (udt_payment, udt_paid) = match util::load_udt_paid(
+                    config_price,
+                    das_wallet_lock.as_reader().into(),
+                )? {
+                    Some(ret) => ret,
+                    None => {
+                        warn!("The renewal should be paid in CKB with an IncomeCell or in one of the UDTs in ConfigCellPrice.udt_payments .");
+                        return Err(code_to_error!(ErrorCode::UdtPaymentError));
+                    }
+                };
+                verifiers::income_cell::verify_income_cells(Map::new())?;
+
+                let (sender_udt_cells, _) =
+                    util::load_udt_amount_of_lock(udt_payment.type_script(), sender_lock.as_reader(), Source::Input)?;
+                all_cells.extend(sender_udt_cells);
 
-                for item in input_income_cell_witness_reader.records().iter() {
-                    if util::is_reader_eq(item.belong_to(), das_wallet_lock.as_reader()) {
-                        exist_capacity += u64::from(item.capacity());
+                paid = udt_paid;
+                quote = util::calc_udt_quote(u8::from(udt_payment.decimals()))?;
+                udt_payment_opt = Some(udt_payment);
+            } else {
+                debug!("Verify if the profit is distribute correctly.");
+                // TODO Unify the following codes to calculate profit from duration.
+
+                let mut profit_map = Map::new();
+
+                let mut exist_capacity = 0;
+                if input_income_cells.len() == 1 {
+                    let input_income_cell_witness =
+                        util::parse_income_cell_witness(input_income_cells[0], Source::Input)?;
+                    let input_income_cell_witness_reader = input_income_cell_witness.as_reader();
+
+                    for item in input_income_cell_witness_reader.records().iter() {
+                        if util::is_reader_eq(item.belong_to(), das_wallet_lock.as_reader()) {
+                            exist_capacity += u64::from(item.capacity());
+                        }
                     }
                 }
-            }
 
-            let output_income_cell_witness = util::parse_income_cell_witness(output_income_cells[0], Source::Output)?;
-            let output_income_cell_witness_reader = output_income_cell_witness.as_reader();
-            let mut paid = 0;
-            for item in output_income_cell_witness_reader.records().iter() {
-                if util::is_reader_eq(item.belong_to(), das_wallet_lock.as_reader()) {
-                    paid += u64::from(item.capacity());
+                let output_income_cell_witness =
+                    util::parse_income_cell_witness(output_income_cells[0], Source::Output)?;
+                let output_income_cell_witness_reader = output_income_cell_witness.as_reader();
+                let mut income = 0;
+                for item in output_income_cell_witness_reader.records().iter() {
+                    if util::is_reader_eq(item.belong_to(), das_wallet_lock.as_reader()) {
+                        income += u64::from(item.capacity());
+                    }
                 }
-            }
 
-            das_assert!(
-                paid > exist_capacity,
-                ErrorCode::IncomeCellConsolidateConditionNotSatisfied,
-                "outputs[{}] There is some record in outputs has less capacity than itself in inputs which is not allowed. (belong_to: {})",
-                output_income_cells[0],
-                das_wallet_lock
-            );
+                das_assert!(
+                    income > exist_capacity,
+                    ErrorCode::IncomeCellConsolidateConditionNotSatisfied,
+                    "outputs[{}] There is some record in outputs has less capacity than itself in inputs which is not allowed. (belong_to: {})",
+                    output_income_cells[0],
+                    das_wallet_lock
+                );
 
-            paid -= exist_capacity;
+                paid = income - exist_capacity;
 
-            map_util::add(&mut profit_map, das_wallet_lock.as_slice().to_vec(), paid);
-            verifiers::income_cell::verify_income_cells(profit_map)?;
+                map_util::add(&mut profit_map, das_wallet_lock.as_slice().to_vec(), paid);
+                verifiers::income_cell::verify_income_cells(profit_map)?;
 
-            debug!("Check if the renewal duration is longer than or equal to one year.");
+                quote = util::load_oracle_data_by_median(OracleCellType::Quote)?;
+            }
 
-            let input_data = util::load_cell_data(input_account_cells[0], Source::Input)?;
-            let output_data = util::load_cell_data(output_account_cells[0], Source::Output)?;
-            let input_expired_at = data_parser::account_cell::get_expired_at(&input_data);
-            let output_expired_at = data_parser::account_cell::get_expired_at(&output_data);
-            let duration = output_expired_at - input_expired_at;
+            debug!("Verify if there is no redundant cells in inputs.");
 
-            das_assert!(
-                duration >= DAYS_OF_YEAR * DAY_SEC,
-                AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
-                "The AccountCell renew should be longer than 1 year. (current: {}, expected: >= 31_536_000)",
-                duration
-            );
+            if is_paid_in_dpoint {
+                verifiers::misc::verify_no_more_cells_with_same_lock_except_type(
+                    sender_lock.as_reader(),
+                    &all_cells,
+                    Source::Input,
+                    config_main.type_id_table().dpoint_cell(),
+                )?;
+            } else {
+                verifiers::misc::verify_no_more_cells_with_same_lock(
+                    sender_lock.as_reader(),
+                    &all_cells,
+                    Source::Input,
+                )?;
+            }
 
-            debug!("Check if the expired_at field has been updated correctly based on the capacity paid by the user.");
+            // The payment for all AccountCells is aggregated in the IncomeCell, so the fees of all AccountCells are summed
+            // up and compared with the payment at last.
+            let mut min_yearly_capacity = 0;
+            let mut min_expected_paid = 0;
+            let mut max_expected_paid = 0;
+            for (&input_account_cell, &output_account_cell) in
+                input_account_cells.iter().zip(output_account_cells.iter())
+            {
+                let (yearly_capacity, min_paid, max_paid) = verify_account_renewal(
+                    config_account,
+                    config_price,
+                    input_account_cell,
+                    output_account_cell,
+                    timestamp,
+                    quote,
+                )?;
 
-            let length_in_price = util::get_length_in_price(output_cell_witness_reader.account().len() as u64);
-            // Find out register price in from ConfigCellRegister.
-            let price = prices
-                .iter()
-                .find(|item| u8::from(item.length()) == length_in_price)
-                .ok_or(ErrorCode::ItemMissing)?;
+                min_yearly_capacity += yearly_capacity;
+                min_expected_paid += min_paid;
+                max_expected_paid += max_paid;
+            }
 
-            let renew_price_in_usd = u64::from(price.renew()); // x USD
-            let quote = util::load_oracle_data(OracleCellType::Quote)?;
+            debug!("Check if the expired_at field has been updated correctly based on the capacity paid by the user.");
 
-            let yearly_capacity = util::calc_yearly_register_fee(renew_price_in_usd, quote, 0)?;
             das_assert!(
-                paid >= yearly_capacity,
+                paid >= min_yearly_capacity,
                 AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
-                "The paid capacity should be at least 1 year. (current: {}, expected: >= {}",
+                "The paid capacity should be at least 1 year for each AccountCell. (current: {}, expected: >= {})",
                 paid,
-                yearly_capacity
+                min_yearly_capacity
             );
 
-            // Renew price for 1 year in CKB = x ÷ y .
-            let expected_duration = util::calc_duration_from_paid(paid, renew_price_in_usd, quote, 0)?;
-            // The duration can be floated within the range of one day.
+            // The duration of each AccountCell can be floated within the range of one day.
             das_assert!(
-                duration >= expected_duration - DAY_SEC && duration <= expected_duration + DAY_SEC,
+                paid >= min_expected_paid && paid <= max_expected_paid,
                 AccountCellErrorCode::AccountCellRenewDurationBiggerThanPayed,
-                "The duration should be equal to {} +/- {}. (current: duration({}), calculation: (paid({}) / (renew_price({}) / quote({}) * 100_000_000) ) * 86400 * 365)",
-                expected_duration,
-                DAY_SEC,
-                duration,
-                paid,
-                renew_price_in_usd,
-                quote
+                "The paid capacity should be in the range of {}..={} which is calculated from the durations. (current: {})",
+                min_expected_paid,
+                max_expected_paid,
+                paid
             );
 
             debug!("Verify if sender get their change properly.");
 
-            let total_input_capacity = util::load_cells_capacity(&balance_cells, Source::Input)?;
+            if is_paid_in_dpoint {
+                debug!("The DPoint can only be transferred from the sender to the transfer whitelist, so the change is verified by the dpoint-cell-type.");
+            } else if let Some(udt_payment) = udt_payment_opt {
+                // The capacity of the UDT cells is decided by the sender, so only the change of the UDT is verified.
+                let (_, total_input_amount) =
+                    util::load_udt_amount_of_lock(udt_payment.type_script(), sender_lock.as_reader(), Source::Input)?;
+
+                if total_input_amount > paid as u128 {
+                    verifiers::misc::verify_user_get_udt_change(
+                        udt_payment.type_script(),
+                        sender_lock.as_reader(),
+                        total_input_amount - paid as u128,
+                    )?;
+                }
+            } else {
+                let total_input_capacity = util::load_cells_capacity(&balance_cells, Source::Input)?;
 
-            if total_input_capacity > paid {
-                verifiers::misc::verify_user_get_change(
-                    config_main,
-                    sender_lock.as_reader(),
-                    total_input_capacity - paid,
-                )?;
+                if total_input_capacity > paid {
+                    verifiers::misc::verify_user_get_change(
+                        config_main,
+                        sender_lock.as_reader(),
+                        total_input_capacity - paid,
+                    )?;
+                }
             }
 
             // The AccountCell can be used as long as it is not modified.
@@ -375,6 +425,10 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                     u8::from(reader.enable_sub_account())
                 }
                 4 => {
+                    let reader = expired_account_witness_reader.try_into_v4().unwrap();
+                    u8::from(reader.enable_sub_account())
+                }
+                5 => {
                     let reader = expired_account_witness_reader.try_into_latest().unwrap();
                     u8::from(reader.enable_sub_account())
                 }
@@ -744,6 +798,10 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                     u8::from(reader.enable_sub_account())
                 }
                 4 => {
+                    let reader = input_account_witness_reader.try_into_v4().unwrap();
+                    u8::from(reader.enable_sub_account())
+                }
+                5 => {
                     let reader = input_account_witness_reader.try_into_latest().unwrap();
                     u8::from(reader.enable_sub_account())
                 }
@@ -893,7 +951,15 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
 
             debug!("Verify if the lock.args is changed during the unlock transaction.");
 
-            // CAREFUL! The owner lock may be changed or not changed, only the keepers know it, so we skip verification here.
+            // The owner lock may be changed or not changed, the relayers attest which owner the account comes back with.
+            let attestation = util::parse_cross_chain_unlock_attestation_witness()?;
+            verifiers::account_cell::verify_cross_chain_unlock_attestation(
+                input_account_cells[0],
+                output_account_cells[0],
+                &input_cell_witness_reader,
+                attestation.as_reader(),
+            )?;
+
             let input_lock =
                 high_level::load_cell_lock(input_account_cells[0], Source::Input).map_err(Error::<ErrorCode>::from)?;
             let input_args = input_lock.as_reader().args().raw_data();
@@ -903,13 +969,13 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
             let (owner_changed, _) = util::diff_das_lock_args(input_args, output_args);
 
             if owner_changed {
-                // The lock is changed, so the records must be cleared.
+                // The lock is changed, so the records and the records delegates must be cleared.
                 verifiers::account_cell::verify_account_witness_consistent(
                     input_account_cells[0],
                     output_account_cells[0],
                     &input_cell_witness_reader,
                     &output_cell_witness_reader,
-                    vec!["status", "records"],
+                    vec!["status", "records", "cross_chain"],
                 )?;
                 verifiers::account_cell::verify_account_witness_record_empty(
                     &output_cell_witness_reader,
@@ -923,7 +989,7 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                     output_account_cells[0],
                     &input_cell_witness_reader,
                     &output_cell_witness_reader,
-                    vec!["status"],
+                    vec!["status", "cross_chain"],
                 )?;
             }
 
@@ -934,8 +1000,6 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
             );
 
             verify_account_is_unlocked_for_cross_chain(output_account_cells[0], &output_cell_witness_reader)?;
-
-            //verify_multi_sign(input_account_cells[0], config_main.das_lock_type_id_table())?;
         }
         Action::BidExpiredAccountDutchAuction => {
             //get configs
@@ -992,8 +1056,15 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                     "last_edit_records_at",
                     "records",
                     "status",
+                    "cross_chain",
                 ],
             )?;
+            // The AccountCell may be locked for cross chain before, it is back to normal after the auction.
+            verifiers::account_cell::verify_account_witness_cross_chain_empty(
+                &output_cell_witness_reader,
+                output_account_cells[0],
+                Source::Output,
+            )?;
 
             // let records_len = output_cell_witness_reader.records().len();
             // das_assert!(
@@ -1220,7 +1291,10 @@ fn action_edit_records<'a>(
         vec![],
         vec!["records", "last_edit_records_at"],
     )?;
-    verifiers::account_cell::verify_records_keys(output_cell_witness_reader.records())?;
+    verifiers::account_cell::verify_records_keys(
+        output_cell_witness_reader.records(),
+        Some(input_cell_witness_reader.records()),
+    )?;
 
     Ok(())
 }
@@ -1232,6 +1306,10 @@ fn action_lock_account_for_cross_chain<'a>(
     output_cell_witness_reader: &Box<dyn AccountCellDataReaderMixer + 'a>,
     timestamp: u64,
 ) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Verify if the AccountCell is locked to a supported chain.");
+
+    let cross_chain = get_cross_chain_from_params()?;
+
     verifiers::account_cell::verify_status(
         &input_cell_witness_reader,
         AccountStatus::Normal,
@@ -1246,10 +1324,15 @@ fn action_lock_account_for_cross_chain<'a>(
         &output_cell_witness_reader,
         None,
         vec![],
-        vec!["status"],
+        vec!["status", "cross_chain"],
     )?;
 
-    verify_account_is_locked_for_cross_chain(output_account_cells[0], &output_cell_witness_reader, timestamp)?;
+    verify_account_is_locked_for_cross_chain(
+        output_account_cells[0],
+        &output_cell_witness_reader,
+        cross_chain,
+        timestamp,
+    )?;
 
     Ok(())
 }
@@ -1437,6 +1520,138 @@ fn get_approval_action<'a>(
     Ok(approval_action)
 }
 
+/// Verify one of the AccountCells in the renew_account transaction, return the fee of 1 year and the range of the fee for
+/// the renewal duration of the AccountCell.
+fn verify_account_renewal(
+    config_account: ConfigCellAccountReader,
+    config_price: ConfigCellPriceReader,
+    input_account_cell: usize,
+    output_account_cell: usize,
+    timestamp: u64,
+    quote: u64,
+) -> Result<(u64, u64, u64), Box<dyn ScriptError>> {
+    let input_cell_witness = util::parse_account_cell_witness(input_account_cell, Source::Input)?;
+    let input_cell_witness_reader = input_cell_witness.as_reader();
+    let output_cell_witness = util::parse_account_cell_witness(output_account_cell, Source::Output)?;
+    let output_cell_witness_reader = output_cell_witness.as_reader();
+
+    verifiers::account_cell::verify_account_capacity_not_decrease(input_account_cell, output_account_cell)?;
+    verifiers::account_cell::verify_account_cell_consistent_with_exception(
+        input_account_cell,
+        output_account_cell,
+        &input_cell_witness_reader,
+        &output_cell_witness_reader,
+        None,
+        vec!["expired_at"],
+        vec![],
+    )?;
+
+    debug!("Verify if the AccountCell is locked for cross chain.");
+
+    let status = u8::from(input_cell_witness_reader.status());
+    das_assert!(
+        status != (AccountStatus::LockedForCrossChain as u8),
+        AccountCellErrorCode::AccountCellStatusLocked,
+        "inputs[{}] The AccountCell has been locked for cross chain, it is required to unlock first for renew.",
+        input_account_cell
+    );
+
+    debug!("Verify if the AccountCell has been expired.");
+
+    let ret = verifiers::account_cell::verify_account_expiration(
+        config_account,
+        input_account_cell,
+        Source::Input,
+        timestamp,
+    );
+    if let Err(err) = ret {
+        das_assert!(
+            err.as_i8() == AccountCellErrorCode::AccountCellInExpirationGracePeriod as i8,
+            AccountCellErrorCode::AccountCellHasExpired,
+            "inputs[{}] The AccountCell has been expired.",
+            input_account_cell
+        );
+    } else {
+        // Ok
+    }
+
+    debug!("Check if the renewal duration is longer than or equal to one year.");
+
+    let input_data = util::load_cell_data(input_account_cell, Source::Input)?;
+    let output_data = util::load_cell_data(output_account_cell, Source::Output)?;
+    let input_expired_at = data_parser::account_cell::get_expired_at(&input_data);
+    let output_expired_at = data_parser::account_cell::get_expired_at(&output_data);
+    let duration = output_expired_at.saturating_sub(input_expired_at);
+
+    das_assert!(
+        duration >= DAYS_OF_YEAR * DAY_SEC,
+        AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
+        "outputs[{}] The AccountCell renew should be longer than 1 year. (current: {}, expected: >= 31_536_000)",
+        output_account_cell,
+        duration
+    );
+
+    let length_in_price = util::get_length_in_price(output_cell_witness_reader.account().len() as u64);
+    // Find out register price in from ConfigCellRegister.
+    let prices = config_price.prices();
+    let price = prices
+        .iter()
+        .find(|item| u8::from(item.length()) == length_in_price)
+        .ok_or(ErrorCode::ItemMissing)?;
+
+    let base_renew_price_in_usd = u64::from(price.renew());
+    let promotion_discount = util::get_promotion_discount(
+        config_price.promotions(),
+        output_cell_witness_reader.account(),
+        timestamp,
+    )?;
+    let multi_year_discount = util::get_multi_year_discount(config_price.multi_year_discounts(), |_| Ok(duration))?;
+    let renew_price_in_usd = util::calc_discounted_price(
+        base_renew_price_in_usd,
+        promotion_discount.saturating_add(multi_year_discount),
+    ); // x USD
+
+    // Renew price for 1 year in CKB = x ÷ y .
+    let yearly_capacity = util::calc_yearly_register_fee(renew_price_in_usd, quote, 0)?;
+    let min_paid = util::calc_paid_from_duration(duration - DAY_SEC, renew_price_in_usd, quote, 0)?;
+    let max_paid = util::calc_paid_from_duration(duration + DAY_SEC, renew_price_in_usd, quote, 0)?;
+
+    debug!(
+        "outputs[{}] The AccountCell is renewed for {} seconds, the expected payment is {}..={}. (renew_price: {}, base: {}, promotion_discount: {}, multi_year_discount: {}, quote: {})",
+        output_account_cell,
+        duration,
+        min_paid,
+        max_paid,
+        renew_price_in_usd,
+        base_renew_price_in_usd,
+        promotion_discount,
+        multi_year_discount,
+        quote
+    );
+
+    Ok((yearly_capacity, min_paid, max_paid))
+}
+
+fn verify_accounts_managed_by_same_lock(input_account_cells: &[usize]) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Verify if all the AccountCells are managed by the same lock.");
+
+    let manager_lock = util::derive_manager_lock_from_cell(input_account_cells[0], Source::Input)?;
+    for &input_account_cell in input_account_cells.iter().skip(1) {
+        let lock = util::derive_manager_lock_from_cell(input_account_cell, Source::Input)?;
+        das_assert!(
+            util::is_entity_eq(&manager_lock, &lock),
+            ErrorCode::InvalidTransactionStructure,
+            "inputs[{}] The AccountCell should be managed by the same lock as inputs[{}]. (expected: {}, current: {})",
+            input_account_cell,
+            input_account_cells[0],
+            manager_lock,
+            lock
+        );
+    }
+
+    Ok(())
+}
+
 fn verify_transaction_fee_spent_correctly(
     action: Action,
     config: ConfigCellAccountReader,
@@ -1461,6 +1676,7 @@ fn verify_transaction_fee_spent_correctly(
         Action::TransferAccount => u64::from(config.transfer_account_fee()),
         Action::EditManager => u64::from(config.edit_manager_fee()),
         Action::EditRecords => u64::from(config.edit_records_fee()),
+        Action::LockAccountForCrossChain => u64::from(get_cross_chain_from_params()?.fee()),
         _ => u64::from(config.common_fee()),
     };
     let storage_capacity = basic_capacity + account_length * ONE_CKB;
@@ -1544,9 +1760,23 @@ fn verify_action_throttle<'a>(
     Ok(())
 }
 
+fn get_cross_chain_from_params() -> Result<CrossChainReader<'static>, Box<dyn ScriptError>> {
+    let parser = WitnessesParserV1::get_instance();
+    match parser.action_params {
+        ActionParams::LockAccountForCrossChain {
+            coin_type, chain_id, ..
+        } => util::find_enabled_cross_chain(Config::get_instance().cross_chain()?, coin_type, chain_id),
+        _ => {
+            warn!("The params of lock_account_for_cross_chain should contain coin_type and chain_id.");
+            Err(code_to_error!(ErrorCode::ParamsDecodingError))
+        }
+    }
+}
+
 fn verify_account_is_locked_for_cross_chain<'a>(
     output_account_index: usize,
     output_witness_reader: &Box<dyn AccountCellDataReaderMixer + 'a>,
+    cross_chain: CrossChainReader,
     current_timestamp: u64,
 ) -> Result<(), Box<dyn ScriptError>> {
     debug!("Check if the AccountCell is corrently locked for cross chain.");
@@ -1576,6 +1806,24 @@ fn verify_account_is_locked_for_cross_chain<'a>(
             "outputs[{}]The AccountCell.witness.status should be LockedForCrossChain .",
             output_account_index
         );
+
+        // The chain is recorded, so the relayers can only unlock the AccountCell from the same chain.
+        let output_witness_reader = output_witness_reader
+            .try_into_latest()
+            .map_err(|_| ErrorCode::NarrowMixerTypeFailed)?;
+        let expected_cross_chain = ChainId::new_builder()
+            .checked(Uint8::from(1))
+            .coin_type(cross_chain.coin_type().to_entity())
+            .chain_id(cross_chain.chain_id().to_entity())
+            .build();
+        das_assert!(
+            util::is_reader_eq(output_witness_reader.cross_chain(), expected_cross_chain.as_reader()),
+            ErrorCode::CrossChainLockError,
+            "outputs[{}]The AccountCell.witness.cross_chain should be the chain which it is locked to.(coin_type: {}, chain_id: {})",
+            output_account_index,
+            u64::from(cross_chain.coin_type()),
+            u64::from(cross_chain.chain_id())
+        );
     }
 
     Ok(())
@@ -1599,5 +1847,11 @@ fn verify_account_is_unlocked_for_cross_chain<'a>(
         );
     }
 
+    verifiers::account_cell::verify_account_witness_cross_chain_empty(
+        output_witness_reader,
+        output_account_index,
+        Source::Output,
+    )?;
+
     Ok(())
 }
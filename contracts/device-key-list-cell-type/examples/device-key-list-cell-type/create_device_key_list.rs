@@ -4,11 +4,11 @@ use ckb_std::ckb_types::packed::Script;
 use das_core::error::ScriptError;
 use das_core::{assert, code_to_error};
 use das_types::constants::das_lock;
-use das_types::packed::{DeviceKeyList, DeviceKeyListCellData};
+use das_types::packed::DeviceKeyList;
 use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
-use crate::helpers::ToNum;
+use crate::helpers::{verify_key_expirations, ToNum};
 use crate::traits::{Action, GetCellWitness, Rule};
 
 pub fn action() -> Action {
@@ -26,9 +26,7 @@ pub fn action() -> Action {
 
     create_action.add_verification(Rule::new("Verify key length", |contract| {
         let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
-        let key_list = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(output_cell_meta)?;
+        let key_list = contract.get_parser().get_device_key_list(output_cell_meta)?;
         assert!(
             key_list.keys().item_count() == 1,
             ErrorCode::KeyListNumberIncorrect,
@@ -37,11 +35,27 @@ pub fn action() -> Action {
         Ok(())
     }));
 
+    create_action.add_verification(Rule::new("Verify key expirations", |contract| {
+        let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
+        let key_list = contract.get_parser().get_device_key_list(output_cell_meta)?;
+        verify_key_expirations(&key_list, None)?;
+        Ok(())
+    }));
+
+    create_action.add_verification(Rule::new("Verify no recovery in progress", |contract| {
+        let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
+        let key_list = contract.get_parser().get_device_key_list(output_cell_meta)?;
+        assert!(
+            key_list.recovery_keys().is_none(),
+            ErrorCode::RecoveryParamsInvalid,
+            "The recovery_keys should be none when create"
+        );
+        Ok(())
+    }));
+
     create_action.add_verification(Rule::new("The lock arg of key list should be ", |contract| {
         let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
-        let key_list = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(output_cell_meta)?;
+        let key_list = contract.get_parser().get_device_key_list(output_cell_meta)?;
         verify_key_list_lock_arg(&contract.get_output_inner_cells()[0].lock(), key_list.keys())?;
         Ok(())
     }));
@@ -69,9 +83,7 @@ pub fn action() -> Action {
 
     create_action.add_verification(Rule::new("Verify refund lock", |contract| {
         let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
-        let key_list = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(output_cell_meta)?;
+        let key_list = contract.get_parser().get_device_key_list(output_cell_meta)?;
         let refund_lock = key_list.refund_lock();
         assert!(
             contract
@@ -3,7 +3,7 @@ use das_types::packed::DeviceKeyListCellData;
 use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
-use crate::helpers::ToNum;
+use crate::helpers::{verify_signing_key_not_expired, ToNum};
 use crate::traits::{Action, GetCellWitness, Rule};
 
 pub fn action() -> Action {
@@ -19,11 +19,15 @@ pub fn action() -> Action {
         Ok(())
     }));
 
+    destroy_action.add_verification(Rule::new("Verify signing key", |contract| {
+        let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
+        let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+        verify_signing_key_not_expired(contract, &key_list_in_input)
+    }));
+
     destroy_action.add_verification(Rule::new("Verify refund lock", |contract| {
         let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
-        let key_list_in_input: DeviceKeyListCellData = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(input_cell_meta)?;
+        let key_list_in_input: DeviceKeyListCellData = contract.get_parser().get_device_key_list(input_cell_meta)?;
         let refund_lock = key_list_in_input.refund_lock();
         assert!(
             contract
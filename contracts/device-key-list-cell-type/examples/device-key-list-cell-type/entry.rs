@@ -9,7 +9,7 @@ use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
 use crate::traits::*;
-use crate::{create_device_key_list, destroy_device_key_list, update_device_key_list};
+use crate::{create_device_key_list, destroy_device_key_list, recover_device_key_list, update_device_key_list};
 pub fn main() -> Result<(), Box<dyn ScriptError>> {
     let mut parser = WitnessesParserLegacy::new()?;
     parser.parse_cell()?;
@@ -21,6 +21,9 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
     actions.register_action(create_device_key_list::action());
     actions.register_action(update_device_key_list::action());
     actions.register_action(destroy_device_key_list::action());
+    actions.register_action(recover_device_key_list::propose_action());
+    actions.register_action(recover_device_key_list::cancel_action());
+    actions.register_action(recover_device_key_list::confirm_action());
 
     let active_action = actions
         .get_active_action(&action_data)
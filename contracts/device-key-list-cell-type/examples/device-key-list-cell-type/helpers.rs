@@ -1,7 +1,25 @@
-use ckb_std::ckb_types::packed::Uint64;
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::packed::{Script, Uint64};
+use ckb_std::high_level;
+use das_core::constants::OracleCellType;
+use das_core::error::ScriptError;
+use das_core::helpers::Comparable;
+use das_core::witness_parser::webauthn_signature::WebAuthnSignature;
+use das_core::{assert, code_to_error, util};
 use das_types::constants::DataType;
+use das_types::packed::{DeviceKey, DeviceKeyExpiration, DeviceKeyList, DeviceKeyListCellData};
+use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
+use crate::traits::Contract;
+
+/// The length of the owner part in the lock args of device-key-list-cell, it is the main_alg_id, sub_alg_id, cid and pubkey.
+pub const DEVICE_KEY_ARGS_LENGTH: usize = 22;
+pub const MAX_KEY_COUNT: usize = 10;
+
 pub trait GetDataType {
     fn get_type_constant() -> DataType;
 }
@@ -34,3 +52,147 @@ impl ToNum for Uint64 {
         Self::Target::from_le_bytes(buf)
     }
 }
+
+/// Verify the key list contains 1 to MAX_KEY_COUNT keys without duplicates, and return them as a set.
+pub fn verify_keys(keys: DeviceKeyList) -> Result<BTreeSet<Comparable<DeviceKey>>, Box<dyn ScriptError>> {
+    let key_count = keys.item_count();
+    assert!(
+        key_count > 0 && key_count <= MAX_KEY_COUNT,
+        ErrorCode::KeyListNumberIncorrect,
+        "The key list length should be from 1 to {}, but {} found",
+        MAX_KEY_COUNT,
+        key_count
+    );
+
+    let key_set: BTreeSet<Comparable<DeviceKey>> = keys.into_iter().map(Comparable).collect();
+    assert!(
+        key_set.len() == key_count,
+        ErrorCode::DuplicatedKeys,
+        "There should be no duplicated keys in the key list"
+    );
+
+    Ok(key_set)
+}
+
+/// Verify the expirations of the key list:
+/// - every expiration should refer to a key in the key list, and each key can only have one expiration;
+/// - the expirations which are new or changed comparing to the previous key list should be later than the current timestamp;
+/// - at least one key should not be expired.
+///
+/// The TimeCell is only required when there is any expiration in the key list.
+pub fn verify_key_expirations(
+    key_list: &DeviceKeyListCellData,
+    prev_key_list: Option<&DeviceKeyListCellData>,
+) -> Result<(), Box<dyn ScriptError>> {
+    if key_list.expirations().is_empty() {
+        return Ok(());
+    }
+
+    let timestamp = util::load_oracle_data(OracleCellType::Time)?;
+    let keys: BTreeSet<Comparable<DeviceKey>> = key_list.keys().into_iter().map(Comparable).collect();
+    let prev_expirations: BTreeSet<Comparable<DeviceKeyExpiration>> = match prev_key_list {
+        Some(prev_key_list) => prev_key_list.expirations().into_iter().map(Comparable).collect(),
+        None => BTreeSet::new(),
+    };
+
+    let mut keys_with_expiration = BTreeSet::new();
+    let mut expired_keys = BTreeSet::new();
+    for expiration in key_list.expirations().into_iter() {
+        let key = Comparable(expiration.key());
+        let expired_at = u64::from(expiration.expired_at());
+
+        assert!(
+            keys.contains(&key),
+            ErrorCode::KeyExpirationInvalid,
+            "The key of the expiration should be in the key list"
+        );
+        assert!(
+            prev_expirations.contains(&Comparable(expiration.clone())) || expired_at > timestamp,
+            ErrorCode::KeyExpirationInvalid,
+            "The new expiration should be later than the current timestamp(expired_at: {}, current: {})",
+            expired_at,
+            timestamp
+        );
+
+        if expired_at <= timestamp {
+            expired_keys.insert(key.clone());
+        }
+        assert!(
+            keys_with_expiration.insert(key),
+            ErrorCode::DuplicatedKeys,
+            "Each key can only have one expiration"
+        );
+    }
+
+    assert!(
+        expired_keys.len() < keys.len(),
+        ErrorCode::AllKeysExpired,
+        "There should be at least one key which is not expired"
+    );
+
+    Ok(())
+}
+
+/// Verify the device key which signs the transaction is not expired.
+///
+/// das-lock accepts the signature of any key in the key list, so the signing key is resolved from the pubkey_index of
+/// the WebAuthn signature in the lock of witnesses[0]. The pubkey_index 255 means the key of the lock args, the others
+/// are indexes of the key list. The TimeCell is only required when there is any expiration in the key list.
+pub fn verify_signing_key_not_expired(
+    contract: &dyn Contract,
+    key_list: &DeviceKeyListCellData,
+) -> Result<(), Box<dyn ScriptError>> {
+    if key_list.expirations().is_empty() {
+        return Ok(());
+    }
+
+    let cell = &contract.get_input_inner_cells()[0];
+    let witness_args = high_level::load_witness_args(cell.meta.index, Source::Input)
+        .map_err(|_| code_to_error!(ErrorCode::WitnessArgsInvalid))?;
+    let lock = match witness_args.lock().to_opt() {
+        Some(lock) => lock.raw_data(),
+        None => return Err(code_to_error!(ErrorCode::WitnessArgsInvalid)),
+    };
+    let signature = WebAuthnSignature::try_from(lock.as_ref())?;
+    let pubkey_index = match signature.pubkey_index() {
+        [index] => *index,
+        _ => return Err(code_to_error!(ErrorCode::WitnessArgsInvalid)),
+    };
+
+    let signing_key = if pubkey_index == 255 {
+        // The owner part of the lock args has the same layout as the DeviceKey.
+        let lock_args = cell.lock().args().raw_data();
+        key_list
+            .keys()
+            .into_iter()
+            .find(|key| lock_args.get(..DEVICE_KEY_ARGS_LENGTH) == Some(key.as_slice()))
+    } else {
+        match key_list.keys().get(pubkey_index as usize) {
+            Some(key) => Some(key),
+            None => return Err(code_to_error!(ErrorCode::WitnessArgsInvalid)),
+        }
+    };
+    // The key of the lock args may have been removed from the key list, it can not have an expiration then.
+    let signing_key = match signing_key {
+        Some(key) => key,
+        None => return Ok(()),
+    };
+
+    let timestamp = util::load_oracle_data(OracleCellType::Time)?;
+    let expired_at_opt = key_list
+        .expirations()
+        .into_iter()
+        .find(|expiration| expiration.key().as_slice() == signing_key.as_slice())
+        .map(|expiration| u64::from(expiration.expired_at()));
+    if let Some(expired_at) = expired_at_opt {
+        assert!(
+            expired_at > timestamp,
+            ErrorCode::SigningKeyExpired,
+            "The key which signs the transaction is expired(expired_at: {}, current: {})",
+            expired_at,
+            timestamp
+        );
+    }
+
+    Ok(())
+}
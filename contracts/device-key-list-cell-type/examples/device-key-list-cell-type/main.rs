@@ -15,6 +15,7 @@ mod create_device_key_list;
 mod destroy_device_key_list;
 mod entry;
 mod helpers;
+mod recover_device_key_list;
 mod traits;
 mod update_device_key_list;
 
@@ -0,0 +1,233 @@
+use alloc::boxed::Box;
+
+use ckb_std::ckb_constants::Source;
+use ckb_std::high_level;
+use das_core::constants::DAY_SEC;
+use das_core::error::ScriptError;
+use das_core::since_util::{self, SinceFlag};
+use das_core::{assert, code_to_error, debug};
+use das_types::packed::{DeviceKeyExpirations, DeviceKeyListCellData, DeviceKeyListOpt};
+use device_key_list_cell_type::error::ErrorCode;
+use molecule::prelude::{Builder, Entity};
+
+use crate::helpers::{verify_keys, verify_signing_key_not_expired, ToNum};
+use crate::traits::{Action, Contract, GetCellWitness, Rule};
+
+/// The delay after which a proposed recovery can be confirmed, any device key can cancel the recovery during it.
+const RECOVERY_DELAY: u64 = 7 * DAY_SEC;
+
+/// The recovery is proposed and confirmed when all devices are lost, so das-lock does not require the signature of any
+/// device key for these actions. Instead, the refund_lock authorizes them by a cell of it in inputs.
+pub fn propose_action() -> Action {
+    let mut propose_action = Action::new("propose_device_key_list_recovery");
+    propose_action.add_verification(Rule::new("Verify cell structure", verify_recovery_cell_structure));
+    propose_action.add_verification(Rule::new("Verify cell consistent", verify_cell_consistent));
+    propose_action.add_verification(Rule::new("Verify refund lock", verify_refund_lock_cells));
+
+    propose_action.add_verification(Rule::new("Verify recovery proposal", |contract| {
+        let (key_list_in_input, key_list_in_output) = load_key_lists(contract)?;
+
+        assert!(
+            key_list_in_input.recovery_keys().is_none(),
+            ErrorCode::RecoveryParamsInvalid,
+            "There should be no recovery in progress"
+        );
+
+        let recovery_keys = match key_list_in_output.recovery_keys().to_opt() {
+            Some(keys) => keys,
+            None => return Err(code_to_error!(ErrorCode::RecoveryParamsInvalid)),
+        };
+        verify_keys(recovery_keys)?;
+
+        let expected_output = key_list_in_input
+            .clone()
+            .as_builder()
+            .recovery_keys(key_list_in_output.recovery_keys())
+            .build();
+        assert!(
+            expected_output.as_slice() == key_list_in_output.as_slice(),
+            ErrorCode::RecoveryParamsInvalid,
+            "Only the recovery_keys can be changed when proposing a recovery"
+        );
+
+        Ok(())
+    }));
+
+    propose_action
+}
+
+pub fn cancel_action() -> Action {
+    let mut cancel_action = Action::new("cancel_device_key_list_recovery");
+    cancel_action.add_verification(Rule::new("Verify cell structure", verify_cell_structure));
+    cancel_action.add_verification(Rule::new("Verify cell consistent", verify_cell_consistent));
+
+    cancel_action.add_verification(Rule::new("Verify signing key", |contract| {
+        let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
+        let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+        verify_signing_key_not_expired(contract, &key_list_in_input)
+    }));
+
+    cancel_action.add_verification(Rule::new("Verify recovery cancellation", |contract| {
+        let (key_list_in_input, key_list_in_output) = load_key_lists(contract)?;
+
+        assert!(
+            key_list_in_input.recovery_keys().is_some(),
+            ErrorCode::RecoveryParamsInvalid,
+            "There should be a recovery in progress"
+        );
+
+        let expected_output = key_list_in_input
+            .as_builder()
+            .recovery_keys(DeviceKeyListOpt::default())
+            .build();
+        assert!(
+            expected_output.as_slice() == key_list_in_output.as_slice(),
+            ErrorCode::RecoveryParamsInvalid,
+            "Only the recovery_keys should be removed when cancelling a recovery"
+        );
+
+        Ok(())
+    }));
+
+    cancel_action
+}
+
+pub fn confirm_action() -> Action {
+    let mut confirm_action = Action::new("confirm_device_key_list_recovery");
+    confirm_action.add_verification(Rule::new("Verify cell structure", verify_recovery_cell_structure));
+    confirm_action.add_verification(Rule::new("Verify cell consistent", verify_cell_consistent));
+    confirm_action.add_verification(Rule::new("Verify refund lock", verify_refund_lock_cells));
+
+    confirm_action.add_verification(Rule::new("Verify recovery delay", |contract| {
+        let mut expected_since = 0u64;
+        expected_since = since_util::set_relative_flag(expected_since, SinceFlag::Relative);
+        expected_since = since_util::set_metric_flag(expected_since, SinceFlag::Timestamp);
+        expected_since = since_util::set_value(expected_since, RECOVERY_DELAY);
+
+        let index = contract.get_input_inner_cells()[0].meta.index;
+        let since = high_level::load_input_since(index, Source::Input)?;
+        assert!(
+            since == expected_since,
+            ErrorCode::RecoverySinceMismatch,
+            "inputs[{}] The since of the device_key_list_cell is not correct.(expected: {}, current: {})",
+            index,
+            expected_since,
+            since
+        );
+
+        Ok(())
+    }));
+
+    confirm_action.add_verification(Rule::new("Verify recovery confirmation", |contract| {
+        let (key_list_in_input, key_list_in_output) = load_key_lists(contract)?;
+
+        let recovery_keys = match key_list_in_input.recovery_keys().to_opt() {
+            Some(keys) => keys,
+            None => return Err(code_to_error!(ErrorCode::RecoveryParamsInvalid)),
+        };
+
+        debug!(
+            "confirm_device_key_list_recovery: replace {} keys with {} recovery keys",
+            key_list_in_input.keys().item_count(),
+            recovery_keys.item_count()
+        );
+
+        // The expirations belong to the lost keys, so they are cleared with them.
+        let expected_output = key_list_in_input
+            .as_builder()
+            .keys(recovery_keys)
+            .expirations(DeviceKeyExpirations::default())
+            .recovery_keys(DeviceKeyListOpt::default())
+            .build();
+        assert!(
+            expected_output.as_slice() == key_list_in_output.as_slice(),
+            ErrorCode::RecoveryParamsInvalid,
+            "The keys should be replaced by the recovery_keys when confirming a recovery"
+        );
+
+        Ok(())
+    }));
+
+    confirm_action
+}
+
+fn verify_cell_structure(contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+    assert!(
+        contract.get_input_outer_cells().len() == 0 && contract.get_output_outer_cells().len() == 0,
+        ErrorCode::InvalidTransactionStructure,
+        "Should not have any balance cells in input or output"
+    );
+    assert!(
+        contract.get_input_inner_cells().len() == 1
+            && contract.get_output_inner_cells().len() == 1
+            && contract.get_input_inner_cells()[0].meta.index == 0
+            && contract.get_output_inner_cells()[0].meta.index == 0,
+        ErrorCode::InvalidTransactionStructure,
+        "Should have 1 device_key_list_cell in input[0] and 1 cell in output[0]"
+    );
+    Ok(())
+}
+
+fn verify_recovery_cell_structure(contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+    assert!(
+        contract.get_input_inner_cells().len() == 1
+            && contract.get_output_inner_cells().len() == 1
+            && contract.get_input_inner_cells()[0].meta.index == 0
+            && contract.get_output_inner_cells()[0].meta.index == 0,
+        ErrorCode::InvalidTransactionStructure,
+        "Should have 1 device_key_list_cell in input[0] and 1 cell in output[0]"
+    );
+    Ok(())
+}
+
+fn verify_refund_lock_cells(contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+    let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
+    let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+    let refund_lock = key_list_in_input.refund_lock();
+
+    assert!(
+        !contract.get_input_outer_cells().is_empty(),
+        ErrorCode::RecoveryNotAuthorized,
+        "There should be at least 1 cell of the refund_lock in inputs to authorize the recovery"
+    );
+    assert!(
+        contract
+            .get_input_outer_cells()
+            .iter()
+            .all(|c| c.lock().as_slice() == refund_lock.as_slice())
+            && contract
+                .get_output_outer_cells()
+                .iter()
+                .all(|c| c.lock().as_slice() == refund_lock.as_slice()),
+        ErrorCode::InconsistentBalanceCellLocks,
+        "All locks for balance-cell should be the refund_lock"
+    );
+    Ok(())
+}
+
+fn verify_cell_consistent(contract: &mut dyn Contract) -> Result<(), Box<dyn ScriptError>> {
+    assert!(
+        i64::try_from(contract.get_input_inner_cells()[0].capacity().to_num()).unwrap()
+            - i64::try_from(contract.get_output_inner_cells()[0].capacity().to_num()).unwrap()
+            <= 10000,
+        ErrorCode::CapacityReduceTooMuch,
+        "Capacity change is too much"
+    );
+    assert!(
+        contract.get_input_inner_cells()[0].lock().as_slice() == contract.get_output_inner_cells()[0].lock().as_slice(),
+        ErrorCode::InvalidLock,
+        "Lock should not change"
+    );
+    Ok(())
+}
+
+fn load_key_lists(
+    contract: &mut dyn Contract,
+) -> Result<(DeviceKeyListCellData, DeviceKeyListCellData), Box<dyn ScriptError>> {
+    let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
+    let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
+    let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+    let key_list_in_output = contract.get_parser().get_device_key_list(output_cell_meta)?;
+
+    Ok((key_list_in_input, key_list_in_output))
+}
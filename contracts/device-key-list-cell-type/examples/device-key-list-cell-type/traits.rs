@@ -11,7 +11,7 @@ use ckb_std::syscalls::SysError;
 use das_core::error::ScriptError;
 use das_core::witness_parser::WitnessesParserLegacy;
 use das_core::{code_to_error, debug};
-use das_types::packed::ActionData;
+use das_types::packed::{ActionData, DeviceKeyListCellData};
 use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
@@ -176,6 +176,7 @@ pub trait Contract {
 
 pub trait GetCellWitness {
     fn get_cell_witness<T: Entity>(&self, meta: CellMeta) -> Result<T, Box<dyn ScriptError>>;
+    fn get_device_key_list(&self, meta: CellMeta) -> Result<DeviceKeyListCellData, Box<dyn ScriptError>>;
 }
 
 impl GetCellWitness for WitnessesParserLegacy {
@@ -186,6 +187,14 @@ impl GetCellWitness for WitnessesParserLegacy {
             T::from_compatible_slice(&bytes.raw_data()).map_err(|_| code_to_error!(ErrorCode::VerificationError))?;
         Ok(res)
     }
+
+    fn get_device_key_list(&self, meta: CellMeta) -> Result<DeviceKeyListCellData, Box<dyn ScriptError>> {
+        let data_type = DeviceKeyListCellData::get_type_constant();
+        let (_, _, bytes) = self.verify_and_get(data_type, meta.index, meta.source)?;
+        let res = das_core::util::parse_device_key_list_cell_data(&bytes.raw_data())
+            .ok_or(code_to_error!(ErrorCode::VerificationError))?;
+        Ok(res)
+    }
 }
 
 impl Contract for MyContract {
@@ -1,12 +1,12 @@
-use alloc::vec::Vec;
+use alloc::collections::BTreeSet;
 
 use das_core::helpers::Comparable;
 use das_core::{assert, code_to_error, debug};
-use das_types::packed::{DeviceKey, DeviceKeyListCellData};
+use das_types::packed::{DeviceKey, DeviceKeyExpiration};
 use device_key_list_cell_type::error::ErrorCode;
 use molecule::prelude::Entity;
 
-use crate::helpers::ToNum;
+use crate::helpers::{verify_key_expirations, verify_keys, verify_signing_key_not_expired, ToNum};
 use crate::traits::{Action, GetCellWitness, Rule};
 
 pub fn action() -> Action {
@@ -28,6 +28,12 @@ pub fn action() -> Action {
         Ok(())
     }));
 
+    update_action.add_verification(Rule::new("Verify signing key", |contract| {
+        let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
+        let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+        verify_signing_key_not_expired(contract, &key_list_in_input)
+    }));
+
     update_action.add_verification(Rule::new("Verify capacity change", |contract| {
         assert!(
             i64::try_from(contract.get_input_inner_cells()[0].capacity().to_num()).unwrap()
@@ -52,12 +58,8 @@ pub fn action() -> Action {
     update_action.add_verification(Rule::new("Verify key list structure", |contract| {
         let input_cell_meta = contract.get_input_inner_cells()[0].get_meta();
         let output_cell_meta = contract.get_output_inner_cells()[0].get_meta();
-        let key_list_in_input = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(input_cell_meta)?;
-        let key_list_in_output = contract
-            .get_parser()
-            .get_cell_witness::<DeviceKeyListCellData>(output_cell_meta)?;
+        let key_list_in_input = contract.get_parser().get_device_key_list(input_cell_meta)?;
+        let key_list_in_output = contract.get_parser().get_device_key_list(output_cell_meta)?;
 
         assert!(
             key_list_in_input.refund_lock().as_slice() == key_list_in_output.refund_lock().as_slice(),
@@ -65,69 +67,35 @@ pub fn action() -> Action {
             "Changes to refund_lock are not allowed"
         );
 
-        das_core::assert!(
-            key_list_in_output.keys().item_count() > 0 && key_list_in_output.keys().item_count() < 11,
+        let keys_in_input: BTreeSet<Comparable<DeviceKey>> =
+            key_list_in_input.keys().into_iter().map(Comparable).collect();
+        let keys_in_output = verify_keys(key_list_in_output.keys())?;
+
+        // Any keys can be added, removed or replaced in one update, but the update should change something.
+        // The recovery can only be proposed and cancelled by its own actions.
+        let expirations_in_input: BTreeSet<Comparable<DeviceKeyExpiration>> =
+            key_list_in_input.expirations().into_iter().map(Comparable).collect();
+        let expirations_in_output: BTreeSet<Comparable<DeviceKeyExpiration>> =
+            key_list_in_output.expirations().into_iter().map(Comparable).collect();
+        assert!(
+            keys_in_input != keys_in_output || expirations_in_input != expirations_in_output,
             ErrorCode::UpdateParamsInvalid,
-            "The key list length should be from 1 to 10"
+            "The keys or expirations should be changed when update"
+        );
+        assert!(
+            key_list_in_input.recovery_keys().as_slice() == key_list_in_output.recovery_keys().as_slice(),
+            ErrorCode::RecoveryParamsInvalid,
+            "Changes to recovery_keys are not allowed"
         );
 
-        let len_diff: i64 = i64::try_from(key_list_in_output.keys().item_count()).unwrap()
-            - i64::try_from(key_list_in_input.keys().item_count()).unwrap();
-        das_core::assert!(
-            len_diff == 1 || len_diff == -1,
-            ErrorCode::KeyListNumberIncorrect,
-            "There should be exactly 1 device key difference when update"
+        debug!(
+            "update_device_key_list: {} keys added, {} keys removed",
+            keys_in_output.difference(&keys_in_input).count(),
+            keys_in_input.difference(&keys_in_output).count()
         );
-        let keys_in_input: alloc::collections::BTreeSet<Comparable<DeviceKey>> = key_list_in_input
-            .keys()
-            .clone()
-            .into_iter()
-            .map(|key| Comparable(key))
-            .collect();
-        let keys_in_output: alloc::collections::BTreeSet<Comparable<DeviceKey>> = key_list_in_output
-            .keys()
-            .clone()
-            .into_iter()
-            .map(|key| Comparable(key))
-            .collect();
 
-        match len_diff {
-            1 => {
-                debug!("update_device_key_list: add key");
-                // Should only append to the tail
-                let mut input_iter = key_list_in_input.keys().into_iter();
-                let mut output_iter = key_list_in_output.keys().clone().into_iter();
-                loop {
-                    match (input_iter.next(), output_iter.next()) {
-                        (Some(a), Some(b)) if a.as_slice() == b.as_slice() => continue,
-                        (Some(_), Some(_)) => Err(code_to_error!(ErrorCode::UpdateParamsInvalid))?,
-                        (None, Some(_)) => break,
-                        _ => unreachable!(),
-                    }
-                }
-                das_core::assert!(
-                    keys_in_output.len() == key_list_in_output.keys().item_count(),
-                    ErrorCode::DuplicatedKeys,
-                    "Should not add duplicated keys"
-                );
-            }
-            -1 => {
-                debug!("update_device_key_list: remove key");
-                das_core::assert!(
-                    keys_in_input.is_superset(&keys_in_output),
-                    ErrorCode::UpdateParamsInvalid,
-                    "Output keys should be subset of input"
-                );
-                let removed_device_key: Vec<Comparable<DeviceKey>> =
-                    keys_in_input.difference(&keys_in_output).cloned().collect();
-                das_core::assert!(
-                    removed_device_key.len() == 1,
-                    ErrorCode::UpdateParamsInvalid,
-                    "Output key should be exactly 1 less than input"
-                );
-            }
-            _ => unreachable!(),
-        };
+        verify_key_expirations(&key_list_in_output, Some(&key_list_in_input))?;
+
         Ok(())
     }));
 
@@ -25,6 +25,12 @@ pub enum ErrorCode {
     DuplicatedKeys,
     ActionNotSupported,
     VerificationError,
+    KeyExpirationInvalid,
+    AllKeysExpired,
+    RecoveryParamsInvalid,
+    RecoveryNotAuthorized,
+    RecoverySinceMismatch,
+    SigningKeyExpired,
 }
 
 impl From<SysError> for ErrorCode {
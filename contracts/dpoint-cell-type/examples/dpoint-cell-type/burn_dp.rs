@@ -11,6 +11,8 @@ use das_core::{code_to_error, das_assert, data_parser, debug, util as core_util}
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
 
+use super::util;
+
 pub fn action() -> Result<Action, Box<dyn ScriptError>> {
     let config_dpoint_reader = Config::get_instance().dpoint()?;
 
@@ -106,6 +108,22 @@ pub fn action() -> Result<Action, Box<dyn ScriptError>> {
                     DPOINT_MAX_LIMIT,
                     value
                 );
+
+                util::verify_vesting_period(*index, Source::Output)?;
+            }
+
+            Ok(())
+        },
+    ));
+
+    let inner_input_cells = input_cells.clone();
+    let inner_output_cells = output_cells.clone();
+    action.add_verification(Rule::new(
+        "Verify if the owner only burned the vested DPoint.",
+        move |_contract| {
+            // The expired DPoint can be burned freely, because the transaction is required to be pushed by the whitelist.
+            if let Some(timestamp) = util::load_timestamp_if_needed(&inner_input_cells, &inner_output_cells)? {
+                util::verify_payer_schedules(&inner_input_cells, &inner_output_cells, timestamp)?;
             }
 
             Ok(())
@@ -13,6 +13,8 @@ use das_types::constants::super_lock;
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
 
+use super::util;
+
 pub fn action() -> Result<Action, Box<dyn ScriptError>> {
     let config_dpoint_reader = Config::get_instance().dpoint()?;
 
@@ -98,6 +100,8 @@ pub fn action() -> Result<Action, Box<dyn ScriptError>> {
                     DPOINT_MAX_LIMIT,
                     value
                 );
+
+                util::verify_vesting_period(*index, Source::Output)?;
             }
 
             Ok(())
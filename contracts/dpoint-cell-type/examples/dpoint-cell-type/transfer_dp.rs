@@ -9,8 +9,10 @@ use das_core::constants::DPOINT_MAX_LIMIT;
 use das_core::contract::defult_structs::{Action as ContractAction, Rule};
 use das_core::error::ScriptError;
 use das_core::{code_to_error, das_assert, data_parser, debug, util as core_util, verifiers};
+use das_types::constants::Action;
 use das_types::packed::*;
 use dpoint_cell_type::error::ErrorCode;
+use witness_parser::WitnessesParserV1;
 
 use super::util;
 
@@ -54,6 +56,12 @@ pub fn action() -> Result<ContractAction, Box<dyn ScriptError>> {
         TransferType::UserToWhitelist
     };
 
+    let parser = WitnessesParserV1::get_instance();
+    let is_payment = matches!(
+        parser.action,
+        Action::PreRegister | Action::RenewAccount | Action::UpdateSubAccount
+    );
+
     let inner_input_cells = input_cells.clone();
     let inner_output_cells = output_cells.clone();
     let inner_grouped_input_cells = grouped_input_cells.clone();
@@ -86,6 +94,17 @@ pub fn action() -> Result<ContractAction, Box<dyn ScriptError>> {
             .map(|(key, _)| key)
             .collect();
 
+        if is_payment {
+            // The DPoint paid for registration and renewal is counted as the DPoint received by the whitelist, so it
+            // can only be transferred from the payer to the whitelist.
+            das_assert!(
+                transfer_type == TransferType::UserToWhitelist,
+                ErrorCode::PaymentShouldBeTransferredToWhitelist,
+                "The DPoint paid in the {} action should be transferred from the payer to the whitelist.",
+                parser.action
+            );
+        }
+
         if transfer_type == TransferType::WhitelistToUser {
             das_assert!(
                 input_user_group_locks.len() == 0 && output_user_group_locks.len() == 1,
@@ -161,6 +180,8 @@ pub fn action() -> Result<ContractAction, Box<dyn ScriptError>> {
                     DPOINT_MAX_LIMIT,
                     value
                 );
+
+                util::verify_vesting_period(*index, Source::Output)?;
             }
 
             Ok(())
@@ -200,6 +221,44 @@ pub fn action() -> Result<ContractAction, Box<dyn ScriptError>> {
         },
     ));
 
+    let inner_input_cells = input_cells.clone();
+    let inner_output_cells = output_cells.clone();
+    let inner_grouped_input_cells = grouped_input_cells.clone();
+    let inner_grouped_output_cells = grouped_output_cells.clone();
+    let inner_expected_lock_hashes = transfer_whitelist_hashes.clone();
+    action.add_verification(Rule::new(
+        "Verify if the expired DPoint is not transferred and the payer only spent the vested DPoint.",
+        move |_contract| {
+            let timestamp = match util::load_timestamp_if_needed(&inner_input_cells, &inner_output_cells)? {
+                Some(timestamp) => timestamp,
+                None => return Ok(()),
+            };
+
+            for (cell, index) in util::load_dpoint_cells(&inner_input_cells, Source::Input)?
+                .iter()
+                .zip(inner_input_cells.iter())
+            {
+                das_assert!(
+                    !cell.is_expired(timestamp),
+                    ErrorCode::DPointIsExpired,
+                    "inputs[{}] The DPointCell is expired, it can only be recycled by the whitelist.(expired_at: {})",
+                    index,
+                    cell.expired_at
+                );
+            }
+
+            for (lock_hash, input_cells) in inner_grouped_input_cells
+                .iter()
+                .filter(|(key, _)| !inner_expected_lock_hashes.contains(key))
+            {
+                let output_cells = inner_grouped_output_cells.get(lock_hash).cloned().unwrap_or_default();
+                util::verify_payer_schedules(input_cells, &output_cells, timestamp)?;
+            }
+
+            Ok(())
+        },
+    ));
+
     let inner_input_cells = input_cells.clone();
     let inner_output_cells = output_cells.clone();
     action.add_verification(Rule::new(
@@ -5,7 +5,10 @@ use alloc::vec::Vec;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::high_level;
+use das_core::constants::OracleCellType;
 use das_core::error::ScriptError;
+use das_core::{code_to_error, das_assert, data_parser, util as core_util, warn};
+use dpoint_cell_type::error::ErrorCode;
 
 pub fn group_cells_by_lock(
     indexes: &[usize],
@@ -24,3 +27,175 @@ pub fn group_cells_by_lock(
 
     Ok(group)
 }
+
+pub struct DPointCell {
+    pub value: u64,
+    // 0 means the DPoint never expires.
+    pub expired_at: u64,
+    pub vesting_period: Option<(u64, u64)>,
+}
+
+impl DPointCell {
+    pub fn load(index: usize, source: Source) -> Result<Self, Box<dyn ScriptError>> {
+        let data = high_level::load_cell_data(index, source)?;
+        let value = match data_parser::dpoint_cell::get_value(&data) {
+            Some(value) => value,
+            None => {
+                warn!("{:?}[{}] The data of DPointCell is corrupted.", source, index);
+                return Err(code_to_error!(ErrorCode::InitialDataError));
+            }
+        };
+
+        Ok(Self {
+            value,
+            expired_at: data_parser::dpoint_cell::get_expired_at(&data).unwrap_or(0),
+            vesting_period: data_parser::dpoint_cell::get_vesting_period(&data),
+        })
+    }
+
+    pub fn has_schedule(&self) -> bool {
+        self.expired_at != 0 || self.vesting_period.is_some()
+    }
+
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        self.expired_at != 0 && timestamp >= self.expired_at
+    }
+
+    /// The expiry for comparison, the DPoint never expires is treated as expiring at the end of time.
+    fn expiry(&self) -> u64 {
+        if self.expired_at == 0 {
+            u64::MAX
+        } else {
+            self.expired_at
+        }
+    }
+
+    /// The DPoint which is not vested yet, it is released linearly from the start to the end of the vesting period.
+    pub fn locked(&self, timestamp: u64) -> u64 {
+        match self.vesting_period {
+            Some((start_at, end_at)) if timestamp < end_at => {
+                if timestamp <= start_at {
+                    self.value
+                } else {
+                    (self.value as u128 * (end_at - timestamp) as u128 / (end_at - start_at) as u128) as u64
+                }
+            }
+            _ => 0,
+        }
+    }
+}
+
+pub fn load_dpoint_cells(indexes: &[usize], source: Source) -> Result<Vec<DPointCell>, Box<dyn ScriptError>> {
+    indexes.iter().map(|i| DPointCell::load(*i, source)).collect()
+}
+
+/// Load the timestamp from the TimeCells only when some DPointCells have expiry or vesting period, so the TimeCells are
+/// not required for the DPointCells without them.
+///
+/// The DPoint may be paid for registration and renewal which require the median of multiple TimeCells, so the median is
+/// used when there are multiple TimeCells.
+pub fn load_timestamp_if_needed(
+    input_cells: &[usize],
+    output_cells: &[usize],
+) -> Result<Option<u64>, Box<dyn ScriptError>> {
+    let has_schedule = load_dpoint_cells(input_cells, Source::Input)?
+        .iter()
+        .chain(load_dpoint_cells(output_cells, Source::Output)?.iter())
+        .any(|cell| cell.has_schedule());
+
+    if has_schedule {
+        Ok(Some(core_util::load_oracle_data_of_any_layout(OracleCellType::Time)?))
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn verify_vesting_period(index: usize, source: Source) -> Result<(), Box<dyn ScriptError>> {
+    let cell = DPointCell::load(index, source)?;
+    if let Some((start_at, end_at)) = cell.vesting_period {
+        das_assert!(
+            start_at < end_at,
+            ErrorCode::InitialDataError,
+            "{:?}[{}] The vesting period of DPointCell should be start_at < end_at.(start_at: {}, end_at: {})",
+            source,
+            index,
+            start_at,
+            end_at
+        );
+    }
+
+    Ok(())
+}
+
+/// Verify if the payer only spent the vested DPoint and did not extend the expiry of the DPoint.
+///
+/// The cells should all belong to the payer, and the expired DPointCells in inputs are excluded because they can only be
+/// recycled.
+pub fn verify_payer_schedules(
+    input_cells: &[usize],
+    output_cells: &[usize],
+    timestamp: u64,
+) -> Result<(), Box<dyn ScriptError>> {
+    let inputs = load_dpoint_cells(input_cells, Source::Input)?
+        .into_iter()
+        .filter(|cell| !cell.is_expired(timestamp))
+        .collect::<Vec<_>>();
+    let outputs = load_dpoint_cells(output_cells, Source::Output)?;
+
+    let locked_in_inputs: u64 = inputs.iter().map(|cell| cell.locked(timestamp)).sum();
+    let locked_in_outputs: u64 = outputs.iter().map(|cell| cell.locked(timestamp)).sum();
+    das_assert!(
+        locked_in_outputs >= locked_in_inputs,
+        ErrorCode::DPointIsLocked,
+        "The DPoint which is not vested should be kept by the payer.(inputs: {}, outputs: {})",
+        locked_in_inputs,
+        locked_in_outputs
+    );
+
+    // The locked DPoint in outputs should not be released faster than in inputs, so the vesting period should start from
+    // now and end no earlier than any of the inputs.
+    let latest_end_at = inputs
+        .iter()
+        .filter_map(|cell| cell.vesting_period.map(|(_, end_at)| end_at))
+        .max()
+        .unwrap_or(0);
+    for (cell, index) in outputs.iter().zip(output_cells.iter()) {
+        if let Some((start_at, end_at)) = cell.vesting_period {
+            das_assert!(
+                start_at >= timestamp && end_at >= latest_end_at,
+                ErrorCode::DPointIsLocked,
+                "outputs[{}] The vesting period should start from now and end no earlier than {}.(start_at: {}, end_at: {})",
+                index,
+                latest_end_at,
+                start_at,
+                end_at
+            );
+        }
+    }
+
+    // For any moment, the DPoint expiring after it in outputs should not be more than in inputs.
+    for output in outputs.iter() {
+        let moment = output.expiry();
+        let expiring_after_in_inputs: u64 = inputs
+            .iter()
+            .filter(|cell| cell.expiry() >= moment)
+            .map(|cell| cell.value)
+            .sum();
+        let expiring_after_in_outputs: u64 = outputs
+            .iter()
+            .filter(|cell| cell.expiry() >= moment)
+            .map(|cell| cell.value)
+            .sum();
+
+        das_assert!(
+            expiring_after_in_outputs <= expiring_after_in_inputs,
+            ErrorCode::DPointExpiryCanNotBeExtended,
+            "The DPoint expiring after {} in outputs should not be more than in inputs.(inputs: {}, outputs: {})",
+            output.expired_at,
+            expiring_after_in_inputs,
+            expiring_after_in_outputs
+        );
+    }
+
+    Ok(())
+}
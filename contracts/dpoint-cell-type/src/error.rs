@@ -22,6 +22,10 @@ pub enum ErrorCode {
     CanNotSpendAnyFee,
     ThereShouldBeSomeDPointCellInInput,
     CapacityRecycleError,
+    PaymentShouldBeTransferredToWhitelist,
+    DPointIsExpired,
+    DPointIsLocked,
+    DPointExpiryCanNotBeExtended,
 }
 
 impl From<SysError> for ErrorCode {
@@ -559,6 +559,12 @@ fn to_semantic_account_witness(
             })?,
         ),
         4 => Box::new(
+            das_packed::AccountCellDataV4::from_slice(entity.as_reader().raw_data()).map_err(|_| {
+                warn!("EIP712 decoding AccountCellDataV4 failed");
+                ErrorCode::WitnessEntityDecodingError
+            })?,
+        ),
+        5 => Box::new(
             das_packed::AccountCellData::from_slice(entity.as_reader().raw_data()).map_err(|_| {
                 warn!("EIP712 decoding AccountCellData failed");
                 ErrorCode::WitnessEntityDecodingError
@@ -1,6 +1,8 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::format;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
@@ -8,9 +10,9 @@ use ckb_std::high_level;
 use das_core::constants::*;
 use das_core::error::*;
 use das_core::witness_parser::WitnessesParserLegacy;
-use das_core::{assert, code_to_error, data_parser, debug, util, warn};
-use das_map::map::Map;
+use das_core::{assert, code_to_error, data_parser, debug, util, verifiers, warn};
 use das_map::util as map_util;
+use das_map::vec_map::VecMap;
 use das_types::constants::{DataType, LockRole};
 use das_types::mixer::AccountCellDataMixer;
 use das_types::packed::*;
@@ -51,11 +53,13 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
         b"accept_offer" => accept_offer_to_semantic,
         b"retract_reverse_record" => retract_reverse_record_to_semantic,
         b"lock_account_for_cross_chain" => lock_account_for_cross_chain_to_semantic,
+        b"renew_account" => renew_account_to_semantic,
         b"create_approval" => create_approval_to_semantic,
         b"delay_approval" => delay_approval_to_semantic,
         b"fulfill_approval" => fulfill_approval_to_semantic,
         b"transfer_dp" => transfer_dp_to_semantic,
         b"burn_dp" => burn_dp_to_semantic,
+        b"claim_income" => claim_income_to_semantic,
         _ => transfer_to_semantic,
     };
 
@@ -100,16 +104,100 @@ fn edit_manager_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Bo
 
 fn edit_records_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Box<dyn ScriptError>> {
     let type_id_table_reader = parser.configs.main()?.type_id_table();
-    let (input_cells, _output_cells) =
+    let (input_cells, output_cells) =
         util::find_cells_by_type_id_in_inputs_and_outputs(ScriptType::Type, type_id_table_reader.account_cell())?;
 
-    // Parse account from the data of the AccountCell in inputs.
-    let data_in_bytes = util::load_cell_data(input_cells[0], Source::Input)?;
-    let account_in_bytes = data_parser::account_cell::get_account(&data_in_bytes);
-    let account = String::from_utf8(account_in_bytes.to_vec()).map_err(|_| ErrorCode::EIP712SerializationError)?;
+    // The AccountCells in inputs and outputs are paired by their order, the same as how account-cell-type verifies them.
+    assert!(
+        input_cells.len() > 0 && input_cells.len() == output_cells.len(),
+        ErrorCode::InvalidTransactionStructure,
+        "There should be the same number of AccountCells in inputs and outputs."
+    );
 
-    // TODO Improve semantic message of this transaction.
-    Ok(format!("EDIT RECORDS OF ACCOUNT {}", account))
+    // Summarize the changes of records for each account, they are listed in the order of the AccountCells.
+    let mut accounts = Vec::new();
+    for (input_cell, output_cell) in input_cells.into_iter().zip(output_cells.into_iter()) {
+        let data_in_bytes = util::load_cell_data(input_cell, Source::Input)?;
+        let account_in_bytes = data_parser::account_cell::get_account(&data_in_bytes);
+        let account = String::from_utf8(account_in_bytes.to_vec()).map_err(|_| ErrorCode::EIP712SerializationError)?;
+
+        let input_witness = parse_account_cell_witness(parser, input_cell, Source::Input)?;
+        let output_witness = parse_account_cell_witness(parser, output_cell, Source::Output)?;
+        let changes = records_changes_to_semantic(
+            input_witness.as_reader().records(),
+            output_witness.as_reader().records(),
+        )?;
+
+        accounts.push(format!("{} ({})", account, changes));
+    }
+
+    if accounts.len() == 1 {
+        Ok(format!("EDIT RECORDS OF ACCOUNT {}", accounts[0]))
+    } else {
+        Ok(format!(
+            "EDIT RECORDS OF {} ACCOUNTS {}",
+            accounts.len(),
+            accounts.join(", ")
+        ))
+    }
+}
+
+/// Summarize the changes of records by their keys in the format of `type.key`, the records sharing the same key with
+/// different labels are treated as one key.
+fn records_changes_to_semantic(
+    prev_records: RecordsReader,
+    records: RecordsReader,
+) -> Result<String, Box<dyn ScriptError>> {
+    fn group_by_key(records: RecordsReader) -> Result<BTreeMap<String, Vec<Vec<u8>>>, Box<dyn ScriptError>> {
+        let mut ret: BTreeMap<String, Vec<Vec<u8>>> = BTreeMap::new();
+        for record in records.iter() {
+            let mut key = String::from_utf8(record.record_type().raw_data().to_vec())
+                .map_err(|_| ErrorCode::EIP712SerializationError)?;
+            key.push('.');
+            key.push_str(
+                core::str::from_utf8(record.record_key().raw_data())
+                    .map_err(|_| ErrorCode::EIP712SerializationError)?,
+            );
+            ret.entry(key).or_default().push(record.as_slice().to_vec());
+        }
+        // The order of records with the same key is not a change.
+        for items in ret.values_mut() {
+            items.sort();
+        }
+
+        Ok(ret)
+    }
+
+    let prev_records = group_by_key(prev_records)?;
+    let records = group_by_key(records)?;
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for (key, items) in records.iter() {
+        match prev_records.get(key) {
+            None => added.push(key.as_str()),
+            Some(prev_items) if prev_items != items => changed.push(key.as_str()),
+            _ => {}
+        }
+    }
+    let removed = prev_records
+        .keys()
+        .filter(|key| !records.contains_key(*key))
+        .map(|key| key.as_str())
+        .collect::<Vec<_>>();
+
+    let mut changes = Vec::new();
+    for (name, keys) in [("ADD", added), ("CHANGE", changed), ("REMOVE", removed)] {
+        if !keys.is_empty() {
+            changes.push(format!("{}: {}", name, keys.join(", ")));
+        }
+    }
+
+    if changes.is_empty() {
+        Ok(String::from("NO CHANGE"))
+    } else {
+        Ok(changes.join("; "))
+    }
 }
 
 fn bid_expired_account_dutch_auction_to_semantic(
@@ -343,7 +431,38 @@ fn lock_account_for_cross_chain_to_semantic(parser: &WitnessesParserLegacy) -> R
     let account_in_bytes = data_parser::account_cell::get_account(&data_in_bytes);
     let account = String::from_utf8(account_in_bytes.to_vec()).map_err(|_| ErrorCode::EIP712SerializationError)?;
 
-    Ok(format!("LOCK {} FOR CROSS CHAIN", account))
+    // Parse the chain from the params, only the chains listed in ConfigCellCrossChain can be displayed.
+    let param_to_u64 = |index: usize| -> Result<u64, Box<dyn ScriptError>> {
+        parser
+            .params
+            .get(index)
+            .and_then(|param| param.as_reader().raw_data().try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or_else(|| -> Box<dyn ScriptError> { code_to_error!(ErrorCode::ParamsDecodingError) })
+    };
+    let (coin_type, chain_id) = (param_to_u64(0)?, param_to_u64(1)?);
+    let chain = util::find_enabled_cross_chain(parser.configs.cross_chain()?, coin_type, chain_id)?;
+    let chain_name =
+        String::from_utf8(chain.name().raw_data().to_vec()).map_err(|_| ErrorCode::EIP712SerializationError)?;
+
+    Ok(format!("LOCK {} FOR CROSS CHAIN TO {}", account, chain_name))
+}
+
+fn renew_account_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Box<dyn ScriptError>> {
+    let type_id_table_reader = parser.configs.main()?.type_id_table();
+    let account_cells =
+        util::find_cells_by_type_id(ScriptType::Type, type_id_table_reader.account_cell(), Source::Input)?;
+
+    // Parse accounts from the data of the AccountCells in inputs, they are listed in the order of the AccountCells.
+    let mut accounts = Vec::new();
+    for i in account_cells {
+        let data_in_bytes = util::load_cell_data(i, Source::Input)?;
+        let account_in_bytes = data_parser::account_cell::get_account(&data_in_bytes);
+        let account = String::from_utf8(account_in_bytes.to_vec()).map_err(|_| ErrorCode::EIP712SerializationError)?;
+        accounts.push(account);
+    }
+
+    Ok(format!("RENEW {}", accounts.join(", ")))
 }
 
 pub fn parse_account_cell_witness(
@@ -379,6 +498,12 @@ pub fn parse_account_cell_witness(
             })?,
         ),
         4 => Box::new(
+            AccountCellDataV4::from_slice(mol_bytes.as_reader().raw_data()).map_err(|_| {
+                warn!("{:?}[{}] Decoding AccountCellDataV4 failed", source, index);
+                ErrorCode::WitnessEntityDecodingError
+            })?,
+        ),
+        5 => Box::new(
             AccountCellData::from_slice(mol_bytes.as_reader().raw_data()).map_err(|_| {
                 warn!("{:?}[{}] Decoding AccountCellData failed", source, index);
                 ErrorCode::WitnessEntityDecodingError
@@ -563,10 +688,35 @@ fn fulfill_approval_to_semantic(parser: &WitnessesParserLegacy) -> Result<String
     }
 }
 
+fn claim_income_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Box<dyn ScriptError>> {
+    let type_id_table_reader = parser.configs.main()?.type_id_table();
+    let (input_cells, output_cells) =
+        util::find_cells_by_type_id_in_inputs_and_outputs(ScriptType::Type, type_id_table_reader.income_cell())?;
+    verifiers::common::verify_cell_number("IncomeCell", &input_cells, 1, &output_cells, 1)?;
+
+    // The IncomeCell has no name, so it is displayed by its out point in inputs.
+    let out_point = high_level::load_input_out_point(input_cells[0], Source::Input)?;
+    let tx_hash = util::hex_string(out_point.as_reader().tx_hash().raw_data());
+    let index = u32::from_le_bytes(out_point.as_reader().index().raw_data().try_into().unwrap());
+
+    // The claimed capacity is the capacity taken from the IncomeCell.
+    let input_capacity = high_level::load_cell_capacity(input_cells[0], Source::Input)?;
+    let output_capacity = high_level::load_cell_capacity(output_cells[0], Source::Output)?;
+    let claimed_capacity = input_capacity.saturating_sub(output_capacity);
+
+    Ok(format!(
+        "CLAIM {} FROM INCOME CELL 0x{}:{}",
+        to_semantic_capacity(claimed_capacity),
+        tx_hash,
+        index
+    ))
+}
+
 fn transfer_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Box<dyn ScriptError>> {
     fn sum_cells(parser: &WitnessesParserLegacy, source: Source) -> Result<String, Box<dyn ScriptError>> {
         let mut i = 0;
-        let mut capacity_map = Map::new();
+        // The VecMap keeps the order of addresses as they appear in the transaction, which is part of the signed text.
+        let mut capacity_map = VecMap::new();
         loop {
             let ret = high_level::load_cell_capacity(i, source);
             match ret {
@@ -613,7 +763,7 @@ fn transfer_dp_to_semantic(parser: &WitnessesParserLegacy) -> Result<String, Box
         cells: Vec<usize>,
         source: Source,
     ) -> Result<String, Box<dyn ScriptError>> {
-        let mut dp_map = Map::new();
+        let mut dp_map = VecMap::new();
         for i in cells.into_iter() {
             let ret = high_level::load_cell_data(i, source);
             match ret {
@@ -380,6 +380,168 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                 );
             }
         }
+        Action::ClaimIncome => {
+            debug!("Find out IncomeCells ...");
+
+            let (input_cells, output_cells) = util::load_self_cells_in_inputs_and_outputs()?;
+            verifiers::common::verify_cell_number("IncomeCell", &input_cells, 1, &output_cells, 1)?;
+
+            verifiers::misc::verify_always_success_lock(output_cells[0], Source::Output)?;
+
+            let config_income = Config::get_instance().income()?;
+            let income_cell_basic_capacity = u64::from(config_income.basic_capacity());
+            let income_cell_max_records = u32::from(config_income.max_records()) as usize;
+            let income_cell_min_transfer_capacity = u64::from(config_income.min_transfer_capacity());
+
+            let input_income_cell_witness: IncomeCellData = parser
+                .get_entity_by_cell_meta(CellMeta {
+                    index: input_cells[0],
+                    source: Source::Input.into(),
+                })
+                .map_err(|_| ErrorCode::WitnessEntityDecodingError)?;
+            let output_income_cell_witness: IncomeCellData = parser
+                .get_entity_by_cell_meta(CellMeta {
+                    index: output_cells[0],
+                    source: Source::Output.into(),
+                })
+                .map_err(|_| ErrorCode::WitnessEntityDecodingError)?;
+
+            #[cfg(debug_assertions)]
+            das_core::inspect::income_cell(
+                Source::Input,
+                input_cells[0],
+                None,
+                Some(input_income_cell_witness.as_reader()),
+            );
+            #[cfg(debug_assertions)]
+            das_core::inspect::income_cell(
+                Source::Output,
+                output_cells[0],
+                None,
+                Some(output_income_cell_witness.as_reader()),
+            );
+
+            assert!(
+                util::is_entity_eq(
+                    &input_income_cell_witness.creator(),
+                    &output_income_cell_witness.creator()
+                ),
+                ErrorCode::IncomeCellClaimError,
+                "The IncomeCell.creator should not be modified."
+            );
+
+            debug!("Find out the record which belongs to the claimer, the claimer should have its lock in inputs.");
+
+            let mut input_records = Vec::new();
+            for record in input_income_cell_witness.records().into_iter() {
+                input_records = merge_record(input_records, record);
+            }
+
+            let mut claimer_records = Vec::new();
+            let mut records_should_keep = Vec::new();
+            for record in input_records.into_iter() {
+                let cells = util::find_cells_by_script(ScriptType::Lock, record.0.as_reader().into(), Source::Input)?;
+                if cells.is_empty() {
+                    records_should_keep.push((record.0, record.1, false));
+                } else {
+                    claimer_records.push(record);
+                }
+            }
+
+            assert!(
+                claimer_records.len() == 1,
+                ErrorCode::IncomeCellClaimError,
+                "There should be exactly one record owner who has its lock in inputs. (current: {})",
+                claimer_records.len()
+            );
+
+            // The record owner claims the income by itself, so there is no consolidating fee.
+            let (records_should_transfer, _, _) = classify_income_records(
+                0,
+                income_cell_max_records,
+                income_cell_basic_capacity,
+                income_cell_min_transfer_capacity,
+                claimer_records,
+            );
+
+            assert!(
+                records_should_transfer.len() == 1,
+                ErrorCode::IncomeCellClaimCapacityNotEnough,
+                "The capacity of the claimed record should be greater than or equal to {} shannon.",
+                income_cell_min_transfer_capacity
+            );
+
+            #[cfg(debug_assertions)]
+            inspect_records("Records should be claimed:", &records_should_transfer);
+            #[cfg(debug_assertions)]
+            inspect_records("Records should be kept:", &records_should_keep);
+
+            debug!("Check if all the other records are kept in the IncomeCell intact.");
+
+            assert!(
+                output_income_cell_witness.records().len() <= income_cell_max_records,
+                ErrorCode::IncomeCellClaimError,
+                "Outputs[{}] The IncomeCell can not store more than {} records.",
+                output_cells[0],
+                income_cell_max_records
+            );
+
+            let mut output_records = Vec::new();
+            for record in output_income_cell_witness.records().into_iter() {
+                output_records = merge_record(output_records, record);
+            }
+
+            assert!(
+                output_records.len() == records_should_keep.len(),
+                ErrorCode::IncomeCellClaimError,
+                "Outputs[{}] The count of records in the IncomeCell is incorrect. (expected: {}, current: {})",
+                output_cells[0],
+                records_should_keep.len(),
+                output_records.len()
+            );
+
+            for record in output_records.iter() {
+                let mut is_exist = false;
+                for expected_record in records_should_keep.iter_mut() {
+                    if util::is_entity_eq(&record.0, &expected_record.0) {
+                        assert!(
+                            record.1 == expected_record.1,
+                            ErrorCode::IncomeCellClaimError,
+                            "The capacity of some records in the outputs is incorrect. (belong_to: {}, expected: {}, current: {})",
+                            record.0,
+                            expected_record.1,
+                            record.1
+                        );
+                        expected_record.2 = true;
+                        is_exist = true;
+                    }
+                }
+
+                assert!(
+                    is_exist,
+                    ErrorCode::IncomeCellClaimError,
+                    "Unexpected record found in outputs. (belong_to: {})", record.0
+                );
+            }
+
+            let records_total_capacity = calc_total_records_capacity(records_should_keep.iter());
+            let cell_capacity = high_level::load_cell_capacity(output_cells[0], Source::Output)?;
+            assert!(
+                records_total_capacity == cell_capacity,
+                ErrorCode::IncomeCellCapacityError,
+                "Outputs[{}] The IncomeCell.capacity should be always equal to the total capacity of its records. (expected: {}, current: {})",
+                output_cells[0],
+                records_total_capacity,
+                cell_capacity
+            );
+            assert!(
+                cell_capacity >= income_cell_basic_capacity,
+                ErrorCode::IncomeCellCapacityError,
+                "Outputs[{}] The IncomeCell.capacity should be always greater than or equal to {} shannon.",
+                output_cells[0],
+                income_cell_basic_capacity
+            );
+        }
         Action::ConfirmProposal => {
             util::require_type_script(
                 TypeScript::ProposalCellType,
@@ -4,7 +4,7 @@ use alloc::string::String;
 #[cfg(debug_assertions)]
 use alloc::string::ToString;
 use core::cmp::Ordering;
-use core::convert::{TryFrom, TryInto};
+use core::convert::TryInto;
 use core::result::Result;
 
 use ckb_std::ckb_constants::Source;
@@ -115,13 +115,18 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
                 debug!("Skip loading TimeCell because of super lock.");
                 u64::MAX
             } else {
-                util::load_oracle_data(OracleCellType::Time)?
+                util::load_oracle_data_by_median(OracleCellType::Time)?
             };
 
+            let paid_udt = util::get_pre_account_cell_paid_udt(&pre_account_cell_witness_reader);
+            if let Some(paid_udt) = paid_udt {
+                verify_paid_udt(config_price, paid_udt)?;
+            }
+
             if cells_with_super_lock.len() > 0 {
                 debug!("Skip PreAccountCell.witness.quote verification because of super lock.");
             } else {
-                verify_quote(&pre_account_cell_witness_reader)?;
+                verify_quote(config_price, &pre_account_cell_witness_reader, paid_udt)?;
             }
 
             verifiers::misc::verify_always_success_lock(output_cells[0], Source::Output)?;
@@ -132,7 +137,14 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
             )?;
             verify_owner_lock_args(&pre_account_cell_witness_reader)?;
             verify_invited_discount(config_price, &pre_account_cell_witness_reader)?;
-            verify_price_and_capacity(config_account, config_price, &pre_account_cell_witness_reader, capacity)?;
+            verify_price_and_capacity(
+                config_account,
+                config_price,
+                &pre_account_cell_witness_reader,
+                capacity,
+                paid_udt.map(|paid_udt| u64::from(paid_udt.amount())),
+                timestamp,
+            )?;
             verify_account_id(&pre_account_cell_witness_reader, account_id)?;
             verify_account_not_exist(dep_account_cells[0], account_id)?;
 
@@ -175,18 +187,32 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
             verifiers::account_cell::verify_account_chars(chars_reader)?;
             verifiers::account_cell::verify_account_chars_max_length(chars_reader)?;
 
+            if cells_with_super_lock.len() > 0 {
+                debug!("Skip confusable account verification because of super lock.");
+            } else {
+                verifiers::account_cell::verify_account_confusables(chars_reader)?;
+            }
+
             match pre_account_cell_witness_reader.version() {
                 2 => {
                     if let Ok(reader) = pre_account_cell_witness_reader.try_into_v2() {
-                        verifiers::account_cell::verify_records_keys(reader.initial_records())?;
+                        verifiers::account_cell::verify_records_keys(reader.initial_records(), None)?;
                     } else {
                         warn!("The PreAccountCellDataReaderMixer.version returned a mismatched version number.");
                         return Err(code_to_error!(ErrorCode::HardCodedError));
                     }
                 }
                 3 => {
+                    if let Ok(reader) = pre_account_cell_witness_reader.try_into_v3() {
+                        verifiers::account_cell::verify_records_keys(reader.initial_records(), None)?;
+                    } else {
+                        warn!("The PreAccountCellDataReaderMixer.version returned a mismatched version number.");
+                        return Err(code_to_error!(ErrorCode::HardCodedError));
+                    }
+                }
+                4 => {
                     if let Ok(reader) = pre_account_cell_witness_reader.try_into_latest() {
-                        verifiers::account_cell::verify_records_keys(reader.initial_records())?;
+                        verifiers::account_cell::verify_records_keys(reader.initial_records(), None)?;
                     } else {
                         warn!("The PreAccountCellDataReaderMixer.version returned a mismatched version number.");
                         return Err(code_to_error!(ErrorCode::HardCodedError));
@@ -240,6 +266,9 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
 
             debug!("Collect the capacities of all PreAccountCells ...");
 
+            // The UDT or DPoint paid for the registration has been transferred to the DAS wallet or the transfer whitelist
+            // in the pre_register transaction, it can not be refunded without their signatures, so only the capacities of
+            // the PreAccountCells are refunded here.
             let mut refund_map = BTreeMap::new();
             for index in input_cells {
                 let pre_account_cell_witness = util::parse_pre_account_cell_witness(index, Source::Input)?;
@@ -409,16 +438,88 @@ fn verify_owner_lock_args<'a>(
     Ok(())
 }
 
-fn verify_quote<'a>(reader: &Box<dyn PreAccountCellDataReaderMixer + 'a>) -> Result<(), Box<dyn ScriptError>> {
-    debug!("Check if PreAccountCell.witness.quote is the same as QuoteCell.");
+fn verify_paid_udt(config_price: ConfigCellPriceReader, paid_udt: PaidUdtReader) -> Result<(), Box<dyn ScriptError>> {
+    let config_main = Config::get_instance().main()?;
+    if util::is_dpoint_type(config_main, paid_udt.type_script()) {
+        debug!("Check if PreAccountCell.witness.paid_udt is the same as the DPoint paid to the transfer whitelist.");
+
+        let expected_amount = u64::from(paid_udt.amount());
+        let current_amount = util::load_dpoint_paid_to_whitelist()?;
+
+        assert!(
+            expected_amount > 0 && expected_amount == current_amount,
+            ErrorCode::DPointPaymentError,
+            "The DPoint paid to the transfer whitelist should be the same as the PreAccountCell.paid_udt.amount .(expected: {}, current: {})",
+            expected_amount,
+            current_amount
+        );
+
+        return Ok(());
+    }
+
+    debug!("Check if PreAccountCell.witness.paid_udt is the same as the UDT paid to the DAS wallet.");
+
+    util::find_udt_payment(config_price, paid_udt.type_script())?;
+
+    let das_wallet_lock = wallet_lock();
+    let expected_amount = u64::from(paid_udt.amount());
+    let current_amount = match util::load_udt_paid(config_price, das_wallet_lock.as_reader().into())? {
+        Some((udt_payment, amount)) if util::is_reader_eq(udt_payment.type_script(), paid_udt.type_script()) => amount,
+        _ => 0,
+    };
+
+    assert!(
+        expected_amount > 0 && expected_amount == current_amount,
+        ErrorCode::UdtPaymentError,
+        "The UDT paid to the DAS wallet should be the same as the PreAccountCell.paid_udt.amount .(expected: {}, current: {})",
+        expected_amount,
+        current_amount
+    );
+
+    Ok(())
+}
+
+fn verify_quote<'a>(
+    config_price: ConfigCellPriceReader,
+    reader: &Box<dyn PreAccountCellDataReaderMixer + 'a>,
+    paid_udt: Option<PaidUdtReader>,
+) -> Result<(), Box<dyn ScriptError>> {
+    let config_main = Config::get_instance().main()?;
+    let expected_quote = if let Some(paid_udt) = paid_udt {
+        debug!("Check if PreAccountCell.witness.paid_udt.ckb_quote is the same as QuoteCell.");
+
+        let expected_ckb_quote = util::load_oracle_data_by_median(OracleCellType::Quote)?;
+        let current_ckb_quote = u64::from(paid_udt.ckb_quote());
 
-    let expected_quote = util::load_oracle_data(OracleCellType::Quote)?;
+        assert!(
+            expected_ckb_quote == current_ckb_quote,
+            PreAccountCellErrorCode::QuoteIsInvalid,
+            "PreAccountCell.paid_udt.ckb_quote should be the same as the QuoteCell.(expected: {:?}, current: {:?})",
+            expected_ckb_quote,
+            current_ckb_quote
+        );
+
+        if util::is_dpoint_type(config_main, paid_udt.type_script()) {
+            debug!("Check if PreAccountCell.witness.quote is the equivalent quote of DPoint.");
+
+            DPOINT_QUOTE
+        } else {
+            debug!("Check if PreAccountCell.witness.quote is the equivalent quote of the UDT.");
+
+            let udt_payment = util::find_udt_payment(config_price, paid_udt.type_script())?;
+            util::calc_udt_quote(u8::from(udt_payment.decimals()))?
+        }
+    } else {
+        debug!("Check if PreAccountCell.witness.quote is the same as QuoteCell.");
+
+        util::load_oracle_data_by_median(OracleCellType::Quote)?
+    };
     let current = u64::from(reader.quote());
 
     assert!(
         expected_quote == current,
         PreAccountCellErrorCode::QuoteIsInvalid,
-        "PreAccountCell.quote should be the same as the QuoteCell.(expected: {:?}, current: {:?})",
+        "PreAccountCell.quote should be the same as the QuoteCell or the equivalent quote of the UDT/DPoint.(expected: {:?}, current: {:?})",
         expected_quote,
         current
     );
@@ -493,54 +594,104 @@ fn verify_price_and_capacity<'a>(
     config_price: ConfigCellPriceReader,
     reader: &Box<dyn PreAccountCellDataReaderMixer + 'a>,
     capacity: u64,
+    paid_udt_amount: Option<u64>,
+    timestamp: u64,
 ) -> Result<(), Box<dyn ScriptError>> {
     let length_in_price = util::get_length_in_price(reader.account().len() as u64);
     let price = reader.price();
     let prices = config_price.prices();
 
     // Find out register price in from ConfigCellRegister.
-    let expected_price = prices
+    let base_price = prices
         .iter()
         .find(|item| u8::from(item.length()) == length_in_price)
         .ok_or(ErrorCode::ItemMissing)?;
 
-    debug!("Check if PreAccountCell.witness.price is selected base on account length.");
-
-    assert!(
-        util::is_reader_eq(expected_price, price),
-        PreAccountCellErrorCode::PriceIsInvalid,
-        "PreAccountCell.price should be the same as which in ConfigCellPrice.(expected: {}, current: {})",
-        expected_price,
-        price
-    );
-
-    let new_account_price_in_usd = u64::from(reader.price().new()); // x USD
     let discount = u32::from(reader.invited_discount());
     let quote = u64::from(reader.quote()); // y CKB/USD
-
-    // Register price for 1 year in CKB = x ÷ y.
-    let register_capacity = util::calc_yearly_register_fee(new_account_price_in_usd, quote, discount)?;
-    // Storage price in CKB = AccountCell base capacity + account.bytes.length
+                                           // Storage price in CKB = AccountCell base capacity + account.bytes.length
     let storage_capacity = util::calc_account_storage_capacity(
         config_account,
         reader.account().as_readable().len() as u64 + 4,
         reader.owner_lock_args(),
     );
+    // When the registration is paid in UDT or DPoint, the PreAccountCell.quote is the equivalent quote of them, so the
+    // amount paid can be calculated just like CKB.
+    let paid = match paid_udt_amount {
+        Some(amount) => amount,
+        None => capacity.saturating_sub(storage_capacity),
+    };
+
+    // The PreAccountCell.price records the price after the promotion and multi-year discounts, so the duration can be
+    // calculated in the confirm_proposal action with the PreAccountCell only.
+    let base_new_price = u64::from(base_price.new());
+    let promotion_discount = util::get_promotion_discount(config_price.promotions(), reader.account(), timestamp)?;
+    // The duration is decided by the price, so it is calculated with the discount of each tier.
+    let multi_year_discount = util::get_multi_year_discount(config_price.multi_year_discounts(), |tier_discount| {
+        let tier_price = util::calc_discounted_price(base_new_price, promotion_discount.saturating_add(tier_discount));
+        util::calc_duration_from_paid(paid, tier_price, quote, discount)
+    })?;
+    let expected_new_price =
+        util::calc_discounted_price(base_new_price, promotion_discount.saturating_add(multi_year_discount));
 
-    debug!("Check if PreAccountCell.capacity is enough for registration: {}(paid) <-> {}(1 year registeration fee) + {}(storage fee)",
-        capacity,
-        register_capacity,
-        storage_capacity
+    debug!(
+        "Check if PreAccountCell.witness.price is selected base on account length and discounts.(promotion_discount: {}, multi_year_discount: {})",
+        promotion_discount,
+        multi_year_discount
     );
 
     assert!(
-        capacity >= register_capacity + storage_capacity,
-        PreAccountCellErrorCode::CKBIsInsufficient,
-        "PreAccountCell.capacity should contains more than 1 year of registeration fee. (expected: {}, current: {})",
-        register_capacity + storage_capacity,
-        capacity
+        util::is_reader_eq(base_price.length(), price.length())
+            && util::is_reader_eq(base_price.renew(), price.renew())
+            && expected_new_price == u64::from(price.new()),
+        PreAccountCellErrorCode::PriceIsInvalid,
+        "PreAccountCell.price should be the same as which in ConfigCellPrice with the discounts applied.(expected_new: {}, base: {}, current: {})",
+        expected_new_price,
+        base_price,
+        price
     );
 
+    let new_account_price_in_usd = u64::from(reader.price().new()); // x USD
+
+    // Register price for 1 year in CKB = x ÷ y.
+    let register_capacity = util::calc_yearly_register_fee(new_account_price_in_usd, quote, discount)?;
+
+    if paid_udt_amount.is_some() {
+        debug!("Check if PreAccountCell.witness.paid_udt is enough for registration: {}(paid) <-> {}(1 year registeration fee)",
+            paid,
+            register_capacity
+        );
+
+        assert!(
+            paid >= register_capacity,
+            PreAccountCellErrorCode::UdtIsInsufficient,
+            "PreAccountCell.witness.paid_udt should contains more than 1 year of registeration fee. (expected: {}, current: {})",
+            register_capacity,
+            paid
+        );
+        assert!(
+            capacity >= storage_capacity,
+            PreAccountCellErrorCode::CKBIsInsufficient,
+            "PreAccountCell.capacity should contains the storage fee at least. (expected: {}, current: {})",
+            storage_capacity,
+            capacity
+        );
+    } else {
+        debug!("Check if PreAccountCell.capacity is enough for registration: {}(paid) <-> {}(1 year registeration fee) + {}(storage fee)",
+            capacity,
+            register_capacity,
+            storage_capacity
+        );
+
+        assert!(
+            capacity >= register_capacity + storage_capacity,
+            PreAccountCellErrorCode::CKBIsInsufficient,
+            "PreAccountCell.capacity should contains more than 1 year of registeration fee. (expected: {}, current: {})",
+            register_capacity + storage_capacity,
+            capacity
+        );
+    }
+
     Ok(())
 }
 
@@ -581,50 +732,44 @@ fn verify_account_release_status<'a>(
 ) -> Result<(), Box<dyn ScriptError>> {
     debug!("Check if account is released for registration.");
 
-    if reader.account().len() >= 10 {
-        debug!("Ths account contains more than 9 characters, skip verification.");
-        return Ok(());
-    }
-
     let apply_header = util::load_header(input_apply_register_cell, Source::Input)?;
     let apply_created_at = util::get_timestamp_from_header(apply_header.as_reader());
 
-    // TODO Verify if the apply_created_at can be used to replace the TimeCell.
-    // 1666094400 is 2022-10-18 12:00:00 UTC.
-    if apply_created_at >= 1666094400 {
-        debug!("It is after 2022-10-18 12:00:00 UTC now, start fully released char-sets verification.");
-
-        let account_chars = reader.account();
-        // Only if all characters are the same char-set, the account_char_set will have value.
-        let mut account_char_set = None;
-        for char in account_chars.iter() {
-            let char_set = CharSetType::try_from(char.char_set_name()).map_err(|_| ErrorCode::CharSetIsUndefined)?;
-            if account_char_set.is_none() {
-                account_char_set = Some(char_set);
-            } else if account_char_set != Some(char_set) {
-                account_char_set = None;
-                break;
-            }
-        }
+    let account_chars = reader.account();
+    let account_length = account_chars.len() as u32;
+    // Only if all characters are the same char-set, the account_char_set will have value.
+    let account_char_set = util::get_account_char_set(account_chars)?;
 
-        debug!("The account_char_set is: {:?}", account_char_set);
+    debug!(
+        "The account_char_set is: {:?}, the account_length is: {}, the apply_created_at is: {}",
+        account_char_set, account_length, apply_created_at
+    );
 
-        let completely_released_char_set =
-            vec![CharSetType::Emoji, CharSetType::Digit, CharSetType::Ko, CharSetType::Th];
-        if let Some(char_set) = account_char_set {
-            // If the account_char_set is in while list and the account's length is greater than or equel to 4, then the account is released.
-            if account_chars.len() >= 4 && completely_released_char_set.contains(&char_set) {
-                debug!(
-                    "The account contains only one fully released char-set: {:?}, skip verification.",
-                    char_set
-                );
-                return Ok(());
-            } else {
-                debug!("The account is not fullfilled the fully released rule, continue the next verification.");
-            }
+    for (i, rule) in config_release.release_rules().iter().enumerate() {
+        let char_set = u32::from(rule.char_set());
+        let min_length = u32::from(rule.min_length());
+        let max_length = u32::from(rule.max_length());
+        let effective_at = u64::from(rule.effective_at());
+
+        if apply_created_at < effective_at {
+            continue;
+        }
+        if account_length < min_length || (max_length != 0 && account_length > max_length) {
+            continue;
+        }
+        if char_set != RELEASE_RULE_ANY_CHAR_SET && account_char_set.map(|val| val as u32) != Some(char_set) {
+            continue;
         }
+
+        debug!(
+            "The account matches the release_rules[{}], skip verification.(char_set: {}, length: {}..={}, effective_at: {})",
+            i, char_set, min_length, max_length, effective_at
+        );
+        return Ok(());
     }
 
+    debug!("The account does not match any release rules, continue the lucky number verification.");
+
     let account: Vec<u8> = [reader.account().as_readable(), ACCOUNT_SUFFIX.as_bytes().to_vec()].concat();
     let hash = util::blake2b_das(account.as_slice());
     let lucky_num = u32::from_be_bytes((&hash[0..4]).try_into().unwrap());
@@ -623,6 +623,8 @@ fn verify_proposal_execution_result(
 
     let default_lock = Script::default();
     let default_lock_reader = default_lock.as_reader();
+    // The capacity the DAS wallet should pay for the profits of the others when PreAccountCells are paid in UDT or DPoint.
+    let mut das_wallet_paid_capacity = 0;
 
     let mut i = 0;
     for (_sl_index, sl_reader) in slices_reader.iter().enumerate() {
@@ -785,9 +787,15 @@ fn verify_proposal_execution_result(
                 is_id_correct(item_index, &output_cell_data, &input_cell_data)?;
                 is_account_correct(item_index, &output_cell_data)?;
                 is_next_correct(item_index, &output_cell_data, item_next)?;
+                // When the registration is paid in UDT, the duration is calculated from the UDT paid instead of the profit.
+                let paid_udt_opt = util::get_pre_account_cell_paid_udt(&input_cell_witness_reader);
+                let paid = match paid_udt_opt {
+                    Some(paid_udt) => u64::from(paid_udt.amount()),
+                    None => profit,
+                };
                 is_expired_at_correct(
                     item_index,
-                    profit,
+                    paid,
                     timestamp,
                     &output_cell_data,
                     &input_cell_witness_reader,
@@ -807,6 +815,35 @@ fn verify_proposal_execution_result(
                 )?;
                 verify_witness_initial_approval(item_index, output_cell_witness_reader)?;
 
+                // The UDT or DPoint paid for the registration has been received by the DAS wallet or the transfer
+                // whitelist in the pre_register transaction, so the profits of the others are calculated from its
+                // equivalent CKB with the quote recorded in the PreAccountCell.
+                let udt_profit = match paid_udt_opt {
+                    Some(paid_udt) => {
+                        let udt_profit = util::calc_udt_value_in_ckb(
+                            u64::from(paid_udt.amount()),
+                            u64::from(input_cell_witness_reader.quote()),
+                            u64::from(paid_udt.ckb_quote()),
+                        )?;
+
+                        debug!(
+                            "  Item[{}] The profit paid in UDT is: {}(udt_profit) = {}(amount) * {}(udt_quote) / {}(ckb_quote)",
+                            item_index,
+                            udt_profit,
+                            paid_udt.amount(),
+                            input_cell_witness_reader.quote(),
+                            paid_udt.ckb_quote()
+                        );
+
+                        udt_profit
+                    }
+                    None => 0,
+                };
+                let ckb_profit = profit;
+                let profit = profit
+                    .checked_add(udt_profit)
+                    .ok_or(code_to_error!(ErrorCode::OverflowError))?;
+
                 let mut inviter_profit = 0;
                 if input_cell_witness_reader.inviter_lock().is_some() {
                     let inviter_lock_reader = input_cell_witness_reader.inviter_lock().to_opt().unwrap();
@@ -858,8 +895,11 @@ fn verify_proposal_execution_result(
                 );
                 // No need to record proposal confirm profit, bacause the transaction creator can take its profit freely and this script do not know which lock script the transaction creator will use.
 
-                let das_profit =
-                    profit - inviter_profit - channel_profit - proposal_create_profit - proposal_confirm_profit;
+                // The profit of DAS paid in UDT or DPoint is already received, so only the CKB left in the PreAccountCell
+                // is recorded, and the DAS wallet pays for the profits of the others which the CKB can not cover.
+                let others_profit = inviter_profit + channel_profit + proposal_create_profit + proposal_confirm_profit;
+                let das_profit = ckb_profit.saturating_sub(others_profit);
+                das_wallet_paid_capacity += others_profit.saturating_sub(ckb_profit);
                 map_util::add(
                     &mut profit_map,
                     das_wallet_lock.as_reader().as_slice().to_vec(),
@@ -867,8 +907,8 @@ fn verify_proposal_execution_result(
                 );
 
                 debug!(
-                    "  Item[{}] lock.args[{}]: {}(das_profit) = {}(profit) - {}(inviter_profit) - {}(channel_profit) - {}(proposal_create_profit) - {}(proposal_confirm_profit)",
-                    item_index, das_wallet_lock.as_reader().args(), das_profit, profit, inviter_profit, channel_profit, proposal_create_profit, proposal_confirm_profit
+                    "  Item[{}] lock.args[{}]: {}(das_profit) = {}(ckb_profit) - {}(inviter_profit) - {}(channel_profit) - {}(proposal_create_profit) - {}(proposal_confirm_profit)",
+                    item_index, das_wallet_lock.as_reader().args(), das_profit, ckb_profit, inviter_profit, channel_profit, proposal_create_profit, proposal_confirm_profit
                 );
             }
 
@@ -878,6 +918,32 @@ fn verify_proposal_execution_result(
 
     verifiers::income_cell::verify_income_cells(profit_map)?;
 
+    if das_wallet_paid_capacity > 0 {
+        verify_das_wallet_paid_capacity(das_wallet_paid_capacity)?;
+    }
+
+    Ok(())
+}
+
+fn verify_das_wallet_paid_capacity(expected_capacity: u64) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Check if the DAS wallet pays for the profits of the registrations paid in UDT or DPoint.");
+
+    let das_wallet_lock = wallet_lock();
+    let input_cells = util::find_cells_by_script(ScriptType::Lock, das_wallet_lock.as_reader().into(), Source::Input)?;
+    let output_cells =
+        util::find_cells_by_script(ScriptType::Lock, das_wallet_lock.as_reader().into(), Source::Output)?;
+    let input_capacity = util::load_cells_capacity(&input_cells, Source::Input)?;
+    let output_capacity = util::load_cells_capacity(&output_cells, Source::Output)?;
+    let paid_capacity = input_capacity.saturating_sub(output_capacity);
+
+    assert!(
+        paid_capacity >= expected_capacity,
+        ErrorCode::UdtPaymentError,
+        "The DAS wallet should pay at least {} shannon for the profits of the others, but {} found.",
+        expected_capacity,
+        paid_capacity
+    );
+
     Ok(())
 }
 
@@ -1082,7 +1148,7 @@ fn is_next_correct(
 
 fn is_expired_at_correct<'a>(
     item_index: usize,
-    profit: u64,
+    paid: u64,
     current_timestamp: u64,
     output_cell_data: &Vec<u8>,
     pre_account_cell_witness: &Box<dyn PreAccountCellDataReaderMixer + 'a>,
@@ -1090,13 +1156,13 @@ fn is_expired_at_correct<'a>(
     let price = u64::from(pre_account_cell_witness.price().new());
     let quote = u64::from(pre_account_cell_witness.quote());
     let discount = u32::from(pre_account_cell_witness.invited_discount());
-    let duration = util::calc_duration_from_paid(profit, price, quote, discount)?;
+    let duration = util::calc_duration_from_paid(paid, price, quote, discount)?;
     let expired_at = data_parser::account_cell::get_expired_at(output_cell_data);
     let calculated_expired_at = current_timestamp + duration;
 
     debug!(
-        "  Item[{}] Params of expired_at calculation: --profit={} --price={} --quote={} --discount={} --current={}",
-        item_index, profit, price, quote, discount, current_timestamp
+        "  Item[{}] Params of expired_at calculation: --paid={} --price={} --quote={} --discount={} --current={}",
+        item_index, paid, price, quote, discount, current_timestamp
     );
     debug!(
         "  Item[{}] Critical value of expired_at calculation process: duration={}, calculated_expired_at={}",
@@ -1263,6 +1329,8 @@ fn verify_witness_initial_records<'a>(
         let expected_records;
         if let Ok(reader) = pre_account_cell_reader.try_into_latest() {
             expected_records = reader.initial_records();
+        } else if let Ok(reader) = pre_account_cell_reader.try_into_v3() {
+            expected_records = reader.initial_records();
         } else if let Ok(reader) = pre_account_cell_reader.try_into_v2() {
             expected_records = reader.initial_records();
         } else {
@@ -1307,15 +1375,28 @@ fn verify_witness_initial_cross_chain_and_status<'a>(
             item_index
         );
 
-        let checked;
+        let initial_cross_chain;
         if let Ok(reader) = pre_account_cell_reader.try_into_latest() {
-            checked = u8::from(reader.initial_cross_chain().checked());
+            initial_cross_chain = reader.initial_cross_chain();
+        } else if let Ok(reader) = pre_account_cell_reader.try_into_v3() {
+            initial_cross_chain = reader.initial_cross_chain();
         } else {
             warn!("  Item[{}] Some version of PreAccountCell is unhandled. It is required to verify the field initial_cross_chain.", item_index);
             return Err(code_to_error!(ErrorCode::HardCodedError));
         }
 
-        if checked == 1 {
+        if u8::from(initial_cross_chain.checked()) == 1 {
+            debug!(
+                "  Item[{}] The PreAccountCell.initial_cross_chain is checked, verify if the chain is supported.",
+                item_index
+            );
+
+            util::find_enabled_cross_chain(
+                Config::get_instance().cross_chain()?,
+                u64::from(initial_cross_chain.coin_type()),
+                u64::from(initial_cross_chain.chain_id()),
+            )?;
+
             assert!(
                 status == AccountStatus::LockedForCrossChain as u8,
                 ErrorCode::ProposalConfirmNewAccountWitnessError,
@@ -1324,6 +1405,13 @@ fn verify_witness_initial_cross_chain_and_status<'a>(
                 AccountStatus::LockedForCrossChain as u8,
                 status
             );
+
+            assert!(
+                util::is_reader_eq(account_cell_reader.cross_chain(), initial_cross_chain),
+                ErrorCode::ProposalConfirmNewAccountWitnessError,
+                "  Item[{}] The AccountCell.cross_chain should be the same as the PreAccountCell.initial_cross_chain in outputs.",
+                item_index
+            );
         } else {
             assert!(
                 status == AccountStatus::Normal as u8,
@@ -1350,6 +1438,15 @@ fn verify_witness_initial_cross_chain_and_status<'a>(
         );
     }
 
+    if status == AccountStatus::Normal as u8 {
+        assert!(
+            util::is_reader_eq(account_cell_reader.cross_chain(), ChainId::default().as_reader()),
+            ErrorCode::ProposalConfirmNewAccountWitnessError,
+            "  Item[{}] The AccountCell.cross_chain should be default value in outputs.",
+            item_index
+        );
+    }
+
     Ok(())
 }
 
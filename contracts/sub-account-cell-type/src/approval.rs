@@ -21,16 +21,16 @@ pub fn transfer_approval_create(
         i
     );
 
-    match sub_account_reader.try_into_latest() {
-        Ok(sub_account_reader) => {
+    match util::get_sub_account_approval(sub_account_reader.as_ref()) {
+        Some(approval_reader) => {
             das_assert!(
-                util::is_reader_eq(sub_account_reader.approval(), AccountApproval::default().as_reader()),
+                util::is_reader_eq(approval_reader, AccountApproval::default().as_reader()),
                 SubAccountCellErrorCode::ApprovalExist,
                 "  witnesses[{:>2}] The sub-account already has approval.",
                 i
             );
         }
-        Err(_) => {
+        None => {
             debug!(
                 "  witnesses[{:>2}] The sub-account does not have approval, could creating a new one.",
                 i
@@ -620,10 +620,32 @@ fn action_update_sub_account() -> Result<(), Box<dyn ScriptError>> {
         debug!("Verify if the profit distribution is correct.");
 
         let _minimal_required_das_profit = sub_action.minimal_required_das_profit;
-        let profit_from_manual_mint = sub_action.profit_from_manual_mint;
-        let profit_from_manual_renew = sub_action.profit_from_manual_renew;
+        let mut profit_from_manual_mint = sub_action.profit_from_manual_mint;
+        let mut profit_from_manual_renew = sub_action.profit_from_manual_renew;
         let profit_from_manual_renew_by_other = sub_action.profit_from_manual_renew_by_other;
-        let profit_total = sub_action.profit_total;
+        let mut profit_total = sub_action.profit_total;
+
+        let dpoint_paid = util::load_dpoint_paid_to_whitelist()?;
+        if dpoint_paid > 0 {
+            debug!(
+                "Found DPoint paid to the transfer whitelist, verify if the manual mint and renew are paid in DPoint."
+            );
+
+            // The DPointCells with das-lock in inputs can only belong to the sender, this is ensured by the
+            // verification of BalanceCells above.
+            das_assert!(
+                smt_root_sign_found && dpoint_paid == sub_action.profit_in_dpoint_from_manual,
+                ErrorCode::DPointPaymentError,
+                "The DPoint paid by the sender should be the same as the price of manual mint and renew. (expected: {}, current: {})",
+                sub_action.profit_in_dpoint_from_manual,
+                dpoint_paid
+            );
+
+            // The profit of manual mint and renew has been paid in DPoint, so it should not be paid in CKB again.
+            profit_total -= profit_from_manual_mint + profit_from_manual_renew;
+            profit_from_manual_mint = 0;
+            profit_from_manual_renew = 0;
+        }
 
         // debug!("minimal_required_das_profit: {:?}", minimal_required_das_profit);
         // debug!("profit_from_manual_mint: {:?}", profit_from_manual_mint);
@@ -38,6 +38,9 @@ pub struct SubAction<'a> {
     pub profit_from_manual_mint: u64,
     pub profit_from_manual_renew: u64,
     pub profit_from_manual_renew_by_other: u64,
+    // The same profit as profit_from_manual_mint and profit_from_manual_renew but in DPoint, it is used when the sender
+    // pays in DPoint.
+    pub profit_in_dpoint_from_manual: u64,
 
     // manual mint fields
     manual_mint_list_smt_root: &'a Option<[u8; 32]>,
@@ -83,6 +86,7 @@ impl<'a> SubAction<'a> {
             profit_from_manual_mint: 0,
             profit_from_manual_renew: 0,
             profit_from_manual_renew_by_other: 0,
+            profit_in_dpoint_from_manual: 0,
             manual_mint_list_smt_root,
             manual_renew_list_smt_root,
             custom_preserved_rules,
@@ -134,9 +138,9 @@ impl<'a> SubAction<'a> {
         );
 
         das_assert!(
-            witness.old_sub_account_version == 2 && witness.new_sub_account_version == 2,
+            witness.old_sub_account_version == 3 && witness.new_sub_account_version == 3,
             SubAccountCellErrorCode::WitnessVersionMismatched,
-            "  witnesses[{:>2}] The old_sub_account_version and new_sub_account_version should be 2.",
+            "  witnesses[{:>2}] The old_sub_account_version and new_sub_account_version should be 3.",
             witness.index
         );
 
@@ -158,6 +162,7 @@ impl<'a> SubAction<'a> {
         verifiers::account_cell::verify_account_chars(account_chars_reader)?;
         verifiers::account_cell::verify_account_chars_min_length(account_chars_reader)?;
         verifiers::account_cell::verify_account_chars_max_length(account_chars_reader)?;
+        verifiers::account_cell::verify_account_confusables(account_chars_reader)?;
 
         verifiers::sub_account_cell::verify_initial_properties(witness.index, sub_account_reader, self.timestamp)?;
 
@@ -211,6 +216,11 @@ impl<'a> SubAction<'a> {
                     self.profit_from_manual_mint += profit;
                     self.profit_total += profit;
                     self.minimal_required_das_profit += profit;
+                    self.profit_in_dpoint_from_manual += calc_total_register_fee_from_reader(
+                        self.config_sub_account.new_sub_account_price(),
+                        DPOINT_QUOTE,
+                        expiration_years,
+                    )?;
 
                     is_manual_minted = true;
                 }
@@ -501,6 +511,11 @@ impl<'a> SubAction<'a> {
         )?;
         if !manually_renew_by_others {
             self.profit_from_manual_renew += profit;
+            self.profit_in_dpoint_from_manual += calc_total_register_fee_from_reader(
+                self.config_sub_account.renew_sub_account_price(),
+                DPOINT_QUOTE,
+                expiration_years,
+            )?;
         } else {
             self.profit_from_manual_renew_by_other += profit;
         }
@@ -595,7 +610,32 @@ impl<'a> SubAction<'a> {
                     &[AccountStatus::Normal, AccountStatus::ApprovedTransfer],
                 )?;
 
-                verifiers::account_cell::verify_records_keys(records.as_reader())?;
+                verifiers::account_cell::verify_records_keys(records.as_reader(), Some(sub_account_reader.records()))?;
+
+                if witness.sign_role == Some(LockRole::Delegate) {
+                    let sub_account_reader = sub_account_reader
+                        .try_into_latest()
+                        .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
+                    let sign_type = witness
+                        .sign_type
+                        .ok_or(code_to_error!(SubAccountCellErrorCode::WitnessParsingError))?;
+                    let lock_args = [&[sign_type as u8], witness.sign_args.as_slice()].concat();
+                    let key_prefixes = verifiers::account_cell::get_delegated_key_prefixes(
+                        sub_account_reader.records_delegates(),
+                        &lock_args,
+                    );
+
+                    verifiers::account_cell::verify_records_changed_by_delegate(
+                        sub_account_reader.records(),
+                        records.as_reader(),
+                        &key_prefixes,
+                    )?;
+                }
+            }
+            SubAccountEditValue::RecordsDelegates(records_delegates) => {
+                verifiers::sub_account_cell::verify_status(witness.index, &sub_account_reader, AccountStatus::Normal)?;
+
+                verifiers::account_cell::verify_records_delegates(records_delegates.as_reader())?;
             }
             // manual::verify_edit_value_not_empty
             SubAccountEditValue::None | _ => {
@@ -672,12 +712,8 @@ impl<'a> SubAction<'a> {
 
         let approval_reader = match witness.action {
             SubAccountAction::CreateApproval => new_sub_account_reader.approval(),
-            _ => {
-                let sub_account_reader = sub_account_reader
-                    .try_into_latest()
-                    .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
-                sub_account_reader.approval()
-            }
+            _ => util::get_sub_account_approval(sub_account_reader.as_ref())
+                .ok_or(code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?,
         };
         let approval_action = approval_reader.action().raw_data();
         let approval_params = approval_reader.params().raw_data();
@@ -762,12 +798,9 @@ impl<'a> SubAction<'a> {
             },
             SubAccountAction::DelayApproval => match approval_action {
                 b"transfer" => {
-                    let sub_account_reader = sub_account_reader
-                        .try_into_latest()
-                        .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
                     approval::transfer_approval_delay(
                         witness.index,
-                        sub_account_reader.approval(),
+                        approval_reader,
                         new_sub_account_reader.approval(),
                     )?;
                 }
@@ -775,13 +808,10 @@ impl<'a> SubAction<'a> {
             },
             SubAccountAction::RevokeApproval => match approval_action {
                 b"transfer" => {
-                    let sub_account_reader = sub_account_reader
-                        .try_into_latest()
-                        .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
                     approval::transfer_approval_revoke(
                         witness.index,
                         self.timestamp,
-                        sub_account_reader.approval(),
+                        approval_reader,
                         new_sub_account_reader,
                     )?;
                 }
@@ -966,11 +996,11 @@ fn smt_verify_sub_account_is_removed(
 
 fn generate_new_sub_account_by_edit_value(witness: &SubAccountWitness) -> Result<SubAccount, Box<dyn ScriptError>> {
     das_assert!(
-        witness.new_sub_account_version == 2,
+        witness.new_sub_account_version == 3,
         SubAccountCellErrorCode::WitnessUpgradeNeeded,
         "  witnesses[{:>2}] SubAccount.new_sub_account_version is invalid.(expected: {}, actual: {})",
         witness.index,
-        2,
+        3,
         witness.new_sub_account_version
     );
 
@@ -994,6 +1024,25 @@ fn generate_new_sub_account_by_edit_value(witness: &SubAccountWitness) -> Result
             .enable_sub_account(sub_account.enable_sub_account().clone())
             .renew_sub_account_price(sub_account.renew_sub_account_price().clone())
             .build()
+    } else if sub_account.version() == 2 {
+        let sub_account = sub_account
+            .try_into_v2()
+            .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
+
+        SubAccount::new_builder()
+            .lock(sub_account.lock().clone())
+            .id(sub_account.id().clone())
+            .account(sub_account.account().clone())
+            .suffix(sub_account.suffix().clone())
+            .registered_at(sub_account.registered_at().clone())
+            .expired_at(sub_account.expired_at().clone())
+            .status(sub_account.status().clone())
+            .records(sub_account.records().clone())
+            .nonce(sub_account.nonce().clone())
+            .enable_sub_account(sub_account.enable_sub_account().clone())
+            .renew_sub_account_price(sub_account.renew_sub_account_price().clone())
+            .approval(sub_account.approval().clone())
+            .build()
     } else {
         sub_account
             .try_into_latest()
@@ -1018,12 +1067,15 @@ fn generate_new_sub_account_by_edit_value(witness: &SubAccountWitness) -> Result
                     sub_account_builder = sub_account_builder.lock(lock_builder.build());
 
                     if let SubAccountEditValue::Owner(_) = edit_value {
-                        sub_account_builder = sub_account_builder.records(Records::default())
+                        sub_account_builder = sub_account_builder
+                            .records(Records::default())
+                            .records_delegates(RecordsDelegates::default())
                     }
 
                     sub_account_builder
                 }
                 SubAccountEditValue::Records(val) => sub_account_builder.records(val.to_owned()),
+                SubAccountEditValue::RecordsDelegates(val) => sub_account_builder.records_delegates(val.to_owned()),
                 _ => return Err(code_to_error!(SubAccountCellErrorCode::WitnessEditKeyInvalid)),
             }
         }
@@ -1071,6 +1123,7 @@ fn generate_new_sub_account_by_edit_value(witness: &SubAccountWitness) -> Result
                         .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessParsingError))?;
                     sub_account_builder = sub_account_builder.lock(approval_params_reader.to_lock().to_entity());
                     sub_account_builder = sub_account_builder.records(Records::default());
+                    sub_account_builder = sub_account_builder.records_delegates(RecordsDelegates::default());
                     // The status should be updated to AccountStatus::Normal when the edit_value is None.
                     sub_account_builder = sub_account_builder.status(Uint8::from(AccountStatus::Normal as u8));
                     sub_account_builder.approval(AccountApproval::default())
@@ -9,7 +9,7 @@ use das_types::constants::*;
 use witness_parser::WitnessesParserV1;
 
 // use simple_ast::types as ast_types;
-use crate::{config_tests, uint_tests, witness_parser_tests};
+use crate::{config_tests, map_tests, uint_tests, witness_parser_tests};
 
 pub fn main() -> Result<(), Box<dyn ScriptError>> {
     debug!("====== Running test-env ======");
@@ -38,6 +38,7 @@ pub fn main() -> Result<(), Box<dyn ScriptError>> {
         "test_uint_basic_interface" => uint_tests::test_basic_interface()?,
         "test_uint_safty" => uint_tests::test_safty()?,
         "perf_uint_price_formula" => uint_tests::perf_price_formula()?,
+        "perf_das_map_vs_vec_map" => map_tests::perf_map_vs_vec_map()?,
         "test_config_account_loading" => config_tests::test_config_account_loading()?,
         "test_config_records_key_namespace_loading" => config_tests::test_config_records_key_namespace_loading()?,
         "test_witness_parser_get_entity_by_cell_meta" => {
@@ -0,0 +1,68 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use ckb_std::syscalls;
+use das_core::error::*;
+use das_core::{code_to_error, das_assert, debug};
+use das_map::map::Map;
+use das_map::util::{self as map_util, Mapping};
+use das_map::vec_map::VecMap;
+
+// The size of a molecule encoded lock script with 21 bytes args, which is the most common key of profit maps.
+const KEY_SIZE: usize = 53 + 21;
+
+fn gen_keys(total: usize) -> Vec<Vec<u8>> {
+    (0..total)
+        .map(|i| {
+            let mut key = Vec::with_capacity(KEY_SIZE);
+            key.resize(KEY_SIZE - 4, 0);
+            // Scatter the keys to avoid inserting them in the sorted order.
+            key.extend(((i as u32).wrapping_mul(2_654_435_761)).to_be_bytes());
+            key
+        })
+        .collect()
+}
+
+fn run<M: Mapping<Vec<u8>, u64>>(name: &str, map: &mut M, keys: &[Vec<u8>]) -> Result<u64, Box<dyn ScriptError>> {
+    let start = syscalls::current_cycles();
+
+    // Every key is added twice, just like a beneficiary who gets profits from multiple sources.
+    for _ in 0..2 {
+        for key in keys.iter() {
+            map_util::add(map, key.clone(), 1);
+        }
+    }
+    for key in keys.iter() {
+        das_assert!(
+            map.get(key) == Some(&2),
+            ErrorCode::UnittestError,
+            "{}: the value of a key should be 2.",
+            name
+        );
+    }
+
+    let cycles = syscalls::current_cycles() - start;
+    debug!("{}: {} keys cost {} cycles", name, keys.len(), cycles);
+
+    Ok(cycles)
+}
+
+pub fn perf_map_vs_vec_map() -> Result<(), Box<dyn ScriptError>> {
+    for total in [10, 100, 500] {
+        let keys = gen_keys(total);
+
+        let vec_map_cycles = run("VecMap", &mut VecMap::new(), &keys)?;
+        let map_cycles = run("Map", &mut Map::new(), &keys)?;
+
+        if total >= 100 {
+            das_assert!(
+                map_cycles < vec_map_cycles,
+                ErrorCode::UnittestError,
+                "The Map should cost less cycles than the VecMap when there are {} keys.",
+                total
+            );
+        }
+    }
+
+    Ok(())
+}
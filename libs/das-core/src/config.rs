@@ -6,6 +6,7 @@ use core::convert::TryFrom;
 
 use das_types::constants::*;
 use das_types::packed::*;
+use das_types::record_key_namespace::RecordKeyNamespace;
 use das_types::util as das_types_util;
 use molecule::prelude::Entity;
 use witness_parser::traits::WitnessQueryable;
@@ -42,9 +43,10 @@ pub struct Config {
     pub reverse_resolution: OnceCell<ConfigCellReverseResolution>,
     pub sub_account: OnceCell<ConfigCellSubAccount>,
     pub dpoint: OnceCell<ConfigCellDPoint>,
-    pub record_key_namespace: OnceCell<Vec<u8>>,
-    pub preserved_account: OnceCell<Vec<u8>>,
-    pub unavailable_account: OnceCell<Vec<u8>>,
+    pub account_list: OnceCell<ConfigCellAccountList>,
+    pub confusables: OnceCell<ConfigCellConfusables>,
+    pub cross_chain: OnceCell<ConfigCellCrossChain>,
+    pub record_key_namespace: OnceCell<RecordKeyNamespace>,
     pub smt_node_white_list: OnceCell<Vec<[u8; 32]>>,
 }
 
@@ -64,9 +66,10 @@ impl Default for Config {
             reverse_resolution: OnceCell::new(),
             sub_account: OnceCell::new(),
             dpoint: OnceCell::new(),
+            account_list: OnceCell::new(),
+            confusables: OnceCell::new(),
+            cross_chain: OnceCell::new(),
             record_key_namespace: OnceCell::new(),
-            preserved_account: OnceCell::new(),
-            unavailable_account: OnceCell::new(),
             smt_node_white_list: OnceCell::new(),
         }
     }
@@ -171,77 +174,44 @@ impl Config {
         get_or_try_init!(self, dpoint, ConfigCellDPoint, DataType::ConfigCellDPoint)
     }
 
-    pub fn record_key_namespace(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.record_key_namespace.get_or_try_init(|| {
-            let data_type = DataType::ConfigCellRecordKeyNamespace;
-            let raw = self.parse_raw_witness(data_type)?;
-            let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
-                None => {
-                    warn!("The data of {:?} is empty.", data_type);
-                    return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
-                }
-            };
-
-            Ok(data)
-        })
+    pub fn account_list(&self) -> Result<ConfigCellAccountListReader, Box<dyn ScriptError>> {
+        get_or_try_init!(
+            self,
+            account_list,
+            ConfigCellAccountList,
+            DataType::ConfigCellAccountList
+        )
     }
 
-    pub fn preserved_account(&self, data_type: DataType) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.preserved_account.get_or_try_init(|| {
-            let raw = match data_type {
-                DataType::ConfigCellPreservedAccount00 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount01 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount02 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount03 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount04 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount05 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount06 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount07 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount08 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount09 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount10 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount11 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount12 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount13 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount14 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount15 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount16 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount17 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount18 => self.parse_raw_witness(data_type)?,
-                DataType::ConfigCellPreservedAccount19 => self.parse_raw_witness(data_type)?,
-                _ => {
-                    warn!("Invalid preserved account data type: {:?}", data_type);
-                    return Err(code_to_error!(ErrorCode::WitnessDataTypeDecodingError).into());
-                }
-            };
-            let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
-                None => {
-                    warn!("The data of {:?} is empty.", data_type);
-
-                    return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
-                }
-            };
+    pub fn confusables(&self) -> Result<ConfigCellConfusablesReader, Box<dyn ScriptError>> {
+        get_or_try_init!(
+            self,
+            confusables,
+            ConfigCellConfusables,
+            DataType::ConfigCellConfusables
+        )
+    }
 
-            Ok(data)
-        })
+    pub fn cross_chain(&self) -> Result<ConfigCellCrossChainReader, Box<dyn ScriptError>> {
+        get_or_try_init!(self, cross_chain, ConfigCellCrossChain, DataType::ConfigCellCrossChain)
     }
 
-    pub fn unavailable_account(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.unavailable_account.get_or_try_init(|| {
-            let data_type = DataType::ConfigCellUnAvailableAccount;
+    pub fn record_key_namespace(&self) -> Result<&RecordKeyNamespace, Box<dyn ScriptError>> {
+        self.record_key_namespace.get_or_try_init(|| {
+            let data_type = DataType::ConfigCellRecordKeyNamespace;
             let raw = self.parse_raw_witness(data_type)?;
-            debug!("raw: {:?}", hex::encode(&raw));
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
+                Some(data) => data,
                 None => {
                     warn!("The data of {:?} is empty.", data_type);
                     return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
                 }
             };
 
-            Ok(data)
+            RecordKeyNamespace::from_slice(data).map_err(|err| {
+                warn!("Decoding {:?} failed: {:?}", data_type, err);
+                code_to_error!(ErrorCode::ConfigCellWitnessDecodingError).into()
+            })
         })
     }
 
@@ -266,6 +236,10 @@ impl Config {
                     DataType::ConfigCellCharSetTh => self.parse_raw_witness(data_type)?,
                     DataType::ConfigCellCharSetTr => self.parse_raw_witness(data_type)?,
                     DataType::ConfigCellCharSetVi => self.parse_raw_witness(data_type)?,
+                    DataType::ConfigCellCharSetAr => self.parse_raw_witness(data_type)?,
+                    DataType::ConfigCellCharSetHi => self.parse_raw_witness(data_type)?,
+                    DataType::ConfigCellCharSetHe => self.parse_raw_witness(data_type)?,
+                    DataType::ConfigCellCharSetEl => self.parse_raw_witness(data_type)?,
                     DataType::ConfigCellCharSetZhHans => self.parse_raw_witness(data_type)?,
                     DataType::ConfigCellCharSetZhHant => self.parse_raw_witness(data_type)?,
                     _ => {
@@ -294,14 +268,7 @@ impl Config {
                     raw.len()
                 );
 
-                let char_set = CharSet {
-                    name: char_set_type,
-                    // skip WITNESS_LENGTH_BYTES bytes length, and the WITNESS_LENGTH_BYTES+1 byte is global flag, then the following bytes is data
-                    global: raw.get(WITNESS_LENGTH_BYTES).unwrap() == &1u8,
-                    data: raw.get((WITNESS_LENGTH_BYTES + 1)..).unwrap().to_vec(),
-                };
-
-                Ok(char_set)
+                CharSet::from_slice(char_set_type, &raw[WITNESS_LENGTH_BYTES..])
             })
         })
     }
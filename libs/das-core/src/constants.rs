@@ -38,6 +38,8 @@ pub const ONE_CKB: u64 = 100_000_000;
 pub const CELL_BASIC_CAPACITY: u64 = 6_1 * ONE_CKB;
 pub const ONE_USD: u64 = 1_000_000;
 pub const DPOINT_MAX_LIMIT: u64 = 10_000_000 * ONE_USD;
+// DPoint is pegged to USD with the same precision as the prices, so this is the equivalent quote of DPoint.
+pub const DPOINT_QUOTE: u64 = ONE_CKB;
 
 pub const RATE_BASE: u64 = 10_000;
 
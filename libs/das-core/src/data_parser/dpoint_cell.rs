@@ -1,9 +1,14 @@
-pub fn get_value(data: &[u8]) -> Option<u64> {
-    if data.len() > 12 {
-        return None;
-    }
+// The data of DPointCell is a list of LV structure u64 fields:
+//
+// - v1: value
+// - v2: value, expired_at, vesting_start_at, vesting_end_at
+const FIELD_SIZE: usize = 12;
+const V1_FIELDS: usize = 1;
+const V2_FIELDS: usize = 4;
 
-    let header = match data.get(0..4) {
+fn get_field(data: &[u8], index: usize) -> Option<u64> {
+    let start = index * FIELD_SIZE;
+    let header = match data.get(start..(start + 4)) {
         Some(bytes) => u32::from_le_bytes(bytes.try_into().unwrap()) as usize,
         None => return None,
     };
@@ -12,8 +17,51 @@ pub fn get_value(data: &[u8]) -> Option<u64> {
         return None;
     }
 
-    match data.get(4..(4 + header)) {
+    match data.get((start + 4)..(start + 4 + header)) {
         Some(bytes) => Some(u64::from_le_bytes(bytes.try_into().unwrap())),
         None => None,
     }
 }
+
+fn is_v2(data: &[u8]) -> bool {
+    data.len() == FIELD_SIZE * V2_FIELDS
+}
+
+/// Get the value of DPoint, None will be returned if any field of the data is corrupted.
+pub fn get_value(data: &[u8]) -> Option<u64> {
+    let fields = if is_v2(data) {
+        V2_FIELDS
+    } else if data.len() == FIELD_SIZE * V1_FIELDS {
+        V1_FIELDS
+    } else {
+        return None;
+    };
+
+    if (1..fields).any(|i| get_field(data, i).is_none()) {
+        return None;
+    }
+
+    get_field(data, 0)
+}
+
+/// Get the timestamp when the DPoint expires, 0 means the DPoint never expires.
+pub fn get_expired_at(data: &[u8]) -> Option<u64> {
+    if !is_v2(data) {
+        return Some(0);
+    }
+
+    get_field(data, 1)
+}
+
+/// Get the period when the DPoint is vested linearly, None means the DPoint is vested immediately.
+pub fn get_vesting_period(data: &[u8]) -> Option<(u64, u64)> {
+    if !is_v2(data) {
+        return None;
+    }
+
+    match (get_field(data, 2), get_field(data, 3)) {
+        (Some(0), Some(0)) => None,
+        (Some(start_at), Some(end_at)) => Some((start_at, end_at)),
+        _ => None,
+    }
+}
@@ -82,6 +82,8 @@ pub enum ErrorCode {
     AccountCharIsInvalid,
     AccountIsTooShort,
     AccountIsTooLong,
+    AccountListProofIsRequired = 70,
+    AccountIsConfusable,
     ProposalSliceIsNotSorted = 90,
     ProposalSliceIsDiscontinuity,
     ProposalSliceRelatedCellNotFound,
@@ -113,6 +115,8 @@ pub enum ErrorCode {
     IncomeCellTransferError,
     IncomeCellCapacityError,
     IncomeCellProfitMismatch,
+    IncomeCellClaimError,
+    IncomeCellClaimCapacityNotEnough,
     EIP712SerializationError = -90,
     EIP712SematicError,
     EIP712DecodingWitnessArgsError,
@@ -140,6 +144,18 @@ pub enum ErrorCode {
     UpgradeDefaultValueOfNewFieldIsError,
     CrossChainLockError,
     CrossChainUnlockError,
+    CrossChainUnlockAttestationIsRequired,
+    CrossChainUnlockAttestationInvalid,
+    CrossChainUnlockThresholdNotReached,
+    CrossChainNotSupported,
+    CrossChainDisabled,
+    UdtPaymentNotSupported,
+    UdtPaymentError,
+    OracleCellsNotEnough,
+    OracleCellIsDuplicated,
+    OracleCellIsStale,
+    DPointPaymentError,
+    OracleCellProviderInvalid,
     OverflowError = -3,
     UnittestError = -2,
     SystemOff = -1,
@@ -228,6 +244,10 @@ pub enum AccountCellErrorCode {
     ApprovalFulfillError,
     //87
     AccountCellBidPriceTooLow,
+    AccountCellRecordValueInvalid,
+    AccountCellRecordTtlTooShort,
+    AccountCellRecordsDelegateInvalid,
+    AccountCellRecordKeyNotDelegated,
 }
 
 impl From<SysError> for AccountCellErrorCode {
@@ -281,6 +301,7 @@ pub enum PreAccountCellErrorCode {
     InviterIdIsInvalid,
     InviteeDiscountShouldBeEmpty,
     InviteeDiscountIsInvalid,
+    UdtIsInsufficient,
 }
 
 impl From<SysError> for PreAccountCellErrorCode {
@@ -520,3 +541,10 @@ impl From<molecule::error::VerificationError> for Box<dyn ScriptError> {
         code_to_error!(ErrorCode::WitnessDataDecodingError)
     }
 }
+
+impl From<das_lv::LvError> for Box<dyn ScriptError> {
+    fn from(err: das_lv::LvError) -> Box<dyn ScriptError> {
+        warn!("  LV encoded witness structure error: {}", err);
+        code_to_error!(ErrorCode::WitnessStructureError)
+    }
+}
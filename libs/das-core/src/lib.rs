@@ -18,6 +18,7 @@ pub mod data_parser;
 pub mod error;
 pub mod helpers;
 pub mod inspect;
+pub mod record_value;
 pub mod sign_util;
 pub mod since_util;
 pub mod traits;
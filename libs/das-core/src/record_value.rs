@@ -0,0 +1,240 @@
+//! The validators of record values, they are bound to record keys by the v2 format of ConfigCellRecordKeyNamespace.
+//!
+//! All values are treated as UTF-8 strings, because that is how the clients store them.
+
+use alloc::vec::Vec;
+use core::str;
+
+use das_types::constants::RecordValueValidator;
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak};
+
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE36_ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+// https://github.com/multiformats/multicodec/blob/master/table.csv
+const MULTIHASH_SHA2_256: u8 = 0x12;
+const CID_V0_LENGTH: usize = 46;
+const CID_V1: u64 = 0x01;
+
+pub fn is_valid(validator: RecordValueValidator, value: &[u8]) -> bool {
+    if validator == RecordValueValidator::Any {
+        return true;
+    }
+
+    let value = match str::from_utf8(value) {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+
+    match validator {
+        RecordValueValidator::Any => true,
+        RecordValueValidator::EvmAddress => is_evm_address(value),
+        RecordValueValidator::Bech32 => bech32::decode(value).is_ok(),
+        RecordValueValidator::Base58Check => is_base58_check(value),
+        RecordValueValidator::ContentHash => is_content_hash(value),
+        RecordValueValidator::Url => is_url(value),
+    }
+}
+
+/// Check the address by EIP-55, the all lowercase and all uppercase addresses carry no checksum, so they are accepted
+/// as they are.
+///
+/// https://eips.ethereum.org/EIPS/eip-55
+fn is_evm_address(value: &str) -> bool {
+    let hex = match value.strip_prefix("0x") {
+        Some(hex) if hex.len() == 40 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()) => hex,
+        _ => return false,
+    };
+
+    if !hex.bytes().any(|byte| byte.is_ascii_lowercase()) || !hex.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        return true;
+    }
+
+    let lowercase = hex.to_ascii_lowercase();
+    let mut hasher = Keccak::v256();
+    hasher.update(lowercase.as_bytes());
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    hex.bytes().enumerate().all(|(i, byte)| {
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+        if byte.is_ascii_digit() {
+            true
+        } else if nibble >= 8 {
+            byte.is_ascii_uppercase()
+        } else {
+            byte.is_ascii_lowercase()
+        }
+    })
+}
+
+fn is_base58_check(value: &str) -> bool {
+    let bytes = match bs58::decode(value).into_vec() {
+        Ok(bytes) if bytes.len() > 4 => bytes,
+        _ => return false,
+    };
+
+    let (payload, checksum) = bytes.split_at(bytes.len() - 4);
+    let digest = Sha256::digest(Sha256::digest(payload));
+
+    &digest[..4] == checksum
+}
+
+/// Check the value is a CIDv0 in base58 or a CIDv1 in base32/base36, the multibase prefixes of CIDv1 are required.
+///
+/// https://github.com/multiformats/cid
+fn is_content_hash(value: &str) -> bool {
+    let cid = value
+        .strip_prefix("ipfs://")
+        .or_else(|| value.strip_prefix("ipns://"))
+        .unwrap_or(value);
+
+    if cid.len() == CID_V0_LENGTH && cid.starts_with("Qm") {
+        // The hash of CIDv0 is always a SHA2-256 multihash.
+        return match bs58::decode(cid).into_vec() {
+            Ok(bytes) => bytes.len() == 34 && bytes[..2] == [MULTIHASH_SHA2_256, 32],
+            Err(_) => false,
+        };
+    }
+
+    let bytes = match cid.as_bytes().split_first() {
+        Some((b'b', rest)) => decode_base32(rest),
+        Some((b'k', rest)) => decode_base_x(BASE36_ALPHABET, rest),
+        _ => None,
+    };
+    let bytes = match bytes {
+        Some(bytes) => bytes,
+        None => return false,
+    };
+
+    let mut rest = bytes.as_slice();
+    match read_varint(&mut rest) {
+        Some(CID_V1) => {}
+        _ => return false,
+    }
+    // The codec of the content, any codec is acceptable.
+    if read_varint(&mut rest).is_none() {
+        return false;
+    }
+
+    is_multihash(rest)
+}
+
+fn is_multihash(bytes: &[u8]) -> bool {
+    let mut rest = bytes;
+    match (read_varint(&mut rest), read_varint(&mut rest)) {
+        (Some(_code), Some(len)) => len > 0 && rest.len() as u64 == len,
+        _ => false,
+    }
+}
+
+/// Check the value is a URL like `scheme://authority/path?query#fragment`, only the scheme and the authority are
+/// required.
+///
+/// https://datatracker.ietf.org/doc/html/rfc3986#section-3
+fn is_url(value: &str) -> bool {
+    let (scheme, rest) = match value.split_once("://") {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let mut scheme_chars = scheme.bytes();
+    match scheme_chars.next() {
+        Some(byte) if byte.is_ascii_alphabetic() => {}
+        _ => return false,
+    }
+    if !scheme_chars.all(|byte| byte.is_ascii_alphanumeric() || b"+-.".contains(&byte)) {
+        return false;
+    }
+
+    // Whitespaces and control chars must be percent-encoded.
+    if !rest.bytes().all(|byte| byte.is_ascii_graphic()) {
+        return false;
+    }
+
+    let authority = match rest.find(|c| c == '/' || c == '?' || c == '#') {
+        Some(end) => &rest[..end],
+        None => rest,
+    };
+    let host_and_port = match authority.rsplit_once('@') {
+        Some((_, host_and_port)) => host_and_port,
+        None => authority,
+    };
+    let (host, port) = if host_and_port.starts_with('[') {
+        // IPv6 address
+        match host_and_port.split_once(']') {
+            Some((host, port)) => (&host[1..], port),
+            None => return false,
+        }
+    } else {
+        match host_and_port.split_once(':') {
+            Some((host, port)) => (host, port),
+            None => (host_and_port, ""),
+        }
+    };
+    let port = port.strip_prefix(':').unwrap_or(port);
+
+    !host.is_empty() && port.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut ret = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        ret |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            *bytes = &bytes[(i + 1)..];
+            return Some(ret);
+        }
+    }
+
+    None
+}
+
+/// Decode the base32 of RFC4648 in lowercase without padding.
+fn decode_base32(chars: &[u8]) -> Option<Vec<u8>> {
+    let mut ret = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for char in chars {
+        let value = BASE32_ALPHABET.iter().position(|item| item == char)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            ret.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(ret)
+}
+
+/// Decode the chars as a big-endian number in the base of the alphabet length, leading zero chars are kept as zero
+/// bytes.
+fn decode_base_x(alphabet: &[u8], chars: &[u8]) -> Option<Vec<u8>> {
+    let base = alphabet.len() as u32;
+    let mut ret: Vec<u8> = Vec::new();
+    for char in chars {
+        let mut carry = alphabet.iter().position(|item| item == char)? as u32;
+        for byte in ret.iter_mut().rev() {
+            carry += (*byte as u32) * base;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            ret.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let leading_zeros = chars.iter().take_while(|char| **char == alphabet[0]).count();
+    let mut zeros = alloc::vec![0u8; leading_zeros];
+    zeros.extend(ret);
+
+    Some(zeros)
+}
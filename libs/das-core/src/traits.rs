@@ -89,6 +89,8 @@ where
             // "DeviceKeyListEntityData" => DataType::DeviceKeyListEntityData,
             "SubAccountRenewSign" => DataType::SubAccountRenewSign,
             "DeviceKeyListCellData" => DataType::DeviceKeyListCellData,
+            "AccountListProof" => DataType::AccountListProof,
+            "CrossChainUnlockAttestation" => DataType::CrossChainUnlockAttestation,
             "ConfigCellAccount" => DataType::ConfigCellAccount,
             "ConfigCellApply" => DataType::ConfigCellApply,
             "ConfigCellIncome" => DataType::ConfigCellIncome,
@@ -105,6 +107,9 @@ where
             "ConfigCellSubAccountBetaList" => DataType::ConfigCellSubAccountBetaList,
             "ConfigCellSystemStatus" => DataType::ConfigCellSystemStatus,
             "ConfigCellSMTNodeWhitelist" => DataType::ConfigCellSMTNodeWhitelist,
+            "ConfigCellAccountList" => DataType::ConfigCellAccountList,
+            "ConfigCellConfusables" => DataType::ConfigCellConfusables,
+            "ConfigCellCrossChain" => DataType::ConfigCellCrossChain,
             "ConfigCellPreservedAccount00" => DataType::ConfigCellPreservedAccount00,
             "ConfigCellPreservedAccount01" => DataType::ConfigCellPreservedAccount01,
             "ConfigCellPreservedAccount02" => DataType::ConfigCellPreservedAccount02,
@@ -136,6 +141,10 @@ where
             "ConfigCellCharSetTr" => DataType::ConfigCellCharSetTr,
             "ConfigCellCharSetTh" => DataType::ConfigCellCharSetTh,
             "ConfigCellCharSetVi" => DataType::ConfigCellCharSetVi,
+            "ConfigCellCharSetAr" => DataType::ConfigCellCharSetAr,
+            "ConfigCellCharSetHi" => DataType::ConfigCellCharSetHi,
+            "ConfigCellCharSetHe" => DataType::ConfigCellCharSetHe,
+            "ConfigCellCharSetEl" => DataType::ConfigCellCharSetEl,
             "Config" => DataType::ConfigCellMain,
             _ => unreachable!(),
         }
@@ -5,11 +5,13 @@ use alloc::vec::Vec;
 use core::cell::OnceCell;
 use core::convert::TryFrom;
 
+use das_types::char_set::CharSetData;
 use das_types::constants::{
     CharSetType, DataType, CHAR_SET_LENGTH, WITNESS_HEADER_BYTES, WITNESS_LENGTH_BYTES, WITNESS_TYPE_BYTES,
 };
 use das_types::packed::*;
 use das_types::prelude::Entity;
+use das_types::record_key_namespace::RecordKeyNamespace;
 use das_types::util as das_types_util;
 
 use super::error::*;
@@ -36,7 +38,28 @@ macro_rules! get_or_try_init {
 pub struct CharSet {
     pub name: CharSetType,
     pub global: bool,
-    pub data: Vec<u8>,
+    pub chars: CharSetData,
+}
+
+impl CharSet {
+    /// Decode the data of ConfigCellCharSet* witnesses without the WITNESS_LENGTH_BYTES length header, both the legacy
+    /// and the indexed encodings are supported.
+    pub fn from_slice(name: CharSetType, slice: &[u8]) -> Result<Self, Box<dyn ScriptError>> {
+        let chars = CharSetData::from_slice(slice).map_err(|err| {
+            warn!("Decoding CharSet[{:?}] failed: {:?}", name, err);
+            code_to_error!(ErrorCode::ConfigCellWitnessDecodingError)
+        })?;
+
+        Ok(CharSet {
+            name,
+            global: chars.is_global(),
+            chars,
+        })
+    }
+
+    pub fn contains(&self, char: &[u8]) -> bool {
+        self.chars.contains(char)
+    }
 }
 
 #[derive(Debug)]
@@ -63,9 +86,10 @@ pub struct Configs {
     pub reverse_resolution: OnceCell<ConfigCellReverseResolution>,
     pub sub_account: OnceCell<ConfigCellSubAccount>,
     pub dpoint: OnceCell<ConfigCellDPoint>,
-    pub record_key_namespace: OnceCell<Vec<u8>>,
-    pub preserved_account: OnceCell<Vec<u8>>,
-    pub unavailable_account: OnceCell<Vec<u8>>,
+    pub account_list: OnceCell<ConfigCellAccountList>,
+    pub confusables: OnceCell<ConfigCellConfusables>,
+    pub cross_chain: OnceCell<ConfigCellCrossChain>,
+    pub record_key_namespace: OnceCell<RecordKeyNamespace>,
     pub sub_account_beta_list: OnceCell<Vec<u8>>,
     pub smt_node_white_list: OnceCell<Vec<[u8; 32]>>,
 }
@@ -87,9 +111,10 @@ impl Configs {
             reverse_resolution: OnceCell::new(),
             sub_account: OnceCell::new(),
             dpoint: OnceCell::new(),
+            account_list: OnceCell::new(),
+            confusables: OnceCell::new(),
+            cross_chain: OnceCell::new(),
             record_key_namespace: OnceCell::new(),
-            preserved_account: OnceCell::new(),
-            unavailable_account: OnceCell::new(),
             sub_account_beta_list: OnceCell::new(),
             smt_node_white_list: OnceCell::new(),
         }
@@ -182,56 +207,49 @@ impl Configs {
         get_or_try_init!(self, dpoint, ConfigCellDPoint, DataType::ConfigCellDPoint)
     }
 
-    pub fn record_key_namespace(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.record_key_namespace.get_or_try_init(|| {
-            let data_type = DataType::ConfigCellRecordKeyNamespace;
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
-            let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
-                None => {
-                    warn!("witnesses[{:>2}] The data of {:?} is empty.", i, data_type);
-                    return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
-                }
-            };
-
-            Ok(data)
-        })
+    pub fn account_list(&self) -> Result<ConfigCellAccountListReader, Box<dyn ScriptError>> {
+        get_or_try_init!(
+            self,
+            account_list,
+            ConfigCellAccountList,
+            DataType::ConfigCellAccountList
+        )
     }
 
-    pub fn preserved_account(&self, data_type: DataType) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.preserved_account.get_or_try_init(|| {
-            let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
-            let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
-                None => {
-                    warn!("witnesses[{:>2}] The data of {:?} is empty.", i, data_type);
-
-                    return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
-                }
-            };
+    pub fn confusables(&self) -> Result<ConfigCellConfusablesReader, Box<dyn ScriptError>> {
+        get_or_try_init!(
+            self,
+            confusables,
+            ConfigCellConfusables,
+            DataType::ConfigCellConfusables
+        )
+    }
 
-            Ok(data)
-        })
+    pub fn cross_chain(&self) -> Result<ConfigCellCrossChainReader, Box<dyn ScriptError>> {
+        get_or_try_init!(self, cross_chain, ConfigCellCrossChain, DataType::ConfigCellCrossChain)
     }
 
-    pub fn unavailable_account(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.unavailable_account.get_or_try_init(|| {
-            let data_type = DataType::ConfigCellUnAvailableAccount;
+    pub fn record_key_namespace(&self) -> Result<&RecordKeyNamespace, Box<dyn ScriptError>> {
+        self.record_key_namespace.get_or_try_init(|| {
+            let data_type = DataType::ConfigCellRecordKeyNamespace;
             let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
-                Some(data) => data.to_vec(),
+                Some(data) => data,
                 None => {
                     warn!("witnesses[{:>2}] The data of {:?} is empty.", i, data_type);
                     return Err(code_to_error!(ErrorCode::ConfigIsPartialMissing).into());
                 }
             };
 
-            Ok(data)
+            RecordKeyNamespace::from_slice(data).map_err(|err| {
+                warn!("witnesses[{:>2}] Decoding {:?} failed: {:?}", i, data_type, err);
+                code_to_error!(ErrorCode::ConfigCellWitnessDecodingError).into()
+            })
         })
     }
 
     pub fn sub_account_beta_list(&self) -> Result<&Vec<u8>, Box<dyn ScriptError>> {
-        self.unavailable_account.get_or_try_init(|| {
+        self.sub_account_beta_list.get_or_try_init(|| {
             let data_type = DataType::ConfigCellSubAccountBetaList;
             let (i, raw) = Self::parse_witness(&self.config_witnesses, data_type)?;
             let data = match raw.get(WITNESS_LENGTH_BYTES..) {
@@ -280,14 +298,7 @@ impl Configs {
                     raw.len()
                 );
 
-                let char_set = CharSet {
-                    name: char_set_type,
-                    // skip WITNESS_LENGTH_BYTES bytes length, and the WITNESS_LENGTH_BYTES+1 byte is global flag, then the following bytes is data
-                    global: raw.get(WITNESS_LENGTH_BYTES).unwrap() == &1u8,
-                    data: raw.get((WITNESS_LENGTH_BYTES + 1)..).unwrap().to_vec(),
-                };
-
-                Ok(char_set)
+                CharSet::from_slice(char_set_type, &raw[WITNESS_LENGTH_BYTES..])
             })
         })
     }
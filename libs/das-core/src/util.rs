@@ -17,8 +17,8 @@ use ckb_std::ckb_types::prelude::*;
 use ckb_std::error::SysError;
 use ckb_std::{high_level, syscalls};
 use das_types::constants::{
-    das_lock, get_das_lock_type_id, height_cell_type, quote_cell_type, super_lock, time_cell_type, Action, DasLockType,
-    DataType, LockRole, TypeScript, ACCOUNT_ID_LENGTH, WITNESS_HEADER,
+    das_lock, get_das_lock_type_id, height_cell_type, quote_cell_type, super_lock, time_cell_type, Action, CharSetType,
+    DasLockType, DataType, LockRole, TypeScript, ACCOUNT_ID_LENGTH, PROMOTION_ANY_CHAR_SET, WITNESS_HEADER,
 };
 use das_types::mixer::*;
 use das_types::packed::{self as das_packed};
@@ -26,6 +26,7 @@ pub use das_types::util::{hex_string, is_entity_eq, is_reader_eq};
 #[cfg(test)]
 use hex::FromHexError;
 use primitive_types::U256;
+use witness_parser::error::WitnessParserError;
 use witness_parser::traits::WitnessQueryable;
 use witness_parser::types::CellMeta;
 use witness_parser::WitnessesParserV1;
@@ -408,7 +409,7 @@ pub fn load_header(index: usize, source: Source) -> Result<Header, Box<dyn Scrip
     }
 }
 
-pub fn load_oracle_data(type_: OracleCellType) -> Result<u64, Box<dyn ScriptError>> {
+fn find_oracle_cells(type_: OracleCellType) -> Result<Vec<usize>, Box<dyn ScriptError>> {
     let type_script;
     match type_ {
         OracleCellType::Height => {
@@ -425,21 +426,17 @@ pub fn load_oracle_data(type_: OracleCellType) -> Result<u64, Box<dyn ScriptErro
         }
     }
 
-    // TODO Verify the lock script of the Cell.
-    // There must be one OracleCell in the cell_deps, no more and no less.
-    let ret = find_cells_by_script(ScriptType::Type, type_script.as_reader().into(), Source::CellDep)?;
-    das_assert!(
-        ret.len() == 1,
-        ErrorCode::OracleCellIsRequired,
-        "There should be one cell of {:?} in cell_deps, no more and no less, but {} found.",
-        type_,
-        ret.len()
-    );
+    // TODO Verify the lock script of the Cell when it is loaded by load_oracle_data, the OracleCells loaded by
+    // load_oracle_data_by_median are verified with ConfigCellMain.oracle.provider_locks.
+    find_cells_by_script(ScriptType::Type, type_script.as_reader().into(), Source::CellDep)
+}
 
-    debug!("cell_deps[{}] Parsing outputs_data of {:?}Cell ...", ret[0], type_);
+/// Parse the outputs_data of an OracleCell, it is the index of the oracle provider, the type of the OracleCell and the
+/// value in big-endian uint64.
+fn parse_oracle_cell_data(index: usize, type_: OracleCellType) -> Result<(u8, u64), Box<dyn ScriptError>> {
+    debug!("cell_deps[{}] Parsing outputs_data of {:?}Cell ...", index, type_);
 
-    // Read the passed timestamp from outputs_data of TimeCell
-    let data = load_cell_data(ret[0], Source::CellDep)?;
+    let data = load_cell_data(index, Source::CellDep)?;
     let data_in_uint = match data.get(2..) {
         Some(bytes) => {
             das_assert!(
@@ -456,7 +453,132 @@ pub fn load_oracle_data(type_: OracleCellType) -> Result<u64, Box<dyn ScriptErro
         }
     };
 
-    Ok(data_in_uint as u64)
+    Ok((data[0], data_in_uint))
+}
+
+pub fn load_oracle_data(type_: OracleCellType) -> Result<u64, Box<dyn ScriptError>> {
+    // There must be one OracleCell in the cell_deps, no more and no less.
+    let ret = find_oracle_cells(type_)?;
+    das_assert!(
+        ret.len() == 1,
+        ErrorCode::OracleCellIsRequired,
+        "There should be one cell of {:?} in cell_deps, no more and no less, but {} found.",
+        type_,
+        ret.len()
+    );
+
+    let (_, data_in_uint) = parse_oracle_cell_data(ret[0], type_)?;
+
+    Ok(data_in_uint)
+}
+
+/// Load the median of the values of the OracleCells with the given type in cell_deps.
+///
+/// Unlike `load_oracle_data`, the OracleCells of different oracle providers can be put in cell_deps together, and they
+/// are verified with ConfigCellMain.oracle:
+/// - there should be at least `min_cells` OracleCells;
+/// - every OracleCell should be locked by one of the `provider_locks` and each oracle provider can only provide one of
+/// them;
+/// - the headers of the OracleCells should be in header_deps, and none of them should be older than `max_age` comparing
+/// to the median of the TimeCells, because the header_deps can be chosen freely by the transaction builder.
+pub fn load_oracle_data_by_median(type_: OracleCellType) -> Result<u64, Box<dyn ScriptError>> {
+    let config_oracle = Config::get_instance().main()?.oracle();
+    let min_cells = u8::from(config_oracle.min_cells()).max(1) as usize;
+    let max_age = u32::from(config_oracle.max_age()) as u64;
+    let provider_locks = config_oracle.provider_locks();
+
+    let cells = find_oracle_cells(type_)?;
+    das_assert!(
+        cells.len() >= min_cells,
+        ErrorCode::OracleCellsNotEnough,
+        "There should be at least {} cells of {:?} in cell_deps, but {} found.",
+        min_cells,
+        type_,
+        cells.len()
+    );
+
+    let mut providers = Vec::new();
+    let mut values = Vec::new();
+    let mut created_at_list = Vec::new();
+    for index in cells.iter() {
+        // The oracle provider is identified by the lock of the OracleCell, not by the index in its data which can be
+        // filled by anyone.
+        let lock = high_level::load_cell_lock(*index, Source::CellDep)?;
+        let provider = match provider_locks
+            .iter()
+            .position(|provider_lock| provider_lock.as_slice() == lock.as_slice())
+        {
+            Some(provider) => provider,
+            None => {
+                warn!(
+                    "cell_deps[{}] The cell of {:?} is not locked by any oracle provider in ConfigCellMain.oracle.",
+                    index, type_
+                );
+                return Err(code_to_error!(ErrorCode::OracleCellProviderInvalid));
+            }
+        };
+        das_assert!(
+            !providers.contains(&provider),
+            ErrorCode::OracleCellIsDuplicated,
+            "cell_deps[{}] The cell of {:?} from the oracle provider {} is duplicated.",
+            index,
+            type_,
+            provider
+        );
+
+        let (_, value) = parse_oracle_cell_data(*index, type_)?;
+
+        let header = load_header(*index, Source::CellDep)?;
+        created_at_list.push(get_timestamp_from_header(header.as_reader()));
+        providers.push(provider);
+        values.push(value);
+    }
+
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    let median = if values.len() % 2 == 0 {
+        ((values[middle - 1] as u128 + values[middle] as u128) / 2) as u64
+    } else {
+        values[middle]
+    };
+
+    debug!(
+        "The median of the cells of {:?} is {}, values: {:?}",
+        type_, median, values
+    );
+
+    let current_timestamp = match type_ {
+        OracleCellType::Time => median,
+        _ => load_oracle_data_by_median(OracleCellType::Time)?,
+    };
+    for (index, created_at) in cells.iter().zip(created_at_list.iter()) {
+        das_assert!(
+            current_timestamp.saturating_sub(*created_at) <= max_age,
+            ErrorCode::OracleCellIsStale,
+            "cell_deps[{}] The cell of {:?} is too old.(created_at: {}, current_timestamp: {}, max_age: {})",
+            index,
+            type_,
+            created_at,
+            current_timestamp,
+            max_age
+        );
+    }
+
+    Ok(median)
+}
+
+/// Load the value of the OracleCells with the given type in cell_deps, it accepts both layouts of cell_deps:
+/// - only one OracleCell, the value is loaded the same as `load_oracle_data`;
+/// - multiple OracleCells of different oracle providers, the value is loaded the same as `load_oracle_data_by_median`.
+///
+/// It is for the scripts which may be executed in the same transaction with the ones requiring the median, so all of
+/// them read the same value.
+pub fn load_oracle_data_of_any_layout(type_: OracleCellType) -> Result<u64, Box<dyn ScriptError>> {
+    if find_oracle_cells(type_)?.len() > 1 {
+        load_oracle_data_by_median(type_)
+    } else {
+        load_oracle_data(type_)
+    }
 }
 
 pub fn load_cells_capacity(cells: &[usize], source: Source) -> Result<u64, Box<dyn ScriptError>> {
@@ -646,58 +768,6 @@ pub fn is_init_day(current_timestamp: u64) -> Result<(), Box<dyn ScriptError>> {
     Ok(())
 }
 
-pub fn is_account_id_in_collection(account_id: &[u8], collection: &[u8]) -> bool {
-    let length = collection.len();
-    if length <= 0 {
-        return false;
-    }
-
-    let first = &collection[0..20];
-    let last = &collection[length - 20..];
-
-    return if account_id < first {
-        debug!("The account is less than the first preserved account, skip.");
-        false
-    } else if account_id > last {
-        debug!("The account is bigger than the last preserved account, skip.");
-        false
-    } else {
-        let accounts_total = collection.len() / ACCOUNT_ID_LENGTH;
-        let mut start_account_index = 0;
-        let mut end_account_index = accounts_total - 1;
-
-        loop {
-            let mid_account_index = (start_account_index + end_account_index) / 2;
-            // debug!("mid_account_index = {:?}", mid_account_index);
-            let mid_account_start_byte_index = mid_account_index * ACCOUNT_ID_LENGTH;
-            let mid_account_end_byte_index = mid_account_start_byte_index + ACCOUNT_ID_LENGTH;
-            let mid_account_bytes = collection
-                .get(mid_account_start_byte_index..mid_account_end_byte_index)
-                .unwrap();
-
-            if mid_account_bytes < account_id {
-                start_account_index = mid_account_index + 1;
-                // debug!("<");
-            } else if mid_account_bytes > account_id {
-                // debug!(">");
-                end_account_index = if mid_account_index > 1 {
-                    mid_account_index - 1
-                } else {
-                    0
-                };
-            } else {
-                return true;
-            }
-
-            if start_account_index > end_account_index || end_account_index == 0 {
-                break;
-            }
-        }
-
-        false
-    };
-}
-
 pub fn calc_account_storage_capacity(
     config_account: das_packed::ConfigCellAccountReader,
     account_name_storage: u64,
@@ -723,10 +793,13 @@ pub fn calc_yearly_register_fee(usd_price: u64, quote: u64, discount: u32) -> Re
     // In order to improve the calculation accuracy of uint, here we have multiplied the numbers by a certain factor:
     // - 1 USDT is represented as 1_000_000 .
     // - 100% is represented as 10_000 .
-    // In order to improve the calculation accuracy, accuracy, division is calculated at the end.
+    // In order to improve the calculation accuracy, division is calculated at the end.
+    //
+    // The discount may be accumulated from several kinds of discounts, so it is limited to 100% here.
 
+    let discount = core::cmp::min(discount as u64, RATE_BASE);
     let total = U256::from(usd_price) * U256::from(ONE_CKB) / U256::from(quote);
-    let total_discount = total * U256::from(discount) / U256::from(10000u64);
+    let total_discount = total * U256::from(discount) / U256::from(RATE_BASE);
     let ret = total - total_discount;
 
     if ret > U256::from(u64::MAX) {
@@ -761,7 +834,7 @@ pub fn calc_duration_from_paid(
     let yearly_fee = U256::from(calc_yearly_register_fee(usd_price, quote, discount)?);
 
     // Original formula: duration = (paid / yearly_capacity) * DAYS_OF_YEAR * DAY_SEC
-    // In order to improve the calculation accuracy, accuracy, division is calculated at the end.
+    // In order to improve the calculation accuracy, division is calculated at the end.
     let ret = U256::from(paid) * U256::from(DAYS_OF_YEAR) * U256::from(DAY_SEC) / yearly_fee;
 
     if ret > U256::from(u64::MAX) {
@@ -771,6 +844,115 @@ pub fn calc_duration_from_paid(
     }
 }
 
+/// Get the char-set of an account, only if all characters belong to the same char-set will the char-set be returned.
+pub fn get_account_char_set(
+    account: das_packed::AccountCharsReader,
+) -> Result<Option<CharSetType>, Box<dyn ScriptError>> {
+    let mut account_char_set = None;
+    for char in account.iter() {
+        let char_set = CharSetType::try_from(char.char_set_name()).map_err(|_| ErrorCode::CharSetIsUndefined)?;
+        if account_char_set.is_none() {
+            account_char_set = Some(char_set);
+        } else if account_char_set != Some(char_set) {
+            return Ok(None);
+        }
+    }
+
+    Ok(account_char_set)
+}
+
+/// Get the biggest discount of the promotions which are active at the timestamp and match the account, 0 will be
+/// returned if there is no such promotion.
+pub fn get_promotion_discount(
+    promotions: das_packed::PromotionsReader,
+    account: das_packed::AccountCharsReader,
+    timestamp: u64,
+) -> Result<u32, Box<dyn ScriptError>> {
+    let account_length = account.len() as u32;
+    let account_char_set = get_account_char_set(account)?;
+
+    let mut ret = 0;
+    for (i, promotion) in promotions.iter().enumerate() {
+        let start_at = u64::from(promotion.start_at());
+        let end_at = u64::from(promotion.end_at());
+        let char_set = u32::from(promotion.char_set());
+        let min_length = u32::from(promotion.min_length());
+        let max_length = u32::from(promotion.max_length());
+        let discount = u32::from(promotion.discount());
+
+        if timestamp < start_at || timestamp >= end_at {
+            continue;
+        }
+        if account_length < min_length || (max_length != 0 && account_length > max_length) {
+            continue;
+        }
+        if char_set != PROMOTION_ANY_CHAR_SET && account_char_set.map(|val| val as u32) != Some(char_set) {
+            continue;
+        }
+
+        debug!(
+            "The account matches the promotions[{}].(start_at: {}, end_at: {}, char_set: {}, length: {}..={}, discount: {})",
+            i, start_at, end_at, char_set, min_length, max_length, discount
+        );
+
+        if discount > ret {
+            ret = discount;
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Apply the discount to the price in USD, the discount is limited to 100%.
+pub fn calc_discounted_price(usd_price: u64, discount: u32) -> u64 {
+    let discount = core::cmp::min(discount as u64, RATE_BASE);
+    (usd_price as u128 * (RATE_BASE - discount) as u128 / RATE_BASE as u128) as u64
+}
+
+/// Get the discount of the highest tier of the multi-year discounts whose years are fully covered by the duration, 0 will
+/// be returned if no tier is reached.
+///
+/// The duration may depend on the discount itself, so it is calculated by `duration_of` with the discount of each tier.
+pub fn get_multi_year_discount(
+    multi_year_discounts: das_packed::MultiYearDiscountsReader,
+    duration_of: impl Fn(u32) -> Result<u64, Box<dyn ScriptError>>,
+) -> Result<u32, Box<dyn ScriptError>> {
+    let mut ret = (0, 0);
+    for tier in multi_year_discounts.iter() {
+        let tier_years = u32::from(tier.years()) as u64;
+        let tier_discount = u32::from(tier.discount());
+        if tier_years < ret.0 {
+            continue;
+        }
+
+        let years = duration_of(tier_discount)? / (DAYS_OF_YEAR * DAY_SEC);
+        if years >= tier_years {
+            ret = (tier_years, tier_discount);
+        }
+    }
+
+    Ok(ret.1)
+}
+
+pub fn calc_paid_from_duration(
+    duration: u64,
+    usd_price: u64,
+    quote: u64,
+    discount: u32,
+) -> Result<u64, Box<dyn ScriptError>> {
+    let yearly_fee = U256::from(calc_yearly_register_fee(usd_price, quote, discount)?);
+
+    // Original formula: paid = yearly_capacity * duration / (DAYS_OF_YEAR * DAY_SEC)
+    // In order to improve the calculation accuracy, division is calculated at the end.
+    let ret = yearly_fee * U256::from(duration) / (U256::from(DAYS_OF_YEAR) * U256::from(DAY_SEC));
+
+    if ret > U256::from(u64::MAX) {
+        Err(code_to_error!(ErrorCode::OverflowError))
+    } else {
+        Ok(ret.as_u64())
+    }
+}
+
 pub fn require_type_script(
     type_script: TypeScript,
     source: Source,
@@ -935,6 +1117,232 @@ pub fn get_sub_account_name_from_reader<'a>(sub_account_reader: &Box<dyn SubAcco
     String::from_utf8(account).unwrap()
 }
 
+/// Get the approval of the sub-account, the SubAccountV1 has no approval so None is returned.
+pub fn get_sub_account_approval<'r>(
+    sub_account_reader: &dyn SubAccountReaderMixer<'r>,
+) -> Option<das_packed::AccountApprovalReader<'r>> {
+    match sub_account_reader.version() {
+        1 => None,
+        2 => sub_account_reader.try_into_v2().ok().map(|reader| reader.approval()),
+        _ => sub_account_reader
+            .try_into_latest()
+            .ok()
+            .map(|reader| reader.approval()),
+    }
+}
+
+/// Parse the DeviceKeyListCellData of any version, the DeviceKeyListCellDataV1 is upgraded with no expirations.
+pub fn parse_device_key_list_cell_data(bytes: &[u8]) -> Option<das_packed::DeviceKeyListCellData> {
+    if let Ok(data) = das_packed::DeviceKeyListCellData::from_compatible_slice(bytes) {
+        return Some(data);
+    }
+
+    das_packed::DeviceKeyListCellDataV1::from_slice(bytes).ok().map(|data| {
+        das_packed::DeviceKeyListCellData::new_builder()
+            .keys(data.keys())
+            .refund_lock(data.refund_lock())
+            .build()
+    })
+}
+
+/// Build the DeviceKeyListCellDataV1 with only the keys which are not expired, it is the layout the sign lib parses
+/// when validating the signatures of device keys.
+///
+/// The TimeCell is only required when there is any expiration in the key list.
+pub fn get_unexpired_device_key_list(
+    device_key_list: &das_packed::DeviceKeyListCellData,
+) -> Result<das_packed::DeviceKeyListCellDataV1, Box<dyn ScriptError>> {
+    let expirations = device_key_list.expirations();
+    let keys = if expirations.is_empty() {
+        device_key_list.keys()
+    } else {
+        let timestamp = load_oracle_data(OracleCellType::Time)?;
+        let mut keys_builder = das_packed::DeviceKeyList::new_builder();
+        for key in device_key_list.keys().into_iter() {
+            let is_expired = expirations.clone().into_iter().any(|expiration| {
+                is_reader_eq(expiration.key().as_reader(), key.as_reader())
+                    && u64::from(expiration.expired_at()) <= timestamp
+            });
+            if !is_expired {
+                keys_builder = keys_builder.push(key);
+            }
+        }
+        keys_builder.build()
+    };
+
+    Ok(das_packed::DeviceKeyListCellDataV1::new_builder()
+        .keys(keys)
+        .refund_lock(device_key_list.refund_lock())
+        .build())
+}
+
+/// Find the chain in ConfigCellCrossChain.chains, only the chains which are listed and enabled can be locked to.
+pub fn find_enabled_cross_chain<'r>(
+    config: das_packed::ConfigCellCrossChainReader<'r>,
+    coin_type: u64,
+    chain_id: u64,
+) -> Result<das_packed::CrossChainReader<'r>, Box<dyn ScriptError>> {
+    let chains = config.chains();
+    let chain = match (0..chains.len())
+        .map(|i| chains.get_unchecked(i))
+        .find(|chain| u64::from(chain.coin_type()) == coin_type && u64::from(chain.chain_id()) == chain_id)
+    {
+        Some(chain) => chain,
+        None => {
+            warn!(
+                "The chain is not supported by ConfigCellCrossChain.chains. (coin_type: {}, chain_id: {})",
+                coin_type, chain_id
+            );
+            return Err(code_to_error!(ErrorCode::CrossChainNotSupported));
+        }
+    };
+
+    das_assert!(
+        u8::from(chain.enabled()) == 1,
+        ErrorCode::CrossChainDisabled,
+        "The chain is disabled currently. (coin_type: {}, chain_id: {})",
+        coin_type,
+        chain_id
+    );
+
+    Ok(chain)
+}
+
+/// Calculate the equivalent quote of a UDT in ConfigCellPrice.udt_payments, so the amount of the UDT can be calculated
+/// from the USD prices directly by the same functions as CKB.
+///
+/// The UDT is pegged to USD, so `usd_price * 10^decimals / ONE_USD` should be equal to `usd_price * ONE_CKB / quote`.
+pub fn calc_udt_quote(decimals: u8) -> Result<u64, Box<dyn ScriptError>> {
+    let base = ONE_USD as u128 * ONE_CKB as u128;
+    let unit = 10u128.checked_pow(decimals as u32).unwrap_or(u128::MAX);
+
+    das_assert!(
+        unit <= base,
+        ErrorCode::UdtPaymentNotSupported,
+        "The decimals of the UDT should be less than or equal to 14. (current: {})",
+        decimals
+    );
+
+    Ok((base / unit) as u64)
+}
+
+/// Convert the amount of a UDT or DPoint into the equivalent amount of CKB with their quotes.
+pub fn calc_udt_value_in_ckb(amount: u64, udt_quote: u64, ckb_quote: u64) -> Result<u64, Box<dyn ScriptError>> {
+    das_assert!(
+        ckb_quote > 0,
+        ErrorCode::OracleCellDataDecodingError,
+        "The quote of CKB should be greater than 0."
+    );
+
+    let value = amount as u128 * udt_quote as u128 / ckb_quote as u128;
+
+    das_assert!(
+        value <= u64::MAX as u128,
+        ErrorCode::OverflowError,
+        "The equivalent amount of CKB is overflowed. (amount: {}, udt_quote: {}, ckb_quote: {})",
+        amount,
+        udt_quote,
+        ckb_quote
+    );
+
+    Ok(value as u64)
+}
+
+/// Find the UDT in ConfigCellPrice.udt_payments which has exactly the same type script.
+pub fn find_udt_payment<'r>(
+    config: das_packed::ConfigCellPriceReader<'r>,
+    type_script: das_packed::ScriptReader,
+) -> Result<das_packed::UdtPaymentReader<'r>, Box<dyn ScriptError>> {
+    let udt_payments = config.udt_payments();
+    match (0..udt_payments.len())
+        .map(|i| udt_payments.get_unchecked(i))
+        .find(|udt_payment| is_reader_eq(udt_payment.type_script(), type_script))
+    {
+        Some(udt_payment) => Ok(udt_payment),
+        None => {
+            warn!(
+                "The UDT is not supported by ConfigCellPrice.udt_payments. (type_script: {})",
+                type_script
+            );
+            Err(code_to_error!(ErrorCode::UdtPaymentNotSupported))
+        }
+    }
+}
+
+/// Load the amount of a UDT cell, it is the first 16 bytes of the outputs_data in little-endian.
+pub fn load_udt_amount(index: usize, source: Source) -> Result<u128, Box<dyn ScriptError>> {
+    let data = high_level::load_cell_data(index, source)?;
+
+    das_assert!(
+        data.len() >= 16,
+        ErrorCode::InvalidCellData,
+        "{:?}[{}] The outputs_data of a UDT cell should be at least 16 bytes.",
+        source,
+        index
+    );
+
+    Ok(u128::from_le_bytes(data[0..16].try_into().unwrap()))
+}
+
+/// Find the UDT cells with the lock and load the total amount of them.
+pub fn load_udt_amount_of_lock(
+    udt_type: das_packed::ScriptReader,
+    lock: ScriptReader,
+    source: Source,
+) -> Result<(Vec<usize>, u128), Box<dyn ScriptError>> {
+    let mut cells = Vec::new();
+    let mut total = 0u128;
+    for i in find_cells_by_script(ScriptType::Type, udt_type.into(), source)? {
+        let cell_lock = high_level::load_cell_lock(i, source)?;
+        if is_reader_eq(cell_lock.as_reader(), lock) {
+            total = total
+                .checked_add(load_udt_amount(i, source)?)
+                .ok_or(code_to_error!(ErrorCode::OverflowError))?;
+            cells.push(i);
+        }
+    }
+
+    Ok((cells, total))
+}
+
+/// Find out the UDT paid to the receiver in the transaction, only one of the UDTs in ConfigCellPrice.udt_payments can be
+/// paid in one transaction.
+pub fn load_udt_paid<'r>(
+    config: das_packed::ConfigCellPriceReader<'r>,
+    receiver: ScriptReader,
+) -> Result<Option<(das_packed::UdtPaymentReader<'r>, u64)>, Box<dyn ScriptError>> {
+    let udt_payments = config.udt_payments();
+    let mut ret = None;
+    for udt_payment in (0..udt_payments.len()).map(|i| udt_payments.get_unchecked(i)) {
+        let (_, input_amount) = load_udt_amount_of_lock(udt_payment.type_script(), receiver, Source::Input)?;
+        let (_, output_amount) = load_udt_amount_of_lock(udt_payment.type_script(), receiver, Source::Output)?;
+
+        das_assert!(
+            output_amount >= input_amount,
+            ErrorCode::UdtPaymentError,
+            "The UDT of the receiver should not decrease. (type_script: {}, inputs: {}, outputs: {})",
+            udt_payment.type_script(),
+            input_amount,
+            output_amount
+        );
+
+        if output_amount == input_amount {
+            continue;
+        }
+
+        das_assert!(
+            ret.is_none(),
+            ErrorCode::UdtPaymentError,
+            "Only one kind of UDT can be paid in one transaction."
+        );
+
+        let paid = u64::try_from(output_amount - input_amount).map_err(|_| code_to_error!(ErrorCode::OverflowError))?;
+        ret = Some((udt_payment, paid));
+    }
+
+    Ok(ret)
+}
+
 pub fn parse_income_cell_witness(
     index: usize,
     source: Source,
@@ -991,6 +1399,43 @@ pub fn parse_proposal_cell_witness(
     Ok(ret)
 }
 
+pub fn parse_account_list_proof_witness() -> Result<das_packed::AccountListProof, Box<dyn ScriptError>> {
+    let parser = WitnessesParserV1::get_instance();
+    let ret = parser
+        .get_entity_by_data_type::<das_packed::AccountListProof>(DataType::AccountListProof)
+        .map_err(|err| match err {
+            WitnessParserError::CanNotFindWitnessByDataType { .. } => {
+                warn!("The AccountListProof witness is required.");
+                ErrorCode::AccountListProofIsRequired
+            }
+            _ => {
+                warn!("Decoding AccountListProof failed: {:?}", err);
+                ErrorCode::WitnessEntityDecodingError
+            }
+        })?;
+
+    Ok(ret)
+}
+
+pub fn parse_cross_chain_unlock_attestation_witness(
+) -> Result<das_packed::CrossChainUnlockAttestation, Box<dyn ScriptError>> {
+    let parser = WitnessesParserV1::get_instance();
+    let ret = parser
+        .get_entity_by_data_type::<das_packed::CrossChainUnlockAttestation>(DataType::CrossChainUnlockAttestation)
+        .map_err(|err| match err {
+            WitnessParserError::CanNotFindWitnessByDataType { .. } => {
+                warn!("The CrossChainUnlockAttestation witness is required.");
+                ErrorCode::CrossChainUnlockAttestationIsRequired
+            }
+            _ => {
+                warn!("Decoding CrossChainUnlockAttestation failed: {:?}", err);
+                ErrorCode::WitnessEntityDecodingError
+            }
+        })?;
+
+    Ok(ret)
+}
+
 pub fn parse_pre_account_cell_witness(
     index: usize,
     source: Source,
@@ -1027,6 +1472,14 @@ pub fn parse_pre_account_cell_witness(
                 })?,
         ),
         3 => Box::new(
+            parser
+                .get_entity_by_cell_meta::<das_packed::PreAccountCellDataV3>(cell_meta)
+                .map_err(|_| {
+                    warn!("{:?}[{}] Decoding PreAccountCellDataV3 failed", source, index);
+                    ErrorCode::WitnessEntityDecodingError
+                })?,
+        ),
+        4 => Box::new(
             parser
                 .get_entity_by_cell_meta::<das_packed::PreAccountCellData>(cell_meta)
                 .map_err(|_| {
@@ -1043,6 +1496,17 @@ pub fn parse_pre_account_cell_witness(
     Ok(ret)
 }
 
+/// Get the PreAccountCell.paid_udt, None will be returned if the registration is paid in CKB or the PreAccountCell is
+/// in an old version.
+pub fn get_pre_account_cell_paid_udt<'r, 'a>(
+    reader: &Box<dyn PreAccountCellDataReaderMixer<'r> + 'a>,
+) -> Option<das_packed::PaidUdtReader<'r>> {
+    reader
+        .try_into_latest()
+        .ok()
+        .and_then(|reader| reader.paid_udt().to_opt())
+}
+
 pub fn parse_account_cell_witness(
     index: usize,
     source: Source,
@@ -1083,6 +1547,14 @@ pub fn parse_account_cell_witness(
                 })?,
         ),
         4 => Box::new(
+            parser
+                .get_entity_by_cell_meta::<das_packed::AccountCellDataV4>(cell_meta)
+                .map_err(|_| {
+                    warn!("{:?}[{}] Decoding AccountCellDataV4 failed", source, index);
+                    ErrorCode::WitnessEntityDecodingError
+                })?,
+        ),
+        5 => Box::new(
             parser
                 .get_entity_by_cell_meta::<das_packed::AccountCellData>(cell_meta)
                 .map_err(|_| {
@@ -1272,6 +1744,49 @@ pub fn get_spent_dpoint_by_lock(
     Ok(total_input - total_output)
 }
 
+/// Check if the script is the type script of DPointCells.
+pub fn is_dpoint_type(config_main: das_packed::ConfigCellMainReader, script: das_packed::ScriptReader) -> bool {
+    let dpoint_type = type_id_to_script(config_main.type_id_table().dpoint_cell());
+    is_type_id_equal(dpoint_type.as_reader().into(), script.into())
+}
+
+/// Find out the DPoint transferred to the locks in ConfigCellDPoint.transfer_whitelist in the transaction.
+///
+/// The dpoint-cell-type ensures the DPoint can only be transferred from one payer to the whitelist in the actions of
+/// payments, so the DPoint received by the whitelist is exactly the DPoint paid by the payer.
+pub fn load_dpoint_paid_to_whitelist() -> Result<u64, Box<dyn ScriptError>> {
+    let config_main = Config::get_instance().main()?;
+    let (input_cells, output_cells) =
+        find_cells_by_type_id_in_inputs_and_outputs(ScriptType::Type, config_main.type_id_table().dpoint_cell())?;
+    // The ConfigCellDPoint is only required when there is any DPointCell in the transaction.
+    if input_cells.is_empty() && output_cells.is_empty() {
+        return Ok(0);
+    }
+
+    let config_dpoint = Config::get_instance().dpoint()?;
+    let transfer_whitelist = config_dpoint.transfer_whitelist();
+    let mut total = 0u64;
+    for lock in (0..transfer_whitelist.len()).map(|i| transfer_whitelist.get_unchecked(i)) {
+        let input_dp = get_total_dpoint_by_lock(lock.into(), &input_cells, Source::Input)?;
+        let output_dp = get_total_dpoint_by_lock(lock.into(), &output_cells, Source::Output)?;
+
+        das_assert!(
+            output_dp >= input_dp,
+            ErrorCode::DPointPaymentError,
+            "The DPoint of the locks in the transfer whitelist should not decrease. (lock: {}, inputs: {}, outputs: {})",
+            lock,
+            input_dp,
+            output_dp
+        );
+
+        total = total
+            .checked_add(output_dp - input_dp)
+            .ok_or(code_to_error!(ErrorCode::OverflowError))?;
+    }
+
+    Ok(total)
+}
+
 // use ethnum::U256;
 //
 // const BIT1: U256 = U256::from_words(0, 999989423469314432);
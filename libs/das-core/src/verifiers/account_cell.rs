@@ -2,14 +2,19 @@ use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::convert::TryFrom;
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::high_level;
+use das_dynamic_libs::constants::DynLibName;
+use das_dynamic_libs::sign_lib::SignLib;
+use das_dynamic_libs::{load_2_methods, load_and_configure_lib, load_lib, log_loading, new_context};
 use das_types::constants::{das_lock, *};
 use das_types::mixer::AccountCellDataReaderMixer;
 use das_types::packed::*;
 use das_types::util as types_util;
+use molecule::prelude::Entity;
 
 use crate::config::Config;
 use crate::constants::*;
@@ -17,7 +22,7 @@ use crate::error::*;
 #[cfg(debug_assertions)]
 use crate::util::print_dp;
 use crate::util::{blake2b_256, find_cells_by_script};
-use crate::{data_parser, util};
+use crate::{data_parser, record_value, util};
 
 pub fn verify_unlock_role(action: Action, role: Option<LockRole>) -> Result<(), Box<dyn ScriptError>> {
     let required_role_opt = types_util::get_action_required_sign_role(action);
@@ -365,7 +370,7 @@ pub fn verify_account_witness_consistent<'a>(
         2 => {
             // The output witness should be upgraded to the latest version.
             das_assert!(
-                output_witness_reader.version() == 4,
+                output_witness_reader.version() == 5,
                 ErrorCode::UpgradeForWitnessIsRequired,
                 "The witness of outputs[{}] should be upgraded to latest version.",
                 output_index
@@ -394,11 +399,20 @@ pub fn verify_account_witness_consistent<'a>(
                     output_index
                 )
             }
+
+            if !except.contains(&"cross_chain") {
+                das_assert!(
+                    util::is_reader_eq(output_witness_reader.cross_chain(), ChainId::default().as_reader()),
+                    AccountCellErrorCode::AccountCellProtectFieldIsModified,
+                    "outputs[{}] The witness.cross_chain should be default value.",
+                    output_index
+                )
+            }
         }
         3 => {
             // The output witness should be upgraded to the latest version.
             das_assert!(
-                output_witness_reader.version() == 4,
+                output_witness_reader.version() == 5,
                 ErrorCode::UpgradeForWitnessIsRequired,
                 "The witness of outputs[{}] should be upgraded to latest version.",
                 output_index
@@ -427,6 +441,49 @@ pub fn verify_account_witness_consistent<'a>(
                     output_index
                 )
             }
+
+            if !except.contains(&"cross_chain") {
+                das_assert!(
+                    util::is_reader_eq(output_witness_reader.cross_chain(), ChainId::default().as_reader()),
+                    AccountCellErrorCode::AccountCellProtectFieldIsModified,
+                    "outputs[{}] The witness.cross_chain should be default value.",
+                    output_index
+                )
+            }
+        }
+        4 => {
+            // The output witness should be upgraded to the latest version.
+            das_assert!(
+                output_witness_reader.version() == 5,
+                ErrorCode::UpgradeForWitnessIsRequired,
+                "The witness of outputs[{}] should be upgraded to latest version.",
+                output_index
+            );
+
+            // Verify if the new fields is consistent.
+            let input_witness_reader = input_witness_reader
+                .try_into_v4()
+                .map_err(|_| ErrorCode::NarrowMixerTypeFailed)?;
+            let output_witness_reader = output_witness_reader
+                .try_into_latest()
+                .map_err(|_| ErrorCode::NarrowMixerTypeFailed)?;
+
+            das_assert_field_consistent_if_not_except!(
+                input_witness_reader,
+                output_witness_reader,
+                (enable_sub_account, "enable_sub_account"),
+                (renew_sub_account_price, "renew_sub_account_price"),
+                (approval, "approval")
+            );
+
+            if !except.contains(&"cross_chain") {
+                das_assert!(
+                    util::is_reader_eq(output_witness_reader.cross_chain(), ChainId::default().as_reader()),
+                    AccountCellErrorCode::AccountCellProtectFieldIsModified,
+                    "outputs[{}] The witness.cross_chain should be default value.",
+                    output_index
+                )
+            }
         }
         _ => {
             // Verify if the new fields is consistent.
@@ -442,7 +499,8 @@ pub fn verify_account_witness_consistent<'a>(
                 output_witness_reader,
                 (enable_sub_account, "enable_sub_account"),
                 (renew_sub_account_price, "renew_sub_account_price"),
-                (approval, "approval")
+                (approval, "approval"),
+                (cross_chain, "cross_chain")
             );
         }
     }
@@ -475,6 +533,27 @@ pub fn verify_account_witness_record_empty<'a>(
     Ok(())
 }
 
+pub fn verify_account_witness_cross_chain_empty<'a>(
+    account_cell_witness_reader: &Box<dyn AccountCellDataReaderMixer + 'a>,
+    cell_index: usize,
+    source: Source,
+) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Check if AccountCell.witness.cross_chain is empty.");
+
+    // The cross_chain field only exists since the latest version.
+    if let Ok(reader) = account_cell_witness_reader.try_into_latest() {
+        das_assert!(
+            util::is_reader_eq(reader.cross_chain(), ChainId::default().as_reader()),
+            AccountCellErrorCode::AccountCellProtectFieldIsModified,
+            "{:?}[{}]The AccountCell.witness.cross_chain should be default value.",
+            source,
+            cell_index
+        );
+    }
+
+    Ok(())
+}
+
 pub fn verify_account_no_other_type_cell_use_das_lock_in_inputs(
     type_id_table: TypeIdTableReader,
 ) -> Result<(), Box<dyn ScriptError>> {
@@ -620,6 +699,12 @@ pub fn verify_sub_account_enabled<'a>(
                 .map_err(|_| ErrorCode::NarrowMixerTypeFailed)?;
             reader.enable_sub_account()
         }
+        4 => {
+            let reader = account_cell_witness_reader
+                .try_into_v4()
+                .map_err(|_| ErrorCode::NarrowMixerTypeFailed)?;
+            reader.enable_sub_account()
+        }
         _ => {
             let reader = account_cell_witness_reader
                 .try_into_latest()
@@ -663,23 +748,49 @@ pub fn verify_account_cell_consistent_with_exception<'a>(
     Ok(())
 }
 
+fn load_account_list_proof(account_id: &[u8]) -> Result<AccountListProof, Box<dyn ScriptError>> {
+    let proof = util::parse_account_list_proof_witness()?;
+
+    das_assert!(
+        proof.as_reader().account_id().raw_data() == account_id,
+        ErrorCode::AccountIdIsInvalid,
+        "The AccountListProof.account_id should be 0x{}, but 0x{} found.",
+        util::hex_string(account_id),
+        util::hex_string(proof.as_reader().account_id().raw_data())
+    );
+
+    Ok(proof)
+}
+
+/// Check if the account is in the SMT of an account list, the proof can be either a non-membership proof or a membership
+/// proof, any other proofs will be treated as invalid.
+fn is_account_in_account_list(account_id: &[u8], root: &[u8], proof: &[u8]) -> Result<bool, Box<dyn ScriptError>> {
+    let key = types_util::gen_account_list_smt_key(account_id);
+    let root: [u8; 32] = root.try_into().unwrap();
+
+    if super::common::verify_smt_proof(key, [0u8; 32], root, proof).is_ok() {
+        return Ok(false);
+    }
+
+    let value = types_util::gen_account_list_smt_value(account_id);
+    super::common::verify_smt_proof(key, value, root, proof)?;
+
+    Ok(true)
+}
+
 pub fn verify_preserved_accounts(account: &[u8]) -> Result<(), Box<dyn ScriptError>> {
     debug!("Verify if account is preserved.");
 
     let account_hash = util::blake2b_256(account);
     let account_id = account_hash.get(..ACCOUNT_ID_LENGTH).unwrap();
-    let index = (account_id[0] % PRESERVED_ACCOUNT_CELL_COUNT) as usize;
-    let data_type = types_util::preserved_accounts_group_to_data_type(index);
-    let preserved_accounts = Config::get_instance().preserved_account(data_type)?;
-
-    // debug!(
-    //     "account: {}, account ID: {:?}, data_type: {:?}",
-    //     String::from_utf8(account.to_vec()).unwrap(),
-    //     account_id,
-    //     data_type
-    // );
-
-    if util::is_account_id_in_collection(account_id, preserved_accounts) {
+    let config_account_list = Config::get_instance().account_list()?;
+    let proof = load_account_list_proof(account_id)?;
+
+    if is_account_in_account_list(
+        account_id,
+        config_account_list.preserved_account_root().raw_data(),
+        proof.as_reader().preserved_account_proof().raw_data(),
+    )? {
         warn!(
             "Account {} is preserved. (hex: 0x{}, hash: 0x{})",
             String::from_utf8(account.to_vec()).unwrap(),
@@ -698,9 +809,14 @@ pub fn verify_unavailable_accounts(account: &[u8]) -> Result<(), Box<dyn ScriptE
 
     let account_hash = util::blake2b_256(account);
     let account_id = account_hash.get(..ACCOUNT_ID_LENGTH).unwrap();
-    let unavailable_accounts = Config::get_instance().unavailable_account()?;
-
-    if util::is_account_id_in_collection(account_id, unavailable_accounts) {
+    let config_account_list = Config::get_instance().account_list()?;
+    let proof = load_account_list_proof(account_id)?;
+
+    if is_account_in_account_list(
+        account_id,
+        config_account_list.unavailable_account_root().raw_data(),
+        proof.as_reader().unavailable_account_proof().raw_data(),
+    )? {
         warn!(
             "Account {} is unavailable. (hex: 0x{}, hash: 0x{})",
             String::from_utf8(account.to_vec()).unwrap(),
@@ -748,41 +864,23 @@ pub fn verify_account_chars(chars_reader: AccountCharsReader) -> Result<(), Box<
         }
     }
 
-    let tmp = vec![0u8];
-    let mut required_char_sets = vec![tmp.as_slice(); CHAR_SET_LENGTH];
     for account_char in chars_reader.iter() {
         let char_set_index = u32::from(account_char.char_set_name()) as usize;
-        if required_char_sets[char_set_index].len() <= 1 {
-            let char_set = match Config::get_instance().char_set(char_set_index) {
-                Some(Ok(char_set)) => char_set,
-                Some(Err(err)) => {
-                    return Err(err);
-                }
-                None => {
-                    warn!("[2] Chan not found CharSet[{}].", char_set_index);
-                    return Err(code_to_error!(ErrorCode::CharSetIsUndefined));
-                }
-            };
-            required_char_sets[char_set_index] = char_set.data.as_slice();
-        }
-
-        let account_char_bytes = account_char.bytes().raw_data();
-        let mut found = false;
-        let mut from = 0;
-        for (i, item) in required_char_sets[char_set_index].iter().enumerate() {
-            if item == &0 {
-                let char_bytes = required_char_sets[char_set_index].get(from..i).unwrap();
-                if account_char_bytes == char_bytes {
-                    found = true;
-                    break;
-                }
-
-                from = i + 1;
+        // The char-sets have been loaded by the loop above, so here is only a lookup in the cache.
+        let char_set = match Config::get_instance().char_set(char_set_index) {
+            Some(Ok(char_set)) => char_set,
+            Some(Err(err)) => {
+                return Err(err);
             }
-        }
+            None => {
+                warn!("[2] Chan not found CharSet[{}].", char_set_index);
+                return Err(code_to_error!(ErrorCode::CharSetIsUndefined));
+            }
+        };
 
+        let account_char_bytes = account_char.bytes().raw_data();
         das_assert!(
-            found,
+            char_set.contains(account_char_bytes),
             ErrorCode::AccountCharIsInvalid,
             "The character {}(utf-8: 0x{}) can not be used in account, because it is not contained by CharSet[{}].",
             String::from_utf8(account_char_bytes.to_vec()).unwrap(),
@@ -794,6 +892,68 @@ pub fn verify_account_chars(chars_reader: AccountCharsReader) -> Result<(), Box<
     Ok(())
 }
 
+/// Verify if the account looks the same as a protected account.
+///
+/// The skeleton of the account is generated by replacing its confusable chars with their prototypes, if any char is
+/// replaced and the hash of the skeleton is one of the protected skeletons, the account is rejected.
+pub fn verify_account_confusables(chars_reader: AccountCharsReader) -> Result<(), Box<dyn ScriptError>> {
+    let config = Config::get_instance().confusables()?;
+
+    let mut skeleton = Vec::new();
+    let mut is_replaced = false;
+    for account_char in chars_reader.iter() {
+        let char_bytes = account_char.bytes().raw_data();
+        match find_confusable_prototype(config.chars(), char_bytes) {
+            Some(prototype) => {
+                skeleton.extend_from_slice(prototype);
+                is_replaced = true;
+            }
+            None => skeleton.extend_from_slice(char_bytes),
+        }
+    }
+
+    if !is_replaced {
+        debug!("The account contains no confusable chars, skip the skeleton verification.");
+        return Ok(());
+    }
+
+    let skeleton_hash = blake2b_256(&skeleton);
+    let protected_skeletons = config.protected_skeletons();
+    let (mut low, mut high) = (0, protected_skeletons.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match protected_skeletons.get(mid).unwrap().raw_data().cmp(&skeleton_hash[..]) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => {
+                warn!(
+                    "The account {} looks the same as the protected account {}.",
+                    String::from_utf8_lossy(&chars_reader.as_readable()),
+                    String::from_utf8_lossy(&skeleton)
+                );
+                return Err(code_to_error!(ErrorCode::AccountIsConfusable));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn find_confusable_prototype<'a>(chars: ConfusableCharsReader<'a>, char: &[u8]) -> Option<&'a [u8]> {
+    let (mut low, mut high) = (0, chars.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let item = chars.get(mid).unwrap();
+        match item.char().raw_data().cmp(char) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(item.prototype().raw_data()),
+        }
+    }
+
+    None
+}
+
 pub fn verify_account_chars_max_length(chars_reader: AccountCharsReader) -> Result<(), Box<dyn ScriptError>> {
     let config = Config::get_instance().account()?;
     let max_chars_length = u32::from(config.max_length());
@@ -825,12 +985,18 @@ pub fn verify_account_chars_min_length(chars_reader: AccountCharsReader) -> Resu
     Ok(())
 }
 
-pub fn verify_records_keys(records: RecordsReader) -> Result<(), Box<dyn ScriptError>> {
+/// Verify the keys of all records, the values and TTLs are only verified for the records which are added or changed
+/// comparing to the prev_records, so the records created under an older namespace are not blocking the editing.
+pub fn verify_records_keys(
+    records: RecordsReader,
+    prev_records: Option<RecordsReader>,
+) -> Result<(), Box<dyn ScriptError>> {
     debug!("Check if records keys are available.");
 
     let config_account = Config::get_instance().account()?;
     let record_key_namespace = Config::get_instance().record_key_namespace()?;
     let records_max_size = u32::from(config_account.record_size_limit()) as usize;
+    let record_min_ttl = u32::from(config_account.record_min_ttl());
 
     das_assert!(
         records.total_size() <= records_max_size,
@@ -839,27 +1005,14 @@ pub fn verify_records_keys(records: RecordsReader) -> Result<(), Box<dyn ScriptE
         records_max_size
     );
 
-    // extract all the keys, which are split by 0
-    let mut key_start_at = 0;
-    let mut key_list = Vec::new();
-    for (index, item) in record_key_namespace.iter().enumerate() {
-        if *item == 0 {
-            let key_vec = &record_key_namespace[key_start_at..index];
-            key_start_at = index + 1;
-
-            key_list.push(key_vec);
-        }
-    }
-
-    fn vec_compare(va: &[u8], vb: &[u8]) -> bool {
-        // zip stops at the shortest
-        (va.len() == vb.len()) && va.iter().zip(vb).all(|(a, b)| a == b)
-    }
-
     // check if all the record.{type+key} are valid
     for record in records.iter() {
         let record_type = Vec::from(record.record_type().raw_data());
         let record_key = Vec::from(record.record_key().raw_data());
+        let is_untouched = match prev_records {
+            Some(prev_records) => prev_records.iter().any(|item| item.as_slice() == record.as_slice()),
+            None => false,
+        };
         match record_type.as_slice() {
             b"custom_key" => {
                 // CAREFUL Triple check
@@ -876,13 +1029,8 @@ pub fn verify_records_keys(records: RecordsReader) -> Result<(), Box<dyn ScriptE
                 record_type_and_key.push(46);
                 record_type_and_key.extend_from_slice(&record_key);
 
-                let mut is_valid = false;
-                for key in &key_list {
-                    if vec_compare(record_type_and_key.as_slice(), *key) {
-                        is_valid = true;
-                        break;
-                    }
-                }
+                let validator = record_key_namespace.get(&record_type_and_key);
+                let mut is_valid = validator.is_some();
 
                 // For compatibility, the address records is allowed to use digit chars.
                 if record_type == b"address" && !is_valid {
@@ -911,8 +1059,246 @@ pub fn verify_records_keys(records: RecordsReader) -> Result<(), Box<dyn ScriptE
                     "Account cell record key is invalid: {:?}",
                     String::from_utf8(record_type)
                 );
+
+                if let (Some(validator), false) = (validator, is_untouched) {
+                    das_assert!(
+                        record_value::is_valid(validator, record.record_value().raw_data()),
+                        AccountCellErrorCode::AccountCellRecordValueInvalid,
+                        "The value of the record {:?} is invalid, it should pass the {} validator.",
+                        String::from_utf8(record_type_and_key),
+                        validator
+                    );
+                }
             }
         }
+
+        // The TTL is only required since the v2 namespace, so the records created before it are still valid.
+        if record_key_namespace.is_v2() && !is_untouched {
+            let record_ttl = u32::from(record.record_ttl());
+            das_assert!(
+                record_ttl >= record_min_ttl,
+                AccountCellErrorCode::AccountCellRecordTtlTooShort,
+                "The ttl of records should be at least {}, but {} found.",
+                record_min_ttl,
+                record_ttl
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn verify_records_delegates(records_delegates: RecordsDelegatesReader) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Check if records delegates are valid.");
+
+    for (i, delegate) in records_delegates.iter().enumerate() {
+        let lock_args = delegate.lock_args().raw_data();
+        let is_lock_args_valid = match lock_args.split_first() {
+            Some((lock_type, args)) => DasLockType::try_from(*lock_type).is_ok() && !args.is_empty(),
+            None => false,
+        };
+
+        das_assert!(
+            is_lock_args_valid,
+            AccountCellErrorCode::AccountCellRecordsDelegateInvalid,
+            "The records_delegates[{}].lock_args should be in the format of type(1) + args.",
+            i
+        );
+
+        das_assert!(
+            !delegate.key_prefixes().is_empty() && delegate.key_prefixes().iter().all(|prefix| !prefix.is_empty()),
+            AccountCellErrorCode::AccountCellRecordsDelegateInvalid,
+            "The records_delegates[{}].key_prefixes should not be empty or contain any empty prefix.",
+            i
+        );
+    }
+
+    Ok(())
+}
+
+/// Collect the key_prefixes of all the delegates whose lock_args is the same as the given one.
+pub fn get_delegated_key_prefixes(records_delegates: RecordsDelegatesReader, lock_args: &[u8]) -> Vec<Vec<u8>> {
+    let mut key_prefixes = Vec::new();
+    for delegate in records_delegates.iter() {
+        if delegate.lock_args().raw_data() == lock_args {
+            for prefix in delegate.key_prefixes().iter() {
+                key_prefixes.push(prefix.raw_data().to_vec());
+            }
+        }
+    }
+
+    key_prefixes
+}
+
+/// Verify if every record which is created, removed or modified has a `type.key` under one of the key_prefixes.
+pub fn verify_records_changed_by_delegate(
+    input_records: RecordsReader,
+    output_records: RecordsReader,
+    key_prefixes: &[Vec<u8>],
+) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Check if the records changed by the delegate are under its key_prefixes.");
+
+    let removed_records = input_records
+        .iter()
+        .filter(|record| !output_records.iter().any(|item| item.as_slice() == record.as_slice()));
+    let created_records = output_records
+        .iter()
+        .filter(|record| !input_records.iter().any(|item| item.as_slice() == record.as_slice()));
+
+    for record in removed_records.chain(created_records) {
+        let mut record_type_and_key = Vec::from(record.record_type().raw_data());
+        record_type_and_key.push(46);
+        record_type_and_key.extend_from_slice(record.record_key().raw_data());
+
+        das_assert!(
+            key_prefixes
+                .iter()
+                .any(|prefix| record_type_and_key.starts_with(prefix.as_slice())),
+            AccountCellErrorCode::AccountCellRecordKeyNotDelegated,
+            "The record {:?} is not under the key_prefixes of the delegate.",
+            String::from_utf8(record_type_and_key)
+        );
+    }
+
+    Ok(())
+}
+
+/// Verify if the AccountCell is unlocked for cross chain as the relayers attested.
+///
+/// The relayers sign the account ID, the attested owner lock args, the coin_type and chain_id of the source chain and
+/// the out point of the AccountCell in inputs, so every attestation can only be used to unlock the AccountCell once.
+/// The source chain should also be the chain which the AccountCell is locked to.
+pub fn verify_cross_chain_unlock_attestation<'a>(
+    input_account_index: usize,
+    output_account_index: usize,
+    input_witness_reader: &Box<dyn AccountCellDataReaderMixer + 'a>,
+    attestation: CrossChainUnlockAttestationReader,
+) -> Result<(), Box<dyn ScriptError>> {
+    debug!("Verify if the AccountCell is unlocked as the relayers attested.");
+
+    let input_data = util::load_cell_data(input_account_index, Source::Input)?;
+    let account_id = data_parser::account_cell::get_id(&input_data);
+    das_assert!(
+        attestation.account_id().raw_data() == account_id,
+        ErrorCode::CrossChainUnlockAttestationInvalid,
+        "The CrossChainUnlockAttestation.account_id should be 0x{}, but 0x{} found.",
+        util::hex_string(account_id),
+        util::hex_string(attestation.account_id().raw_data())
+    );
+
+    let output_lock = high_level::load_cell_lock(output_account_index, Source::Output)?;
+    let output_args = output_lock.as_reader().args().raw_data();
+    let (owner_type, owner_args, _, _) = data_parser::das_lock_args::get_owner_and_manager(output_args)?;
+    let output_owner_lock_args = [&[owner_type], owner_args].concat();
+    das_assert!(
+        attestation.owner_lock_args().raw_data() == output_owner_lock_args.as_slice(),
+        ErrorCode::CrossChainUnlockError,
+        "outputs[{}] The owner lock args should be 0x{} as the relayers attested, but 0x{} found.",
+        output_account_index,
+        util::hex_string(attestation.owner_lock_args().raw_data()),
+        util::hex_string(&output_owner_lock_args)
+    );
+
+    let config = Config::get_instance().cross_chain()?;
+    let coin_type = u64::from(attestation.coin_type());
+    let chain_id = u64::from(attestation.chain_id());
+    util::find_enabled_cross_chain(config, coin_type, chain_id)?;
+
+    // The chain is recorded since the latest version, the AccountCells locked before can not be checked here.
+    if let Ok(input_witness_reader) = input_witness_reader.try_into_latest() {
+        let locked_chain = input_witness_reader.cross_chain();
+        das_assert!(
+            u8::from(locked_chain.checked()) == 1
+                && u64::from(locked_chain.coin_type()) == coin_type
+                && u64::from(locked_chain.chain_id()) == chain_id,
+            ErrorCode::CrossChainUnlockAttestationInvalid,
+            "inputs[{}] The AccountCell is locked to the chain (coin_type: {}, chain_id: {}), but the relayers attested the chain (coin_type: {}, chain_id: {}).",
+            input_account_index,
+            u64::from(locked_chain.coin_type()),
+            u64::from(locked_chain.chain_id()),
+            coin_type,
+            chain_id
+        );
+    }
+
+    let relayers = config.relayers();
+    let threshold = u8::from(config.threshold()) as usize;
+
+    let mut prev_relayer_index: Option<u8> = None;
+    for signature in attestation.signatures().iter() {
+        let relayer_index = u8::from(signature.relayer_index());
+        das_assert!(
+            (relayer_index as usize) < relayers.len(),
+            ErrorCode::CrossChainUnlockAttestationInvalid,
+            "The relayer index {} is out of ConfigCellCrossChain.relayers.",
+            relayer_index
+        );
+        das_assert!(
+            prev_relayer_index.map_or(true, |prev| prev < relayer_index),
+            ErrorCode::CrossChainUnlockAttestationInvalid,
+            "The signatures should be sorted by relayer index in ascending order without duplicates."
+        );
+        prev_relayer_index = Some(relayer_index);
+    }
+
+    das_assert!(
+        threshold > 0 && attestation.signatures().len() >= threshold,
+        ErrorCode::CrossChainUnlockThresholdNotReached,
+        "The signatures of relayers are not enough. (expected: >= {}, current: {})",
+        threshold,
+        attestation.signatures().len()
+    );
+
+    if cfg!(feature = "dev") {
+        // CAREFUL Signature verification has been skipped in development mode.
+        debug!("Skip verifying the signatures of relayers.");
+        return Ok(());
+    }
+
+    let out_point = high_level::load_input_out_point(input_account_index, Source::Input)?;
+    let type_id_table = Config::get_instance().main()?.das_lock_type_id_table();
+    let mut sign_lib = SignLib::new();
+    load_and_configure_lib!(sign_lib, ETH, type_id_table, eth, load_2_methods);
+    load_and_configure_lib!(sign_lib, TRON, type_id_table, tron, load_2_methods);
+    load_and_configure_lib!(sign_lib, DOGE, type_id_table, doge, load_2_methods);
+
+    for signature in attestation.signatures().iter() {
+        let relayer_index = u8::from(signature.relayer_index());
+        let relayer = relayers.get(relayer_index as usize).unwrap();
+        let (das_lock_type, args) = match relayer.raw_data().split_first() {
+            Some((lock_type, args)) => match DasLockType::try_from(*lock_type) {
+                Ok(val @ (DasLockType::ETH | DasLockType::ETHTypedData | DasLockType::TRON | DasLockType::Doge)) => {
+                    (val, args)
+                }
+                _ => {
+                    warn!(
+                        "The lock type of the relayer {} is not supported.(lock_args: 0x{})",
+                        relayer_index,
+                        util::hex_string(relayer.raw_data())
+                    );
+                    return Err(code_to_error!(ErrorCode::CrossChainUnlockAttestationInvalid));
+                }
+            },
+            None => return Err(code_to_error!(ErrorCode::CrossChainUnlockAttestationInvalid)),
+        };
+
+        let ret = sign_lib.verify_cross_chain_unlock_sig(
+            das_lock_type,
+            account_id.to_vec(),
+            attestation.owner_lock_args().raw_data().to_vec(),
+            attestation.coin_type().as_slice().to_vec(),
+            attestation.chain_id().as_slice().to_vec(),
+            out_point.as_slice().to_vec(),
+            signature.signature().raw_data().to_vec(),
+            args.to_vec(),
+        );
+        if let Err(_error_code) = ret {
+            warn!(
+                "The signature of the relayer {} is invalid, the error_code returned by dynamic library is: {}",
+                relayer_index, _error_code
+            );
+            return Err(code_to_error!(ErrorCode::CrossChainUnlockAttestationInvalid));
+        }
     }
 
     Ok(())
@@ -7,6 +7,7 @@ use das_types::constants::{das_lock, DasLockType};
 use das_types::packed as das_packed;
 use das_types::prelude::{Builder, Entity, Reader};
 
+use crate::config::Config;
 use crate::constants::*;
 use crate::error::*;
 use crate::util::{self};
@@ -15,7 +16,7 @@ use crate::{code_to_error, data_parser, warn};
 pub fn verify_das_lock_always_with_type(
     config_main_reader: das_packed::ConfigCellMainReader,
 ) -> Result<(), Box<dyn ScriptError>> {
-    debug!("Check if any cells with das-lock in outputs lack of one of balance-cell-type, account-cell-type, account-sale-cell-type, account-auction-cell-type.");
+    debug!("Check if any cells with das-lock in outputs lack of one of balance-cell-type, account-cell-type, account-sale-cell-type, account-auction-cell-type or the UDTs for payments.");
 
     let das_lock = das_lock();
     let das_lock_reader = das_lock.as_reader();
@@ -74,6 +75,17 @@ pub fn verify_das_lock_always_with_type(
                         }
                     }
 
+                    if !pass {
+                        // The UDTs which can be used for payments are also allowed to be held by das-lock. The
+                        // ConfigCellPrice is only required when such cells exist, so it is fine to skip when it is missing.
+                        if let Ok(config_price) = Config::get_instance().price() {
+                            pass = config_price
+                                .udt_payments()
+                                .iter()
+                                .any(|udt_payment| util::is_reader_eq(udt_payment.type_script(), type_reader));
+                        }
+                    }
+
                     if !pass {
                         warn!("Outputs[{}] This cell has das-lock, so it should also has one of the specific type scripts.", index);
                         return Err(code_to_error!(ErrorCode::BalanceCellFoundSomeOutputsLackOfType));
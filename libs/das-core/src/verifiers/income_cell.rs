@@ -88,23 +88,23 @@ pub fn verify_income_cells(profit_map: Map<Vec<u8>, u64>) -> Result<(), Box<dyn
 
     #[cfg(debug_assertions)]
     {
-        debug!("  Profit map: {} total", profit_map.items.len());
-        for (script_bytes, capacity) in profit_map.items.iter() {
+        debug!("  Profit map: {} total", profit_map.len());
+        for (script_bytes, capacity) in profit_map.iter() {
             let script = Script::from_slice(&script_bytes.as_slice()).unwrap();
             debug!("    {{ script.args: {}, capacity: {} }}", script.args(), capacity);
         }
     }
 
-    let total_profit = if profit_map.items.len() == 0 {
+    let total_profit = if profit_map.is_empty() {
         0
     } else {
-        profit_map.items.iter().map(|v| v.1).reduce(|acc, v| acc + v).unwrap()
+        profit_map.values().copied().reduce(|acc, v| acc + v).unwrap()
     };
     let config_main = Config::get_instance().main()?;
 
     let (input_income_cells, output_income_cells) =
         util::find_cells_by_type_id_in_inputs_and_outputs(ScriptType::Type, config_main.type_id_table().income_cell())?;
-    if profit_map.items.len() == 0 || total_profit == 0 {
+    if profit_map.is_empty() || total_profit == 0 {
         debug!("Since the profit is empty, there should be no IncomeCell in either the inputs or outputs.");
 
         super::common::verify_cell_number("IncomeCell", &input_income_cells, 0, &output_income_cells, 0)?;
@@ -172,7 +172,7 @@ pub fn verify_income_cells(profit_map: Map<Vec<u8>, u64>) -> Result<(), Box<dyn
     if let Some(exist_records) = exist_records_opt.as_ref() {
         debug!("  Verify if the records in the IncomeCell in inputs is reserved correctly in outputs");
 
-        for (key, exist_capacity) in exist_records.items.iter() {
+        for (key, exist_capacity) in exist_records.iter() {
             if let Some(current_capacity) = output_records.get(key) {
                 assert!(
                     current_capacity >= exist_capacity,
@@ -195,7 +195,7 @@ pub fn verify_income_cells(profit_map: Map<Vec<u8>, u64>) -> Result<(), Box<dyn
     // Compare every records with profit_map to find out if every user get their profit properly.
     debug!("  Verify if the records in IncomeCell in outputs has carried profits of all users properly.");
 
-    for (key, value) in output_records.items.iter() {
+    for (key, value) in output_records.iter() {
         let mut current_capacity = *value;
 
         if let Some(exist_records) = exist_records_opt.as_ref() {
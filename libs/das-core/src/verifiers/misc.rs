@@ -138,6 +138,25 @@ pub fn verify_user_get_change(
     Ok(())
 }
 
+pub fn verify_user_get_udt_change(
+    udt_type: ScriptReader,
+    user_lock_reader: ckb_packed::ScriptReader,
+    expected_output_amount: u128,
+) -> Result<(), Box<dyn ScriptError>> {
+    let (_, output_amount) = util::load_udt_amount_of_lock(udt_type, user_lock_reader, Source::Output)?;
+
+    assert!(
+        output_amount >= expected_output_amount,
+        ErrorCode::ChangeError,
+        "The change of UDT should be {} in outputs.(current: {}, user_lock: {})",
+        expected_output_amount,
+        output_amount,
+        util::hex_string(user_lock_reader.args().raw_data())
+    );
+
+    Ok(())
+}
+
 pub fn verify_user_get_change_when_inputs_removed(
     config_reader: ConfigCellMainReader,
     user_lock_reader: ckb_packed::ScriptReader,
@@ -58,6 +58,16 @@ pub fn verify_unlock_role(witness: &SubAccountWitness) -> Result<(), Box<dyn Scr
         _ => LockRole::Owner,
     };
 
+    if let SubAccountEditValue::Records(_) = witness.edit_value {
+        if witness.sign_role == Some(LockRole::Delegate) {
+            debug!(
+                "  witnesses[{:>2}] The witness is unlocked by a delegate, its permission will be verified by the action.",
+                witness.index
+            );
+            return Ok(());
+        }
+    }
+
     das_assert!(
         witness.sign_role == Some(required_role),
         AccountCellErrorCode::AccountCellPermissionDenied,
@@ -264,7 +274,7 @@ pub fn verify_initial_properties<'a>(
         );
 
         let records_reader = sub_account_reader.records();
-        verifiers::account_cell::verify_records_keys(records_reader)?;
+        verifiers::account_cell::verify_records_keys(records_reader, None)?;
     } else {
         warn!(
             "  witnesses[{:>2}] The witness.sub_account.records of {} should be empty or only one default record.",
@@ -318,6 +328,13 @@ pub fn verify_initial_properties<'a>(
         sub_account_index
     );
 
+    das_assert!(
+        sub_account_reader.records_delegates().is_empty(),
+        SubAccountCellErrorCode::SubAccountInitialValueError,
+        "  witnesses[{:>2}] The records_delegates of new SubAccount should be empty.",
+        sub_account_index
+    );
+
     Ok(())
 }
 
@@ -377,6 +394,7 @@ pub fn verify_sub_account_mint_sign(
             // TODO: args for WebAuthn actually has sub_alg_id. Need to remove. This is a temporary walkaround.
             .get(args.index(..))
             .ok_or(code_to_error!(ErrorCode::WitnessStructureError))?;
+        let device_key_list = util::get_unexpired_device_key_list(device_key_list)?;
 
         if cfg!(feature = "dev") {
             return Ok(());
@@ -558,6 +576,7 @@ pub fn verify_sub_account_edit_sign(
             .device_key_lists
             .get(args.index(..))
             .ok_or(code_to_error!(ErrorCode::WitnessStructureError))?;
+        let device_key_list = util::get_unexpired_device_key_list(device_key_list)?;
 
         if cfg!(feature = "dev") {
             return Ok(());
@@ -661,11 +680,9 @@ pub fn verify_sub_account_approval_sign(
         }
     };
 
-    let sub_account_reader = witness
-        .sub_account
-        .as_reader()
-        .try_into_latest()
-        .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
+    let sub_account_reader = witness.sub_account.as_reader();
+    let approval_reader = util::get_sub_account_approval(sub_account_reader.as_ref())
+        .ok_or(code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
 
     let nonce = sub_account_reader.nonce().as_slice().to_vec();
     let signature = witness.signature.as_slice();
@@ -681,7 +698,7 @@ pub fn verify_sub_account_approval_sign(
         ) != 255
     {
         let action_bytes = witness.action.to_string().as_bytes().to_vec();
-        let approval_bytes = sub_account_reader.approval().as_slice().to_vec();
+        let approval_bytes = approval_reader.as_slice().to_vec();
         let data = [action_bytes, approval_bytes, nonce, sign_expired_at].concat();
         let message = util::blake2b_256(&data);
 
@@ -691,6 +708,7 @@ pub fn verify_sub_account_approval_sign(
             .device_key_lists
             .get(args.index(..))
             .ok_or(code_to_error!(ErrorCode::WitnessStructureError))?;
+        let device_key_list = util::get_unexpired_device_key_list(device_key_list)?;
 
         if cfg!(feature = "dev") {
             return Ok(());
@@ -712,7 +730,7 @@ pub fn verify_sub_account_approval_sign(
         sign_lib.verify_sub_account_approval_sig(
             das_lock_type,
             witness.action,
-            sub_account_reader.approval(),
+            approval_reader,
             nonce,
             signature.to_vec(),
             args.to_vec(),
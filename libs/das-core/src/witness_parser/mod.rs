@@ -1,5 +1,3 @@
-mod lv_parser;
-
 pub mod device_key_list;
 pub mod general_witness_parser;
 pub mod reverse_record;
@@ -9,15 +9,14 @@ use core::str::FromStr;
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
 use ckb_std::syscalls::{self};
+use das_lv::LvDecode;
 use das_types::constants::*;
+use das_types::lv_witness::RawReverseRecordWitness;
 use das_types::packed::{ConfigCellMainReader, DeviceKeyListCellData};
-use das_types::prelude::Entity;
 
 use super::super::error::*;
 use super::super::util;
 use super::device_key_list::get_device_key_list_cell_deps;
-use super::lv_parser::*;
-use crate::traits::Blake2BHash;
 use crate::util::load_das_witnesses;
 
 // Binary format: 'das'(3) + DATA_TYPE(4) + binary_data
@@ -112,8 +111,8 @@ impl ReverseRecordWitnessesParser {
 
                             let start = WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES;
                             // Every ReverseRecord witness has the next fields, here we parse it one by one.
-                            let (start, _) = parse_field("version", &buf, start)?;
-                            let (_, action_bytes) = parse_field("action", &buf, start)?;
+                            let (start, _) = das_lv::parse_field("version", &buf, start)?;
+                            let (_, action_bytes) = das_lv::parse_field("action", &buf, start)?;
                             if action_bytes == ReverseRecordAction::Update.to_string().as_bytes() {
                                 contains_updating = true;
                             } else if action_bytes == ReverseRecordAction::Remove.to_string().as_bytes() {
@@ -122,9 +121,10 @@ impl ReverseRecordWitnessesParser {
                         }
                         Ok(DataType::DeviceKeyListCellData) => {
                             let ret = &load_das_witnesses(i)?[7..];
-                            let device_list = DeviceKeyListCellData::from_slice(ret)
-                                .map_err(|_| code_to_error!(ErrorCode::WitnessDataDecodingError))?;
-                            let cell_dep = cell_deps.get(device_list.blake2b_256().index(..));
+                            let device_list = util::parse_device_key_list_cell_data(ret)
+                                .ok_or(code_to_error!(ErrorCode::WitnessDataDecodingError))?;
+                            // The hash is calculated from the raw bytes, because DeviceKeyListCellDataV1 is upgraded after parsing.
+                            let cell_dep = cell_deps.get(util::blake2b_256(ret).index(..));
                             if let Some(cell_dep) = cell_dep {
                                 device_key_lists.insert(cell_dep.slice(1..22).to_vec(), device_list);
                             } else {
@@ -168,27 +168,9 @@ impl ReverseRecordWitnessesParser {
         debug!("  witnesses[{:>2}] Parsing ReverseRecordWitness ...", index);
 
         let raw = util::load_das_witnesses(index)?;
-        let start = WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES;
+        let witness = RawReverseRecordWitness::decode_lv(&raw[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])?;
 
-        // Every ReverseRecord witness has the next fields, here we parse it one by one.
-        let (start, version) = parse_field("version", &raw, start)?;
-        let (start, action) = parse_field("action", &raw, start)?;
-        let (start, signature) = parse_field("signature", &raw, start)?;
-        let (start, sign_type) = parse_field("sign_type", &raw, start)?;
-        let (start, address_payload) = parse_field("address_payload", &raw, start)?;
-        let (start, proof) = parse_field("proof", &raw, start)?;
-        let (start, prev_nonce) = parse_field("prev_nonce", &raw, start)?;
-        let (start, prev_account) = parse_field("prev_account", &raw, start)?;
-        let (start, next_root) = parse_field("next_root", &raw, start)?;
-        let (_, next_account) = parse_field("next_account", &raw, start)?;
-
-        let version = u32::from_le_bytes(version.try_into().map_err(|_| {
-            warn!(
-                "  witnesses[{:>2}] ReverseRecordWitness.version should be 4 bytes.",
-                index
-            );
-            ErrorCode::WitnessStructureError
-        })?);
+        let version = witness.version;
         if version != 1 {
             warn!(
                 "  witnesses[{:>2}] ReverseRecordWitness.version is {} which is invalid for now.",
@@ -197,34 +179,15 @@ impl ReverseRecordWitnessesParser {
             return Err(code_to_error!(ErrorCode::WitnessVersionOrTypeInvalid));
         }
 
-        let action = match String::from_utf8(action.to_vec()) {
-            Ok(action) => match ReverseRecordAction::from_str(action.as_str()) {
-                Ok(val) => val,
-                Err(e) => {
-                    warn!(
-                        "  witnesses[{:>2}] ReverseRecordWitness.action field parse failed: {:?}",
-                        index, e
-                    );
-                    return Err(code_to_error!(ErrorCode::WitnessStructureError));
-                }
-            },
-            Err(e) => {
-                warn!(
-                    "  witnesses[{:>2}] ReverseRecordWitness.action field parse failed: {}",
-                    index, e
-                );
-                return Err(code_to_error!(ErrorCode::WitnessStructureError));
-            }
-        };
-
-        let sign_type = DasLockType::try_from(u8::from_le_bytes(sign_type.try_into().map_err(|_| {
+        let action = ReverseRecordAction::from_str(witness.action).map_err(|e| {
             warn!(
-                "  witnesses[{:>2}] ReverseRecordWitness.sign_type should be 1 byte.",
-                index
+                "  witnesses[{:>2}] ReverseRecordWitness.action field parse failed: {:?}",
+                index, e
             );
             ErrorCode::WitnessStructureError
-        })?))
-        .map_err(|_| {
+        })?;
+
+        let sign_type = DasLockType::try_from(witness.sign_type).map_err(|_| {
             warn!(
                 "  witnesses[{:>2}] ReverseRecordWitness.sign_type should be a valid DasLockType .",
                 index
@@ -232,21 +195,11 @@ impl ReverseRecordWitnessesParser {
             ErrorCode::WitnessStructureError
         })?;
 
-        let prev_nonce = if prev_nonce.is_empty() {
-            None
-        } else {
-            Some(u32::from_le_bytes(prev_nonce.try_into().map_err(|_| {
-                warn!(
-                    "  witnesses[{:>2}] ReverseRecordWitness.prev_nonce should be 4 bytes.",
-                    index
-                );
-                ErrorCode::WitnessStructureError
-            })?))
-        };
+        let prev_nonce = witness.prev_nonce;
         let prev_account = if prev_nonce.is_none() {
             String::from("")
         } else {
-            String::from_utf8(prev_account.to_vec()).map_err(|_| {
+            String::from_utf8(witness.prev_account.to_vec()).map_err(|_| {
                 warn!(
                     "  witnesses[{:>2}] ReverseRecordWitness.prev_account should be a valid string.",
                     index
@@ -254,20 +207,11 @@ impl ReverseRecordWitnessesParser {
                 ErrorCode::WitnessStructureError
             })?
         };
-        let next_root: [u8; 32] = next_root.try_into().map_err(|_| {
-            warn!(
-                "  witnesses[{:>2}] ReverseRecordWitness.next_root should be 32 bytes.",
-                index
-            );
-            ErrorCode::WitnessStructureError
-        })?;
-        let next_account = String::from_utf8(next_account.to_vec()).map_err(|_| {
-            warn!(
-                "  witnesses[{:>2}] ReverseRecordWitness.next_account should be a valid string.",
-                index
-            );
-            ErrorCode::WitnessStructureError
-        })?;
+        let next_root = witness.next_root;
+        let next_account = witness.next_account.to_string();
+        let signature = witness.signature;
+        let address_payload = witness.address_payload;
+        let proof = witness.proof;
 
         debug!(
             "  ReverseRecord witnesses[{:>2}]: {{ version: {}, action: {}, signature: 0x{}, sign_type: {}, address_payload: 0x{}, proof: 0x{}, prev_nonce: {:?}, prev_account: {}, next_root: 0x{}, next_account: {} }}",
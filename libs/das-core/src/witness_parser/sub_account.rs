@@ -9,8 +9,10 @@ use core::str::FromStr;
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
 use ckb_std::syscalls;
+use das_lv::LvDecode;
 use das_types::constants::*;
-use das_types::mixer::SubAccountMixer;
+use das_types::lv_witness::RawSubAccountWitness;
+use das_types::mixer::{SubAccountMixer, SubAccountReaderMixer};
 use das_types::packed::*;
 use das_types::prelude::*;
 #[cfg(all(debug_assertions))]
@@ -20,7 +22,6 @@ use simple_ast::{types as ast_types, util as ast_util};
 use super::super::error::*;
 use super::super::{data_parser, util};
 use super::device_key_list::get_device_key_list_cell_deps;
-use crate::traits::Blake2BHash;
 use crate::util::load_das_witnesses;
 
 // Binary format: 'das'(3) + DATA_TYPE(4) + binary_data
@@ -64,6 +65,7 @@ pub enum SubAccountEditValue {
     Owner(Vec<u8>),
     Manager(Vec<u8>),
     Records(Records),
+    RecordsDelegates(RecordsDelegates),
     Proof,
     Channel(Vec<u8>, u64),
     ExpiredAt(u64),
@@ -200,9 +202,10 @@ impl SubAccountWitnessesParser {
                         Ok(DataType::DeviceKeyListCellData) => {
                             debug!("cell deps: {:?}, ", cell_deps);
                             let ret = &load_das_witnesses(i)?[7..];
-                            let device_list = DeviceKeyListCellData::from_slice(ret)
-                                .map_err(|_| code_to_error!(ErrorCode::WitnessDataDecodingError))?;
-                            let cell_dep = cell_deps.get(device_list.blake2b_256().index(..));
+                            let device_list = util::parse_device_key_list_cell_data(ret)
+                                .ok_or(code_to_error!(ErrorCode::WitnessDataDecodingError))?;
+                            // The hash is calculated from the raw bytes, because DeviceKeyListCellDataV1 is upgraded after parsing.
+                            let cell_dep = cell_deps.get(util::blake2b_256(ret).index(..));
                             if let Some(cell_dep) = cell_dep {
                                 device_key_lists.insert(cell_dep.slice(1..22).to_vec(), device_list);
                             } else {
@@ -395,63 +398,27 @@ impl SubAccountWitnessesParser {
         debug!("  witnesses[{:>2}] Parsing SubAccountWitness ...", i);
 
         let raw = util::load_das_witnesses(i)?;
-        let start = WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES;
-
-        // Every sub-account witness has the next fields, here we parse it one by one.
-        let (start, version_bytes) = Self::parse_field("version", &raw, start)?;
-        das_assert!(
-            version_bytes.len() == 4,
-            ErrorCode::WitnessStructureError,
-            "  witnesses[{:>2}] SubAccount.version should be 4 bytes.",
-            i
-        );
-        let version = u32::from_le_bytes(version_bytes.try_into().unwrap());
-
-        let (start, action_bytes) = Self::parse_field("action", &raw, start)?;
-        let (start, signature) = Self::parse_field("signature", &raw, start)?;
-        let (start, sign_role_byte) = Self::parse_field("sign_role", &raw, start)?;
-        let (start, sign_expired_at_bytes) = Self::parse_field("sign_expired_at", &raw, start)?;
-        let (start, new_root) = Self::parse_field("new_root", &raw, start)?;
-        let (start, proof) = Self::parse_field("proof", &raw, start)?;
-        let (start, old_sub_account_version, new_sub_account_version) = if version == 3 {
-            let (start, old_sub_account_version_bytes) = Self::parse_field("old_sub_account_version", &raw, start)?;
-            let (start, new_sub_account_version_bytes) = Self::parse_field("new_sub_account_version", &raw, start)?;
-            das_assert!(
-                old_sub_account_version_bytes.len() == 4 && new_sub_account_version_bytes.len() == 4,
-                ErrorCode::WitnessStructureError,
-                "  witnesses[{:>2}] SubAccount.old_sub_account_version and SubAccount.new_sub_account_version both should be 4 bytes.",
-                i
+        let witness = RawSubAccountWitness::decode_lv(&raw[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])?;
+
+        let version = witness.version;
+        let old_sub_account_version = witness.old_sub_account_version.unwrap_or(1);
+        let new_sub_account_version = witness.new_sub_account_version.unwrap_or(1);
+        let signature = witness.signature;
+        let sign_role_byte = witness.sign_role;
+        let sign_expired_at_bytes = witness.sign_expired_at;
+        let new_root = witness.new_root;
+        let proof = witness.proof;
+        let sub_account_bytes = witness.sub_account;
+        let edit_key = witness.edit_key;
+        let edit_value_bytes = witness.edit_value;
+
+        let action = SubAccountAction::from_str(witness.action).map_err(|e| {
+            warn!(
+                "  witnesses[{:>2}] SubAccountWitness.action field parse failed: {:?}",
+                i, e
             );
-            let old_sub_account_version = u32::from_le_bytes(old_sub_account_version_bytes.try_into().unwrap());
-            let new_sub_account_version = u32::from_le_bytes(new_sub_account_version_bytes.try_into().unwrap());
-
-            (start, old_sub_account_version, new_sub_account_version)
-        } else {
-            (start, 1, 1)
-        };
-        let (start, sub_account_bytes) = Self::parse_field("sub_account", &raw, start)?;
-        let (start, edit_key) = Self::parse_field("edit_key", &raw, start)?;
-        let (_, edit_value_bytes) = Self::parse_field("edit_value", &raw, start)?;
-
-        let action = match String::from_utf8(action_bytes.to_vec()) {
-            Ok(action) => match SubAccountAction::from_str(action.as_str()) {
-                Ok(val) => val,
-                Err(e) => {
-                    warn!(
-                        "  witnesses[{:>2}] SubAccountWitness.action field parse failed: {:?}",
-                        i, e
-                    );
-                    return Err(code_to_error!(ErrorCode::WitnessStructureError));
-                }
-            },
-            Err(e) => {
-                warn!(
-                    "  witnesses[{:>2}] SubAccountWitness.action field parse failed: {}",
-                    i, e
-                );
-                return Err(code_to_error!(ErrorCode::WitnessStructureError));
-            }
-        };
+            code_to_error!(ErrorCode::WitnessStructureError)
+        })?;
 
         let sub_account: Box<dyn SubAccountMixer> = match action {
             SubAccountAction::Create => {
@@ -483,6 +450,19 @@ impl SubAccountWitnessesParser {
                     Box::new(sub_account)
                 }
                 2 => {
+                    let sub_account = match SubAccountV2::from_compatible_slice(sub_account_bytes) {
+                        Ok(val) => val,
+                        Err(e) => {
+                            warn!(
+                                    "  witnesses[{:>2}] SubAccountWitness.sub_account(SubAccountV2) field parse failed: {} (old_version: {})",
+                                    i, e, old_sub_account_version
+                                );
+                            return Err(code_to_error!(ErrorCode::WitnessStructureError));
+                        }
+                    };
+                    Box::new(sub_account)
+                }
+                3 => {
                     let sub_account = match SubAccount::from_compatible_slice(sub_account_bytes) {
                         Ok(val) => val,
                         Err(e) => {
@@ -537,9 +517,15 @@ impl SubAccountWitnessesParser {
             );
             sign_expired_at = u64::from_le_bytes(sign_expired_at_bytes.try_into().unwrap());
 
-            let lock_args_reader = sub_account.as_reader().lock().args();
-            _lock_args = lock_args_reader.raw_data().to_vec();
-            (sign_role, sign_type, sign_args) = Self::parse_sign_info(i, sign_role_byte, &_lock_args, false)?;
+            if action == SubAccountAction::Edit && sign_role_byte.first() == Some(&(LockRole::Delegate as u8)) {
+                let sub_account_reader = sub_account.as_reader();
+                (sign_role, sign_type, sign_args) =
+                    Self::parse_delegate_sign_info(i, sign_role_byte, sub_account_reader.as_ref())?;
+            } else {
+                let lock_args_reader = sub_account.as_reader().lock().args();
+                _lock_args = lock_args_reader.raw_data().to_vec();
+                (sign_role, sign_type, sign_args) = Self::parse_sign_info(i, sign_role_byte, &_lock_args, false)?;
+            }
         } else if action == SubAccountAction::RevokeApproval {
             debug!(
                 "  witnesses[{:>2}] Parse the sub_account.approval.params.platform_lock as the signing lock ...",
@@ -554,11 +540,9 @@ impl SubAccountWitnessesParser {
             );
             sign_expired_at = u64::from_le_bytes(sign_expired_at_bytes.try_into().unwrap());
 
-            let sub_account_reader = sub_account
-                .as_reader()
-                .try_into_latest()
-                .map_err(|_| code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
-            let approval = sub_account_reader.approval();
+            let sub_account_reader = sub_account.as_reader();
+            let approval = util::get_sub_account_approval(sub_account_reader.as_ref())
+                .ok_or(code_to_error!(SubAccountCellErrorCode::WitnessVersionMismatched))?;
             let approval_action = approval.action().raw_data();
             let approval_params = approval.params().raw_data();
 
@@ -691,6 +675,20 @@ impl SubAccountWitnessesParser {
 
                         SubAccountEditValue::Records(records)
                     }
+                    b"records_delegates" => {
+                        let records_delegates = match RecordsDelegates::from_slice(edit_value_bytes) {
+                            Ok(val) => val,
+                            Err(e) => {
+                                warn!(
+                                    "  witnesses[{:>2}] Sub-account witness structure error, decoding edit_value to records_delegates failed: {}",
+                                    i, e
+                                );
+                                return Err(code_to_error!(ErrorCode::WitnessStructureError));
+                            }
+                        };
+
+                        SubAccountEditValue::RecordsDelegates(records_delegates)
+                    }
                     _ => SubAccountEditValue::None,
                 };
             }
@@ -846,6 +844,54 @@ impl SubAccountWitnessesParser {
         Ok((sign_role, sign_type, sign_args))
     }
 
+    /// The sign_role of delegates is LockRole::Delegate(1) + index(1), the index is the position of the delegate in
+    /// SubAccount.records_delegates, so the signing lock can be found.
+    fn parse_delegate_sign_info(
+        index: usize,
+        sign_role_byte: &[u8],
+        sub_account_reader: &dyn SubAccountReaderMixer,
+    ) -> Result<(Option<LockRole>, Option<DasLockType>, Vec<u8>), Box<dyn ScriptError>> {
+        debug!("  witnesses[{:>2}] Start parsing sign info of the delegate ...", index);
+
+        das_assert!(
+            sign_role_byte.len() == 2,
+            ErrorCode::WitnessStructureError,
+            "  witnesses[{:>2}] The sign_role of delegates should be 2 bytes.",
+            index
+        );
+
+        let sub_account_reader = sub_account_reader.try_into_latest().map_err(|_| {
+            warn!(
+                "  witnesses[{:>2}] The sub-account has no records delegates before the latest version.",
+                index
+            );
+            code_to_error!(AccountCellErrorCode::AccountCellPermissionDenied)
+        })?;
+        let delegate = match sub_account_reader.records_delegates().get(sign_role_byte[1] as usize) {
+            Some(delegate) => delegate,
+            None => {
+                warn!(
+                    "  witnesses[{:>2}] The delegate at index {} is not found in SubAccount.records_delegates.",
+                    index, sign_role_byte[1]
+                );
+                return Err(code_to_error!(AccountCellErrorCode::AccountCellPermissionDenied));
+            }
+        };
+
+        let (sign_type, sign_args) = match delegate.lock_args().raw_data().split_first() {
+            Some((lock_type, args)) => (DasLockType::try_from(*lock_type).ok(), args.to_vec()),
+            None => (None, Vec::new()),
+        };
+
+        debug!(
+            "  witnesses[{:>2}] Parse sign_role as {:?}",
+            index,
+            Some(LockRole::Delegate)
+        );
+
+        Ok((Some(LockRole::Delegate), sign_type, sign_args))
+    }
+
     pub fn iter(&self) -> SubAccountWitnessesIter {
         SubAccountWitnessesIter {
             parser: self,
@@ -89,6 +89,17 @@ impl WitnessesParserLegacy {
                             // Ignore ReverseRecord witnesses in this parser.
                             debug!("witnesses[{:>2}] Found ReverseRecorw witness skip parsing.", i);
                         }
+                        Ok(DataType::AccountListProof) => {
+                            // Ignore AccountListProof witnesses in this parser.
+                            debug!("witnesses[{:>2}] Found AccountListProof witness skip parsing.", i);
+                        }
+                        Ok(DataType::CrossChainUnlockAttestation) => {
+                            // Ignore CrossChainUnlockAttestation witnesses in this parser.
+                            debug!(
+                                "witnesses[{:>2}] Found CrossChainUnlockAttestation witness skip parsing.",
+                                i
+                            );
+                        }
                         Ok(data_type) => {
                             if !das_witnesses_started {
                                 assert!(
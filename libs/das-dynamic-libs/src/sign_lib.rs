@@ -298,6 +298,29 @@ impl SignLib {
         }
     }
 
+    pub fn verify_cross_chain_unlock_sig(
+        &self,
+        das_lock_type: DasLockType,
+        account_id: Vec<u8>,
+        owner_lock_args: Vec<u8>,
+        coin_type: Vec<u8>,
+        chain_id: Vec<u8>,
+        out_point: Vec<u8>,
+        sig: Vec<u8>,
+        args: Vec<u8>,
+    ) -> Result<(), i32> {
+        let data = [account_id, owner_lock_args, coin_type, chain_id, out_point].concat();
+        let message = self.gen_digest(das_lock_type, data)?;
+        let type_no = 0i32;
+        let m_len = message.len();
+        let ret = self.validate_str(das_lock_type, type_no, message, m_len, sig, args);
+        if let Err(error_code) = ret {
+            Err(error_code)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn gen_digest(&self, das_lock_type: DasLockType, data: Vec<u8>) -> Result<Vec<u8>, i32> {
         let mut blake2b = util::new_blake2b();
         blake2b.update(&data);
@@ -0,0 +1,180 @@
+//! Derive macros of the `das-lv` crate, please use them through the `das-lv` crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam, Ident, Lifetime, LifetimeParam, LitInt, Type};
+
+struct LvFieldDef {
+    ident: Ident,
+    ty: Type,
+    is_version: bool,
+    since_version: Option<u32>,
+}
+
+/// Derive `das_lv::LvDecode`, the fields are decoded in the order of their definitions.
+///
+/// - `#[lv(version)]` marks the u32 field as the version of the struct;
+/// - `#[lv(since_version = N)]` marks the `Option<T>` field only exists when the version is greater than or equal to N.
+#[proc_macro_derive(LvDecode, attributes(lv))]
+pub fn derive_lv_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_decode(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derive `das_lv::LvEncode`, the attributes are the same as `LvDecode`.
+#[proc_macro_derive(LvEncode, attributes(lv))]
+pub fn derive_lv_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_encode(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<Vec<LvFieldDef>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "Only structs with named fields are supported.",
+                ))
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(input, "Only structs are supported.")),
+    };
+
+    let mut ret: Vec<LvFieldDef> = vec![];
+    for field in fields {
+        let mut is_version = false;
+        let mut since_version = None;
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("lv")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("version") {
+                    is_version = true;
+                    Ok(())
+                } else if meta.path.is_ident("since_version") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    since_version = Some(lit.base10_parse::<u32>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported lv attribute, expect version or since_version = N ."))
+                }
+            })?;
+        }
+
+        if since_version.is_some() && !ret.iter().any(|item| item.is_version) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "The field marked with #[lv(version)] should be defined before any field with #[lv(since_version = N)] .",
+            ));
+        }
+
+        ret.push(LvFieldDef {
+            ident: field.ident.clone().unwrap(),
+            ty: field.ty.clone(),
+            is_version,
+            since_version,
+        });
+    }
+
+    if ret.iter().filter(|item| item.is_version).count() > 1 {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Only one field can be marked with #[lv(version)] .",
+        ));
+    }
+
+    Ok(ret)
+}
+
+fn expand_decode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(input)?;
+    let name = &input.ident;
+
+    // Reuse the first lifetime of the struct as the lifetime of the bytes, or add a new one.
+    let mut generics = input.generics.clone();
+    let lifetime = match generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime = Lifetime::new("'__lv", Span::call_site());
+            generics
+                .params
+                .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+            lifetime
+        }
+    };
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let version_ident = fields.iter().find(|item| item.is_version).map(|item| &item.ident);
+    let decode_fields = fields.iter().map(|item| {
+        let ident = &item.ident;
+        let ty = &item.ty;
+        let field_name = ident.to_string();
+        match (item.since_version, version_ident) {
+            (Some(since_version), Some(version_ident)) => quote! {
+                let (start, #ident): (usize, #ty) = if #version_ident >= #since_version {
+                    let (start, field_bytes) = ::das_lv::parse_field(#field_name, bytes, start)?;
+                    (start, Some(::das_lv::LvField::decode_field(#field_name, field_bytes)?))
+                } else {
+                    (start, None)
+                };
+            },
+            _ => quote! {
+                let (start, field_bytes) = ::das_lv::parse_field(#field_name, bytes, start)?;
+                let #ident: #ty = ::das_lv::LvField::decode_field(#field_name, field_bytes)?;
+            },
+        }
+    });
+    let idents = fields.iter().map(|item| &item.ident);
+
+    Ok(quote! {
+        impl #impl_generics ::das_lv::LvDecode<#lifetime> for #name #ty_generics #where_clause {
+            fn decode_lv(bytes: &#lifetime [u8]) -> Result<Self, ::das_lv::LvError> {
+                let start = 0usize;
+                #(#decode_fields)*
+                // The bytes after the last field are ignored for compatibility.
+                let _ = start;
+
+                Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+fn expand_encode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let encode_fields = fields.iter().map(|item| {
+        let ident = &item.ident;
+        if item.since_version.is_some() {
+            // The field is omitted when it is None, it should be consistent with the version.
+            quote! {
+                if let Some(val) = &self.#ident {
+                    ::das_lv::push_field(&mut buf, &::das_lv::LvField::encode_field(val));
+                }
+            }
+        } else {
+            quote! {
+                ::das_lv::push_field(&mut buf, &::das_lv::LvField::encode_field(&self.#ident));
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::das_lv::LvEncode for #name #ty_generics #where_clause {
+            fn encode_lv(&self) -> Vec<u8> {
+                let mut buf = Vec::new();
+                #(#encode_fields)*
+
+                buf
+            }
+        }
+    })
+}
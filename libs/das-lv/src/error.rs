@@ -0,0 +1,26 @@
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(feature = "no_std")]
+use thiserror_no_std::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LvError {
+    #[error("[{field}] expect 4 bytes of LE uint32 as length in {start}..{end} .")]
+    LengthMissing {
+        field: &'static str,
+        start: usize,
+        end: usize,
+    },
+    #[error("[{field}] expect {length} bytes in {start}..{end} .")]
+    DataMissing {
+        field: &'static str,
+        length: usize,
+        start: usize,
+        end: usize,
+    },
+    #[error("[{field}] should be {expected}.")]
+    InvalidValue {
+        field: &'static str,
+        expected: &'static str,
+    },
+}
@@ -0,0 +1,89 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::error::LvError;
+
+/// The types which can be used as fields of the structs deriving `LvDecode` and `LvEncode`.
+pub trait LvField<'a>: Sized {
+    fn decode_field(field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError>;
+
+    fn encode_field(&self) -> Vec<u8>;
+}
+
+impl<'a> LvField<'a> for &'a [u8] {
+    fn decode_field(_field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError> {
+        Ok(bytes)
+    }
+
+    fn encode_field(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl<'a> LvField<'a> for &'a str {
+    fn decode_field(field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError> {
+        core::str::from_utf8(bytes).map_err(|_| LvError::InvalidValue {
+            field,
+            expected: "a valid utf-8 string",
+        })
+    }
+
+    fn encode_field(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+impl<'a, const N: usize> LvField<'a> for [u8; N] {
+    fn decode_field(field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError> {
+        bytes.try_into().map_err(|_| LvError::InvalidValue {
+            field,
+            expected: "bytes with fixed length",
+        })
+    }
+
+    fn encode_field(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+macro_rules! impl_lv_field_for_uint {
+    ($type:ty, $expected:expr) => {
+        impl<'a> LvField<'a> for $type {
+            fn decode_field(field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError> {
+                bytes
+                    .try_into()
+                    .map(<$type>::from_le_bytes)
+                    .map_err(|_| LvError::InvalidValue {
+                        field,
+                        expected: $expected,
+                    })
+            }
+
+            fn encode_field(&self) -> Vec<u8> {
+                self.to_le_bytes().to_vec()
+            }
+        }
+    };
+}
+
+impl_lv_field_for_uint!(u8, "1 byte");
+impl_lv_field_for_uint!(u32, "4 bytes of LE uint32");
+impl_lv_field_for_uint!(u64, "8 bytes of LE uint64");
+
+/// An empty field is decoded as `None`.
+impl<'a, T: LvField<'a>> LvField<'a> for Option<T> {
+    fn decode_field(field: &'static str, bytes: &'a [u8]) -> Result<Self, LvError> {
+        if bytes.is_empty() {
+            Ok(None)
+        } else {
+            T::decode_field(field, bytes).map(Some)
+        }
+    }
+
+    fn encode_field(&self) -> Vec<u8> {
+        match self {
+            Some(val) => val.encode_field(),
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! The LV(length-value) encoding is used by the witnesses which are not encoded in molecule, like the witnesses of
+//! sub-account and reverse record. Every field is encoded as 4 bytes of LE uint32 as its length and then its bytes.
+//!
+//! The structs of these witnesses should derive [`LvDecode`] to decode them without copying bytes, and derive
+//! [`LvEncode`] when the `std` feature is enabled to encode them:
+//!
+//! ```ignore
+//! #[derive(LvDecode)]
+//! #[cfg_attr(feature = "std", derive(LvEncode))]
+//! pub struct RawWitness<'a> {
+//!     #[lv(version)]
+//!     pub version: u32,
+//!     pub action: &'a str,
+//!     // This field only exists since version 2.
+//!     #[lv(since_version = 2)]
+//!     pub nonce: Option<u32>,
+//!     pub data: &'a [u8],
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+pub use das_lv_derive::LvDecode;
+#[cfg(feature = "std")]
+pub use das_lv_derive::LvEncode;
+
+mod error;
+mod field;
+
+pub use error::LvError;
+pub use field::LvField;
+
+pub const LENGTH_BYTES: usize = 4;
+
+pub trait LvDecode<'a>: Sized {
+    fn decode_lv(bytes: &'a [u8]) -> Result<Self, LvError>;
+}
+
+#[cfg(feature = "std")]
+pub trait LvEncode {
+    fn encode_lv(&self) -> Vec<u8>;
+}
+
+/// Parse one field which starts from `start`, it returns the start of the next field and the bytes of the field.
+pub fn parse_field<'a>(field: &'static str, bytes: &'a [u8], start: usize) -> Result<(usize, &'a [u8]), LvError> {
+    let length = bytes
+        .get(start..(start + LENGTH_BYTES))
+        .map(|val| u32::from_le_bytes(val.try_into().unwrap()) as usize)
+        .ok_or(LvError::LengthMissing {
+            field,
+            start,
+            end: start + LENGTH_BYTES,
+        })?;
+
+    let from = start + LENGTH_BYTES;
+    let to = from + length;
+    let field_bytes = bytes.get(from..to).ok_or(LvError::DataMissing {
+        field,
+        length,
+        start: from,
+        end: to,
+    })?;
+
+    Ok((to, field_bytes))
+}
+
+/// Push one field to the end of the `buf`.
+pub fn push_field(buf: &mut Vec<u8>, field_bytes: &[u8]) {
+    buf.extend_from_slice(&(field_bytes.len() as u32).to_le_bytes());
+    buf.extend_from_slice(field_bytes);
+}
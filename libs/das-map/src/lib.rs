@@ -5,3 +5,4 @@ extern crate no_std_compat as std;
 
 pub mod map;
 pub mod util;
+pub mod vec_map;
@@ -1,13 +1,18 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::slice;
 use std::prelude::v1::*;
 
+/// A map which keeps its items sorted by keys.
+///
+/// The `get`, `contains` and the lookup part of `insert` and `remove` are all binary searches, and the iteration order is
+/// always the order of keys no matter in which order the items are inserted.
 #[derive(Clone, Debug, Default)]
-pub struct Map<K: Debug + PartialEq, V: Clone + Debug + PartialEq> {
-    pub items: Vec<(K, V)>,
+pub struct Map<K: Debug + Ord, V: Clone + Debug + PartialEq> {
+    items: Vec<(K, V)>,
 }
 
-impl<K: Debug + PartialEq, V: Clone + Debug + PartialEq> Map<K, V> {
+impl<K: Debug + Ord, V: Clone + Debug + PartialEq> Map<K, V> {
     pub fn new() -> Self {
         Map { items: Vec::new() }
     }
@@ -20,80 +25,197 @@ impl<K: Debug + PartialEq, V: Clone + Debug + PartialEq> Map<K, V> {
         self.items.is_empty()
     }
 
-    pub fn insert(&mut self, key: K, value: V) {
-        let mut index = None;
-        for (i, item) in self.items.iter().enumerate() {
-            if item.0 == key {
-                index = Some(i);
-                break;
-            }
-        }
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.items.binary_search_by(|item| item.0.cmp(key))
+    }
 
-        match index {
-            Some(i) => {
+    pub fn insert(&mut self, key: K, value: V) {
+        match self.search(&key) {
+            Ok(i) => {
                 self.items[i] = (key, value);
             }
-            None => {
-                self.items.push((key, value));
+            Err(i) => {
+                self.items.insert(i, (key, value));
             }
         }
     }
 
     pub fn remove(&mut self, key: &K) -> (K, V) {
-        let mut index_opt = None;
-        for (i, item) in self.items.iter().enumerate() {
-            if &item.0 == key {
-                index_opt = Some(i);
-                break;
-            }
+        match self.try_remove(key) {
+            Some(item) => item,
+            None => panic!("removal key (is {:?}) does not exist", key),
         }
+    }
 
-        if let Some(index) = index_opt {
-            self.items.remove(index)
-        } else {
-            panic!("removal key (is {:?}) does not exist", key);
+    pub fn try_remove(&mut self, key: &K) -> Option<(K, V)> {
+        match self.search(key) {
+            Ok(i) => Some(self.items.remove(i)),
+            Err(_) => None,
         }
     }
 
     pub fn get(&self, key: &K) -> Option<&V> {
-        for item in self.items.iter() {
-            if &item.0 == key {
-                return Some(&item.1);
-            }
+        match self.search(key) {
+            Ok(i) => Some(&self.items[i].1),
+            Err(_) => None,
         }
+    }
 
-        None
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.search(key) {
+            Ok(i) => Some(&mut self.items[i].1),
+            Err(_) => None,
+        }
     }
 
     pub fn get_all_keys(&self) -> Option<Vec<&K>> {
-        let mut keys = Vec::new();
         if self.is_empty() {
             return None;
         }
-        for item in self.items.iter() {
-            keys.push(&item.0);
-        }
-        Some(keys)
+        Some(self.keys().collect())
     }
 
     pub fn find(&self, value: &V) -> Option<&K> {
-        for item in self.items.iter() {
-            if &item.1 == value {
-                return Some(&item.0);
-            }
+        self.items.iter().find(|item| &item.1 == value).map(|item| &item.0)
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.search(key).is_ok()
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.search(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry { map: self, key, index }),
         }
+    }
 
-        None
+    /// Iterate over all items in the order of keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.items.iter().map(|(key, value)| (key, value))
     }
 
-    pub fn contains(&self, key: &K) -> bool {
-        for item in self.items.iter() {
-            if &item.0 == key {
-                return true;
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.items.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.items.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Debug + Ord, V: Clone + Debug + PartialEq> IntoIterator for Map<K, V> {
+    type Item = (K, V);
+    type IntoIter = alloc::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> IntoIterator for &'a Map<K, V> {
+    type Item = &'a (K, V);
+    type IntoIter = slice::Iter<'a, (K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<K: Debug + Ord, V: Clone + Debug + PartialEq> FromIterator<(K, V)> for Map<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Map::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+/// A view into a single item of a `Map`, it is constructed by `Map::entry`.
+pub enum Entry<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> {
+    map: &'a mut Map<K, V>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> {
+    map: &'a mut Map<K, V>,
+    key: K,
+    index: usize,
+}
+
+impl<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
             }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
         }
+    }
+}
+
+impl<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.map.items[self.index].0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.map.items[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.items[self.index].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.items[self.index].1
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> (K, V) {
+        self.map.items.remove(self.index)
+    }
+}
+
+impl<'a, K: Debug + Ord, V: Clone + Debug + PartialEq> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
 
-        false
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.items.insert(self.index, (self.key, value));
+        &mut self.map.items[self.index].1
     }
 }
 
@@ -116,6 +238,10 @@ mod test {
         assert_eq!(map.get(&key0.as_slice()), Some(&0));
         assert_eq!(map.get(&key1.as_slice()), Some(&1));
         assert_eq!(map.get(&key2.as_slice()), Some(&2));
+
+        map.insert(key1.as_slice(), 10);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&key1.as_slice()), Some(&10));
     }
 
     #[test]
@@ -132,6 +258,30 @@ mod test {
         assert_eq!(ret.1, 0);
     }
 
+    #[test]
+    #[should_panic]
+    fn test_remove_not_exist() {
+        let key0 = vec![0u8, 0u8, 0u8];
+        let key1 = vec![0u8, 0u8, 1u8];
+
+        let mut map = Map::new();
+        map.insert(key0.as_slice(), 0);
+        map.remove(&key1.as_slice());
+    }
+
+    #[test]
+    fn test_try_remove() {
+        let key0 = vec![0u8, 0u8, 0u8];
+        let key1 = vec![0u8, 0u8, 1u8];
+
+        let mut map = Map::new();
+        map.insert(key0.as_slice(), 0);
+
+        assert_eq!(map.try_remove(&key1.as_slice()), None);
+        assert_eq!(map.try_remove(&key0.as_slice()), Some((key0.as_slice(), 0)));
+        assert!(map.is_empty());
+    }
+
     #[test]
     fn test_contains() {
         let key0 = vec![0u8, 0u8, 0u8];
@@ -143,7 +293,59 @@ mod test {
         map.insert(key1.as_slice(), 1);
 
         assert_eq!(map.len(), 2);
-        assert_eq!(map.contains(&key0.as_slice()), true);
-        assert_eq!(map.contains(&key2.as_slice()), false);
+        assert!(map.contains(&key0.as_slice()));
+        assert!(!map.contains(&key2.as_slice()));
+    }
+
+    #[test]
+    fn test_iteration_order() {
+        let keys = [vec![3u8], vec![1u8], vec![2u8], vec![0u8]];
+
+        let mut map = Map::new();
+        for (i, key) in keys.iter().enumerate() {
+            map.insert(key.clone(), i);
+        }
+
+        let sorted_keys = map.keys().map(|key| key[0]).collect::<Vec<_>>();
+        assert_eq!(sorted_keys, vec![0, 1, 2, 3]);
+        assert_eq!(
+            map.get_all_keys(),
+            Some(vec![&vec![0u8], &vec![1u8], &vec![2u8], &vec![3u8]])
+        );
+
+        let reversed = keys
+            .iter()
+            .rev()
+            .cloned()
+            .enumerate()
+            .map(|(i, key)| (key, 3 - i))
+            .collect::<Map<_, _>>();
+        assert_eq!(
+            reversed.into_iter().collect::<Vec<_>>(),
+            map.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map = Map::new();
+
+        *map.entry(vec![1u8]).or_insert(0) += 10;
+        *map.entry(vec![1u8]).or_insert(0) += 10;
+        map.entry(vec![0u8])
+            .and_modify(|value| *value += 1)
+            .or_insert_with(|| 5);
+        map.entry(vec![0u8])
+            .and_modify(|value| *value += 1)
+            .or_insert_with(|| 5);
+
+        assert_eq!(map.get(&vec![1u8]), Some(&20));
+        assert_eq!(map.get(&vec![0u8]), Some(&6));
+
+        match map.entry(vec![1u8]) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), (vec![1u8], 20)),
+            Entry::Vacant(_) => panic!("the entry should be occupied"),
+        }
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&vec![0u8]]);
     }
 }
@@ -1,9 +1,37 @@
 use core::fmt::Debug;
 
 use super::map::Map;
+use super::vec_map::VecMap;
 
-pub fn add<K, V>(map: &mut Map<K, V>, key: K, value: V)
+/// The common operations of `Map` and `VecMap` which are required by the helper functions in this module.
+pub trait Mapping<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Debug + Ord, V: Clone + Debug + PartialEq> Mapping<K, V> for Map<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        Map::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        Map::insert(self, key, value)
+    }
+}
+
+impl<K: Debug + PartialEq, V: Clone + Debug + PartialEq> Mapping<K, V> for VecMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        VecMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        VecMap::insert(self, key, value)
+    }
+}
+
+pub fn add<M, K, V>(map: &mut M, key: K, value: V)
 where
+    M: Mapping<K, V>,
     K: Clone + Debug + PartialEq,
     V: Clone + Debug + PartialEq + core::ops::Add<Output = V>,
 {
@@ -30,8 +58,21 @@ mod test {
 
         assert_eq!(map.get(&key0.as_slice()), Some(&0));
 
-        add(&mut map, &key0.as_slice(), 100);
+        add(&mut map, key0.as_slice(), 100);
 
         assert_eq!(map.get(&key0.as_slice()), Some(&100));
     }
+
+    #[test]
+    fn test_add_to_vec_map() {
+        let key0 = vec![0u8, 0u8, 0u8];
+        let key1 = vec![0u8, 0u8, 1u8];
+        let mut map = VecMap::new();
+
+        add(&mut map, key1.as_slice(), 100);
+        add(&mut map, key0.as_slice(), 100);
+        add(&mut map, key1.as_slice(), 100);
+
+        assert_eq!(map.items, vec![(key1.as_slice(), 200), (key0.as_slice(), 100)]);
+    }
 }
@@ -0,0 +1,203 @@
+//! The encodings of the data of ConfigCellCharSet* witnesses, the data here means the bytes after the
+//! WITNESS_LENGTH_BYTES length header:
+//!
+//! - Legacy: flags(1) + char + 0x00 + char + 0x00 ..., chars can only be found by a linear scan.
+//! - Indexed: flags(1) + count(4) + offset(4) * count + chars, the chars are sorted by their bytes in ascending order
+//!   and concatenated without separators, each offset is the start of a char in the chars part, so chars can be found
+//!   by binary search.
+//!
+//! The flags is made of the CHAR_SET_FLAG_* bits, the legacy data has only the CHAR_SET_FLAG_GLOBAL bit, so it can
+//! still be decoded as it is.
+
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryInto;
+
+use super::constants::{CHAR_SET_FLAG_GLOBAL, CHAR_SET_FLAG_INDEXED};
+
+const COUNT_BYTES: usize = 4;
+const OFFSET_BYTES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharSetDecodingError {
+    DataIsEmpty,
+    IndexIsTruncated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Legacy,
+    Indexed { count: usize },
+}
+
+/// The decoded data of a char-set which supports membership lookup of chars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharSetData {
+    global: bool,
+    encoding: Encoding,
+    // The bytes after the flags for legacy data, or the bytes after the count for indexed data.
+    data: Vec<u8>,
+}
+
+impl CharSetData {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, CharSetDecodingError> {
+        let flags = *slice.first().ok_or(CharSetDecodingError::DataIsEmpty)?;
+        let global = flags & CHAR_SET_FLAG_GLOBAL == CHAR_SET_FLAG_GLOBAL;
+
+        if flags & CHAR_SET_FLAG_INDEXED != CHAR_SET_FLAG_INDEXED {
+            return Ok(CharSetData {
+                global,
+                encoding: Encoding::Legacy,
+                data: slice[1..].to_vec(),
+            });
+        }
+
+        let count = slice
+            .get(1..(1 + COUNT_BYTES))
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or(CharSetDecodingError::IndexIsTruncated)?;
+        let data = &slice[(1 + COUNT_BYTES)..];
+        if data.len() < count * OFFSET_BYTES {
+            return Err(CharSetDecodingError::IndexIsTruncated);
+        }
+
+        Ok(CharSetData {
+            global,
+            encoding: Encoding::Indexed { count },
+            data: data.to_vec(),
+        })
+    }
+
+    pub fn is_global(&self) -> bool {
+        self.global
+    }
+
+    pub fn is_indexed(&self) -> bool {
+        matches!(self.encoding, Encoding::Indexed { .. })
+    }
+
+    pub fn contains(&self, char: &[u8]) -> bool {
+        match self.encoding {
+            Encoding::Legacy => self.iter().any(|item| item == char),
+            Encoding::Indexed { count } => {
+                let (mut low, mut high) = (0, count);
+                while low < high {
+                    let mid = low + (high - low) / 2;
+                    let item = match self.indexed_char(mid, count) {
+                        Some(item) => item,
+                        // The index is broken, so nothing can be trusted.
+                        None => return false,
+                    };
+                    match item.cmp(char) {
+                        Ordering::Less => low = mid + 1,
+                        Ordering::Greater => high = mid,
+                        Ordering::Equal => return true,
+                    }
+                }
+
+                false
+            }
+        }
+    }
+
+    pub fn iter(&self) -> CharSetIter<'_> {
+        CharSetIter { data: self, cursor: 0 }
+    }
+
+    fn indexed_char(&self, index: usize, count: usize) -> Option<&[u8]> {
+        let offset_of = |i: usize| -> Option<usize> {
+            self.data
+                .get((i * OFFSET_BYTES)..((i + 1) * OFFSET_BYTES))
+                .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+        };
+
+        let chars = &self.data[(count * OFFSET_BYTES)..];
+        let start = offset_of(index)?;
+        let end = if index + 1 < count {
+            offset_of(index + 1)?
+        } else {
+            chars.len()
+        };
+
+        chars.get(start..end)
+    }
+}
+
+pub struct CharSetIter<'a> {
+    data: &'a CharSetData,
+    // The byte position for legacy data, or the index of chars for indexed data.
+    cursor: usize,
+}
+
+impl<'a> Iterator for CharSetIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.encoding {
+            Encoding::Legacy => {
+                // The bytes after the last 0x00 are not a complete char, so they are ignored.
+                let rest = self.data.data.get(self.cursor..)?;
+                let len = rest.iter().position(|byte| *byte == 0)?;
+                self.cursor += len + 1;
+
+                Some(&rest[..len])
+            }
+            Encoding::Indexed { count } => {
+                if self.cursor >= count {
+                    return None;
+                }
+                let item = self.data.indexed_char(self.cursor, count)?;
+                self.cursor += 1;
+
+                Some(item)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn encode_flags(global: bool, indexed: bool) -> u8 {
+    let mut flags = 0;
+    if global {
+        flags |= CHAR_SET_FLAG_GLOBAL;
+    }
+    if indexed {
+        flags |= CHAR_SET_FLAG_INDEXED;
+    }
+
+    flags
+}
+
+/// Encode chars in the legacy format, the order of chars is kept.
+#[cfg(feature = "std")]
+pub fn encode_legacy<T: AsRef<[u8]>>(global: bool, chars: &[T]) -> Vec<u8> {
+    let mut ret = vec![encode_flags(global, false)];
+    for char in chars {
+        ret.extend(char.as_ref());
+        ret.push(0);
+    }
+
+    ret
+}
+
+/// Encode chars in the indexed format, the chars will be sorted and deduplicated.
+#[cfg(feature = "std")]
+pub fn encode_indexed<T: AsRef<[u8]>>(global: bool, chars: &[T]) -> Vec<u8> {
+    let mut sorted = chars.iter().map(|char| char.as_ref()).collect::<Vec<_>>();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut ret = vec![encode_flags(global, true)];
+    ret.extend((sorted.len() as u32).to_le_bytes());
+    let mut offset = 0u32;
+    for char in sorted.iter() {
+        ret.extend(offset.to_le_bytes());
+        offset += char.len() as u32;
+    }
+    for char in sorted {
+        ret.extend(char);
+    }
+
+    ret
+}
@@ -61,6 +61,8 @@ pub enum DataType {
     DeviceKeyListEntityData,
     SubAccountRenewSign,
     DeviceKeyListCellData,
+    AccountListProof,
+    CrossChainUnlockAttestation,
     ConfigCellAccount = 100,              // args: 0x64000000
     ConfigCellApply = 101,                // args: 0x65000000
     ConfigCellIncome = 103,               // args: 0x67000000
@@ -78,6 +80,9 @@ pub enum DataType {
     ConfigCellSystemStatus,               // args: 0x73000000
     ConfigCellSMTNodeWhitelist,           // args: 0x74000000
     ConfigCellDPoint,                     // args: 0x75000000
+    ConfigCellAccountList,                // args: 0x76000000
+    ConfigCellConfusables,                // args: 0x77000000
+    ConfigCellCrossChain,                 // args: 0x78000000
     ConfigCellPreservedAccount00 = 10000, // args: 0x10270000
     ConfigCellPreservedAccount01,
     ConfigCellPreservedAccount02,
@@ -109,6 +114,10 @@ pub enum DataType {
     ConfigCellCharSetTr,
     ConfigCellCharSetTh,
     ConfigCellCharSetVi,
+    ConfigCellCharSetAr = 100011, // args: 0xab860100
+    ConfigCellCharSetHi = 100012, // args: 0xac860100
+    ConfigCellCharSetHe = 100013, // args: 0xad860100
+    ConfigCellCharSetEl = 100014, // args: 0xae860100
     OrderInfo = 199999,
 }
 
@@ -129,7 +138,40 @@ impl<'r> TryFrom<Uint32Reader<'r>> for DataType {
 }
 
 // The length of CharSetType
-pub const CHAR_SET_LENGTH: usize = 11;
+pub const CHAR_SET_LENGTH: usize = 15;
+
+// The bit flags in the first byte of the data of ConfigCellCharSet* witnesses.
+pub const CHAR_SET_FLAG_GLOBAL: u8 = 0b0000_0001;
+pub const CHAR_SET_FLAG_INDEXED: u8 = 0b0000_0010;
+
+// The char_set of ReleaseRule which means the rule matches accounts of any char-sets.
+pub const RELEASE_RULE_ANY_CHAR_SET: u32 = u32::MAX;
+
+// The char_set of Promotion which means the promotion matches accounts of any char-sets.
+pub const PROMOTION_ANY_CHAR_SET: u32 = u32::MAX;
+
+// The first byte of the data of ConfigCellRecordKeyNamespace witnesses which means the data is in the v2 format, the
+// legacy data always starts with an ASCII char of a key.
+pub const RECORD_KEY_NAMESPACE_V2_FLAG: u8 = 0xff;
+
+/// The validators of record values, every record key in the v2 format of ConfigCellRecordKeyNamespace is bound to one
+/// of them.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, TryFromPrimitive, EnumString, Display)]
+#[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum RecordValueValidator {
+    Any,
+    // 0x prefixed hex with EIP-55 checksum, the all lowercase and all uppercase forms are also accepted.
+    EvmAddress,
+    // bech32 or bech32m with valid checksum.
+    Bech32,
+    // base58 with the double SHA256 checksum.
+    Base58Check,
+    // IPFS/IPNS CIDv0 or CIDv1, the ipfs:// and ipns:// schemes are optional.
+    ContentHash,
+    // URL with a scheme and a non-empty authority.
+    Url,
+}
 
 #[derive(Debug, PartialEq, Copy, Clone, TryFromPrimitive, EnumString, Display)]
 #[cfg_attr(not(feature = "no_std"), derive(Serialize, Deserialize))]
@@ -145,7 +187,11 @@ pub enum CharSetType {
     Ru,
     Tr,
     Th,
-    Vi, // ⚠️ DO NOT Forget to update CHAR_SET_LENGTH at the same time.
+    Vi,
+    Ar, // Arabic
+    Hi, // Devanagari
+    He, // Hebrew
+    El, // Greek, ⚠️ DO NOT Forget to update CHAR_SET_LENGTH at the same time.
 }
 
 impl Default for CharSetType {
@@ -262,6 +308,9 @@ pub enum DasLockType {
 pub enum LockRole {
     Owner,
     Manager,
+    // The delegate of a sub-account, it can only edit the records whose keys are under its key_prefixes, see
+    // SubAccount.records_delegates in cell.mol .
+    Delegate,
 }
 
 #[derive(Debug, PartialEq, Copy, Clone, EnumString, Display)]
@@ -445,6 +494,8 @@ pub enum Action {
     CreateIncome,
     #[strum(serialize = "consolidate_income")]
     ConsolidateIncome,
+    #[strum(serialize = "claim_income")]
+    ClaimIncome,
     #[strum(serialize = "transfer")]
     Transfer,
     #[strum(serialize = "create_device_key_list")]
@@ -453,6 +504,12 @@ pub enum Action {
     UpdateDeviceKeyList,
     #[strum(serialize = "destroy_device_key_list")]
     DestroyDeviceKeyList,
+    #[strum(serialize = "propose_device_key_list_recovery")]
+    ProposeDeviceKeyListRecovery,
+    #[strum(serialize = "cancel_device_key_list_recovery")]
+    CancelDeviceKeyListRecovery,
+    #[strum(serialize = "confirm_device_key_list_recovery")]
+    ConfirmDeviceKeyListRecovery,
 
     #[default]
     Others,
@@ -2,11 +2,14 @@
 #[cfg(feature = "no_std")]
 extern crate alloc;
 
+pub mod char_set;
 pub mod constants;
 pub mod convert;
 pub mod data_parser;
+pub mod lv_witness;
 pub mod mixer;
 pub mod prettier;
+pub mod record_key_namespace;
 pub mod types;
 pub mod util;
 
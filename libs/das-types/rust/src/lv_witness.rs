@@ -0,0 +1,52 @@
+//! The structs of the witnesses which are encoded in LV(length-value) format, they are wrapped in the same way as
+//! other witnesses: 'das'(3) + DATA_TYPE(4) + LV encoded fields.
+//!
+//! These structs only split the fields out without copying, the meanings of them should be checked by their users.
+
+use das_lv::LvDecode;
+#[cfg(feature = "std")]
+use das_lv::LvEncode;
+
+/// The witness with DataType::ReverseRecord .
+#[derive(Debug, Clone, PartialEq, LvDecode)]
+#[cfg_attr(feature = "std", derive(LvEncode))]
+pub struct RawReverseRecordWitness<'a> {
+    pub version: u32,
+    /// The string of ReverseRecordAction.
+    pub action: &'a str,
+    pub signature: &'a [u8],
+    /// The u8 of DasLockType.
+    pub sign_type: u8,
+    pub address_payload: &'a [u8],
+    pub proof: &'a [u8],
+    /// It is empty when the reverse record is not exist in the SMT.
+    pub prev_nonce: Option<u32>,
+    pub prev_account: &'a [u8],
+    pub next_root: [u8; 32],
+    pub next_account: &'a str,
+}
+
+/// The witness with DataType::SubAccount .
+#[derive(Debug, Clone, PartialEq, LvDecode)]
+#[cfg_attr(feature = "std", derive(LvEncode))]
+pub struct RawSubAccountWitness<'a> {
+    #[lv(version)]
+    pub version: u32,
+    /// The string of SubAccountAction.
+    pub action: &'a str,
+    pub signature: &'a [u8],
+    /// The u8 of LockRole, it may be empty in some actions.
+    pub sign_role: &'a [u8],
+    /// The LE u64 timestamp, it may be empty in some actions.
+    pub sign_expired_at: &'a [u8],
+    pub new_root: &'a [u8],
+    pub proof: &'a [u8],
+    #[lv(since_version = 3)]
+    pub old_sub_account_version: Option<u32>,
+    #[lv(since_version = 3)]
+    pub new_sub_account_version: Option<u32>,
+    /// The molecule encoded SubAccount, its version is decided by the action and the old_sub_account_version.
+    pub sub_account: &'a [u8],
+    pub edit_key: &'a [u8],
+    pub edit_value: &'a [u8],
+}
@@ -60,7 +60,7 @@ impl PreAccountCellDataMixer for PreAccountCellDataV2 {
     }
 }
 
-impl PreAccountCellDataMixer for PreAccountCellData {
+impl PreAccountCellDataMixer for PreAccountCellDataV3 {
     fn version(&self) -> u32 {
         3
     }
@@ -70,11 +70,22 @@ impl PreAccountCellDataMixer for PreAccountCellData {
     }
 }
 
+impl PreAccountCellDataMixer for PreAccountCellData {
+    fn version(&self) -> u32 {
+        4
+    }
+
+    fn as_reader(&self) -> Box<dyn PreAccountCellDataReaderMixer + '_> {
+        Box::new(self.as_reader())
+    }
+}
+
 pub trait PreAccountCellDataReaderMixer<'r> {
     gen_trait_common_fns!({
         version -> u32,
         try_into_v1 -> VerificationResult<PreAccountCellDataV1Reader<'r>>,
         try_into_v2 -> VerificationResult<PreAccountCellDataV2Reader<'r>>,
+        try_into_v3 -> VerificationResult<PreAccountCellDataV3Reader<'r>>,
         try_into_latest -> VerificationResult<PreAccountCellDataReader<'r>>
     });
 
@@ -107,6 +118,12 @@ impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataV1Reader<'r> {
         ))
     }
 
+    fn try_into_v3(&self) -> VerificationResult<PreAccountCellDataV3Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataV1Reader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<PreAccountCellDataReader<'r>> {
         Err(VerificationError::OffsetsNotMatch(
             "PreAccountCellDataV1Reader".to_string(),
@@ -142,6 +159,12 @@ impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataV2Reader<'r> {
         PreAccountCellDataV2Reader::from_slice(self.as_slice())
     }
 
+    fn try_into_v3(&self) -> VerificationResult<PreAccountCellDataV3Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataV2Reader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<PreAccountCellDataReader<'r>> {
         Err(VerificationError::OffsetsNotMatch(
             "PreAccountCellDataV2Reader".to_string(),
@@ -162,11 +185,52 @@ impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataV2Reader<'r> {
     });
 }
 
-impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataReader<'r> {
+impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataV3Reader<'r> {
     fn version(&self) -> u32 {
         3
     }
 
+    fn try_into_v1(&self) -> VerificationResult<PreAccountCellDataV1Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataV3Reader".to_string(),
+        ))
+    }
+
+    fn try_into_v2(&self) -> VerificationResult<PreAccountCellDataV2Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataV3Reader".to_string(),
+        ))
+    }
+
+    fn try_into_v3(&self) -> VerificationResult<PreAccountCellDataV3Reader<'r>> {
+        PreAccountCellDataV3Reader::from_slice(self.as_slice())
+    }
+
+    fn try_into_latest(&self) -> VerificationResult<PreAccountCellDataReader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataV3Reader".to_string(),
+        ))
+    }
+
+    gen_impl_field_fns!({
+        account -> AccountCharsReader<'r>,
+        refund_lock -> ScriptReader<'r>,
+        owner_lock_args -> BytesReader<'r>,
+        inviter_id -> BytesReader<'r>,
+        inviter_lock -> ScriptOptReader<'r>,
+        channel_lock -> ScriptOptReader<'r>,
+        price -> PriceConfigReader<'r>,
+        quote -> Uint64Reader<'r>,
+        invited_discount -> Uint32Reader<'r>,
+        created_at -> Uint64Reader<'r>
+    });
+}
+
+impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataReader<'r> {
+    fn version(&self) -> u32 {
+        4
+    }
+
     fn try_into_v1(&self) -> VerificationResult<PreAccountCellDataV1Reader<'r>> {
         Err(VerificationError::OffsetsNotMatch(
             "PreAccountCellDataReader".to_string(),
@@ -179,6 +243,12 @@ impl<'r> PreAccountCellDataReaderMixer<'r> for PreAccountCellDataReader<'r> {
         ))
     }
 
+    fn try_into_v3(&self) -> VerificationResult<PreAccountCellDataV3Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "PreAccountCellDataReader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<PreAccountCellDataReader<'r>> {
         PreAccountCellDataReader::from_slice(self.as_slice())
     }
@@ -224,7 +294,7 @@ impl AccountCellDataMixer for AccountCellDataV3 {
     }
 }
 
-impl AccountCellDataMixer for AccountCellData {
+impl AccountCellDataMixer for AccountCellDataV4 {
     fn version(&self) -> u32 {
         4
     }
@@ -234,11 +304,22 @@ impl AccountCellDataMixer for AccountCellData {
     }
 }
 
+impl AccountCellDataMixer for AccountCellData {
+    fn version(&self) -> u32 {
+        5
+    }
+
+    fn as_reader(&self) -> Box<dyn AccountCellDataReaderMixer + '_> {
+        Box::new(self.as_reader())
+    }
+}
+
 pub trait AccountCellDataReaderMixer<'r> {
     gen_trait_common_fns!({
         version -> u32,
         try_into_v2 -> VerificationResult<AccountCellDataV2Reader<'r>>,
         try_into_v3 -> VerificationResult<AccountCellDataV3Reader<'r>>,
+        try_into_v4 -> VerificationResult<AccountCellDataV4Reader<'r>>,
         try_into_latest -> VerificationResult<AccountCellDataReader<'r>>
     });
 
@@ -269,6 +350,12 @@ impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataV2Reader<'r> {
         ))
     }
 
+    fn try_into_v4(&self) -> VerificationResult<AccountCellDataV4Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "AccountCellDataV4Reader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<AccountCellDataReader<'r>> {
         Err(VerificationError::OffsetsNotMatch("AccountCellDataReader".to_string()))
     }
@@ -300,6 +387,12 @@ impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataV3Reader<'r> {
         AccountCellDataV3Reader::from_slice(self.as_slice())
     }
 
+    fn try_into_v4(&self) -> VerificationResult<AccountCellDataV4Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "AccountCellDataV4Reader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<AccountCellDataReader<'r>> {
         Err(VerificationError::OffsetsNotMatch("AccountCellDataReader".to_string()))
     }
@@ -316,7 +409,7 @@ impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataV3Reader<'r> {
     });
 }
 
-impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataReader<'r> {
+impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataV4Reader<'r> {
     fn version(&self) -> u32 {
         4
     }
@@ -333,6 +426,49 @@ impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataReader<'r> {
         ))
     }
 
+    fn try_into_v4(&self) -> VerificationResult<AccountCellDataV4Reader<'r>> {
+        AccountCellDataV4Reader::from_slice(self.as_slice())
+    }
+
+    fn try_into_latest(&self) -> VerificationResult<AccountCellDataReader<'r>> {
+        Err(VerificationError::OffsetsNotMatch("AccountCellDataReader".to_string()))
+    }
+
+    gen_impl_field_fns!({
+        id -> AccountIdReader<'r>,
+        account -> AccountCharsReader<'r>,
+        registered_at -> Uint64Reader<'r>,
+        last_transfer_account_at -> Uint64Reader<'r>,
+        last_edit_manager_at -> Uint64Reader<'r>,
+        last_edit_records_at -> Uint64Reader<'r>,
+        status -> Uint8Reader<'r>,
+        records -> RecordsReader<'r>
+    });
+}
+
+impl<'r> AccountCellDataReaderMixer<'r> for AccountCellDataReader<'r> {
+    fn version(&self) -> u32 {
+        5
+    }
+
+    fn try_into_v2(&self) -> VerificationResult<AccountCellDataV2Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "AccountCellDataV2Reader".to_string(),
+        ))
+    }
+
+    fn try_into_v3(&self) -> VerificationResult<AccountCellDataV3Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "AccountCellDataV3Reader".to_string(),
+        ))
+    }
+
+    fn try_into_v4(&self) -> VerificationResult<AccountCellDataV4Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch(
+            "AccountCellDataV4Reader".to_string(),
+        ))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<AccountCellDataReader<'r>> {
         AccountCellDataReader::from_slice(self.as_slice())
     }
@@ -444,6 +580,7 @@ pub trait SubAccountMixer {
         as_reader -> Box<dyn SubAccountReaderMixer + '_>,
         as_slice -> &[u8],
         try_into_v1 -> VerificationResult<SubAccountV1>,
+        try_into_v2 -> VerificationResult<SubAccountV2>,
         try_into_latest -> VerificationResult<SubAccount>,
         clone -> Box<dyn SubAccountMixer>,
         as_prettier -> String
@@ -467,6 +604,10 @@ impl SubAccountMixer for SubAccountV1 {
         SubAccountV1::from_slice(Entity::as_slice(self))
     }
 
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountV2".to_string()))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<SubAccount> {
         Err(VerificationError::OffsetsNotMatch("SubAccount".to_string()))
     }
@@ -480,7 +621,7 @@ impl SubAccountMixer for SubAccountV1 {
     }
 }
 
-impl SubAccountMixer for SubAccount {
+impl SubAccountMixer for SubAccountV2 {
     fn version(&self) -> u32 {
         2
     }
@@ -494,6 +635,44 @@ impl SubAccountMixer for SubAccount {
     }
 
     fn try_into_v1(&self) -> VerificationResult<SubAccountV1> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountV2".to_string()))
+    }
+
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2> {
+        SubAccountV2::from_slice(Entity::as_slice(self))
+    }
+
+    fn try_into_latest(&self) -> VerificationResult<SubAccount> {
+        Err(VerificationError::OffsetsNotMatch("SubAccount".to_string()))
+    }
+
+    fn clone(&self) -> Box<dyn SubAccountMixer> {
+        Box::new(Clone::clone(self))
+    }
+
+    fn as_prettier(&self) -> String {
+        Prettier::as_prettier(self)
+    }
+}
+
+impl SubAccountMixer for SubAccount {
+    fn version(&self) -> u32 {
+        3
+    }
+
+    fn as_reader(&self) -> Box<dyn SubAccountReaderMixer + '_> {
+        Box::new(self.as_reader())
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        Entity::as_slice(self)
+    }
+
+    fn try_into_v1(&self) -> VerificationResult<SubAccountV1> {
+        Err(VerificationError::OffsetsNotMatch("SubAccount".to_string()))
+    }
+
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2> {
         Err(VerificationError::OffsetsNotMatch("SubAccount".to_string()))
     }
 
@@ -514,6 +693,7 @@ pub trait SubAccountReaderMixer<'r> {
     gen_trait_common_fns!({
         version -> u32,
         try_into_v1 -> VerificationResult<SubAccountV1Reader<'r>>,
+        try_into_v2 -> VerificationResult<SubAccountV2Reader<'r>>,
         try_into_latest -> VerificationResult<SubAccountReader<'r>>,
         as_slice -> &[u8],
         as_prettier -> String
@@ -543,6 +723,10 @@ impl<'r> SubAccountReaderMixer<'r> for SubAccountV1Reader<'r> {
         SubAccountV1Reader::from_slice(Reader::as_slice(self))
     }
 
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountV2Reader".to_string()))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<SubAccountReader<'r>> {
         Err(VerificationError::OffsetsNotMatch("SubAccountReader".to_string()))
     }
@@ -570,7 +754,7 @@ impl<'r> SubAccountReaderMixer<'r> for SubAccountV1Reader<'r> {
     });
 }
 
-impl<'r> SubAccountReaderMixer<'r> for SubAccountReader<'r> {
+impl<'r> SubAccountReaderMixer<'r> for SubAccountV2Reader<'r> {
     fn version(&self) -> u32 {
         2
     }
@@ -579,6 +763,50 @@ impl<'r> SubAccountReaderMixer<'r> for SubAccountReader<'r> {
         Err(VerificationError::OffsetsNotMatch("SubAccountV1Reader".to_string()))
     }
 
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2Reader<'r>> {
+        SubAccountV2Reader::from_slice(Reader::as_slice(self))
+    }
+
+    fn try_into_latest(&self) -> VerificationResult<SubAccountReader<'r>> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountReader".to_string()))
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        Reader::as_slice(self)
+    }
+
+    fn as_prettier(&self) -> String {
+        Prettier::as_prettier(self)
+    }
+
+    gen_impl_field_fns!({
+        lock -> ScriptReader<'r>,
+        id -> AccountIdReader<'r>,
+        account -> AccountCharsReader<'r>,
+        suffix -> BytesReader<'r>,
+        registered_at -> Uint64Reader<'r>,
+        expired_at -> Uint64Reader<'r>,
+        status -> Uint8Reader<'r>,
+        records -> RecordsReader<'r>,
+        nonce -> Uint64Reader<'r>,
+        enable_sub_account -> Uint8Reader<'r>,
+        renew_sub_account_price -> Uint64Reader<'r>
+    });
+}
+
+impl<'r> SubAccountReaderMixer<'r> for SubAccountReader<'r> {
+    fn version(&self) -> u32 {
+        3
+    }
+
+    fn try_into_v1(&self) -> VerificationResult<SubAccountV1Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountV1Reader".to_string()))
+    }
+
+    fn try_into_v2(&self) -> VerificationResult<SubAccountV2Reader<'r>> {
+        Err(VerificationError::OffsetsNotMatch("SubAccountV2Reader".to_string()))
+    }
+
     fn try_into_latest(&self) -> VerificationResult<SubAccountReader<'r>> {
         SubAccountReader::from_slice(Reader::as_slice(self))
     }
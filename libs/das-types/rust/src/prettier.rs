@@ -12,7 +12,7 @@ use core::convert::TryFrom;
 use std::convert::TryFrom;
 
 use super::schemas::packed::*;
-use crate::constants::CharSetType;
+use crate::constants::{CharSetType, PROMOTION_ANY_CHAR_SET, RELEASE_RULE_ANY_CHAR_SET};
 
 macro_rules! print_fields {
     ($self:expr, $struct_name:expr, {$( $tt:tt ),+}) => {
@@ -291,6 +291,31 @@ impl<'a> Prettier for PreAccountCellDataV2Reader<'a> {
     }
 }
 
+impl Prettier for PreAccountCellDataV3 {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for PreAccountCellDataV3Reader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "PreAccountCellData", {
+            account,
+            refund_lock,
+            owner_lock_args,
+            inviter_id,
+            inviter_lock,
+            channel_lock,
+            price,
+            quote,
+            invited_discount,
+            created_at,
+            initial_records,
+            initial_cross_chain
+        })
+    }
+}
+
 impl Prettier for PreAccountCellData {
     fn as_prettier(&self) -> String {
         self.as_reader().as_prettier()
@@ -311,7 +336,40 @@ impl<'a> Prettier for PreAccountCellDataReader<'a> {
             invited_discount,
             created_at,
             initial_records,
-            initial_cross_chain
+            initial_cross_chain,
+            paid_udt
+        })
+    }
+}
+
+impl Prettier for PaidUdtOpt {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for PaidUdtOptReader<'a> {
+    fn as_prettier(&self) -> String {
+        if let Some(v) = self.to_opt() {
+            format!("PaidUdtOpt(Some({}))", v.as_prettier())
+        } else {
+            String::from("PaidUdtOpt(None)")
+        }
+    }
+}
+
+impl Prettier for PaidUdt {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for PaidUdtReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "PaidUdt", {
+            type_script,
+            amount,
+            ckb_quote
         })
     }
 }
@@ -368,6 +426,45 @@ impl<'a> Prettier for RecordReader<'a> {
     }
 }
 
+impl Prettier for RecordsDelegates {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for RecordsDelegatesReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+
+        for item in self.iter() {
+            ret = ret + &item.as_prettier() + ", ";
+        }
+
+        ret + "]"
+    }
+}
+
+impl Prettier for RecordsDelegate {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for RecordsDelegateReader<'a> {
+    fn as_prettier(&self) -> String {
+        let fmt_key_prefixes = self
+            .key_prefixes()
+            .iter()
+            .map(|prefix| String::from_utf8_lossy(prefix.raw_data()).into_owned())
+            .collect::<Vec<_>>();
+        let fmt_key_prefixes = format!("{:?}", fmt_key_prefixes);
+        print_fields!(self, "RecordsDelegate", {
+            lock_args,
+            (key_prefixes -> &fmt_key_prefixes)
+        })
+    }
+}
+
 impl Prettier for AccountCellDataV2 {
     fn as_prettier(&self) -> String {
         self.as_reader().as_prettier()
@@ -412,6 +509,30 @@ impl<'a> Prettier for AccountCellDataV3Reader<'a> {
     }
 }
 
+impl Prettier for AccountCellDataV4 {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for AccountCellDataV4Reader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "AccountCellDataV4", {
+            id,
+            account,
+            registered_at,
+            last_transfer_account_at,
+            last_edit_manager_at,
+            last_edit_records_at,
+            status,
+            records,
+            enable_sub_account,
+            renew_sub_account_price,
+            approval
+        })
+    }
+}
+
 impl Prettier for AccountCellData {
     fn as_prettier(&self) -> String {
         self.as_reader().as_prettier()
@@ -431,7 +552,8 @@ impl<'a> Prettier for AccountCellDataReader<'a> {
             records,
             enable_sub_account,
             renew_sub_account_price,
-            approval
+            approval,
+            cross_chain
         })
     }
 }
@@ -716,6 +838,40 @@ impl Prettier for ConfigCellMain {
 impl<'a> Prettier for ConfigCellMainReader<'a> {
     fn as_prettier(&self) -> String {
         print_fields!(self, "ConfigCellMain", {
+            status,
+            type_id_table,
+            das_lock_out_point_table,
+            das_lock_type_id_table,
+            oracle
+        })
+    }
+}
+
+impl Prettier for OracleConfig {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for OracleConfigReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "OracleConfig", {
+            min_cells,
+            max_age,
+            provider_locks
+        })
+    }
+}
+
+impl Prettier for ConfigCellMainV12 {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfigCellMainV12Reader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "ConfigCellMainV12", {
             status,
             type_id_table,
             das_lock_out_point_table,
@@ -1277,7 +1433,10 @@ impl<'a> Prettier for ConfigCellPriceReader<'a> {
     fn as_prettier(&self) -> String {
         print_fields!(self, "ConfigCellApply", {
             discount,
-            prices
+            prices,
+            multi_year_discounts,
+            promotions,
+            udt_payments
         })
     }
 }
@@ -1328,6 +1487,117 @@ impl<'a> Prettier for PriceConfigReader<'a> {
     }
 }
 
+impl Prettier for MultiYearDiscounts {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for MultiYearDiscountsReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+
+        for item in self.iter() {
+            ret = ret + &item.as_prettier() + ", ";
+        }
+
+        ret + "]"
+    }
+}
+
+impl Prettier for MultiYearDiscount {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for MultiYearDiscountReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "MultiYearDiscount", {
+            years,
+            discount
+        })
+    }
+}
+
+impl Prettier for Promotions {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for PromotionsReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+
+        for item in self.iter() {
+            ret = ret + &item.as_prettier() + ", ";
+        }
+
+        ret + "]"
+    }
+}
+
+impl Prettier for Promotion {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for PromotionReader<'a> {
+    fn as_prettier(&self) -> String {
+        let char_set_type_raw = u32::from(self.char_set());
+        let char_set_type_str = if char_set_type_raw == PROMOTION_ANY_CHAR_SET {
+            String::from("Any")
+        } else {
+            let char_set_type = CharSetType::try_from(char_set_type_raw).expect("Decoding char_set_type failed.");
+            format!("{:?}", char_set_type)
+        };
+
+        print_fields!(self, "Promotion", {
+            start_at,
+            end_at,
+            (char_set -> &char_set_type_str),
+            min_length,
+            max_length,
+            discount
+        })
+    }
+}
+
+impl Prettier for UdtPayments {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for UdtPaymentsReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+
+        for item in self.iter() {
+            ret = ret + &item.as_prettier() + ", ";
+        }
+
+        ret + "]"
+    }
+}
+
+impl Prettier for UdtPayment {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for UdtPaymentReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "UdtPayment", {
+            type_script,
+            decimals
+        })
+    }
+}
+
 impl Prettier for ConfigCellProposal {
     fn as_prettier(&self) -> String {
         self.as_reader().as_prettier()
@@ -1465,7 +1735,53 @@ impl Prettier for ConfigCellRelease {
 
 impl<'a> Prettier for ConfigCellReleaseReader<'a> {
     fn as_prettier(&self) -> String {
-        print_fields!(self, "ConfigCellRelease", { lucky_number })
+        print_fields!(self, "ConfigCellRelease", {
+            lucky_number,
+            release_rules
+        })
+    }
+}
+
+impl Prettier for ReleaseRules {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ReleaseRulesReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+
+        for item in self.iter() {
+            ret = ret + &item.as_prettier() + ", ";
+        }
+
+        ret + "]"
+    }
+}
+
+impl Prettier for ReleaseRule {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ReleaseRuleReader<'a> {
+    fn as_prettier(&self) -> String {
+        let char_set_type_raw = u32::from(self.char_set());
+        let char_set_type_str = if char_set_type_raw == RELEASE_RULE_ANY_CHAR_SET {
+            String::from("Any")
+        } else {
+            let char_set_type = CharSetType::try_from(char_set_type_raw).expect("Decoding char_set_type failed.");
+            format!("{:?}", char_set_type)
+        };
+
+        print_fields!(self, "ReleaseRule", {
+            (char_set -> &char_set_type_str),
+            min_length,
+            max_length,
+            effective_at
+        })
     }
 }
 
@@ -1494,6 +1810,32 @@ impl<'a> Prettier for SubAccountV1Reader<'a> {
     }
 }
 
+impl Prettier for SubAccountV2 {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for SubAccountV2Reader<'a> {
+    fn as_prettier(&self) -> String {
+        let fmt_suffix = String::from_utf8(self.suffix().raw_data().to_vec()).expect("Encoding utf-8 failed.");
+        print_fields!(self, "SubAccountV2", {
+            lock,
+            id,
+            account,
+            (suffix -> &fmt_suffix),
+            registered_at,
+            expired_at,
+            status,
+            records,
+            nonce,
+            enable_sub_account,
+            renew_sub_account_price,
+            approval
+        })
+    }
+}
+
 impl Prettier for SubAccount {
     fn as_prettier(&self) -> String {
         self.as_reader().as_prettier()
@@ -1515,7 +1857,8 @@ impl<'a> Prettier for SubAccountReader<'a> {
             nonce,
             enable_sub_account,
             renew_sub_account_price,
-            approval
+            approval,
+            records_delegates
         })
     }
 }
@@ -1574,3 +1917,216 @@ impl<'a> Prettier for ConfigCellDPointReader<'a> {
         })
     }
 }
+
+impl Prettier for ConfigCellAccountList {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfigCellAccountListReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "ConfigCellAccountList", {
+            preserved_account_root,
+            unavailable_account_root
+        })
+    }
+}
+
+impl Prettier for ConfigCellConfusables {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfigCellConfusablesReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "ConfigCellConfusables", {
+            chars,
+            protected_skeletons
+        })
+    }
+}
+
+impl Prettier for ConfusableChars {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfusableCharsReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+        let mut comma = "";
+        for item in self.iter() {
+            ret += comma;
+            ret += item.as_prettier().as_str();
+            comma = ", ";
+        }
+        ret + " ]"
+    }
+}
+
+impl Prettier for ConfusableChar {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfusableCharReader<'a> {
+    fn as_prettier(&self) -> String {
+        let char = String::from_utf8_lossy(self.char().raw_data());
+        let prototype = String::from_utf8_lossy(self.prototype().raw_data());
+        print_fields!(self, "ConfusableChar", {
+            (char -> &char),
+            (prototype -> &prototype)
+        })
+    }
+}
+
+impl Prettier for HashList {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for HashListReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+        let mut comma = "";
+        for item in self.iter() {
+            ret += comma;
+            ret += item.as_prettier().as_str();
+            comma = ", ";
+        }
+        ret + " ]"
+    }
+}
+
+impl Prettier for ConfigCellCrossChain {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for ConfigCellCrossChainReader<'a> {
+    fn as_prettier(&self) -> String {
+        let fmt_relayers = self
+            .relayers()
+            .iter()
+            .map(|relayer| relayer.as_prettier())
+            .collect::<Vec<_>>();
+        let fmt_relayers = format!("[ {} ]", fmt_relayers.join(", "));
+        print_fields!(self, "ConfigCellCrossChain", {
+            threshold,
+            (relayers -> &fmt_relayers),
+            chains
+        })
+    }
+}
+
+impl Prettier for CrossChainList {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for CrossChainListReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+        let mut comma = "";
+        for item in self.iter() {
+            ret += comma;
+            ret += item.as_prettier().as_str();
+            comma = ", ";
+        }
+        ret + " ]"
+    }
+}
+
+impl Prettier for CrossChain {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for CrossChainReader<'a> {
+    fn as_prettier(&self) -> String {
+        let fmt_name = String::from_utf8_lossy(self.name().raw_data()).into_owned();
+        print_fields!(self, "CrossChain", {
+            coin_type,
+            chain_id,
+            (name -> &fmt_name),
+            fee,
+            enabled
+        })
+    }
+}
+
+impl Prettier for AccountListProof {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for AccountListProofReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "AccountListProof", {
+            account_id,
+            preserved_account_proof,
+            unavailable_account_proof
+        })
+    }
+}
+
+impl Prettier for CrossChainUnlockAttestation {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for CrossChainUnlockAttestationReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "CrossChainUnlockAttestation", {
+            account_id,
+            owner_lock_args,
+            coin_type,
+            chain_id,
+            signatures
+        })
+    }
+}
+
+impl Prettier for RelayerSignatures {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for RelayerSignaturesReader<'a> {
+    fn as_prettier(&self) -> String {
+        let mut ret = String::from("[ ");
+        let mut comma = "";
+        for item in self.iter() {
+            ret += comma;
+            ret += item.as_prettier().as_str();
+            comma = ", ";
+        }
+        ret + " ]"
+    }
+}
+
+impl Prettier for RelayerSignature {
+    fn as_prettier(&self) -> String {
+        self.as_reader().as_prettier()
+    }
+}
+
+impl<'a> Prettier for RelayerSignatureReader<'a> {
+    fn as_prettier(&self) -> String {
+        print_fields!(self, "RelayerSignature", {
+            relayer_index,
+            signature
+        })
+    }
+}
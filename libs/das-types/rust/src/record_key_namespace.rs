@@ -0,0 +1,111 @@
+//! The encodings of the data of ConfigCellRecordKeyNamespace witnesses, the data here means the bytes after the
+//! WITNESS_LENGTH_BYTES length header:
+//!
+//! - Legacy: key + 0x00 + key + 0x00 ..., the values of records are not validated.
+//! - V2: RECORD_KEY_NAMESPACE_V2_FLAG(1) + key + 0x00 + validator(1) + key + 0x00 + validator(1) ..., the validator
+//!   is a RecordValueValidator which the values of records with the key must pass.
+//!
+//! The keys are made of ASCII chars, so the legacy data can never start with RECORD_KEY_NAMESPACE_V2_FLAG.
+
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use super::constants::{RecordValueValidator, RECORD_KEY_NAMESPACE_V2_FLAG};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordKeyNamespaceDecodingError {
+    DataIsEmpty,
+    KeyIsTruncated,
+    ValidatorIsMissing,
+    ValidatorIsUndefined(u8),
+}
+
+/// The decoded data of ConfigCellRecordKeyNamespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordKeyNamespace {
+    v2: bool,
+    keys: Vec<(Vec<u8>, RecordValueValidator)>,
+}
+
+impl RecordKeyNamespace {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, RecordKeyNamespaceDecodingError> {
+        let flag = *slice.first().ok_or(RecordKeyNamespaceDecodingError::DataIsEmpty)?;
+        let v2 = flag == RECORD_KEY_NAMESPACE_V2_FLAG;
+
+        let mut rest = if v2 { &slice[1..] } else { slice };
+        let mut keys = Vec::new();
+        while !rest.is_empty() {
+            let len = rest
+                .iter()
+                .position(|byte| *byte == 0)
+                .ok_or(RecordKeyNamespaceDecodingError::KeyIsTruncated)?;
+            let key = rest[..len].to_vec();
+            rest = &rest[(len + 1)..];
+
+            let validator = if v2 {
+                let byte = *rest
+                    .first()
+                    .ok_or(RecordKeyNamespaceDecodingError::ValidatorIsMissing)?;
+                rest = &rest[1..];
+                RecordValueValidator::try_from(byte)
+                    .map_err(|_| RecordKeyNamespaceDecodingError::ValidatorIsUndefined(byte))?
+            } else {
+                RecordValueValidator::Any
+            };
+
+            keys.push((key, validator));
+        }
+
+        Ok(RecordKeyNamespace { v2, keys })
+    }
+
+    pub fn is_v2(&self) -> bool {
+        self.v2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Return the validator of the key if the key exists, the key should be in the format of `type.key`.
+    pub fn get(&self, key: &[u8]) -> Option<RecordValueValidator> {
+        self.keys
+            .iter()
+            .find(|(item, _)| item.as_slice() == key)
+            .map(|(_, validator)| *validator)
+    }
+
+    pub fn contains(&self, key: &[u8]) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], RecordValueValidator)> {
+        self.keys.iter().map(|(key, validator)| (key.as_slice(), *validator))
+    }
+}
+
+/// Encode keys in the legacy format, the order of keys is kept.
+#[cfg(feature = "std")]
+pub fn encode_legacy<T: AsRef<[u8]>>(keys: &[T]) -> Vec<u8> {
+    let mut ret = Vec::new();
+    for key in keys {
+        ret.extend(key.as_ref());
+        ret.push(0);
+    }
+
+    ret
+}
+
+/// Encode keys with their validators in the v2 format, the order of keys is kept.
+#[cfg(feature = "std")]
+pub fn encode_v2<T: AsRef<[u8]>>(keys: &[(T, RecordValueValidator)]) -> Vec<u8> {
+    let mut ret = vec![RECORD_KEY_NAMESPACE_V2_FLAG];
+    for (key, validator) in keys {
+        ret.extend(key.as_ref());
+        ret.push(0);
+        ret.push(*validator as u8);
+    }
+
+    ret
+}
@@ -7149,6 +7149,361 @@ impl molecule::prelude::Builder for ConfigCellMainV11Builder {
     }
 }
 #[derive(Clone)]
+pub struct ConfigCellMainV12(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellMainV12 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ConfigCellMainV12 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ConfigCellMainV12 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "type_id_table", self.type_id_table())?;
+        write!(
+            f,
+            ", {}: {}",
+            "das_lock_out_point_table",
+            self.das_lock_out_point_table()
+        )?;
+        write!(f, ", {}: {}", "das_lock_type_id_table", self.das_lock_type_id_table())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ConfigCellMainV12 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            81, 4, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 53, 2, 0, 0, 81, 3, 0, 0, 0, 32, 2, 0, 0, 64, 0, 0, 0, 96, 0, 0, 0,
+            128, 0, 0, 0, 160, 0, 0, 0, 192, 0, 0, 0, 224, 0, 0, 0, 0, 1, 0, 0, 32, 1, 0, 0, 64, 1, 0, 0, 96, 1, 0, 0,
+            128, 1, 0, 0, 160, 1, 0, 0, 192, 1, 0, 0, 224, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            28, 1, 0, 0, 32, 0, 0, 0, 68, 0, 0, 0, 104, 0, 0, 0, 140, 0, 0, 0, 176, 0, 0, 0, 212, 0, 0, 0, 248, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 96, 0, 0, 0, 128, 0, 0, 0, 160, 0, 0, 0, 192, 0, 0, 0, 224, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ConfigCellMainV12::new_unchecked(v.into())
+    }
+}
+impl ConfigCellMainV12 {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn status(&self) -> Uint8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint8::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn type_id_table(&self) -> TypeIdTable {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        TypeIdTable::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn das_lock_out_point_table(&self) -> DasLockOutPointTable {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        DasLockOutPointTable::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn das_lock_type_id_table(&self) -> DasLockTypeIdTable {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            DasLockTypeIdTable::new_unchecked(self.0.slice(start..end))
+        } else {
+            DasLockTypeIdTable::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ConfigCellMainV12Reader<'r> {
+        ConfigCellMainV12Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ConfigCellMainV12 {
+    type Builder = ConfigCellMainV12Builder;
+    const NAME: &'static str = "ConfigCellMainV12";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ConfigCellMainV12(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellMainV12Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellMainV12Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .status(self.status())
+            .type_id_table(self.type_id_table())
+            .das_lock_out_point_table(self.das_lock_out_point_table())
+            .das_lock_type_id_table(self.das_lock_type_id_table())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ConfigCellMainV12Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellMainV12Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ConfigCellMainV12Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ConfigCellMainV12Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "type_id_table", self.type_id_table())?;
+        write!(
+            f,
+            ", {}: {}",
+            "das_lock_out_point_table",
+            self.das_lock_out_point_table()
+        )?;
+        write!(f, ", {}: {}", "das_lock_type_id_table", self.das_lock_type_id_table())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ConfigCellMainV12Reader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn status(&self) -> Uint8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint8Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn type_id_table(&self) -> TypeIdTableReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        TypeIdTableReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn das_lock_out_point_table(&self) -> DasLockOutPointTableReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        DasLockOutPointTableReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn das_lock_type_id_table(&self) -> DasLockTypeIdTableReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            DasLockTypeIdTableReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            DasLockTypeIdTableReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellMainV12Reader<'r> {
+    type Entity = ConfigCellMainV12;
+    const NAME: &'static str = "ConfigCellMainV12Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ConfigCellMainV12Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint8Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        TypeIdTableReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        DasLockOutPointTableReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        DasLockTypeIdTableReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ConfigCellMainV12Builder {
+    pub(crate) status: Uint8,
+    pub(crate) type_id_table: TypeIdTable,
+    pub(crate) das_lock_out_point_table: DasLockOutPointTable,
+    pub(crate) das_lock_type_id_table: DasLockTypeIdTable,
+}
+impl ConfigCellMainV12Builder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn status(mut self, v: Uint8) -> Self {
+        self.status = v;
+        self
+    }
+    pub fn type_id_table(mut self, v: TypeIdTable) -> Self {
+        self.type_id_table = v;
+        self
+    }
+    pub fn das_lock_out_point_table(mut self, v: DasLockOutPointTable) -> Self {
+        self.das_lock_out_point_table = v;
+        self
+    }
+    pub fn das_lock_type_id_table(mut self, v: DasLockTypeIdTable) -> Self {
+        self.das_lock_type_id_table = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ConfigCellMainV12Builder {
+    type Entity = ConfigCellMainV12;
+    const NAME: &'static str = "ConfigCellMainV12Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.status.as_slice().len()
+            + self.type_id_table.as_slice().len()
+            + self.das_lock_out_point_table.as_slice().len()
+            + self.das_lock_type_id_table.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.status.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.type_id_table.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.das_lock_out_point_table.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.das_lock_type_id_table.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.status.as_slice())?;
+        writer.write_all(self.type_id_table.as_slice())?;
+        writer.write_all(self.das_lock_out_point_table.as_slice())?;
+        writer.write_all(self.das_lock_type_id_table.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ConfigCellMainV12::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct TypeIdTableV8(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for TypeIdTableV8 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -7159,33 +7514,619 @@ impl ::core::fmt::LowerHex for TypeIdTableV8 {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TypeIdTableV8 {
+impl ::core::fmt::Debug for TypeIdTableV8 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TypeIdTableV8 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_cell", self.account_cell())?;
+        write!(f, ", {}: {}", "apply_register_cell", self.apply_register_cell())?;
+        write!(f, ", {}: {}", "balance_cell", self.balance_cell())?;
+        write!(f, ", {}: {}", "income_cell", self.income_cell())?;
+        write!(f, ", {}: {}", "pre_account_cell", self.pre_account_cell())?;
+        write!(f, ", {}: {}", "proposal_cell", self.proposal_cell())?;
+        write!(f, ", {}: {}", "account_sale_cell", self.account_sale_cell())?;
+        write!(f, ", {}: {}", "account_auction_cell", self.account_auction_cell())?;
+        write!(f, ", {}: {}", "offer_cell", self.offer_cell())?;
+        write!(f, ", {}: {}", "reverse_record_cell", self.reverse_record_cell())?;
+        write!(f, ", {}: {}", "sub_account_cell", self.sub_account_cell())?;
+        write!(f, ", {}: {}", "eip712_lib", self.eip712_lib())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reverse_record_root_cell",
+            self.reverse_record_root_cell()
+        )?;
+        write!(f, ", {}: {}", "key_list_config_cell", self.key_list_config_cell())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TypeIdTableV8 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            252, 1, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 124, 0, 0, 0, 156, 0, 0, 0, 188, 0, 0, 0, 220, 0, 0, 0, 252, 0, 0,
+            0, 28, 1, 0, 0, 60, 1, 0, 0, 92, 1, 0, 0, 124, 1, 0, 0, 156, 1, 0, 0, 188, 1, 0, 0, 220, 1, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        TypeIdTableV8::new_unchecked(v.into())
+    }
+}
+impl TypeIdTableV8 {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn account_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn apply_register_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn balance_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn income_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pre_account_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proposal_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn account_sale_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn account_auction_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn offer_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reverse_record_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sub_account_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn eip712_lib(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reverse_record_root_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn key_list_config_cell(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[60..]) as usize;
+            Hash::new_unchecked(self.0.slice(start..end))
+        } else {
+            Hash::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TypeIdTableV8Reader<'r> {
+        TypeIdTableV8Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TypeIdTableV8 {
+    type Builder = TypeIdTableV8Builder;
+    const NAME: &'static str = "TypeIdTableV8";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TypeIdTableV8(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TypeIdTableV8Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TypeIdTableV8Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .account_cell(self.account_cell())
+            .apply_register_cell(self.apply_register_cell())
+            .balance_cell(self.balance_cell())
+            .income_cell(self.income_cell())
+            .pre_account_cell(self.pre_account_cell())
+            .proposal_cell(self.proposal_cell())
+            .account_sale_cell(self.account_sale_cell())
+            .account_auction_cell(self.account_auction_cell())
+            .offer_cell(self.offer_cell())
+            .reverse_record_cell(self.reverse_record_cell())
+            .sub_account_cell(self.sub_account_cell())
+            .eip712_lib(self.eip712_lib())
+            .reverse_record_root_cell(self.reverse_record_root_cell())
+            .key_list_config_cell(self.key_list_config_cell())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TypeIdTableV8Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TypeIdTableV8Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TypeIdTableV8Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TypeIdTableV8Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_cell", self.account_cell())?;
+        write!(f, ", {}: {}", "apply_register_cell", self.apply_register_cell())?;
+        write!(f, ", {}: {}", "balance_cell", self.balance_cell())?;
+        write!(f, ", {}: {}", "income_cell", self.income_cell())?;
+        write!(f, ", {}: {}", "pre_account_cell", self.pre_account_cell())?;
+        write!(f, ", {}: {}", "proposal_cell", self.proposal_cell())?;
+        write!(f, ", {}: {}", "account_sale_cell", self.account_sale_cell())?;
+        write!(f, ", {}: {}", "account_auction_cell", self.account_auction_cell())?;
+        write!(f, ", {}: {}", "offer_cell", self.offer_cell())?;
+        write!(f, ", {}: {}", "reverse_record_cell", self.reverse_record_cell())?;
+        write!(f, ", {}: {}", "sub_account_cell", self.sub_account_cell())?;
+        write!(f, ", {}: {}", "eip712_lib", self.eip712_lib())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reverse_record_root_cell",
+            self.reverse_record_root_cell()
+        )?;
+        write!(f, ", {}: {}", "key_list_config_cell", self.key_list_config_cell())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TypeIdTableV8Reader<'r> {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn account_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn apply_register_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn balance_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn income_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pre_account_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proposal_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn account_sale_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn account_auction_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn offer_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reverse_record_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sub_account_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn eip712_lib(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reverse_record_root_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn key_list_config_cell(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[60..]) as usize;
+            HashReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HashReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TypeIdTableV8Reader<'r> {
+    type Entity = TypeIdTableV8;
+    const NAME: &'static str = "TypeIdTableV8Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TypeIdTableV8Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HashReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        HashReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HashReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        HashReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        HashReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        HashReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        HashReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        HashReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        HashReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        HashReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        HashReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        HashReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        HashReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        HashReader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TypeIdTableV8Builder {
+    pub(crate) account_cell: Hash,
+    pub(crate) apply_register_cell: Hash,
+    pub(crate) balance_cell: Hash,
+    pub(crate) income_cell: Hash,
+    pub(crate) pre_account_cell: Hash,
+    pub(crate) proposal_cell: Hash,
+    pub(crate) account_sale_cell: Hash,
+    pub(crate) account_auction_cell: Hash,
+    pub(crate) offer_cell: Hash,
+    pub(crate) reverse_record_cell: Hash,
+    pub(crate) sub_account_cell: Hash,
+    pub(crate) eip712_lib: Hash,
+    pub(crate) reverse_record_root_cell: Hash,
+    pub(crate) key_list_config_cell: Hash,
+}
+impl TypeIdTableV8Builder {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn account_cell(mut self, v: Hash) -> Self {
+        self.account_cell = v;
+        self
+    }
+    pub fn apply_register_cell(mut self, v: Hash) -> Self {
+        self.apply_register_cell = v;
+        self
+    }
+    pub fn balance_cell(mut self, v: Hash) -> Self {
+        self.balance_cell = v;
+        self
+    }
+    pub fn income_cell(mut self, v: Hash) -> Self {
+        self.income_cell = v;
+        self
+    }
+    pub fn pre_account_cell(mut self, v: Hash) -> Self {
+        self.pre_account_cell = v;
+        self
+    }
+    pub fn proposal_cell(mut self, v: Hash) -> Self {
+        self.proposal_cell = v;
+        self
+    }
+    pub fn account_sale_cell(mut self, v: Hash) -> Self {
+        self.account_sale_cell = v;
+        self
+    }
+    pub fn account_auction_cell(mut self, v: Hash) -> Self {
+        self.account_auction_cell = v;
+        self
+    }
+    pub fn offer_cell(mut self, v: Hash) -> Self {
+        self.offer_cell = v;
+        self
+    }
+    pub fn reverse_record_cell(mut self, v: Hash) -> Self {
+        self.reverse_record_cell = v;
+        self
+    }
+    pub fn sub_account_cell(mut self, v: Hash) -> Self {
+        self.sub_account_cell = v;
+        self
+    }
+    pub fn eip712_lib(mut self, v: Hash) -> Self {
+        self.eip712_lib = v;
+        self
+    }
+    pub fn reverse_record_root_cell(mut self, v: Hash) -> Self {
+        self.reverse_record_root_cell = v;
+        self
+    }
+    pub fn key_list_config_cell(mut self, v: Hash) -> Self {
+        self.key_list_config_cell = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TypeIdTableV8Builder {
+    type Entity = TypeIdTableV8;
+    const NAME: &'static str = "TypeIdTableV8Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.account_cell.as_slice().len()
+            + self.apply_register_cell.as_slice().len()
+            + self.balance_cell.as_slice().len()
+            + self.income_cell.as_slice().len()
+            + self.pre_account_cell.as_slice().len()
+            + self.proposal_cell.as_slice().len()
+            + self.account_sale_cell.as_slice().len()
+            + self.account_auction_cell.as_slice().len()
+            + self.offer_cell.as_slice().len()
+            + self.reverse_record_cell.as_slice().len()
+            + self.sub_account_cell.as_slice().len()
+            + self.eip712_lib.as_slice().len()
+            + self.reverse_record_root_cell.as_slice().len()
+            + self.key_list_config_cell.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.account_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.apply_register_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.balance_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.income_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pre_account_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proposal_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.account_sale_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.account_auction_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.offer_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reverse_record_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sub_account_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.eip712_lib.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reverse_record_root_cell.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.key_list_config_cell.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.account_cell.as_slice())?;
+        writer.write_all(self.apply_register_cell.as_slice())?;
+        writer.write_all(self.balance_cell.as_slice())?;
+        writer.write_all(self.income_cell.as_slice())?;
+        writer.write_all(self.pre_account_cell.as_slice())?;
+        writer.write_all(self.proposal_cell.as_slice())?;
+        writer.write_all(self.account_sale_cell.as_slice())?;
+        writer.write_all(self.account_auction_cell.as_slice())?;
+        writer.write_all(self.offer_cell.as_slice())?;
+        writer.write_all(self.reverse_record_cell.as_slice())?;
+        writer.write_all(self.sub_account_cell.as_slice())?;
+        writer.write_all(self.eip712_lib.as_slice())?;
+        writer.write_all(self.reverse_record_root_cell.as_slice())?;
+        writer.write_all(self.key_list_config_cell.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TypeIdTableV8::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ConfigCellAccountV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellAccountV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ConfigCellAccountV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TypeIdTableV8 {
+impl ::core::fmt::Display for ConfigCellAccountV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account_cell", self.account_cell())?;
-        write!(f, ", {}: {}", "apply_register_cell", self.apply_register_cell())?;
-        write!(f, ", {}: {}", "balance_cell", self.balance_cell())?;
-        write!(f, ", {}: {}", "income_cell", self.income_cell())?;
-        write!(f, ", {}: {}", "pre_account_cell", self.pre_account_cell())?;
-        write!(f, ", {}: {}", "proposal_cell", self.proposal_cell())?;
-        write!(f, ", {}: {}", "account_sale_cell", self.account_sale_cell())?;
-        write!(f, ", {}: {}", "account_auction_cell", self.account_auction_cell())?;
-        write!(f, ", {}: {}", "offer_cell", self.offer_cell())?;
-        write!(f, ", {}: {}", "reverse_record_cell", self.reverse_record_cell())?;
-        write!(f, ", {}: {}", "sub_account_cell", self.sub_account_cell())?;
-        write!(f, ", {}: {}", "eip712_lib", self.eip712_lib())?;
+        write!(f, "{}: {}", "max_length", self.max_length())?;
+        write!(f, ", {}: {}", "basic_capacity", self.basic_capacity())?;
+        write!(f, ", {}: {}", "prepared_fee_capacity", self.prepared_fee_capacity())?;
+        write!(f, ", {}: {}", "expiration_grace_period", self.expiration_grace_period())?;
+        write!(f, ", {}: {}", "record_min_ttl", self.record_min_ttl())?;
+        write!(f, ", {}: {}", "record_size_limit", self.record_size_limit())?;
+        write!(f, ", {}: {}", "transfer_account_fee", self.transfer_account_fee())?;
+        write!(f, ", {}: {}", "edit_manager_fee", self.edit_manager_fee())?;
+        write!(f, ", {}: {}", "edit_records_fee", self.edit_records_fee())?;
         write!(
             f,
             ", {}: {}",
-            "reverse_record_root_cell",
-            self.reverse_record_root_cell()
+            "transfer_account_throttle",
+            self.transfer_account_throttle()
         )?;
-        write!(f, ", {}: {}", "key_list_config_cell", self.key_list_config_cell())?;
+        write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
+        write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7193,30 +8134,19 @@ impl ::core::fmt::Display for TypeIdTableV8 {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TypeIdTableV8 {
+impl ::core::default::Default for ConfigCellAccountV1 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            252, 1, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 124, 0, 0, 0, 156, 0, 0, 0, 188, 0, 0, 0, 220, 0, 0, 0, 252, 0, 0,
-            0, 28, 1, 0, 0, 60, 1, 0, 0, 92, 1, 0, 0, 124, 1, 0, 0, 156, 1, 0, 0, 188, 1, 0, 0, 220, 1, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            120, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0,
+            92, 0, 0, 0, 100, 0, 0, 0, 108, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        TypeIdTableV8::new_unchecked(v.into())
+        ConfigCellAccountV1::new_unchecked(v.into())
     }
 }
-impl TypeIdTableV8 {
-    pub const FIELD_COUNT: usize = 14;
+impl ConfigCellAccountV1 {
+    pub const FIELD_COUNT: usize = 12;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7233,103 +8163,91 @@ impl TypeIdTableV8 {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn account_cell(&self) -> Hash {
+    pub fn max_length(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn apply_register_cell(&self) -> Hash {
+    pub fn basic_capacity(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn balance_cell(&self) -> Hash {
+    pub fn prepared_fee_capacity(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn income_cell(&self) -> Hash {
+    pub fn expiration_grace_period(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn pre_account_cell(&self) -> Hash {
+    pub fn record_min_ttl(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn proposal_cell(&self) -> Hash {
+    pub fn record_size_limit(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn account_sale_cell(&self) -> Hash {
+    pub fn transfer_account_fee(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn account_auction_cell(&self) -> Hash {
+    pub fn edit_manager_fee(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn offer_cell(&self) -> Hash {
+    pub fn edit_records_fee(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn reverse_record_cell(&self) -> Hash {
+    pub fn transfer_account_throttle(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
         let end = molecule::unpack_number(&slice[44..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn sub_account_cell(&self) -> Hash {
+    pub fn edit_manager_throttle(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[44..]) as usize;
         let end = molecule::unpack_number(&slice[48..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
+        Uint32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn eip712_lib(&self) -> Hash {
+    pub fn edit_records_throttle(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn reverse_record_root_cell(&self) -> Hash {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
-        let end = molecule::unpack_number(&slice[56..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn key_list_config_cell(&self) -> Hash {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[56..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Hash::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            Uint32::new_unchecked(self.0.slice(start..end))
         } else {
-            Hash::new_unchecked(self.0.slice(start..))
+            Uint32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TypeIdTableV8Reader<'r> {
-        TypeIdTableV8Reader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ConfigCellAccountV1Reader<'r> {
+        ConfigCellAccountV1Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TypeIdTableV8 {
-    type Builder = TypeIdTableV8Builder;
-    const NAME: &'static str = "TypeIdTableV8";
+impl molecule::prelude::Entity for ConfigCellAccountV1 {
+    type Builder = ConfigCellAccountV1Builder;
+    const NAME: &'static str = "ConfigCellAccountV1";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TypeIdTableV8(data)
+        ConfigCellAccountV1(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7338,35 +8256,33 @@ impl molecule::prelude::Entity for TypeIdTableV8 {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TypeIdTableV8Reader::from_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellAccountV1Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TypeIdTableV8Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellAccountV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .account_cell(self.account_cell())
-            .apply_register_cell(self.apply_register_cell())
-            .balance_cell(self.balance_cell())
-            .income_cell(self.income_cell())
-            .pre_account_cell(self.pre_account_cell())
-            .proposal_cell(self.proposal_cell())
-            .account_sale_cell(self.account_sale_cell())
-            .account_auction_cell(self.account_auction_cell())
-            .offer_cell(self.offer_cell())
-            .reverse_record_cell(self.reverse_record_cell())
-            .sub_account_cell(self.sub_account_cell())
-            .eip712_lib(self.eip712_lib())
-            .reverse_record_root_cell(self.reverse_record_root_cell())
-            .key_list_config_cell(self.key_list_config_cell())
+            .max_length(self.max_length())
+            .basic_capacity(self.basic_capacity())
+            .prepared_fee_capacity(self.prepared_fee_capacity())
+            .expiration_grace_period(self.expiration_grace_period())
+            .record_min_ttl(self.record_min_ttl())
+            .record_size_limit(self.record_size_limit())
+            .transfer_account_fee(self.transfer_account_fee())
+            .edit_manager_fee(self.edit_manager_fee())
+            .edit_records_fee(self.edit_records_fee())
+            .transfer_account_throttle(self.transfer_account_throttle())
+            .edit_manager_throttle(self.edit_manager_throttle())
+            .edit_records_throttle(self.edit_records_throttle())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TypeIdTableV8Reader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TypeIdTableV8Reader<'r> {
+pub struct ConfigCellAccountV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7375,33 +8291,31 @@ impl<'r> ::core::fmt::LowerHex for TypeIdTableV8Reader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TypeIdTableV8Reader<'r> {
+impl<'r> ::core::fmt::Debug for ConfigCellAccountV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TypeIdTableV8Reader<'r> {
+impl<'r> ::core::fmt::Display for ConfigCellAccountV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account_cell", self.account_cell())?;
-        write!(f, ", {}: {}", "apply_register_cell", self.apply_register_cell())?;
-        write!(f, ", {}: {}", "balance_cell", self.balance_cell())?;
-        write!(f, ", {}: {}", "income_cell", self.income_cell())?;
-        write!(f, ", {}: {}", "pre_account_cell", self.pre_account_cell())?;
-        write!(f, ", {}: {}", "proposal_cell", self.proposal_cell())?;
-        write!(f, ", {}: {}", "account_sale_cell", self.account_sale_cell())?;
-        write!(f, ", {}: {}", "account_auction_cell", self.account_auction_cell())?;
-        write!(f, ", {}: {}", "offer_cell", self.offer_cell())?;
-        write!(f, ", {}: {}", "reverse_record_cell", self.reverse_record_cell())?;
-        write!(f, ", {}: {}", "sub_account_cell", self.sub_account_cell())?;
-        write!(f, ", {}: {}", "eip712_lib", self.eip712_lib())?;
+        write!(f, "{}: {}", "max_length", self.max_length())?;
+        write!(f, ", {}: {}", "basic_capacity", self.basic_capacity())?;
+        write!(f, ", {}: {}", "prepared_fee_capacity", self.prepared_fee_capacity())?;
+        write!(f, ", {}: {}", "expiration_grace_period", self.expiration_grace_period())?;
+        write!(f, ", {}: {}", "record_min_ttl", self.record_min_ttl())?;
+        write!(f, ", {}: {}", "record_size_limit", self.record_size_limit())?;
+        write!(f, ", {}: {}", "transfer_account_fee", self.transfer_account_fee())?;
+        write!(f, ", {}: {}", "edit_manager_fee", self.edit_manager_fee())?;
+        write!(f, ", {}: {}", "edit_records_fee", self.edit_records_fee())?;
         write!(
             f,
             ", {}: {}",
-            "reverse_record_root_cell",
-            self.reverse_record_root_cell()
+            "transfer_account_throttle",
+            self.transfer_account_throttle()
         )?;
-        write!(f, ", {}: {}", "key_list_config_cell", self.key_list_config_cell())?;
+        write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
+        write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7409,8 +8323,8 @@ impl<'r> ::core::fmt::Display for TypeIdTableV8Reader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TypeIdTableV8Reader<'r> {
-    pub const FIELD_COUNT: usize = 14;
+impl<'r> ConfigCellAccountV1Reader<'r> {
+    pub const FIELD_COUNT: usize = 12;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7427,103 +8341,91 @@ impl<'r> TypeIdTableV8Reader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn account_cell(&self) -> HashReader<'r> {
+    pub fn max_length(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn apply_register_cell(&self) -> HashReader<'r> {
+    pub fn basic_capacity(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn balance_cell(&self) -> HashReader<'r> {
+    pub fn prepared_fee_capacity(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn income_cell(&self) -> HashReader<'r> {
+    pub fn expiration_grace_period(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
         let end = molecule::unpack_number(&slice[20..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn pre_account_cell(&self) -> HashReader<'r> {
+    pub fn record_min_ttl(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn proposal_cell(&self) -> HashReader<'r> {
+    pub fn record_size_limit(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn account_sale_cell(&self) -> HashReader<'r> {
+    pub fn transfer_account_fee(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn account_auction_cell(&self) -> HashReader<'r> {
+    pub fn edit_manager_fee(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn offer_cell(&self) -> HashReader<'r> {
+    pub fn edit_records_fee(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn reverse_record_cell(&self) -> HashReader<'r> {
+    pub fn transfer_account_throttle(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
         let end = molecule::unpack_number(&slice[44..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn sub_account_cell(&self) -> HashReader<'r> {
+    pub fn edit_manager_throttle(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[44..]) as usize;
         let end = molecule::unpack_number(&slice[48..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn eip712_lib(&self) -> HashReader<'r> {
+    pub fn edit_records_throttle(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn reverse_record_root_cell(&self) -> HashReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
-        let end = molecule::unpack_number(&slice[56..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn key_list_config_cell(&self) -> HashReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[56..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            HashReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            HashReader::new_unchecked(&self.as_slice()[start..])
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TypeIdTableV8Reader<'r> {
-    type Entity = TypeIdTableV8;
-    const NAME: &'static str = "TypeIdTableV8Reader";
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV1Reader<'r> {
+    type Entity = ConfigCellAccountV1;
+    const NAME: &'static str = "ConfigCellAccountV1Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TypeIdTableV8Reader(slice)
+        ConfigCellAccountV1Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -7565,180 +8467,160 @@ impl<'r> molecule::prelude::Reader<'r> for TypeIdTableV8Reader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        HashReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        HashReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        HashReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        HashReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        HashReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        HashReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        HashReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        HashReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        HashReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        HashReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        HashReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
-        HashReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
-        HashReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
-        HashReader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TypeIdTableV8Builder {
-    pub(crate) account_cell: Hash,
-    pub(crate) apply_register_cell: Hash,
-    pub(crate) balance_cell: Hash,
-    pub(crate) income_cell: Hash,
-    pub(crate) pre_account_cell: Hash,
-    pub(crate) proposal_cell: Hash,
-    pub(crate) account_sale_cell: Hash,
-    pub(crate) account_auction_cell: Hash,
-    pub(crate) offer_cell: Hash,
-    pub(crate) reverse_record_cell: Hash,
-    pub(crate) sub_account_cell: Hash,
-    pub(crate) eip712_lib: Hash,
-    pub(crate) reverse_record_root_cell: Hash,
-    pub(crate) key_list_config_cell: Hash,
+pub struct ConfigCellAccountV1Builder {
+    pub(crate) max_length: Uint32,
+    pub(crate) basic_capacity: Uint64,
+    pub(crate) prepared_fee_capacity: Uint64,
+    pub(crate) expiration_grace_period: Uint32,
+    pub(crate) record_min_ttl: Uint32,
+    pub(crate) record_size_limit: Uint32,
+    pub(crate) transfer_account_fee: Uint64,
+    pub(crate) edit_manager_fee: Uint64,
+    pub(crate) edit_records_fee: Uint64,
+    pub(crate) transfer_account_throttle: Uint32,
+    pub(crate) edit_manager_throttle: Uint32,
+    pub(crate) edit_records_throttle: Uint32,
 }
-impl TypeIdTableV8Builder {
-    pub const FIELD_COUNT: usize = 14;
-    pub fn account_cell(mut self, v: Hash) -> Self {
-        self.account_cell = v;
-        self
-    }
-    pub fn apply_register_cell(mut self, v: Hash) -> Self {
-        self.apply_register_cell = v;
-        self
-    }
-    pub fn balance_cell(mut self, v: Hash) -> Self {
-        self.balance_cell = v;
+impl ConfigCellAccountV1Builder {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn max_length(mut self, v: Uint32) -> Self {
+        self.max_length = v;
         self
     }
-    pub fn income_cell(mut self, v: Hash) -> Self {
-        self.income_cell = v;
+    pub fn basic_capacity(mut self, v: Uint64) -> Self {
+        self.basic_capacity = v;
         self
     }
-    pub fn pre_account_cell(mut self, v: Hash) -> Self {
-        self.pre_account_cell = v;
+    pub fn prepared_fee_capacity(mut self, v: Uint64) -> Self {
+        self.prepared_fee_capacity = v;
         self
     }
-    pub fn proposal_cell(mut self, v: Hash) -> Self {
-        self.proposal_cell = v;
+    pub fn expiration_grace_period(mut self, v: Uint32) -> Self {
+        self.expiration_grace_period = v;
         self
     }
-    pub fn account_sale_cell(mut self, v: Hash) -> Self {
-        self.account_sale_cell = v;
+    pub fn record_min_ttl(mut self, v: Uint32) -> Self {
+        self.record_min_ttl = v;
         self
     }
-    pub fn account_auction_cell(mut self, v: Hash) -> Self {
-        self.account_auction_cell = v;
+    pub fn record_size_limit(mut self, v: Uint32) -> Self {
+        self.record_size_limit = v;
         self
     }
-    pub fn offer_cell(mut self, v: Hash) -> Self {
-        self.offer_cell = v;
+    pub fn transfer_account_fee(mut self, v: Uint64) -> Self {
+        self.transfer_account_fee = v;
         self
     }
-    pub fn reverse_record_cell(mut self, v: Hash) -> Self {
-        self.reverse_record_cell = v;
+    pub fn edit_manager_fee(mut self, v: Uint64) -> Self {
+        self.edit_manager_fee = v;
         self
     }
-    pub fn sub_account_cell(mut self, v: Hash) -> Self {
-        self.sub_account_cell = v;
+    pub fn edit_records_fee(mut self, v: Uint64) -> Self {
+        self.edit_records_fee = v;
         self
     }
-    pub fn eip712_lib(mut self, v: Hash) -> Self {
-        self.eip712_lib = v;
+    pub fn transfer_account_throttle(mut self, v: Uint32) -> Self {
+        self.transfer_account_throttle = v;
         self
     }
-    pub fn reverse_record_root_cell(mut self, v: Hash) -> Self {
-        self.reverse_record_root_cell = v;
+    pub fn edit_manager_throttle(mut self, v: Uint32) -> Self {
+        self.edit_manager_throttle = v;
         self
     }
-    pub fn key_list_config_cell(mut self, v: Hash) -> Self {
-        self.key_list_config_cell = v;
+    pub fn edit_records_throttle(mut self, v: Uint32) -> Self {
+        self.edit_records_throttle = v;
         self
     }
 }
-impl molecule::prelude::Builder for TypeIdTableV8Builder {
-    type Entity = TypeIdTableV8;
-    const NAME: &'static str = "TypeIdTableV8Builder";
+impl molecule::prelude::Builder for ConfigCellAccountV1Builder {
+    type Entity = ConfigCellAccountV1;
+    const NAME: &'static str = "ConfigCellAccountV1Builder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.account_cell.as_slice().len()
-            + self.apply_register_cell.as_slice().len()
-            + self.balance_cell.as_slice().len()
-            + self.income_cell.as_slice().len()
-            + self.pre_account_cell.as_slice().len()
-            + self.proposal_cell.as_slice().len()
-            + self.account_sale_cell.as_slice().len()
-            + self.account_auction_cell.as_slice().len()
-            + self.offer_cell.as_slice().len()
-            + self.reverse_record_cell.as_slice().len()
-            + self.sub_account_cell.as_slice().len()
-            + self.eip712_lib.as_slice().len()
-            + self.reverse_record_root_cell.as_slice().len()
-            + self.key_list_config_cell.as_slice().len()
+            + self.max_length.as_slice().len()
+            + self.basic_capacity.as_slice().len()
+            + self.prepared_fee_capacity.as_slice().len()
+            + self.expiration_grace_period.as_slice().len()
+            + self.record_min_ttl.as_slice().len()
+            + self.record_size_limit.as_slice().len()
+            + self.transfer_account_fee.as_slice().len()
+            + self.edit_manager_fee.as_slice().len()
+            + self.edit_records_fee.as_slice().len()
+            + self.transfer_account_throttle.as_slice().len()
+            + self.edit_manager_throttle.as_slice().len()
+            + self.edit_records_throttle.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.account_cell.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.apply_register_cell.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.balance_cell.as_slice().len();
+        total_size += self.max_length.as_slice().len();
         offsets.push(total_size);
-        total_size += self.income_cell.as_slice().len();
+        total_size += self.basic_capacity.as_slice().len();
         offsets.push(total_size);
-        total_size += self.pre_account_cell.as_slice().len();
+        total_size += self.prepared_fee_capacity.as_slice().len();
         offsets.push(total_size);
-        total_size += self.proposal_cell.as_slice().len();
+        total_size += self.expiration_grace_period.as_slice().len();
         offsets.push(total_size);
-        total_size += self.account_sale_cell.as_slice().len();
+        total_size += self.record_min_ttl.as_slice().len();
         offsets.push(total_size);
-        total_size += self.account_auction_cell.as_slice().len();
+        total_size += self.record_size_limit.as_slice().len();
         offsets.push(total_size);
-        total_size += self.offer_cell.as_slice().len();
+        total_size += self.transfer_account_fee.as_slice().len();
         offsets.push(total_size);
-        total_size += self.reverse_record_cell.as_slice().len();
+        total_size += self.edit_manager_fee.as_slice().len();
         offsets.push(total_size);
-        total_size += self.sub_account_cell.as_slice().len();
+        total_size += self.edit_records_fee.as_slice().len();
         offsets.push(total_size);
-        total_size += self.eip712_lib.as_slice().len();
+        total_size += self.transfer_account_throttle.as_slice().len();
         offsets.push(total_size);
-        total_size += self.reverse_record_root_cell.as_slice().len();
+        total_size += self.edit_manager_throttle.as_slice().len();
         offsets.push(total_size);
-        total_size += self.key_list_config_cell.as_slice().len();
+        total_size += self.edit_records_throttle.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.account_cell.as_slice())?;
-        writer.write_all(self.apply_register_cell.as_slice())?;
-        writer.write_all(self.balance_cell.as_slice())?;
-        writer.write_all(self.income_cell.as_slice())?;
-        writer.write_all(self.pre_account_cell.as_slice())?;
-        writer.write_all(self.proposal_cell.as_slice())?;
-        writer.write_all(self.account_sale_cell.as_slice())?;
-        writer.write_all(self.account_auction_cell.as_slice())?;
-        writer.write_all(self.offer_cell.as_slice())?;
-        writer.write_all(self.reverse_record_cell.as_slice())?;
-        writer.write_all(self.sub_account_cell.as_slice())?;
-        writer.write_all(self.eip712_lib.as_slice())?;
-        writer.write_all(self.reverse_record_root_cell.as_slice())?;
-        writer.write_all(self.key_list_config_cell.as_slice())?;
+        writer.write_all(self.max_length.as_slice())?;
+        writer.write_all(self.basic_capacity.as_slice())?;
+        writer.write_all(self.prepared_fee_capacity.as_slice())?;
+        writer.write_all(self.expiration_grace_period.as_slice())?;
+        writer.write_all(self.record_min_ttl.as_slice())?;
+        writer.write_all(self.record_size_limit.as_slice())?;
+        writer.write_all(self.transfer_account_fee.as_slice())?;
+        writer.write_all(self.edit_manager_fee.as_slice())?;
+        writer.write_all(self.edit_records_fee.as_slice())?;
+        writer.write_all(self.transfer_account_throttle.as_slice())?;
+        writer.write_all(self.edit_manager_throttle.as_slice())?;
+        writer.write_all(self.edit_records_throttle.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TypeIdTableV8::new_unchecked(inner.into())
+        ConfigCellAccountV1::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ConfigCellAccountV1(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ConfigCellAccountV1 {
+pub struct ConfigCellAccountV2(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellAccountV2 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7747,12 +8629,12 @@ impl ::core::fmt::LowerHex for ConfigCellAccountV1 {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ConfigCellAccountV1 {
+impl ::core::fmt::Debug for ConfigCellAccountV2 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ConfigCellAccountV1 {
+impl ::core::fmt::Display for ConfigCellAccountV2 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "max_length", self.max_length())?;
@@ -7772,6 +8654,7 @@ impl ::core::fmt::Display for ConfigCellAccountV1 {
         )?;
         write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
         write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
+        write!(f, ", {}: {}", "common_throttle", self.common_throttle())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7779,19 +8662,19 @@ impl ::core::fmt::Display for ConfigCellAccountV1 {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ConfigCellAccountV1 {
+impl ::core::default::Default for ConfigCellAccountV2 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            120, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0,
-            92, 0, 0, 0, 100, 0, 0, 0, 108, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            128, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 68, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0,
+            96, 0, 0, 0, 104, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 120, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        ConfigCellAccountV1::new_unchecked(v.into())
+        ConfigCellAccountV2::new_unchecked(v.into())
     }
 }
-impl ConfigCellAccountV1 {
-    pub const FIELD_COUNT: usize = 12;
+impl ConfigCellAccountV2 {
+    pub const FIELD_COUNT: usize = 13;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7877,22 +8760,28 @@ impl ConfigCellAccountV1 {
     pub fn edit_records_throttle(&self) -> Uint32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn common_throttle(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[52..]) as usize;
+            let end = molecule::unpack_number(&slice[56..]) as usize;
             Uint32::new_unchecked(self.0.slice(start..end))
         } else {
             Uint32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ConfigCellAccountV1Reader<'r> {
-        ConfigCellAccountV1Reader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ConfigCellAccountV2Reader<'r> {
+        ConfigCellAccountV2Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ConfigCellAccountV1 {
-    type Builder = ConfigCellAccountV1Builder;
-    const NAME: &'static str = "ConfigCellAccountV1";
+impl molecule::prelude::Entity for ConfigCellAccountV2 {
+    type Builder = ConfigCellAccountV2Builder;
+    const NAME: &'static str = "ConfigCellAccountV2";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ConfigCellAccountV1(data)
+        ConfigCellAccountV2(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7901,10 +8790,10 @@ impl molecule::prelude::Entity for ConfigCellAccountV1 {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ConfigCellAccountV1Reader::from_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellAccountV2Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ConfigCellAccountV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellAccountV2Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -7923,11 +8812,12 @@ impl molecule::prelude::Entity for ConfigCellAccountV1 {
             .transfer_account_throttle(self.transfer_account_throttle())
             .edit_manager_throttle(self.edit_manager_throttle())
             .edit_records_throttle(self.edit_records_throttle())
+            .common_throttle(self.common_throttle())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ConfigCellAccountV1Reader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV1Reader<'r> {
+pub struct ConfigCellAccountV2Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7936,12 +8826,12 @@ impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV1Reader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ConfigCellAccountV1Reader<'r> {
+impl<'r> ::core::fmt::Debug for ConfigCellAccountV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ConfigCellAccountV1Reader<'r> {
+impl<'r> ::core::fmt::Display for ConfigCellAccountV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "max_length", self.max_length())?;
@@ -7961,6 +8851,7 @@ impl<'r> ::core::fmt::Display for ConfigCellAccountV1Reader<'r> {
         )?;
         write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
         write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
+        write!(f, ", {}: {}", "common_throttle", self.common_throttle())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7968,8 +8859,8 @@ impl<'r> ::core::fmt::Display for ConfigCellAccountV1Reader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ConfigCellAccountV1Reader<'r> {
-    pub const FIELD_COUNT: usize = 12;
+impl<'r> ConfigCellAccountV2Reader<'r> {
+    pub const FIELD_COUNT: usize = 13;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8055,22 +8946,28 @@ impl<'r> ConfigCellAccountV1Reader<'r> {
     pub fn edit_records_throttle(&self) -> Uint32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn common_throttle(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[52..]) as usize;
+            let end = molecule::unpack_number(&slice[56..]) as usize;
             Uint32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
             Uint32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV1Reader<'r> {
-    type Entity = ConfigCellAccountV1;
-    const NAME: &'static str = "ConfigCellAccountV1Reader";
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV2Reader<'r> {
+    type Entity = ConfigCellAccountV2;
+    const NAME: &'static str = "ConfigCellAccountV2Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ConfigCellAccountV1Reader(slice)
+        ConfigCellAccountV2Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8124,11 +9021,12 @@ impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV1Reader<'r> {
         Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
         Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
         Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ConfigCellAccountV1Builder {
+pub struct ConfigCellAccountV2Builder {
     pub(crate) max_length: Uint32,
     pub(crate) basic_capacity: Uint64,
     pub(crate) prepared_fee_capacity: Uint64,
@@ -8141,9 +9039,10 @@ pub struct ConfigCellAccountV1Builder {
     pub(crate) transfer_account_throttle: Uint32,
     pub(crate) edit_manager_throttle: Uint32,
     pub(crate) edit_records_throttle: Uint32,
+    pub(crate) common_throttle: Uint32,
 }
-impl ConfigCellAccountV1Builder {
-    pub const FIELD_COUNT: usize = 12;
+impl ConfigCellAccountV2Builder {
+    pub const FIELD_COUNT: usize = 13;
     pub fn max_length(mut self, v: Uint32) -> Self {
         self.max_length = v;
         self
@@ -8192,10 +9091,14 @@ impl ConfigCellAccountV1Builder {
         self.edit_records_throttle = v;
         self
     }
+    pub fn common_throttle(mut self, v: Uint32) -> Self {
+        self.common_throttle = v;
+        self
+    }
 }
-impl molecule::prelude::Builder for ConfigCellAccountV1Builder {
-    type Entity = ConfigCellAccountV1;
-    const NAME: &'static str = "ConfigCellAccountV1Builder";
+impl molecule::prelude::Builder for ConfigCellAccountV2Builder {
+    type Entity = ConfigCellAccountV2;
+    const NAME: &'static str = "ConfigCellAccountV2Builder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.max_length.as_slice().len()
@@ -8210,6 +9113,7 @@ impl molecule::prelude::Builder for ConfigCellAccountV1Builder {
             + self.transfer_account_throttle.as_slice().len()
             + self.edit_manager_throttle.as_slice().len()
             + self.edit_records_throttle.as_slice().len()
+            + self.common_throttle.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -8238,6 +9142,8 @@ impl molecule::prelude::Builder for ConfigCellAccountV1Builder {
         total_size += self.edit_manager_throttle.as_slice().len();
         offsets.push(total_size);
         total_size += self.edit_records_throttle.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.common_throttle.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -8254,18 +9160,19 @@ impl molecule::prelude::Builder for ConfigCellAccountV1Builder {
         writer.write_all(self.transfer_account_throttle.as_slice())?;
         writer.write_all(self.edit_manager_throttle.as_slice())?;
         writer.write_all(self.edit_records_throttle.as_slice())?;
+        writer.write_all(self.common_throttle.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ConfigCellAccountV1::new_unchecked(inner.into())
+        ConfigCellAccountV2::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ConfigCellAccountV2(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ConfigCellAccountV2 {
+pub struct ConfigCellPriceV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellPriceV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8274,32 +9181,16 @@ impl ::core::fmt::LowerHex for ConfigCellAccountV2 {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ConfigCellAccountV2 {
+impl ::core::fmt::Debug for ConfigCellPriceV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ConfigCellAccountV2 {
+impl ::core::fmt::Display for ConfigCellPriceV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "max_length", self.max_length())?;
-        write!(f, ", {}: {}", "basic_capacity", self.basic_capacity())?;
-        write!(f, ", {}: {}", "prepared_fee_capacity", self.prepared_fee_capacity())?;
-        write!(f, ", {}: {}", "expiration_grace_period", self.expiration_grace_period())?;
-        write!(f, ", {}: {}", "record_min_ttl", self.record_min_ttl())?;
-        write!(f, ", {}: {}", "record_size_limit", self.record_size_limit())?;
-        write!(f, ", {}: {}", "transfer_account_fee", self.transfer_account_fee())?;
-        write!(f, ", {}: {}", "edit_manager_fee", self.edit_manager_fee())?;
-        write!(f, ", {}: {}", "edit_records_fee", self.edit_records_fee())?;
-        write!(
-            f,
-            ", {}: {}",
-            "transfer_account_throttle",
-            self.transfer_account_throttle()
-        )?;
-        write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
-        write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
-        write!(f, ", {}: {}", "common_throttle", self.common_throttle())?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(f, ", {}: {}", "prices", self.prices())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8307,19 +9198,279 @@ impl ::core::fmt::Display for ConfigCellAccountV2 {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ConfigCellAccountV2 {
+impl ::core::default::Default for ConfigCellPriceV1 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            128, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 68, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0,
-            96, 0, 0, 0, 104, 0, 0, 0, 112, 0, 0, 0, 116, 0, 0, 0, 120, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            28, 0, 0, 0, 12, 0, 0, 0, 24, 0, 0, 0, 12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        ConfigCellPriceV1::new_unchecked(v.into())
+    }
+}
+impl ConfigCellPriceV1 {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn discount(&self) -> DiscountConfig {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        DiscountConfig::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn prices(&self) -> PriceConfigList {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            PriceConfigList::new_unchecked(self.0.slice(start..end))
+        } else {
+            PriceConfigList::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ConfigCellPriceV1Reader<'r> {
+        ConfigCellPriceV1Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ConfigCellPriceV1 {
+    type Builder = ConfigCellPriceV1Builder;
+    const NAME: &'static str = "ConfigCellPriceV1";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ConfigCellPriceV1(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellPriceV1Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellPriceV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().discount(self.discount()).prices(self.prices())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ConfigCellPriceV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellPriceV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ConfigCellPriceV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ConfigCellPriceV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(f, ", {}: {}", "prices", self.prices())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ConfigCellPriceV1Reader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn discount(&self) -> DiscountConfigReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        DiscountConfigReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn prices(&self) -> PriceConfigListReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            PriceConfigListReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            PriceConfigListReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellPriceV1Reader<'r> {
+    type Entity = ConfigCellPriceV1;
+    const NAME: &'static str = "ConfigCellPriceV1Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ConfigCellPriceV1Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        DiscountConfigReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        PriceConfigListReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ConfigCellPriceV1Builder {
+    pub(crate) discount: DiscountConfig,
+    pub(crate) prices: PriceConfigList,
+}
+impl ConfigCellPriceV1Builder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn discount(mut self, v: DiscountConfig) -> Self {
+        self.discount = v;
+        self
+    }
+    pub fn prices(mut self, v: PriceConfigList) -> Self {
+        self.prices = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ConfigCellPriceV1Builder {
+    type Entity = ConfigCellPriceV1;
+    const NAME: &'static str = "ConfigCellPriceV1Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.discount.as_slice().len() + self.prices.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.discount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.prices.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.discount.as_slice())?;
+        writer.write_all(self.prices.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ConfigCellPriceV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ConfigCellPriceV2(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellPriceV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ConfigCellPriceV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ConfigCellPriceV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(f, ", {}: {}", "prices", self.prices())?;
+        write!(f, ", {}: {}", "multi_year_discounts", self.multi_year_discounts())?;
+        write!(f, ", {}: {}", "promotions", self.promotions())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ConfigCellPriceV2 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            44, 0, 0, 0, 20, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 4, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        ConfigCellAccountV2::new_unchecked(v.into())
+        ConfigCellPriceV2::new_unchecked(v.into())
     }
 }
-impl ConfigCellAccountV2 {
-    pub const FIELD_COUNT: usize = 13;
+impl ConfigCellPriceV2 {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8336,97 +9487,43 @@ impl ConfigCellAccountV2 {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn max_length(&self) -> Uint32 {
+    pub fn discount(&self) -> DiscountConfig {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        DiscountConfig::new_unchecked(self.0.slice(start..end))
     }
-    pub fn basic_capacity(&self) -> Uint64 {
+    pub fn prices(&self) -> PriceConfigList {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        PriceConfigList::new_unchecked(self.0.slice(start..end))
     }
-    pub fn prepared_fee_capacity(&self) -> Uint64 {
+    pub fn multi_year_discounts(&self) -> MultiYearDiscounts {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+        MultiYearDiscounts::new_unchecked(self.0.slice(start..end))
     }
-    pub fn expiration_grace_period(&self) -> Uint32 {
+    pub fn promotions(&self) -> Promotions {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn record_min_ttl(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn record_size_limit(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn transfer_account_fee(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn edit_manager_fee(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn edit_records_fee(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn transfer_account_throttle(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn edit_manager_throttle(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
-        let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn edit_records_throttle(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn common_throttle(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[56..]) as usize;
-            Uint32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Promotions::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint32::new_unchecked(self.0.slice(start..))
+            Promotions::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ConfigCellAccountV2Reader<'r> {
-        ConfigCellAccountV2Reader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ConfigCellPriceV2Reader<'r> {
+        ConfigCellPriceV2Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ConfigCellAccountV2 {
-    type Builder = ConfigCellAccountV2Builder;
-    const NAME: &'static str = "ConfigCellAccountV2";
+impl molecule::prelude::Entity for ConfigCellPriceV2 {
+    type Builder = ConfigCellPriceV2Builder;
+    const NAME: &'static str = "ConfigCellPriceV2";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ConfigCellAccountV2(data)
+        ConfigCellPriceV2(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8435,34 +9532,25 @@ impl molecule::prelude::Entity for ConfigCellAccountV2 {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ConfigCellAccountV2Reader::from_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellPriceV2Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ConfigCellAccountV2Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ConfigCellPriceV2Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .max_length(self.max_length())
-            .basic_capacity(self.basic_capacity())
-            .prepared_fee_capacity(self.prepared_fee_capacity())
-            .expiration_grace_period(self.expiration_grace_period())
-            .record_min_ttl(self.record_min_ttl())
-            .record_size_limit(self.record_size_limit())
-            .transfer_account_fee(self.transfer_account_fee())
-            .edit_manager_fee(self.edit_manager_fee())
-            .edit_records_fee(self.edit_records_fee())
-            .transfer_account_throttle(self.transfer_account_throttle())
-            .edit_manager_throttle(self.edit_manager_throttle())
-            .edit_records_throttle(self.edit_records_throttle())
-            .common_throttle(self.common_throttle())
+            .discount(self.discount())
+            .prices(self.prices())
+            .multi_year_discounts(self.multi_year_discounts())
+            .promotions(self.promotions())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ConfigCellAccountV2Reader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV2Reader<'r> {
+pub struct ConfigCellPriceV2Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellPriceV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8471,32 +9559,18 @@ impl<'r> ::core::fmt::LowerHex for ConfigCellAccountV2Reader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ConfigCellAccountV2Reader<'r> {
+impl<'r> ::core::fmt::Debug for ConfigCellPriceV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ConfigCellAccountV2Reader<'r> {
+impl<'r> ::core::fmt::Display for ConfigCellPriceV2Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "max_length", self.max_length())?;
-        write!(f, ", {}: {}", "basic_capacity", self.basic_capacity())?;
-        write!(f, ", {}: {}", "prepared_fee_capacity", self.prepared_fee_capacity())?;
-        write!(f, ", {}: {}", "expiration_grace_period", self.expiration_grace_period())?;
-        write!(f, ", {}: {}", "record_min_ttl", self.record_min_ttl())?;
-        write!(f, ", {}: {}", "record_size_limit", self.record_size_limit())?;
-        write!(f, ", {}: {}", "transfer_account_fee", self.transfer_account_fee())?;
-        write!(f, ", {}: {}", "edit_manager_fee", self.edit_manager_fee())?;
-        write!(f, ", {}: {}", "edit_records_fee", self.edit_records_fee())?;
-        write!(
-            f,
-            ", {}: {}",
-            "transfer_account_throttle",
-            self.transfer_account_throttle()
-        )?;
-        write!(f, ", {}: {}", "edit_manager_throttle", self.edit_manager_throttle())?;
-        write!(f, ", {}: {}", "edit_records_throttle", self.edit_records_throttle())?;
-        write!(f, ", {}: {}", "common_throttle", self.common_throttle())?;
+        write!(f, "{}: {}", "discount", self.discount())?;
+        write!(f, ", {}: {}", "prices", self.prices())?;
+        write!(f, ", {}: {}", "multi_year_discounts", self.multi_year_discounts())?;
+        write!(f, ", {}: {}", "promotions", self.promotions())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8504,8 +9578,8 @@ impl<'r> ::core::fmt::Display for ConfigCellAccountV2Reader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ConfigCellAccountV2Reader<'r> {
-    pub const FIELD_COUNT: usize = 13;
+impl<'r> ConfigCellPriceV2Reader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -8522,97 +9596,43 @@ impl<'r> ConfigCellAccountV2Reader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn max_length(&self) -> Uint32Reader<'r> {
+    pub fn discount(&self) -> DiscountConfigReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        DiscountConfigReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn basic_capacity(&self) -> Uint64Reader<'r> {
+    pub fn prices(&self) -> PriceConfigListReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        PriceConfigListReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn prepared_fee_capacity(&self) -> Uint64Reader<'r> {
+    pub fn multi_year_discounts(&self) -> MultiYearDiscountsReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        MultiYearDiscountsReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn expiration_grace_period(&self) -> Uint32Reader<'r> {
+    pub fn promotions(&self) -> PromotionsReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn record_min_ttl(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn record_size_limit(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn transfer_account_fee(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn edit_manager_fee(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn edit_records_fee(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn transfer_account_throttle(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn edit_manager_throttle(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
-        let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn edit_records_throttle(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn common_throttle(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[56..]) as usize;
-            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            PromotionsReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint32Reader::new_unchecked(&self.as_slice()[start..])
+            PromotionsReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV2Reader<'r> {
-    type Entity = ConfigCellAccountV2;
-    const NAME: &'static str = "ConfigCellAccountV2Reader";
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellPriceV2Reader<'r> {
+    type Entity = ConfigCellPriceV2;
+    const NAME: &'static str = "ConfigCellPriceV2Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ConfigCellAccountV2Reader(slice)
+        ConfigCellPriceV2Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8651,168 +9671,78 @@ impl<'r> molecule::prelude::Reader<'r> for ConfigCellAccountV2Reader<'r> {
             .map(|x| molecule::unpack_number(x) as usize)
             .collect();
         offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
-        Ok(())
-    }
-}
-#[derive(Debug, Default)]
-pub struct ConfigCellAccountV2Builder {
-    pub(crate) max_length: Uint32,
-    pub(crate) basic_capacity: Uint64,
-    pub(crate) prepared_fee_capacity: Uint64,
-    pub(crate) expiration_grace_period: Uint32,
-    pub(crate) record_min_ttl: Uint32,
-    pub(crate) record_size_limit: Uint32,
-    pub(crate) transfer_account_fee: Uint64,
-    pub(crate) edit_manager_fee: Uint64,
-    pub(crate) edit_records_fee: Uint64,
-    pub(crate) transfer_account_throttle: Uint32,
-    pub(crate) edit_manager_throttle: Uint32,
-    pub(crate) edit_records_throttle: Uint32,
-    pub(crate) common_throttle: Uint32,
-}
-impl ConfigCellAccountV2Builder {
-    pub const FIELD_COUNT: usize = 13;
-    pub fn max_length(mut self, v: Uint32) -> Self {
-        self.max_length = v;
-        self
-    }
-    pub fn basic_capacity(mut self, v: Uint64) -> Self {
-        self.basic_capacity = v;
-        self
-    }
-    pub fn prepared_fee_capacity(mut self, v: Uint64) -> Self {
-        self.prepared_fee_capacity = v;
-        self
-    }
-    pub fn expiration_grace_period(mut self, v: Uint32) -> Self {
-        self.expiration_grace_period = v;
-        self
-    }
-    pub fn record_min_ttl(mut self, v: Uint32) -> Self {
-        self.record_min_ttl = v;
-        self
-    }
-    pub fn record_size_limit(mut self, v: Uint32) -> Self {
-        self.record_size_limit = v;
-        self
-    }
-    pub fn transfer_account_fee(mut self, v: Uint64) -> Self {
-        self.transfer_account_fee = v;
-        self
-    }
-    pub fn edit_manager_fee(mut self, v: Uint64) -> Self {
-        self.edit_manager_fee = v;
-        self
-    }
-    pub fn edit_records_fee(mut self, v: Uint64) -> Self {
-        self.edit_records_fee = v;
-        self
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        DiscountConfigReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        PriceConfigListReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        MultiYearDiscountsReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PromotionsReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
     }
-    pub fn transfer_account_throttle(mut self, v: Uint32) -> Self {
-        self.transfer_account_throttle = v;
+}
+#[derive(Debug, Default)]
+pub struct ConfigCellPriceV2Builder {
+    pub(crate) discount: DiscountConfig,
+    pub(crate) prices: PriceConfigList,
+    pub(crate) multi_year_discounts: MultiYearDiscounts,
+    pub(crate) promotions: Promotions,
+}
+impl ConfigCellPriceV2Builder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn discount(mut self, v: DiscountConfig) -> Self {
+        self.discount = v;
         self
     }
-    pub fn edit_manager_throttle(mut self, v: Uint32) -> Self {
-        self.edit_manager_throttle = v;
+    pub fn prices(mut self, v: PriceConfigList) -> Self {
+        self.prices = v;
         self
     }
-    pub fn edit_records_throttle(mut self, v: Uint32) -> Self {
-        self.edit_records_throttle = v;
+    pub fn multi_year_discounts(mut self, v: MultiYearDiscounts) -> Self {
+        self.multi_year_discounts = v;
         self
     }
-    pub fn common_throttle(mut self, v: Uint32) -> Self {
-        self.common_throttle = v;
+    pub fn promotions(mut self, v: Promotions) -> Self {
+        self.promotions = v;
         self
     }
 }
-impl molecule::prelude::Builder for ConfigCellAccountV2Builder {
-    type Entity = ConfigCellAccountV2;
-    const NAME: &'static str = "ConfigCellAccountV2Builder";
+impl molecule::prelude::Builder for ConfigCellPriceV2Builder {
+    type Entity = ConfigCellPriceV2;
+    const NAME: &'static str = "ConfigCellPriceV2Builder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.max_length.as_slice().len()
-            + self.basic_capacity.as_slice().len()
-            + self.prepared_fee_capacity.as_slice().len()
-            + self.expiration_grace_period.as_slice().len()
-            + self.record_min_ttl.as_slice().len()
-            + self.record_size_limit.as_slice().len()
-            + self.transfer_account_fee.as_slice().len()
-            + self.edit_manager_fee.as_slice().len()
-            + self.edit_records_fee.as_slice().len()
-            + self.transfer_account_throttle.as_slice().len()
-            + self.edit_manager_throttle.as_slice().len()
-            + self.edit_records_throttle.as_slice().len()
-            + self.common_throttle.as_slice().len()
+            + self.discount.as_slice().len()
+            + self.prices.as_slice().len()
+            + self.multi_year_discounts.as_slice().len()
+            + self.promotions.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.max_length.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.basic_capacity.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.prepared_fee_capacity.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.expiration_grace_period.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.record_min_ttl.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.record_size_limit.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transfer_account_fee.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.edit_manager_fee.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.edit_records_fee.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transfer_account_throttle.as_slice().len();
+        total_size += self.discount.as_slice().len();
         offsets.push(total_size);
-        total_size += self.edit_manager_throttle.as_slice().len();
+        total_size += self.prices.as_slice().len();
         offsets.push(total_size);
-        total_size += self.edit_records_throttle.as_slice().len();
+        total_size += self.multi_year_discounts.as_slice().len();
         offsets.push(total_size);
-        total_size += self.common_throttle.as_slice().len();
+        total_size += self.promotions.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.max_length.as_slice())?;
-        writer.write_all(self.basic_capacity.as_slice())?;
-        writer.write_all(self.prepared_fee_capacity.as_slice())?;
-        writer.write_all(self.expiration_grace_period.as_slice())?;
-        writer.write_all(self.record_min_ttl.as_slice())?;
-        writer.write_all(self.record_size_limit.as_slice())?;
-        writer.write_all(self.transfer_account_fee.as_slice())?;
-        writer.write_all(self.edit_manager_fee.as_slice())?;
-        writer.write_all(self.edit_records_fee.as_slice())?;
-        writer.write_all(self.transfer_account_throttle.as_slice())?;
-        writer.write_all(self.edit_manager_throttle.as_slice())?;
-        writer.write_all(self.edit_records_throttle.as_slice())?;
-        writer.write_all(self.common_throttle.as_slice())?;
+        writer.write_all(self.discount.as_slice())?;
+        writer.write_all(self.prices.as_slice())?;
+        writer.write_all(self.multi_year_discounts.as_slice())?;
+        writer.write_all(self.promotions.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ConfigCellAccountV2::new_unchecked(inner.into())
+        ConfigCellPriceV2::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -10789,6 +11719,241 @@ impl molecule::prelude::Builder for ConfigCellSubAccountV1Builder {
     }
 }
 #[derive(Clone)]
+pub struct ConfigCellReleaseV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ConfigCellReleaseV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ConfigCellReleaseV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ConfigCellReleaseV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lucky_number", self.lucky_number())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ConfigCellReleaseV1 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        ConfigCellReleaseV1::new_unchecked(v.into())
+    }
+}
+impl ConfigCellReleaseV1 {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lucky_number(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Uint32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ConfigCellReleaseV1Reader<'r> {
+        ConfigCellReleaseV1Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ConfigCellReleaseV1 {
+    type Builder = ConfigCellReleaseV1Builder;
+    const NAME: &'static str = "ConfigCellReleaseV1";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ConfigCellReleaseV1(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellReleaseV1Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ConfigCellReleaseV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().lucky_number(self.lucky_number())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ConfigCellReleaseV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ConfigCellReleaseV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ConfigCellReleaseV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ConfigCellReleaseV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lucky_number", self.lucky_number())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ConfigCellReleaseV1Reader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lucky_number(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ConfigCellReleaseV1Reader<'r> {
+    type Entity = ConfigCellReleaseV1;
+    const NAME: &'static str = "ConfigCellReleaseV1Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ConfigCellReleaseV1Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ConfigCellReleaseV1Builder {
+    pub(crate) lucky_number: Uint32,
+}
+impl ConfigCellReleaseV1Builder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn lucky_number(mut self, v: Uint32) -> Self {
+        self.lucky_number = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ConfigCellReleaseV1Builder {
+    type Entity = ConfigCellReleaseV1;
+    const NAME: &'static str = "ConfigCellReleaseV1Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.lucky_number.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.lucky_number.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.lucky_number.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ConfigCellReleaseV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct ConfigCellSystemStatusV1(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for ConfigCellSystemStatusV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -3,6 +3,7 @@ mod cell;
 mod cell_v1;
 mod cell_v2;
 mod cell_v3;
+mod cell_v4;
 mod config_history;
 
 pub mod packed {
@@ -13,5 +14,6 @@ pub mod packed {
     pub use super::cell_v1::*;
     pub use super::cell_v2::*;
     pub use super::cell_v3::*;
+    pub use super::cell_v4::*;
     pub use super::config_history::*;
 }
@@ -27,6 +27,8 @@ pub fn is_other_data_type(data_type: &DataType) -> bool {
         DataType::DeviceKeyListCellData,
         DataType::ReverseRecord,
         DataType::OrderInfo,
+        DataType::AccountListProof,
+        DataType::CrossChainUnlockAttestation,
     ]
     .contains(data_type)
 }
@@ -97,6 +99,18 @@ pub fn preserved_accounts_group_to_data_type(group: usize) -> DataType {
     DataType::try_from(group as u32 + 10000).unwrap()
 }
 
+/// Generate the key of an account in the SMT of ConfigCellAccountList, it is the account ID padded with zeros.
+pub fn gen_account_list_smt_key(account_id: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..ACCOUNT_ID_LENGTH].copy_from_slice(&account_id[..ACCOUNT_ID_LENGTH]);
+    key
+}
+
+/// Generate the value of an account in the SMT of ConfigCellAccountList, the value of accounts not in the list is zero.
+pub fn gen_account_list_smt_value(account_id: &[u8]) -> [u8; 32] {
+    blake2b_256(&account_id[..ACCOUNT_ID_LENGTH])
+}
+
 #[cfg(not(feature = "no_std"))]
 pub fn wrap_data_entity(version: u32, index: u32, entity: impl Entity) -> DataEntity {
     DataEntity::new_builder()
@@ -194,17 +208,20 @@ pub enum EntityWrapper {
     ActionData(ActionData),
     PreAccountCellDataV1(PreAccountCellDataV1),
     PreAccountCellDataV2(PreAccountCellDataV2),
+    PreAccountCellDataV3(PreAccountCellDataV3),
     PreAccountCellData(PreAccountCellData),
     ProposalCellData(ProposalCellData),
     AccountCellData(AccountCellData),
     AccountCellDataV2(AccountCellDataV2),
     AccountCellDataV3(AccountCellDataV3),
+    AccountCellDataV4(AccountCellDataV4),
     AccountSaleCellData(AccountSaleCellData),
     AccountSaleCellDataV1(AccountSaleCellDataV1),
     AccountAuctionCellData(AccountAuctionCellData),
     IncomeCellData(IncomeCellData),
     OfferCellData(OfferCellData),
     SubAccountV1(SubAccountV1),
+    SubAccountV2(SubAccountV2),
     SubAccount(SubAccount),
     ConfigCellAccount(ConfigCellAccount),
     ConfigCellApply(ConfigCellApply),
@@ -219,6 +236,9 @@ pub enum EntityWrapper {
     ConfigCellSubAccount(ConfigCellSubAccount),
     ConfigCellSytemStatus(ConfigCellSystemStatus),
     ConfigCellDPoint(ConfigCellDPoint),
+    ConfigCellAccountList(ConfigCellAccountList),
+    ConfigCellConfusables(ConfigCellConfusables),
+    ConfigCellCrossChain(ConfigCellCrossChain),
 }
 
 // The function returns Bytes which is not the proper type required by the transaction builder, which case so many places
@@ -276,6 +296,9 @@ pub fn wrap_entity_witness_v4(data_type: DataType, entity: EntityWrapper) -> Vec
         EntityWrapper::ConfigCellSubAccount(entity) => entity.as_slice().to_vec(),
         EntityWrapper::ConfigCellSytemStatus(entity) => entity.as_slice().to_vec(),
         EntityWrapper::ConfigCellDPoint(entity) => entity.as_slice().to_vec(),
+        EntityWrapper::ConfigCellAccountList(entity) => entity.as_slice().to_vec(),
+        EntityWrapper::ConfigCellConfusables(entity) => entity.as_slice().to_vec(),
+        EntityWrapper::ConfigCellCrossChain(entity) => entity.as_slice().to_vec(),
         _ => unreachable!(),
     };
     data.append(&mut entity_bytes);
@@ -296,17 +319,20 @@ pub fn wrap_data_entity_v3(version: u32, index: usize, entity: EntityWrapper) ->
     match entity {
         EntityWrapper::PreAccountCellDataV1(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::PreAccountCellDataV2(entity) => wrap_data_entity(version, index, entity),
+        EntityWrapper::PreAccountCellDataV3(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::PreAccountCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::ProposalCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellDataV2(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountCellDataV3(entity) => wrap_data_entity(version, index, entity),
+        EntityWrapper::AccountCellDataV4(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountSaleCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountSaleCellDataV1(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::AccountAuctionCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::IncomeCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::OfferCellData(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::SubAccountV1(entity) => wrap_data_entity(version, index, entity),
+        EntityWrapper::SubAccountV2(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::SubAccount(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::ConfigCellAccount(entity) => wrap_data_entity(version, index, entity),
         EntityWrapper::ConfigCellApply(entity) => wrap_data_entity(version, index, entity),
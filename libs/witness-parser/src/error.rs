@@ -15,6 +15,7 @@ pub enum WitnessParserError {
     Unreachable { index: usize },
     #[error("The WitnessParser is not inited.")]
     InitializationRequired,
+    #[cfg(feature = "no_std")]
     #[error("witnesses[{index}] SysError: {err:?}")]
     SysError { index: usize, err: SysError },
     #[error("witnesses[{index}] Do not exist")]
@@ -49,8 +50,8 @@ pub enum WitnessParserError {
     },
     #[error("Failed to find the witness at witnesses[{index}]")]
     CanNotFindWitnessByIndex { index: usize },
-    #[error("Failed to find the witness by {source:?}[{index}]")]
-    CanNotFindWitnessByCellMeta { source: Source, index: usize },
+    #[error("Failed to find the witness by {cell_source:?}[{index}]")]
+    CanNotFindWitnessByCellMeta { cell_source: Source, index: usize },
     #[error("Failed to find the witness by {data_type}")]
     CanNotFindWitnessByDataType { data_type: DataType },
     #[error("witnesses[{index}] Failed to decode the bytes of Entity, the expected type is {data_type} v{version}.")]
@@ -59,4 +60,12 @@ pub enum WitnessParserError {
         data_type: DataType,
         version: u32,
     },
+    #[error("Failed to find the cell by {cell_source:?}[{index}]")]
+    CanNotFindCell { index: usize, cell_source: Source },
+    #[error("The transaction has {expected} cells in {cell_source:?}, but {actual} resolved cells are provided.")]
+    ResolvedCellsMismatched {
+        cell_source: Source,
+        expected: usize,
+        actual: usize,
+    },
 }
@@ -10,7 +10,11 @@ pub mod constants;
 pub mod error;
 pub mod parsers;
 pub mod traits;
+#[cfg(feature = "std")]
+pub mod tx_resolver;
 pub mod types;
 pub mod util;
 
 pub use parsers::v1::witness_parser::WitnessesParser as WitnessesParserV1;
+#[cfg(feature = "std")]
+pub use tx_resolver::TxResolver;
@@ -1,3 +1,4 @@
+#[cfg(feature = "no_std")]
 macro_rules! debug {
     ($($arg:tt)*) => {
         #[cfg(all(debug_assertions))]
@@ -5,6 +6,13 @@ macro_rules! debug {
     };
 }
 
+#[cfg(feature = "std")]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        log::debug!($($arg)*);
+    };
+}
+
 macro_rules! err_assert {
     ($condition:expr, $error_code:expr) => {
         if !$condition {
@@ -2,7 +2,6 @@
 use alloc::string::{String, ToString};
 #[cfg(feature = "no_std")]
 use alloc::vec::Vec;
-#[cfg(feature = "no_std")]
 use core::str::FromStr;
 
 use das_types::constants::{Action, ActionParams, LockRole, WITNESS_HEADER_BYTES, WITNESS_TYPE_BYTES};
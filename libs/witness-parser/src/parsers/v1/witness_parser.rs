@@ -11,10 +11,6 @@ use std::collections::BTreeMap;
 #[cfg(feature = "std")]
 use std::format;
 
-#[cfg(feature = "no_std")]
-use ckb_std::error::SysError;
-#[cfg(feature = "no_std")]
-use ckb_std::syscalls;
 use das_types::constants::{
     config_cell_type, Action, ActionParams, DataType, Source, TypeScript, WITNESS_HEADER, WITNESS_HEADER_BYTES,
     WITNESS_TYPE_BYTES,
@@ -26,8 +22,10 @@ use super::action_parser::parse_action;
 use crate::constants::ScriptType;
 use crate::error::WitnessParserError;
 use crate::traits::WitnessQueryable;
+#[cfg(feature = "std")]
+use crate::tx_resolver::TxResolver;
 use crate::types::{CellMeta, Hash, WitnessMeta};
-use crate::util;
+use crate::util::{self, WitnessHeader};
 
 #[derive(Debug, Default)]
 pub struct WitnessesParser {
@@ -39,9 +37,20 @@ pub struct WitnessesParser {
     witnesses: Vec<WitnessMeta>,
     cell_meta_map: BTreeMap<CellMeta, usize>,
     data_type_map: BTreeMap<DataType, usize>,
+    #[cfg(feature = "std")]
+    tx: TxResolver,
 }
 
 impl WitnessesParser {
+    #[cfg(feature = "std")]
+    pub fn new(tx: TxResolver) -> Self {
+        Self {
+            tx,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "no_std")]
     pub fn get_instance() -> &'static mut Self {
         static mut WITNESS_PARSER: OnceCell<WitnessesParser> = OnceCell::new();
         unsafe {
@@ -62,14 +71,12 @@ impl WitnessesParser {
         debug!("=== Init witness parser ===");
 
         loop {
-            let mut buf = [0u8; (WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)];
-            // TODO Replace all syscalls with tx-resolver to support std environment.
-            let ret = syscalls::load_witness(&mut buf, 0, i, Source::Input.into());
+            let ret = self.load_witness_header(i)?;
 
             match ret {
                 // Data which length is too short to be DAS witnesses, so ignore it.
-                Ok(_) => i += 1,
-                Err(SysError::LengthNotEnough(_)) => {
+                WitnessHeader::TooShort => i += 1,
+                WitnessHeader::Loaded(buf) => {
                     if let Some(raw) = buf.get(..WITNESS_HEADER_BYTES) {
                         if das_witnesses_started {
                             // If it is parsing DAS witnesses currently, end the parsing.
@@ -140,8 +147,7 @@ impl WitnessesParser {
 
                     i += 1;
                 }
-                Err(SysError::IndexOutOfBound) => break,
-                Err(e) => return Err(WitnessParserError::SysError { index: i, err: e }),
+                WitnessHeader::OutOfBound => break,
             }
         }
 
@@ -169,7 +175,7 @@ impl WitnessesParser {
         for source in [Source::CellDep, Source::Input, Source::Output] {
             let args = packed::Bytes::from((data_type.to_owned() as u32).to_le_bytes().to_vec());
             let type_script = config_cell_type().clone().as_builder().args(args).build();
-            let config_cells = util::find_cells_by_script(index, ScriptType::Type, type_script.as_reader(), source)?;
+            let config_cells = self.find_cells_by_script(index, ScriptType::Type, type_script.as_reader(), source)?;
 
             // For any type of ConfigCell, there should be one Cell in the cell_deps, no more and no less.
             match config_cells.len() {
@@ -181,7 +187,7 @@ impl WitnessesParser {
             }
 
             let cell_index = config_cells[0];
-            let hash_in_cell_data = Self::load_witness_hash_from_cell(index, cell_index, source)?;
+            let hash_in_cell_data = self.load_witness_hash_from_cell(index, cell_index, source)?;
 
             debug!(
                 "  witnesses[{:>2}] {{ data_type: {:?}, index: {}, source: {:?}, hash_in_cell: {} }}",
@@ -219,7 +225,7 @@ impl WitnessesParser {
             index, data_type
         );
 
-        let buf = util::load_das_witnesses(index)?;
+        let buf = self.load_das_witnesses(index)?;
         let data = util::parse_data_from_witness(index, &buf)?;
 
         let mut entities = vec![];
@@ -254,7 +260,7 @@ impl WitnessesParser {
         for (source, data_entity) in entities {
             let cell_index = u32::from(data_entity.index()) as usize;
             let version = u32::from(data_entity.version());
-            let hash_in_cell_data = Self::load_witness_hash_from_cell(index, cell_index, source)?;
+            let hash_in_cell_data = self.load_witness_hash_from_cell(index, cell_index, source)?;
 
             debug!(
                 "  witnesses[{:>2}] {{ data_type: {:?}, index: {}, source: {:?}, hash_in_cell: {} }}",
@@ -296,7 +302,7 @@ impl WitnessesParser {
             index, data_type
         );
 
-        let buf = util::load_das_witnesses(index)?;
+        let buf = self.load_das_witnesses(index)?;
         let hash_in_cell_data =
             types_util::blake2b_256(buf.get((WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..).unwrap());
         debug!(
@@ -305,6 +311,8 @@ impl WitnessesParser {
             data_type,
             hex::encode(&hash_in_cell_data)
         );
+        // Only the first witness of a DataType can be found by get_entity_by_data_type, the others need to be found by index.
+        self.data_type_map.entry(data_type).or_insert(self.witnesses.len());
         self.witnesses.push(WitnessMeta {
             index,
             version: 0,
@@ -320,11 +328,12 @@ impl WitnessesParser {
         Ok(())
     }
     fn load_witness_hash_from_cell(
+        &self,
         witness_index: usize,
         cell_index: usize,
         source: Source,
     ) -> Result<[u8; 32], WitnessParserError> {
-        let data = util::load_cell_data(cell_index, source.into())?;
+        let data = self.load_cell_data(cell_index, source)?;
         debug!(
             "  witnesses[{:>2}] Loading expected hash from {:?}[{}]",
             witness_index, source, cell_index
@@ -347,8 +356,42 @@ impl WitnessesParser {
         Ok(expected_entity_hash)
     }
 
+    fn load_witness_header(&self, index: usize) -> Result<WitnessHeader, WitnessParserError> {
+        #[cfg(feature = "no_std")]
+        return util::load_witness_header(index);
+        #[cfg(feature = "std")]
+        return util::load_witness_header(&self.tx, index);
+    }
+
+    fn load_das_witnesses(&self, index: usize) -> Result<Vec<u8>, WitnessParserError> {
+        #[cfg(feature = "no_std")]
+        return util::load_das_witnesses(index);
+        #[cfg(feature = "std")]
+        return util::load_das_witnesses(&self.tx, index);
+    }
+
+    fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, WitnessParserError> {
+        #[cfg(feature = "no_std")]
+        return util::load_cell_data(index, source);
+        #[cfg(feature = "std")]
+        return util::load_cell_data(&self.tx, index, source);
+    }
+
+    fn find_cells_by_script(
+        &self,
+        witness_index: usize,
+        script_type: ScriptType,
+        script: packed::ScriptReader,
+        source: Source,
+    ) -> Result<Vec<usize>, WitnessParserError> {
+        #[cfg(feature = "no_std")]
+        return util::find_cells_by_script(witness_index, script_type, script, source);
+        #[cfg(feature = "std")]
+        return util::find_cells_by_script(&self.tx, witness_index, script_type, script, source);
+    }
+
     fn parse_action(&mut self, index: usize) -> Result<(), WitnessParserError> {
-        let bytes = util::load_das_witnesses(index)?;
+        let bytes = self.load_das_witnesses(index)?;
         let (action_data, action, action_params) = parse_action(index, bytes)?;
         self.action_data = action_data;
         self.action = action;
@@ -377,7 +420,7 @@ impl WitnessQueryable for WitnessesParser {
             .cell_meta_map
             .get(&cell_meta)
             .ok_or(WitnessParserError::CanNotFindWitnessByCellMeta {
-                source: cell_meta.source,
+                cell_source: cell_meta.source,
                 index: cell_meta.index,
             })?
             .to_owned();
@@ -423,7 +466,7 @@ impl WitnessQueryable for WitnessesParser {
             .cell_meta_map
             .get(&cell_meta)
             .ok_or(WitnessParserError::CanNotFindWitnessByCellMeta {
-                source: cell_meta.source,
+                cell_source: cell_meta.source,
                 index: cell_meta.index,
             })?
             .to_owned();
@@ -433,7 +476,7 @@ impl WitnessQueryable for WitnessesParser {
             .get(index)
             .ok_or(WitnessParserError::CanNotFindWitnessByIndex { index })?;
 
-        let buf = util::load_das_witnesses(witness_meta.index)?;
+        let buf = self.load_das_witnesses(witness_meta.index)?;
         let data = util::parse_data_from_witness(index, &buf)?;
         let data_entity = match witness_meta.cell_meta.source {
             Source::CellDep => data.dep().to_opt(),
@@ -484,7 +527,7 @@ impl WitnessQueryable for WitnessesParser {
             .get(index)
             .ok_or(WitnessParserError::CanNotFindWitnessByIndex { index })?;
 
-        let buf = util::load_das_witnesses(witness_meta.index)?;
+        let buf = self.load_das_witnesses(witness_meta.index)?;
         let data = util::parse_raw_from_witness(index, &buf)?;
 
         let entity_hash = types_util::blake2b_256(&data);
@@ -515,7 +558,7 @@ impl WitnessQueryable for WitnessesParser {
             .get(index)
             .ok_or(WitnessParserError::CanNotFindWitnessByIndex { index })?;
 
-        let buf = util::load_das_witnesses(witness_meta.index)?;
+        let buf = self.load_das_witnesses(witness_meta.index)?;
 
         let buf_hash = types_util::blake2b_256(buf.get((WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..).unwrap());
         err_assert!(
@@ -535,7 +578,7 @@ impl WitnessQueryable for WitnessesParser {
             .cell_meta_map
             .get(&cell_meta)
             .ok_or(WitnessParserError::CanNotFindWitnessByCellMeta {
-                source: cell_meta.source,
+                cell_source: cell_meta.source,
                 index: cell_meta.index,
             })?
             .to_owned();
@@ -0,0 +1,127 @@
+use ckb_jsonrpc_types as json_types;
+use ckb_types::packed;
+use ckb_types::prelude::*;
+use das_types::constants::Source;
+
+use crate::error::WitnessParserError;
+use crate::types::Hash;
+
+/// A cell which is already resolved from its out point, only the fields the parser needs are kept.
+#[derive(Clone, Debug, Default)]
+pub struct ResolvedCell {
+    pub lock_hash: Hash,
+    pub type_hash: Option<Hash>,
+    pub data: Vec<u8>,
+}
+
+impl ResolvedCell {
+    pub fn new(output: json_types::CellOutput, data: json_types::JsonBytes) -> Self {
+        let output = packed::CellOutput::from(output);
+
+        let mut lock_hash = Hash::default();
+        lock_hash.copy_from_slice(output.lock().calc_script_hash().as_slice());
+        let type_hash = output.type_().to_opt().map(|script| {
+            let mut hash = Hash::default();
+            hash.copy_from_slice(script.calc_script_hash().as_slice());
+            hash
+        });
+
+        Self {
+            lock_hash,
+            type_hash,
+            data: data.into_bytes().to_vec(),
+        }
+    }
+}
+
+/// The std replacement of the ckb-std syscalls, it provides the same view of a transaction as the one scripts see in
+/// ckb-vm, so the WitnessesParser can decode transactions off-chain with the exact on-chain rules.
+#[derive(Clone, Debug, Default)]
+pub struct TxResolver {
+    pub tx_hash: Hash,
+    witnesses: Vec<Vec<u8>>,
+    inputs: Vec<ResolvedCell>,
+    outputs: Vec<ResolvedCell>,
+    cell_deps: Vec<ResolvedCell>,
+}
+
+impl TxResolver {
+    /// Create a resolver from a transaction and its resolved cells.
+    ///
+    /// - `inputs` should be the previous outputs of `tx.inner.inputs` with their data, in the same order.
+    /// - `cell_deps` should be the cells in `tx.inner.cell_deps` with their data, the dep groups should be expanded
+    /// just like ckb-vm does.
+    pub fn new(
+        tx: json_types::TransactionView,
+        inputs: Vec<(json_types::CellOutput, json_types::JsonBytes)>,
+        cell_deps: Vec<(json_types::CellOutput, json_types::JsonBytes)>,
+    ) -> Result<Self, WitnessParserError> {
+        err_assert!(
+            tx.inner.inputs.len() == inputs.len(),
+            WitnessParserError::ResolvedCellsMismatched {
+                cell_source: Source::Input,
+                expected: tx.inner.inputs.len(),
+                actual: inputs.len(),
+            }
+        );
+        err_assert!(
+            tx.inner.outputs.len() == tx.inner.outputs_data.len(),
+            WitnessParserError::ResolvedCellsMismatched {
+                cell_source: Source::Output,
+                expected: tx.inner.outputs.len(),
+                actual: tx.inner.outputs_data.len(),
+            }
+        );
+
+        let mut tx_hash = Hash::default();
+        tx_hash.copy_from_slice(tx.hash.as_bytes());
+
+        let json_types::Transaction {
+            outputs,
+            outputs_data,
+            witnesses,
+            ..
+        } = tx.inner;
+
+        Ok(Self {
+            tx_hash,
+            witnesses: witnesses
+                .into_iter()
+                .map(|witness| witness.into_bytes().to_vec())
+                .collect(),
+            inputs: inputs
+                .into_iter()
+                .map(|(output, data)| ResolvedCell::new(output, data))
+                .collect(),
+            outputs: outputs
+                .into_iter()
+                .zip(outputs_data.into_iter())
+                .map(|(output, data)| ResolvedCell::new(output, data))
+                .collect(),
+            cell_deps: cell_deps
+                .into_iter()
+                .map(|(output, data)| ResolvedCell::new(output, data))
+                .collect(),
+        })
+    }
+
+    pub fn witness(&self, index: usize) -> Option<&[u8]> {
+        self.witnesses.get(index).map(|witness| witness.as_slice())
+    }
+
+    pub fn cell(&self, index: usize, source: Source) -> Option<&ResolvedCell> {
+        match source {
+            Source::Input => self.inputs.get(index),
+            Source::Output => self.outputs.get(index),
+            Source::CellDep => self.cell_deps.get(index),
+        }
+    }
+
+    pub fn cells(&self, source: Source) -> &[ResolvedCell] {
+        match source {
+            Source::Input => &self.inputs,
+            Source::Output => &self.outputs,
+            Source::CellDep => &self.cell_deps,
+        }
+    }
+}
@@ -4,8 +4,6 @@ use alloc::string::{String, ToString};
 use alloc::vec;
 #[cfg(feature = "no_std")]
 use alloc::vec::Vec;
-#[cfg(feature = "std")]
-use std::vec;
 
 #[cfg(feature = "no_std")]
 use ckb_std::high_level;
@@ -18,7 +16,48 @@ use molecule::prelude::Entity;
 
 use crate::constants::ScriptType;
 use crate::error::WitnessParserError;
+#[cfg(feature = "std")]
+use crate::tx_resolver::TxResolver;
+
+/// The result of loading the first bytes of a witness.
+pub enum WitnessHeader {
+    /// The witness is too short to be DAS witnesses.
+    TooShort,
+    /// The first `WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES` bytes of the witness.
+    Loaded([u8; WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES]),
+    /// There is no more witnesses.
+    OutOfBound,
+}
+
+#[cfg(feature = "no_std")]
+pub fn load_witness_header(index: usize) -> Result<WitnessHeader, WitnessParserError> {
+    let mut buf = [0u8; (WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)];
+    let ret = syscalls::load_witness(&mut buf, 0, index, Source::Input.into());
+
+    match ret {
+        Ok(_) => Ok(WitnessHeader::TooShort),
+        Err(SysError::LengthNotEnough(_)) => Ok(WitnessHeader::Loaded(buf)),
+        Err(SysError::IndexOutOfBound) => Ok(WitnessHeader::OutOfBound),
+        Err(err) => Err(WitnessParserError::SysError { index, err }),
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn load_witness_header(tx: &TxResolver, index: usize) -> Result<WitnessHeader, WitnessParserError> {
+    let mut buf = [0u8; (WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)];
 
+    match tx.witness(index) {
+        // Keep the same behavior as the syscall, a witness fits in the buffer is treated as too short.
+        Some(witness) if witness.len() <= buf.len() => Ok(WitnessHeader::TooShort),
+        Some(witness) => {
+            buf.copy_from_slice(&witness[..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)]);
+            Ok(WitnessHeader::Loaded(buf))
+        }
+        None => Ok(WitnessHeader::OutOfBound),
+    }
+}
+
+#[cfg(feature = "no_std")]
 pub fn load_das_witnesses(index: usize) -> Result<Vec<u8>, WitnessParserError> {
     let mut buf = [0u8; 7];
     let ret = syscalls::load_witness(&mut buf, 0, index, Source::Input.into());
@@ -59,6 +98,41 @@ pub fn load_das_witnesses(index: usize) -> Result<Vec<u8>, WitnessParserError> {
     }
 }
 
+#[cfg(feature = "std")]
+pub fn load_das_witnesses(tx: &TxResolver, index: usize) -> Result<Vec<u8>, WitnessParserError> {
+    let witness = tx
+        .witness(index)
+        .ok_or(WitnessParserError::CanNotFindWitnessByIndex { index })?;
+
+    err_assert!(
+        witness.len() > WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES,
+        WitnessParserError::BasicDataStructureError {
+            index,
+            msg: String::from("The witness is too short to be DID witness."),
+        }
+    );
+    err_assert!(
+        witness[..3] == WITNESS_HEADER,
+        WitnessParserError::BasicDataStructureError {
+            index,
+            msg: String::from("The witness should start with \"das\" 3 bytes."),
+        }
+    );
+    // WARNING This limit should be kept the same as the one in no_std environment.
+    err_assert!(
+        witness.len() <= 33000,
+        WitnessParserError::BasicDataStructureError {
+            index,
+            msg: String::from(
+                "The witness should be less than 32KB because the signall lock do not support more than that.",
+            ),
+        }
+    );
+
+    Ok(witness.to_vec())
+}
+
+#[cfg(feature = "no_std")]
 pub fn load_cell_data(index: usize, source: Source) -> Result<Vec<u8>, WitnessParserError> {
     let mut buf = vec![0u8; 32];
     let ret = syscalls::load_cell_data(&mut buf, 0, index, source.into());
@@ -75,6 +149,16 @@ pub fn load_cell_data(index: usize, source: Source) -> Result<Vec<u8>, WitnessPa
     }
 }
 
+#[cfg(feature = "std")]
+pub fn load_cell_data(tx: &TxResolver, index: usize, source: Source) -> Result<Vec<u8>, WitnessParserError> {
+    tx.cell(index, source)
+        .map(|cell| cell.data.clone())
+        .ok_or(WitnessParserError::CanNotFindCell {
+            index,
+            cell_source: source,
+        })
+}
+
 pub fn parse_date_type_from_witness(index: usize, buf: &[u8]) -> Result<DataType, WitnessParserError> {
     let data_type_in_int = u32::from_le_bytes(
         buf.get(WITNESS_HEADER_BYTES..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES))
@@ -113,6 +197,7 @@ pub fn parse_raw_from_witness(index: usize, buf: &[u8]) -> Result<Vec<u8>, Witne
     }
 }
 
+#[cfg(feature = "no_std")]
 pub fn find_cells_by_script(
     witness_index: usize,
     script_type: ScriptType,
@@ -123,7 +208,6 @@ pub fn find_cells_by_script(
     let mut cell_indexes = Vec::new();
     let expected_hash = types_util::blake2b_256(script.as_slice());
     loop {
-        // TODO replace the high_level functions with tx-resolver to support std environment.
         let ret = match script_type {
             ScriptType::Lock => high_level::load_cell_lock_hash(i, source.into()).map(Some),
             _ => high_level::load_cell_type_hash(i, source.into()),
@@ -150,3 +234,26 @@ pub fn find_cells_by_script(
 
     Ok(cell_indexes)
 }
+
+#[cfg(feature = "std")]
+pub fn find_cells_by_script(
+    tx: &TxResolver,
+    _witness_index: usize,
+    script_type: ScriptType,
+    script: packed::ScriptReader,
+    source: Source,
+) -> Result<Vec<usize>, WitnessParserError> {
+    let expected_hash = types_util::blake2b_256(script.as_slice());
+    let cell_indexes = tx
+        .cells(source)
+        .iter()
+        .enumerate()
+        .filter(|(_, cell)| match script_type {
+            ScriptType::Lock => cell.lock_hash == expected_hash,
+            _ => cell.type_hash == Some(expected_hash),
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    Ok(cell_indexes)
+}
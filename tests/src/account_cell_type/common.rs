@@ -1,4 +1,5 @@
 use das_types::constants::*;
+use das_types::packed::{MultiYearDiscount, Promotion};
 use serde_json::{json, Value};
 
 use crate::util::accounts::*;
@@ -27,12 +28,23 @@ pub fn init(action: &str, params_opt: Option<&str>) -> TemplateGenerator {
 }
 
 pub fn init_for_renew(action: &str, params_opt: Option<&str>) -> TemplateGenerator {
+    init_for_renew_with_discounts(action, params_opt, vec![], vec![])
+}
+
+pub fn init_for_renew_with_discounts(
+    action: &str,
+    params_opt: Option<&str>,
+    multi_year_discounts: Vec<MultiYearDiscount>,
+    promotions: Vec<Promotion>,
+) -> TemplateGenerator {
     let mut template = init(action, params_opt);
 
     template.push_contract_cell("income-cell-type", ContractType::Contract);
     template.push_contract_cell("balance-cell-type", ContractType::Contract);
 
     template.push_oracle_cell(1, OracleCellType::Quote, 1000);
+    template.multi_year_discounts = multi_year_discounts;
+    template.promotions = promotions;
     template.push_config_cell(DataType::ConfigCellPrice, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellIncome, Source::CellDep);
 
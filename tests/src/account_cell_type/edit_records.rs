@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use das_types::constants::*;
-use serde_json::json;
+use serde_json::{json, Value};
 
 use super::common::init;
 use crate::util::accounts::*;
@@ -8,6 +10,7 @@ use crate::util::error::*;
 use crate::util::template_common_cell::*;
 use crate::util::template_generator::*;
 use crate::util::template_parser::*;
+use crate::util::{self};
 
 fn before_each() -> TemplateGenerator {
     let mut template = init("edit_records", Some("0x01"));
@@ -81,7 +84,7 @@ fn test_account_edit_records() {
                     },
                     {
                         "type": "address",
-                        "key": "60",
+                        "key": "eth",
                         "label": "Company",
                         "value": "0x0000000000000000000000000000000000001111",
                     }
@@ -94,14 +97,24 @@ fn test_account_edit_records() {
 }
 
 #[test]
-fn challenge_account_edit_records_multiple_cells() {
+fn challenge_account_edit_records_mismatched_cells() {
     let mut template = before_each();
 
     // inputs
-    // Simulate editing multiple AccountCells in one transaction.
-    push_input_account_cell(&mut template, json!({}));
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "data": {
+                "account": ACCOUNT_2
+            },
+            "witness": {
+                "account": ACCOUNT_2
+            }
+        }),
+    );
 
     // outputs
+    // Simulate the number of AccountCells in outputs is not the same as which in inputs.
     push_output_account_cell(
         &mut template,
         json!({
@@ -110,18 +123,107 @@ fn challenge_account_edit_records_multiple_cells() {
             }
         }),
     );
+
+    challenge_tx(template.as_json(), ErrorCode::InvalidTransactionStructure)
+}
+
+fn push_input_account_cell_of_account_2(template: &mut TemplateGenerator, manager: &str) {
+    push_input_account_cell(
+        template,
+        json!({
+            "lock": {
+                "manager_lock_args": manager
+            },
+            "data": {
+                "account": ACCOUNT_2
+            },
+            "witness": {
+                "account": ACCOUNT_2
+            }
+        }),
+    );
+}
+
+fn push_output_account_cell_of_account_2(template: &mut TemplateGenerator, manager: &str, key: &str) {
     push_output_account_cell(
-        &mut template,
+        template,
         json!({
+            "lock": {
+                "manager_lock_args": manager
+            },
+            "data": {
+                "account": ACCOUNT_2
+            },
             "witness": {
-                "last_edit_records_at": TIMESTAMP
+                "account": ACCOUNT_2,
+                "last_edit_records_at": TIMESTAMP,
+                "records": [
+                    {
+                        "type": "address",
+                        "key": key,
+                        "label": "Personal",
+                        "value": "0x0000000000000000000000000000000000000000",
+                    }
+                ]
+            }
+        }),
+    );
+}
+
+fn push_output_account_cell_of_account_1(template: &mut TemplateGenerator) {
+    push_output_account_cell(
+        template,
+        json!({
+            "witness": {
+                "last_edit_records_at": TIMESTAMP,
+                "records": [
+                    {
+                        "type": "address",
+                        "key": "eth",
+                        "label": "Personal",
+                        "value": "0x0000000000000000000000000000000000000000",
+                    }
+                ]
             }
         }),
     );
+}
+
+#[test]
+fn test_account_edit_records_multiple_accounts() {
+    let mut template = before_each();
+    push_input_account_cell_of_account_2(&mut template, MANAGER);
+
+    push_output_account_cell_of_account_1(&mut template);
+    push_output_account_cell_of_account_2(&mut template, MANAGER, "eth");
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_account_edit_records_multiple_accounts_with_different_managers() {
+    let mut template = before_each();
+    // Simulate editing the records of an AccountCell which is managed by another lock.
+    push_input_account_cell_of_account_2(&mut template, SENDER);
+
+    push_output_account_cell_of_account_1(&mut template);
+    push_output_account_cell_of_account_2(&mut template, SENDER, "eth");
 
     challenge_tx(template.as_json(), ErrorCode::InvalidTransactionStructure)
 }
 
+#[test]
+fn challenge_account_edit_records_multiple_accounts_invalid_key() {
+    let mut template = before_each();
+    push_input_account_cell_of_account_2(&mut template, MANAGER);
+
+    push_output_account_cell_of_account_1(&mut template);
+    // Simulate one of the AccountCells contains an invalid record key.
+    push_output_account_cell_of_account_2(&mut template, MANAGER, "xxxx");
+
+    challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellRecordKeyInvalid)
+}
+
 #[test]
 fn challenge_account_edit_records_with_other_cells() {
     let mut template = init("edit_records", Some("0x01"));
@@ -228,3 +330,216 @@ fn challenge_account_edit_records_invalid_coin_type() {
 
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellRecordKeyInvalid)
 }
+
+fn gen_edit_records_tx_with_validators(legacy_namespace: bool, records: Value) -> Value {
+    gen_edit_records_tx_with_prev_records(legacy_namespace, None, records)
+}
+
+fn gen_edit_records_tx_with_prev_records(legacy_namespace: bool, prev_records: Option<Value>, records: Value) -> Value {
+    let mut template = init("edit_records", Some("0x01"));
+    template.legacy_record_key_namespace = legacy_namespace;
+    template.record_value_validators = HashMap::from([
+        (String::from("address.eth"), RecordValueValidator::EvmAddress),
+        (String::from("address.btc"), RecordValueValidator::Base58Check),
+        (String::from("address.ckb"), RecordValueValidator::Bech32),
+        (String::from("dweb.ipfs"), RecordValueValidator::ContentHash),
+        (String::from("profile.website"), RecordValueValidator::Url),
+    ]);
+    template.push_config_cell(DataType::ConfigCellRecordKeyNamespace, Source::CellDep);
+
+    match prev_records {
+        Some(prev_records) => push_input_account_cell(&mut template, json!({ "witness": { "records": prev_records } })),
+        None => push_input_account_cell(&mut template, json!({})),
+    }
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "last_edit_records_at": TIMESTAMP,
+                "records": records
+            }
+        }),
+    );
+
+    template.as_json()
+}
+
+fn gen_record(type_: &str, key: &str, value: &str) -> Value {
+    json!({
+        "type": type_,
+        "key": key,
+        "label": "",
+        "value": util::bytes_to_hex(value.as_bytes()),
+    })
+}
+
+#[test]
+fn test_account_edit_records_with_validated_values() {
+    let tx = gen_edit_records_tx_with_validators(
+        false,
+        json!([
+            gen_record("address", "eth", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            gen_record("address", "eth", "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            gen_record("address", "btc", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            gen_record("address", "ckb", "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            gen_record("dweb", "ipfs", "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+            gen_record(
+                "dweb",
+                "ipfs",
+                "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+            ),
+            gen_record("profile", "website", "https://d.id/bit?from=records"),
+            // The keys without validators accept any values.
+            gen_record("profile", "twitter", "\u{1f600}"),
+        ]),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn test_account_edit_records_with_legacy_namespace() {
+    // The legacy namespace carries no validators, and the TTL is not required either.
+    let mut record = gen_record("address", "eth", "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    record["ttl"] = json!(0);
+    let tx = gen_edit_records_tx_with_validators(true, json!([record]));
+
+    test_tx(tx);
+}
+
+#[test]
+fn challenge_account_edit_records_invalid_evm_address() {
+    // Simulate the EIP-55 checksum of the address is broken.
+    let tx = gen_edit_records_tx_with_validators(
+        false,
+        json!([gen_record(
+            "address",
+            "eth",
+            "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        )]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_invalid_base58_address() {
+    // Simulate the base58 checksum of the address is broken.
+    let tx = gen_edit_records_tx_with_validators(
+        false,
+        json!([gen_record("address", "btc", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb")]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_invalid_bech32_address() {
+    // Simulate the bech32 checksum of the address is broken.
+    let tx = gen_edit_records_tx_with_validators(
+        false,
+        json!([gen_record(
+            "address",
+            "ckb",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"
+        )]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_invalid_content_hash() {
+    // Simulate the CID is truncated.
+    let tx = gen_edit_records_tx_with_validators(
+        false,
+        json!([gen_record(
+            "dweb",
+            "ipfs",
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzd"
+        )]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_invalid_url() {
+    // Simulate the URL contains whitespaces.
+    let tx =
+        gen_edit_records_tx_with_validators(false, json!([gen_record("profile", "website", "https://d.id/my bit")]));
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_ttl_too_short() {
+    let mut record = gen_record("profile", "twitter", "das");
+    // Simulate the TTL is less than the record_min_ttl in ConfigCellAccount.
+    record["ttl"] = json!(299);
+    let tx = gen_edit_records_tx_with_validators(false, json!([record]));
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordTtlTooShort);
+}
+
+fn gen_legacy_record() -> Value {
+    // The record is created before the validator and the TTL are required.
+    let mut record = gen_record("address", "eth", "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    record["ttl"] = json!(0);
+    record
+}
+
+#[test]
+fn test_account_edit_records_keeping_legacy_records() {
+    let tx = gen_edit_records_tx_with_prev_records(
+        false,
+        Some(json!([gen_legacy_record()])),
+        json!([
+            gen_legacy_record(),
+            gen_record("address", "eth", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+        ]),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn challenge_account_edit_records_changing_legacy_records() {
+    let mut record = gen_legacy_record();
+    // Simulate changing the label of the legacy record, so it is validated as a new record.
+    record["label"] = json!("Personal");
+    let tx = gen_edit_records_tx_with_prev_records(false, Some(json!([gen_legacy_record()])), json!([record]));
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
+#[test]
+fn challenge_account_edit_records_by_delegate() {
+    let mut template = init("edit_records", Some("0x02"));
+    template.push_config_cell(DataType::ConfigCellRecordKeyNamespace, Source::CellDep);
+
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    gen_record("profile", "twitter", "das"),
+                ]
+            }
+        }),
+    );
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "last_edit_records_at": TIMESTAMP,
+                "records": [
+                    gen_record("profile", "twitter", "bit"),
+                ]
+            }
+        }),
+    );
+
+    // Simulate signing with the delegate role, the delegates only exist in sub-accounts.
+    challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellPermissionDenied);
+}
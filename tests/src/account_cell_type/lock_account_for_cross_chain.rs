@@ -1,7 +1,8 @@
-use das_types::constants::AccountStatus;
-use serde_json::json;
+use das_types::constants::{AccountStatus, DataType, Source};
+use serde_json::{json, Value};
 
 use super::common::*;
+use crate::util;
 use crate::util::accounts::*;
 use crate::util::constants::*;
 use crate::util::error::*;
@@ -9,11 +10,28 @@ use crate::util::template_common_cell::*;
 use crate::util::template_generator::{ContractType, *};
 use crate::util::template_parser::*;
 
+// The params are 8 bytes coin_type, 8 bytes chain_id and 1 byte role, the numbers are in little-endian.
+const PARAMS_ETHEREUM: &str = "0x3c00000000000000010000000000000000";
+const PARAMS_UNSUPPORTED_CHAIN: &str = "0x3c00000000000000020000000000000000";
+const PARAMS_DISABLED_CHAIN: &str = "0xc603000000000000890000000000000000";
+
+fn ethereum_cross_chain() -> Value {
+    json!({
+        "checked": 1,
+        "coin_type": 60,
+        "chain_id": 1
+    })
+}
+
+fn init_for_lock(params: &str) -> TemplateGenerator {
+    let mut template = init("lock_account_for_cross_chain", Some(params));
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
+
+    template
+}
+
 fn before_each() -> TemplateGenerator {
-    let mut template = init(
-        "lock_account_for_cross_chain",
-        Some("0x0000000000000011000000000000002200"),
-    );
+    let mut template = init_for_lock(PARAMS_ETHEREUM);
 
     // inputs
     push_input_account_cell(
@@ -42,7 +60,8 @@ fn test_account_lock_account_for_cross_chain() {
                 "manager_lock_args": SENDER
             },
             "witness": {
-                "status": (AccountStatus::LockedForCrossChain as u8)
+                "status": (AccountStatus::LockedForCrossChain as u8),
+                "cross_chain": ethereum_cross_chain()
             }
         }),
     );
@@ -51,12 +70,122 @@ fn test_account_lock_account_for_cross_chain() {
 }
 
 #[test]
-fn challenge_account_lock_account_for_cross_chain_account_multiple_cells() {
-    let mut template = init(
-        "lock_account_for_cross_chain",
-        Some("0x0000000000000011000000000000002200"),
+fn test_account_lock_account_for_cross_chain_spend_chain_fee() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            // The fee of the chain in ConfigCellCrossChain.chains can be taken from the AccountCell.
+            "capacity": util::gen_account_cell_capacity(5) - CROSS_CHAIN_LOCK_FEE,
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::LockedForCrossChain as u8),
+                "cross_chain": ethereum_cross_chain()
+            }
+        }),
     );
 
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_spend_fee_error() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            // Simulate spending more fee than the fee of the chain.
+            "capacity": util::gen_account_cell_capacity(5) - CROSS_CHAIN_LOCK_FEE - 1,
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::LockedForCrossChain as u8)
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::TxFeeSpentError)
+}
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_unsupported_chain() {
+    // Simulate locking the AccountCell to a chain which is not listed in ConfigCellCrossChain.chains.
+    let mut template = init_for_lock(PARAMS_UNSUPPORTED_CHAIN);
+
+    // inputs
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            }
+        }),
+    );
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::LockedForCrossChain as u8)
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainNotSupported)
+}
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_disabled_chain() {
+    // Simulate locking the AccountCell to a chain which is disabled in ConfigCellCrossChain.chains.
+    let mut template = init_for_lock(PARAMS_DISABLED_CHAIN);
+
+    // inputs
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            }
+        }),
+    );
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::LockedForCrossChain as u8)
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainDisabled)
+}
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_account_multiple_cells() {
+    let mut template = init_for_lock(PARAMS_ETHEREUM);
+
     // Simulate locking multiple AccountCells at one time.
     // inputs
     push_input_account_cell(
@@ -109,10 +238,7 @@ fn challenge_account_lock_account_for_cross_chain_account_multiple_cells() {
 
 #[test]
 fn challenge_account_lock_account_for_cross_chain_account_with_other_cells() {
-    let mut template = init(
-        "lock_account_for_cross_chain",
-        Some("0x0000000000000011000000000000002200"),
-    );
+    let mut template = init_for_lock(PARAMS_ETHEREUM);
 
     template.push_contract_cell("balance-cell-type", ContractType::Contract);
 
@@ -375,10 +501,7 @@ fn challenge_account_lock_account_for_cross_chain_modify_witness_last_edit_recor
 
 #[test]
 fn challenge_account_lock_account_for_cross_chain_is_near_expired() {
-    let mut template = init(
-        "lock_account_for_cross_chain",
-        Some("0x0000000000000011000000000000002200"),
-    );
+    let mut template = init_for_lock(PARAMS_ETHEREUM);
     let expired_at = TIMESTAMP + 30 * DAY_SEC - 1;
 
     // inputs
@@ -437,3 +560,52 @@ fn challenge_account_lock_account_for_cross_chain_modify_witness_status_error()
 
     challenge_tx(template.as_json(), ErrorCode::CrossChainLockError)
 }
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_without_chain() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                // Simulate not recording the chain which the AccountCell is locked to.
+                "status": (AccountStatus::LockedForCrossChain as u8)
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainLockError)
+}
+
+#[test]
+fn challenge_account_lock_account_for_cross_chain_record_other_chain() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::LockedForCrossChain as u8),
+                // Simulate recording a different chain from the params.
+                "cross_chain": {
+                    "checked": 1,
+                    "coin_type": 60,
+                    "chain_id": 56
+                }
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainLockError)
+}
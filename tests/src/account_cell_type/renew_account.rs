@@ -1,12 +1,13 @@
-use das_types::constants::AccountStatus;
-use serde_json::json;
+use das_types::constants::{AccountStatus, DataType, Source, PROMOTION_ANY_CHAR_SET};
+use das_types::packed::{MultiYearDiscount, Promotion};
+use serde_json::{json, Value};
 
 use super::common::*;
 use crate::util::accounts::*;
 use crate::util::constants::*;
 use crate::util::error::*;
 use crate::util::template_common_cell::*;
-use crate::util::template_generator::TemplateGenerator;
+use crate::util::template_generator::{gen_multi_year_discount, gen_promotion, ContractType, TemplateGenerator};
 use crate::util::template_parser::*;
 
 fn push_simple_output_income_cell(template: &mut TemplateGenerator) {
@@ -448,3 +449,398 @@ fn challenge_account_renew_expired_account() {
 
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellHasExpired)
 }
+
+fn gen_renew_tx(
+    multi_year_discounts: Vec<MultiYearDiscount>,
+    promotions: Vec<Promotion>,
+    years: u64,
+    paid: u64,
+) -> Value {
+    let mut template = init_for_renew_with_discounts("renew_account", None, multi_year_discounts, promotions);
+
+    // inputs
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER
+            },
+            "data": {
+                "expired_at": TIMESTAMP
+            }
+        }),
+    );
+    push_input_balance_cell(&mut template, paid, OWNER);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER,
+            },
+            "data": {
+                "expired_at": TIMESTAMP + 31_536_000 * years,
+            }
+        }),
+    );
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": DAS_WALLET_LOCK_ARGS
+                        },
+                        "capacity": paid.to_string()
+                    }
+                ]
+            }
+        }),
+    );
+
+    template.as_json()
+}
+
+#[test]
+fn test_account_renew_with_promotion() {
+    let promotions = vec![gen_promotion(
+        TIMESTAMP,
+        TIMESTAMP + DAY_SEC,
+        PROMOTION_ANY_CHAR_SET,
+        0,
+        0,
+        5000,
+    )];
+
+    test_tx(gen_renew_tx(vec![], promotions, 1, 250_000_000_000));
+}
+
+#[test]
+fn challenge_account_renew_with_inactive_promotion() {
+    // The promotion has not started yet, so the payment is only half of the renew fee.
+    let promotions = vec![gen_promotion(
+        TIMESTAMP + 1,
+        TIMESTAMP + DAY_SEC,
+        PROMOTION_ANY_CHAR_SET,
+        0,
+        0,
+        5000,
+    )];
+
+    challenge_tx(
+        gen_renew_tx(vec![], promotions, 1, 250_000_000_000),
+        AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
+    )
+}
+
+#[test]
+fn test_account_renew_with_multi_year_discount() {
+    let multi_year_discounts = vec![gen_multi_year_discount(3, 1000)];
+
+    test_tx(gen_renew_tx(multi_year_discounts, vec![], 3, 450_000_000_000 * 3));
+}
+
+#[test]
+fn challenge_account_renew_with_unreached_multi_year_discount() {
+    // The duration is less than 3 years, so the discount is not applied.
+    let multi_year_discounts = vec![gen_multi_year_discount(3, 1000)];
+
+    challenge_tx(
+        gen_renew_tx(multi_year_discounts, vec![], 2, 450_000_000_000 * 2),
+        AccountCellErrorCode::AccountCellRenewDurationBiggerThanPayed,
+    )
+}
+
+fn before_each_batch(accounts: &[(&str, u64, u8)], paid: u64) -> TemplateGenerator {
+    let mut template = init_for_renew("renew_account", None);
+
+    // inputs
+    for (account, _, status) in accounts.iter() {
+        push_input_account_cell(
+            &mut template,
+            json!({
+                "lock": {
+                    "owner_lock_args": OWNER
+                },
+                "data": {
+                    "account": account,
+                    "expired_at": TIMESTAMP
+                },
+                "witness": {
+                    "account": account,
+                    "status": status
+                }
+            }),
+        );
+    }
+    push_input_balance_cell(&mut template, paid, OWNER);
+
+    // outputs
+    for (account, years, status) in accounts.iter() {
+        push_output_account_cell(
+            &mut template,
+            json!({
+                "lock": {
+                    "owner_lock_args": OWNER,
+                },
+                "data": {
+                    "account": account,
+                    "expired_at": TIMESTAMP + 31_536_000 * years,
+                },
+                "witness": {
+                    "account": account,
+                    "status": status
+                }
+            }),
+        );
+    }
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": DAS_WALLET_LOCK_ARGS
+                        },
+                        "capacity": paid.to_string()
+                    }
+                ]
+            }
+        }),
+    );
+
+    template
+}
+
+#[test]
+fn test_account_renew_batch() {
+    let normal = AccountStatus::Normal as u8;
+    let template = before_each_batch(&[(ACCOUNT_1, 1, normal), (ACCOUNT_2, 2, normal)], 500_000_000_000 * 3);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_account_renew_batch_payment_less_than_increment() {
+    let normal = AccountStatus::Normal as u8;
+    // Simulate paying for 2 years in total while renewing the accounts for 3 years in total.
+    let template = before_each_batch(&[(ACCOUNT_1, 1, normal), (ACCOUNT_2, 2, normal)], 500_000_000_000 * 2);
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellRenewDurationBiggerThanPayed,
+    )
+}
+
+#[test]
+fn challenge_account_renew_batch_less_than_one_year() {
+    let normal = AccountStatus::Normal as u8;
+    // Simulate one of the accounts is not renewed.
+    let template = before_each_batch(&[(ACCOUNT_1, 2, normal), (ACCOUNT_2, 0, normal)], 500_000_000_000 * 2);
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
+    )
+}
+
+#[test]
+fn challenge_account_renew_batch_locked_for_cross_chain() {
+    let template = before_each_batch(
+        &[
+            (ACCOUNT_1, 1, AccountStatus::Normal as u8),
+            (ACCOUNT_2, 1, AccountStatus::LockedForCrossChain as u8),
+        ],
+        500_000_000_000 * 2,
+    );
+
+    challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellStatusLocked)
+}
+
+fn before_each_paid_in_udt(udt_amount: u128) -> TemplateGenerator {
+    let mut template = init_for_renew("renew_account", None);
+
+    // inputs
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER
+            },
+            "data": {
+                "expired_at": TIMESTAMP
+            }
+        }),
+    );
+    push_input_udt_cell(
+        &mut template,
+        udt_amount,
+        json!({
+            "owner_lock_args": OWNER,
+            "manager_lock_args": OWNER
+        }),
+    );
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER,
+            },
+            "data": {
+                "expired_at": TIMESTAMP + 31_536_000,
+            }
+        }),
+    );
+
+    template
+}
+
+#[test]
+fn test_account_renew_paid_in_udt() {
+    let mut template = before_each_paid_in_udt(10_000_000);
+
+    // outputs
+    // The renewal fee for 1 year is 5 USD, so it is 5_000_000 in the UDT with 6 decimals.
+    push_output_udt_cell(
+        &mut template,
+        5_000_000,
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": DAS_WALLET_LOCK_ARGS
+        }),
+    );
+    push_output_udt_cell(
+        &mut template,
+        5_000_000,
+        json!({
+            "owner_lock_args": OWNER,
+            "manager_lock_args": OWNER
+        }),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_account_renew_paid_in_udt_less_than_one_year() {
+    let mut template = before_each_paid_in_udt(10_000_000);
+
+    // outputs
+    // Simulate paying less UDT than the renewal fee for 1 year.
+    push_output_udt_cell(
+        &mut template,
+        5_000_000 - 1,
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": DAS_WALLET_LOCK_ARGS
+        }),
+    );
+    push_output_udt_cell(
+        &mut template,
+        5_000_000 + 1,
+        json!({
+            "owner_lock_args": OWNER,
+            "manager_lock_args": OWNER
+        }),
+    );
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
+    )
+}
+
+#[test]
+fn challenge_account_renew_paid_in_udt_change_amount() {
+    let mut template = before_each_paid_in_udt(10_000_000);
+
+    // outputs
+    push_output_udt_cell(
+        &mut template,
+        5_000_000,
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": DAS_WALLET_LOCK_ARGS
+        }),
+    );
+    // Simulate the sender getting less UDT change than expected.
+    push_output_udt_cell(
+        &mut template,
+        5_000_000 - 1,
+        json!({
+            "owner_lock_args": OWNER,
+            "manager_lock_args": OWNER
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::ChangeError)
+}
+
+fn before_each_paid_in_dpoint(dpoint: u64) -> TemplateGenerator {
+    let mut template = init_for_renew("renew_account", None);
+    template.push_contract_cell("dpoint-cell-type", ContractType::Contract);
+    template.push_config_cell(DataType::ConfigCellDPoint, Source::CellDep);
+
+    // inputs
+    push_input_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER
+            },
+            "data": {
+                "expired_at": TIMESTAMP
+            }
+        }),
+    );
+    push_input_dpoint_cell(&mut template, dpoint, OWNER);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": OWNER,
+            },
+            "data": {
+                "expired_at": TIMESTAMP + 31_536_000,
+            }
+        }),
+    );
+
+    template
+}
+
+#[test]
+fn test_account_renew_paid_in_dpoint() {
+    let mut template = before_each_paid_in_dpoint(10 * USD_1);
+
+    // outputs
+    // The renewal fee for 1 year is 5 USD, and DPoint is pegged to USD, so it is 5 USD in DPoint.
+    push_output_dpoint_cell(&mut template, 5 * USD_1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell(&mut template, 5 * USD_1, OWNER);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_account_renew_paid_in_dpoint_less_than_one_year() {
+    let mut template = before_each_paid_in_dpoint(10 * USD_1);
+
+    // outputs
+    // Simulate paying less DPoint than the renewal fee for 1 year.
+    push_output_dpoint_cell(&mut template, 5 * USD_1 - 1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell(&mut template, 5 * USD_1 + 1, OWNER);
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear,
+    )
+}
@@ -1,4 +1,4 @@
-use das_types::constants::AccountStatus;
+use das_types::constants::{AccountStatus, DataType, Source};
 use serde_json::{json, Value};
 
 use super::common::*;
@@ -10,7 +10,7 @@ use crate::util::template_generator::TemplateGenerator;
 use crate::util::template_parser::*;
 use crate::util::{self};
 
-pub fn push_input_account_cell_with_multi_sign(template: &mut TemplateGenerator, cell_partial: Value) {
+pub fn push_input_account_cell_with_multi_sign(template: &mut TemplateGenerator, cell_partial: Value, version: u32) {
     let mut cell = json!({
         "capacity": util::gen_account_cell_capacity(5),
         "lock": {
@@ -36,15 +36,30 @@ pub fn push_input_account_cell_with_multi_sign(template: &mut TemplateGenerator,
     });
     util::merge_json(&mut cell, cell_partial);
 
-    template.push_input(cell, None, Some(2));
+    template.push_input(cell, None, Some(version));
     template.push_multi_sign_witness(0, 3, 5, "0x567419c40d0f2c3566e7630ee32697560fa97a7b543d8ec90d784f60cf920e76a359ae83839a5e7a14dd22136ce74aee2a007c71e5440143dab7b326619b019a75910e04d5f215ace571e5600d48b6766d6a5e1df00e2cf82dd4dcfbba444a94119ae2de");
 }
 
-fn before_each() -> TemplateGenerator {
+fn init_for_unlock() -> TemplateGenerator {
     let mut template = init("unlock_account_for_cross_chain", Some("0x00"));
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
+
+    template
+}
+
+fn push_attestation(template: &mut TemplateGenerator, owner_lock_args: &str) {
+    template.push_cross_chain_unlock_attestation(json!({
+        "account": ACCOUNT_1,
+        "owner_lock_args": owner_lock_args,
+        "relayer_indexes": [0, 2]
+    }));
+}
+
+fn before_each() -> TemplateGenerator {
+    let mut template = init_for_unlock();
 
     // inputs
-    push_input_account_cell_with_multi_sign(&mut template, json!({}));
+    push_input_account_cell_with_multi_sign(&mut template, json!({}), 2);
 
     template
 }
@@ -67,6 +82,8 @@ fn test_account_unlock_account_for_cross_chain_keep_owner() {
         }),
     );
 
+    push_attestation(&mut template, SENDER);
+
     test_tx(template.as_json())
 }
 
@@ -88,12 +105,14 @@ fn test_account_unlock_account_for_cross_chain_change_owner() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     test_tx(template.as_json())
 }
 
 #[test]
 fn test_account_unlock_account_for_cross_chain_change_manager() {
-    let mut template = init("unlock_account_for_cross_chain", Some("0x00"));
+    let mut template = init_for_unlock();
 
     // inputs
     push_input_account_cell_with_multi_sign(
@@ -104,6 +123,7 @@ fn test_account_unlock_account_for_cross_chain_change_manager() {
                 "manager_lock_args": MANAGER
             },
         }),
+        2,
     );
 
     // outputs
@@ -120,12 +140,14 @@ fn test_account_unlock_account_for_cross_chain_change_manager() {
         }),
     );
 
+    push_attestation(&mut template, SENDER);
+
     test_tx(template.as_json())
 }
 
 #[test]
 fn challenge_account_unlock_account_for_cross_chain_owner_and_manager_not_match() {
-    let mut template = init("unlock_account_for_cross_chain", Some("0x00"));
+    let mut template = init_for_unlock();
 
     // inputs
     push_input_account_cell_with_multi_sign(
@@ -136,6 +158,7 @@ fn challenge_account_unlock_account_for_cross_chain_owner_and_manager_not_match(
                 "manager_lock_args": SENDER
             },
         }),
+        2,
     );
 
     // outputs
@@ -152,12 +175,14 @@ fn challenge_account_unlock_account_for_cross_chain_owner_and_manager_not_match(
         }),
     );
 
+    push_attestation(&mut template, SENDER);
+
     challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockError);
 }
 
 #[test]
 fn challenge_account_unlock_account_for_cross_chain_account_multiple_cells() {
-    let mut template = init("unlock_account_for_cross_chain", Some("0x00"));
+    let mut template = init_for_unlock();
 
     // Simulate unlocking multiple AccountCells at one time.
     // inputs
@@ -212,6 +237,8 @@ fn challenge_account_unlock_account_for_cross_chain_account_multiple_cells() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(template.as_json(), ErrorCode::InvalidTransactionStructure)
 }
 
@@ -237,6 +264,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_data_account() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellDataNotConsistent)
 }
 
@@ -262,6 +291,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_data_next() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellDataNotConsistent)
 }
 
@@ -287,6 +318,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_data_expired_at() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellDataNotConsistent)
 }
 
@@ -310,6 +343,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_account() {
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(
         template.as_json(),
         AccountCellErrorCode::AccountCellProtectFieldIsModified,
@@ -336,6 +371,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_registered_at
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(
         template.as_json(),
         AccountCellErrorCode::AccountCellProtectFieldIsModified,
@@ -362,6 +399,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_last_transfer
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(
         template.as_json(),
         AccountCellErrorCode::AccountCellProtectFieldIsModified,
@@ -388,6 +427,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_last_edit_man
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(
         template.as_json(),
         AccountCellErrorCode::AccountCellProtectFieldIsModified,
@@ -414,6 +455,8 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_last_edit_rec
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
     challenge_tx(
         template.as_json(),
         AccountCellErrorCode::AccountCellProtectFieldIsModified,
@@ -439,5 +482,267 @@ fn challenge_account_unlock_account_for_cross_chain_modify_witness_status_error(
         }),
     );
 
+    push_attestation(&mut template, RECEIVER);
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockError)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_without_attestation() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    // Simulate the CrossChainUnlockAttestation witness is missing.
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockAttestationIsRequired)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_owner_not_attested() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    // Simulate the owner in outputs is not the one attested by relayers.
+    push_attestation(&mut template, SENDER);
+
     challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockError)
 }
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_attestation_account_mismatch() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    template.push_cross_chain_unlock_attestation(json!({
+        // Simulate the attestation is for another account.
+        "account": ACCOUNT_2,
+        "owner_lock_args": RECEIVER,
+        "relayer_indexes": [0, 1]
+    }));
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockAttestationInvalid)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_duplicate_relayers() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    template.push_cross_chain_unlock_attestation(json!({
+        "account": ACCOUNT_1,
+        "owner_lock_args": RECEIVER,
+        // Simulate the same relayer signs twice.
+        "relayer_indexes": [1, 1]
+    }));
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockAttestationInvalid)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_threshold_not_reached() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    template.push_cross_chain_unlock_attestation(json!({
+        "account": ACCOUNT_1,
+        "owner_lock_args": RECEIVER,
+        // Simulate only one relayer signs while the threshold is 2.
+        "relayer_indexes": [2]
+    }));
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockThresholdNotReached)
+}
+
+fn before_each_locked_to_chain(coin_type: u64, chain_id: u64) -> TemplateGenerator {
+    let mut template = init_for_unlock();
+
+    // inputs
+    push_input_account_cell_with_multi_sign(
+        &mut template,
+        json!({
+            "witness": {
+                "cross_chain": {
+                    "checked": 1,
+                    "coin_type": coin_type,
+                    "chain_id": chain_id
+                }
+            }
+        }),
+        5,
+    );
+
+    template
+}
+
+#[test]
+fn test_account_unlock_account_for_cross_chain_from_locked_chain() {
+    let mut template = before_each_locked_to_chain(60, 1);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    push_attestation(&mut template, RECEIVER);
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_from_other_chain() {
+    // Simulate the AccountCell is locked to another chain while the relayers attest it comes back from Ethereum.
+    let mut template = before_each_locked_to_chain(60, 56);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    push_attestation(&mut template, RECEIVER);
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainUnlockAttestationInvalid)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_from_unsupported_chain() {
+    let mut template = before_each_locked_to_chain(60, 1);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": RECEIVER,
+                "manager_lock_args": RECEIVER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+
+    template.push_cross_chain_unlock_attestation(json!({
+        "account": ACCOUNT_1,
+        "owner_lock_args": RECEIVER,
+        // Simulate the relayers attest a chain which is not listed in ConfigCellCrossChain.chains.
+        "coin_type": 60,
+        "chain_id": 2,
+        "relayer_indexes": [0, 2]
+    }));
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainNotSupported)
+}
+
+#[test]
+fn challenge_account_unlock_account_for_cross_chain_keep_cross_chain() {
+    let mut template = before_each_locked_to_chain(60, 1);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "lock": {
+                "owner_lock_args": SENDER,
+                "manager_lock_args": SENDER
+            },
+            "witness": {
+                "status": (AccountStatus::Normal as u8),
+                // Simulate keeping the chain after the AccountCell is unlocked.
+                "cross_chain": {
+                    "checked": 1,
+                    "coin_type": 60,
+                    "chain_id": 1
+                }
+            }
+        }),
+    );
+
+    push_attestation(&mut template, SENDER);
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellProtectFieldIsModified,
+    )
+}
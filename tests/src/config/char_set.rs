@@ -0,0 +1,52 @@
+use das_types::char_set::{self, CharSetData, CharSetDecodingError};
+
+const CHARS: [&str; 6] = ["b", "a", "ฆี่", "大", "✨", "a"];
+
+#[test]
+fn test_char_set_legacy_encoding() {
+    let data = CharSetData::from_slice(&char_set::encode_legacy(true, &CHARS)).unwrap();
+
+    assert!(data.is_global());
+    assert!(!data.is_indexed());
+    assert_eq!(
+        data.iter().collect::<Vec<_>>(),
+        CHARS.iter().map(|char| char.as_bytes()).collect::<Vec<_>>()
+    );
+    for char in CHARS {
+        assert!(data.contains(char.as_bytes()), "The char {} should be found.", char);
+    }
+    assert!(!data.contains("c".as_bytes()));
+    assert!(!data.contains("ฆี".as_bytes()));
+}
+
+#[test]
+fn test_char_set_indexed_encoding() {
+    let data = CharSetData::from_slice(&char_set::encode_indexed(false, &CHARS)).unwrap();
+
+    assert!(!data.is_global());
+    assert!(data.is_indexed());
+
+    let mut expected = CHARS.iter().map(|char| char.as_bytes()).collect::<Vec<_>>();
+    expected.sort();
+    expected.dedup();
+    assert_eq!(data.iter().collect::<Vec<_>>(), expected);
+
+    for char in CHARS {
+        assert!(data.contains(char.as_bytes()), "The char {} should be found.", char);
+    }
+    assert!(!data.contains("c".as_bytes()));
+    assert!(!data.contains("ฆี".as_bytes()));
+    assert!(!data.contains(&[]));
+}
+
+#[test]
+fn test_char_set_decoding_error() {
+    assert_eq!(CharSetData::from_slice(&[]), Err(CharSetDecodingError::DataIsEmpty));
+
+    let mut raw = char_set::encode_indexed(false, &CHARS);
+    raw.truncate(1 + 4 + 4);
+    assert_eq!(
+        CharSetData::from_slice(&raw),
+        Err(CharSetDecodingError::IndexIsTruncated)
+    );
+}
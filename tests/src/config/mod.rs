@@ -1,3 +1,6 @@
+mod char_set;
+mod record_key_namespace;
+
 use ckb_hash::blake2b_256;
 use das_types::constants::*;
 use das_types::packed::*;
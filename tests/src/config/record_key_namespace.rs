@@ -0,0 +1,63 @@
+use das_types::constants::RecordValueValidator;
+use das_types::record_key_namespace::{self, RecordKeyNamespace, RecordKeyNamespaceDecodingError};
+
+const KEYS: [&str; 3] = ["address.eth", "dweb.ipfs", "profile.twitter"];
+
+#[test]
+fn test_record_key_namespace_legacy_encoding() {
+    let namespace = RecordKeyNamespace::from_slice(&record_key_namespace::encode_legacy(&KEYS)).unwrap();
+
+    assert!(!namespace.is_v2());
+    for key in KEYS {
+        assert_eq!(namespace.get(key.as_bytes()), Some(RecordValueValidator::Any));
+    }
+    assert!(!namespace.contains(b"address.btc"));
+    assert!(!namespace.contains(b"address"));
+}
+
+#[test]
+fn test_record_key_namespace_v2_encoding() {
+    let keys = [
+        (KEYS[0], RecordValueValidator::EvmAddress),
+        (KEYS[1], RecordValueValidator::ContentHash),
+        (KEYS[2], RecordValueValidator::Any),
+    ];
+    let namespace = RecordKeyNamespace::from_slice(&record_key_namespace::encode_v2(&keys)).unwrap();
+
+    assert!(namespace.is_v2());
+    assert_eq!(
+        namespace.iter().collect::<Vec<_>>(),
+        keys.iter()
+            .map(|(key, validator)| (key.as_bytes(), *validator))
+            .collect::<Vec<_>>()
+    );
+    assert!(!namespace.contains(b"address.btc"));
+}
+
+#[test]
+fn test_record_key_namespace_decoding_error() {
+    assert_eq!(
+        RecordKeyNamespace::from_slice(&[]),
+        Err(RecordKeyNamespaceDecodingError::DataIsEmpty)
+    );
+
+    let mut raw = record_key_namespace::encode_legacy(&KEYS);
+    raw.pop();
+    assert_eq!(
+        RecordKeyNamespace::from_slice(&raw),
+        Err(RecordKeyNamespaceDecodingError::KeyIsTruncated)
+    );
+
+    let mut raw = record_key_namespace::encode_v2(&[(KEYS[0], RecordValueValidator::Url)]);
+    raw.pop();
+    assert_eq!(
+        RecordKeyNamespace::from_slice(&raw),
+        Err(RecordKeyNamespaceDecodingError::ValidatorIsMissing)
+    );
+
+    raw.push(0xff);
+    assert_eq!(
+        RecordKeyNamespace::from_slice(&raw),
+        Err(RecordKeyNamespaceDecodingError::ValidatorIsUndefined(0xff))
+    );
+}
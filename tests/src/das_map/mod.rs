@@ -0,0 +1,20 @@
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+fn init(name: &str) -> TemplateGenerator {
+    let mut template = TemplateGenerator::new("unit_test", Some(name.as_bytes().to_vec()));
+
+    template.push_contract_cell("always_success", ContractType::DeployedContract);
+    template.push_contract_cell("test-env", ContractType::Contract);
+
+    push_input_test_env_cell(&mut template);
+
+    template
+}
+
+#[test]
+fn perf_das_map_vs_vec_map() {
+    let template = init("perf_das_map_vs_vec_map");
+    perf_tx(template.as_json());
+}
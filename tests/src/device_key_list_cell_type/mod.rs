@@ -4,7 +4,7 @@ use ckb_types::core::ScriptHashType;
 use ckb_types::packed::{Byte32, Bytes, Script};
 use ckb_types::prelude::{Builder, Entity};
 use das_types::constants::{DataType, Source, WITNESS_HEADER};
-use das_types::packed::{Data, DataEntity, DataEntityOpt, DeviceKey, DeviceKeyListCellData};
+use das_types::packed::{Byte10, Data, DataEntity, DataEntityOpt, DeviceKey, DeviceKeyListCellData};
 use hex::{FromHex, ToHex};
 use serde_json::{json, Value};
 
@@ -13,6 +13,7 @@ use crate::util::template_generator::{ContractType, TemplateGenerator};
 
 mod create;
 mod destroy;
+mod recovery;
 mod update;
 
 #[derive(Debug, Clone)]
@@ -61,6 +62,10 @@ impl DeviceKeyListCell {
     }
 
     fn push(&self, template: &mut TemplateGenerator, source: Source) {
+        self.push_with_since(template, source, None)
+    }
+
+    fn push_with_since(&self, template: &mut TemplateGenerator, source: Source, since_opt: Option<u64>) {
         let cell = json!({
             "capacity": self.capacity,
             "type": {
@@ -76,7 +81,7 @@ impl DeviceKeyListCell {
             "tmp_type": "full"
         });
 
-        let index = template.push_cell_json(cell, source, None);
+        let index = template.push_cell_json(cell, source, since_opt);
 
         let data_entity_opt = DataEntityOpt::new_builder()
             .set(Some(
@@ -201,6 +206,21 @@ fn init(action_name: impl AsRef<str>) -> TemplateGenerator {
     template
 }
 
+/// Push the WebAuthn signature of the DeviceKeyListCell in inputs[0], the type script resolves the signing key from its
+/// pubkey_index, 255 means the key of the lock args and others are the indexes of the key list.
+fn push_webauthn_signature(template: &mut TemplateGenerator, pubkey_index: u8) {
+    let mut signature = vec![1, pubkey_index];
+    // The signature, pubkey and authenticator_data are not verified by the type script, so they are 1 byte placeholders.
+    for _ in 0..3 {
+        signature.extend([1, 0]);
+    }
+    // The client_data_json is prefixed with its length in 2 bytes.
+    signature.extend(1u16.to_le_bytes());
+    signature.push(0);
+
+    template.push_witness_args(Some(&signature), None, None);
+}
+
 fn name_to_code_hash(name: impl AsRef<str>) -> Byte32 {
     Byte32::from_slice(
         Vec::<u8>::from_hex(TYPE_ID_TABLE.get(name.as_ref()).unwrap().trim_start_matches("0x"))
@@ -209,3 +229,9 @@ fn name_to_code_hash(name: impl AsRef<str>) -> Byte32 {
     )
     .unwrap()
 }
+
+fn gen_device_key(cid: u8) -> DeviceKey {
+    DeviceKey::new_builder()
+        .cid(Byte10::new_builder().nth0(cid.into()).build())
+        .build()
+}
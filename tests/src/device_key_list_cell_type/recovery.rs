@@ -0,0 +1,302 @@
+use ckb_types::packed::Script;
+use ckb_types::prelude::{Builder, Entity};
+use das_types::constants::Source;
+use das_types::packed::{
+    DeviceKey, DeviceKeyExpiration, DeviceKeyExpirations, DeviceKeyList, DeviceKeyListCellData, DeviceKeyListOpt,
+    Uint64,
+};
+use device_key_list_cell_type::error::ErrorCode;
+
+use super::{
+    gen_device_key, init, name_to_code_hash, push_webauthn_signature, BalanceCell, BuildLockArg, BuildRefundLock,
+    DeviceKeyListCell,
+};
+use crate::util::constants::{OracleCellType, DAY_SEC, TIMESTAMP};
+use crate::util::since_util::SinceFlag;
+use crate::util::template_generator::{gen_since, TemplateGenerator};
+use crate::util::template_parser::{challenge_tx, test_tx};
+
+const RECOVERY_DELAY: u64 = 7 * DAY_SEC;
+
+fn to_das_script(script: &Script) -> das_types::packed::Script {
+    das_types::packed::Script::from_slice(script.as_slice()).unwrap()
+}
+
+/// Build the das-lock of the DeviceKeyListCell, both its owner and manager are the device key.
+fn gen_das_lock(key: &DeviceKey) -> Script {
+    key.build_lock_arg()
+        .build_refund_lock(name_to_code_hash("fake-das-lock"))
+}
+
+fn gen_key_list(
+    refund_lock: &Script,
+    keys: &[DeviceKey],
+    recovery_keys: Option<&[DeviceKey]>,
+) -> DeviceKeyListCellData {
+    DeviceKeyListCellData::new_builder()
+        .refund_lock(to_das_script(refund_lock))
+        .keys(DeviceKeyList::new_builder().extend(keys.iter().cloned()).build())
+        .recovery_keys(
+            DeviceKeyListOpt::new_builder()
+                .set(recovery_keys.map(|keys| DeviceKeyList::new_builder().extend(keys.iter().cloned()).build()))
+                .build(),
+        )
+        .build()
+}
+
+fn push_key_lists(
+    template: &mut TemplateGenerator,
+    lock: &Script,
+    input: DeviceKeyListCellData,
+    output: DeviceKeyListCellData,
+    since_opt: Option<u64>,
+) {
+    DeviceKeyListCell::new(10_000_000_000, lock.clone(), input).push_with_since(template, Source::Input, since_opt);
+    DeviceKeyListCell::new(9_999_995_000, lock.clone(), output).push(template, Source::Output);
+}
+
+/// Push the cells of the refund_lock which authorize the recovery, they should be pushed after the DeviceKeyListCells.
+fn push_refund_lock_cells(template: &mut TemplateGenerator, refund_lock: &Script) {
+    BalanceCell::new(10_000_000_000, refund_lock.clone()).push(template, Source::Input);
+    BalanceCell::new(9_999_995_000, refund_lock.clone()).push(template, Source::Output);
+}
+
+fn gen_recovery_since(delay: u64) -> Option<u64> {
+    gen_since(SinceFlag::Relative, SinceFlag::Timestamp, delay)
+}
+
+#[test]
+fn should_pass_on_propose_recovery() {
+    let mut template = init("propose_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], None),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2])),
+        None,
+    );
+    push_refund_lock_cells(&mut template, &refund_lock);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn should_fail_on_propose_recovery_without_refund_lock_cell() {
+    let mut template = init("propose_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate proposing a recovery without any cell of the refund_lock in inputs.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], None),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2])),
+        None,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryNotAuthorized);
+}
+
+#[test]
+fn should_fail_on_propose_recovery_with_other_lock_cell() {
+    let mut template = init("propose_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], None),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2.clone()])),
+        None,
+    );
+    // Simulate taking the capacity of the refund_lock to another lock.
+    BalanceCell::new(10_000_000_000, refund_lock).push(&mut template, Source::Input);
+    BalanceCell::new(9_999_995_000, key_2.build_default_refund_lock()).push(&mut template, Source::Output);
+
+    challenge_tx(template.as_json(), ErrorCode::InconsistentBalanceCellLocks);
+}
+
+#[test]
+fn should_fail_on_propose_recovery_changing_keys() {
+    let mut template = init("propose_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate replacing the keys directly when proposing a recovery.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1], None),
+        gen_key_list(&refund_lock, &[key_2.clone()], Some(&[key_2])),
+        None,
+    );
+    push_refund_lock_cells(&mut template, &refund_lock);
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryParamsInvalid);
+}
+
+#[test]
+fn should_pass_on_cancel_recovery() {
+    let mut template = init("cancel_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], Some(&[key_2])),
+        gen_key_list(&refund_lock, &[key_1], None),
+        None,
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn should_fail_on_cancel_recovery_signed_by_expired_key() {
+    let mut template = init("cancel_device_key_list_recovery");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+    let expirations = DeviceKeyExpirations::new_builder()
+        .push(
+            DeviceKeyExpiration::new_builder()
+                .key(key_2.clone())
+                .expired_at(Uint64::from(TIMESTAMP))
+                .build(),
+        )
+        .build();
+
+    // Simulate the expired key_2 cancelling the recovery.
+    push_webauthn_signature(&mut template, 1);
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone(), key_2.clone()], Some(&[key_3]))
+            .as_builder()
+            .expirations(expirations.clone())
+            .build(),
+        gen_key_list(&refund_lock, &[key_1, key_2], None)
+            .as_builder()
+            .expirations(expirations)
+            .build(),
+        None,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::SigningKeyExpired);
+}
+
+#[test]
+fn should_fail_on_cancel_recovery_changing_keys() {
+    let mut template = init("cancel_device_key_list_recovery");
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate updating the keys when cancelling a recovery.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], Some(&[key_2])),
+        gen_key_list(&refund_lock, &[key_1, key_3], None),
+        None,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryParamsInvalid);
+}
+
+#[test]
+fn should_fail_on_update_recovery_keys() {
+    let mut template = init("update_device_key_list");
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate dropping the recovery with update_device_key_list.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1.clone()], Some(&[key_2])),
+        gen_key_list(&refund_lock, &[key_1, key_3], None),
+        None,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryParamsInvalid);
+}
+
+#[test]
+fn should_pass_on_confirm_recovery() {
+    let mut template = init("confirm_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2.clone()])),
+        gen_key_list(&refund_lock, &[key_2], None),
+        gen_recovery_since(RECOVERY_DELAY),
+    );
+    push_refund_lock_cells(&mut template, &refund_lock);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn should_fail_on_confirm_recovery_too_early() {
+    let mut template = init("confirm_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate confirming the recovery before the delay is over.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2.clone()])),
+        gen_key_list(&refund_lock, &[key_2], None),
+        gen_recovery_since(RECOVERY_DELAY - 1),
+    );
+    push_refund_lock_cells(&mut template, &refund_lock);
+
+    challenge_tx(template.as_json(), ErrorCode::RecoverySinceMismatch);
+}
+
+#[test]
+fn should_fail_on_confirm_recovery_with_other_keys() {
+    let mut template = init("confirm_device_key_list_recovery");
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate replacing the keys with the keys which are not proposed.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2])),
+        gen_key_list(&refund_lock, &[key_3], None),
+        gen_recovery_since(RECOVERY_DELAY),
+    );
+    push_refund_lock_cells(&mut template, &refund_lock);
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryParamsInvalid);
+}
+
+#[test]
+fn should_fail_on_confirm_recovery_without_refund_lock_cell() {
+    let mut template = init("confirm_device_key_list_recovery");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate confirming a recovery without any cell of the refund_lock in inputs.
+    push_key_lists(
+        &mut template,
+        &gen_das_lock(&key_1),
+        gen_key_list(&refund_lock, &[key_1], Some(&[key_2.clone()])),
+        gen_key_list(&refund_lock, &[key_2], None),
+        gen_recovery_since(RECOVERY_DELAY),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::RecoveryNotAuthorized);
+}
@@ -1,9 +1,15 @@
+use ckb_types::packed::Script;
 use ckb_types::prelude::{Builder, Entity};
 use das_types::constants::Source;
-use das_types::packed::{Byte10, DeviceKey, DeviceKeyList, DeviceKeyListCellData};
+use das_types::packed::{
+    Byte10, DeviceKey, DeviceKeyExpiration, DeviceKeyExpirations, DeviceKeyList, DeviceKeyListCellData,
+    DeviceKeyListCellDataV1, Uint64,
+};
 use device_key_list_cell_type::error::ErrorCode;
 
-use super::{init, BuildRefundLock, DeviceKeyListCell};
+use super::{gen_device_key, init, push_webauthn_signature, BuildRefundLock, DeviceKeyListCell};
+use crate::util::constants::{OracleCellType, DAY_SEC, TIMESTAMP};
+use crate::util::template_generator::TemplateGenerator;
 use crate::util::template_parser::{challenge_tx, test_tx};
 #[test]
 fn should_pass_on_normal_add() {
@@ -201,7 +207,7 @@ fn should_fail_on_inconsistent_lock() {
 }
 
 #[test]
-fn should_fail_on_multiple_add() {
+fn should_pass_on_multiple_add() {
     let mut template = init("update_device_key_list");
     let first_device_key = DeviceKey::new_builder().build();
     let second_device_key = DeviceKey::new_builder()
@@ -239,7 +245,7 @@ fn should_fail_on_multiple_add() {
     input_cell.push(&mut template, Source::Input);
     output_cell.push(&mut template, Source::Output);
 
-    challenge_tx(template.as_json(), ErrorCode::KeyListNumberIncorrect);
+    test_tx(template.as_json());
 }
 
 #[test]
@@ -279,7 +285,7 @@ fn should_fail_on_duplicated_keys() {
 }
 
 #[test]
-fn should_fail_on_wrong_order() {
+fn should_pass_on_inserting_at_head() {
     let mut template = init("update_device_key_list");
     let first_device_key = DeviceKey::new_builder().build();
     let second_device_key = DeviceKey::new_builder()
@@ -313,11 +319,11 @@ fn should_fail_on_wrong_order() {
     input_cell.push(&mut template, Source::Input);
     output_cell.push(&mut template, Source::Output);
 
-    challenge_tx(template.as_json(), ErrorCode::UpdateParamsInvalid);
+    test_tx(template.as_json());
 }
 
 #[test]
-fn should_fail_on_delete2_add1() {
+fn should_pass_on_delete2_add1() {
     let mut template = init("update_device_key_list");
     let device_key_1 = DeviceKey::new_builder().build();
     let device_key_2 = DeviceKey::new_builder()
@@ -368,5 +374,255 @@ fn should_fail_on_delete2_add1() {
     input_cell.push(&mut template, Source::Input);
     output_cell.push(&mut template, Source::Output);
 
+    test_tx(template.as_json());
+}
+
+fn gen_key_list(refund_lock: &Script, keys: &[DeviceKey], expirations: &[(DeviceKey, u64)]) -> DeviceKeyListCellData {
+    DeviceKeyListCellData::new_builder()
+        .refund_lock(das_types::packed::Script::from_slice(refund_lock.as_slice()).unwrap())
+        .keys(DeviceKeyList::new_builder().extend(keys.iter().cloned()).build())
+        .expirations(
+            DeviceKeyExpirations::new_builder()
+                .extend(expirations.iter().map(|(key, expired_at)| {
+                    DeviceKeyExpiration::new_builder()
+                        .key(key.clone())
+                        .expired_at(Uint64::from(*expired_at))
+                        .build()
+                }))
+                .build(),
+        )
+        .build()
+}
+
+fn push_key_lists(
+    template: &mut TemplateGenerator,
+    lock: &Script,
+    input: DeviceKeyListCellData,
+    output: DeviceKeyListCellData,
+) {
+    DeviceKeyListCell::new(10_000_000_000, lock.clone(), input).push(template, Source::Input);
+    DeviceKeyListCell::new(9_999_995_000, lock.clone(), output).push(template, Source::Output);
+}
+
+#[test]
+fn should_fail_on_too_many_keys() {
+    let mut template = init("update_device_key_list");
+    let keys = (1..=11).map(gen_device_key).collect::<Vec<_>>();
+    let refund_lock = keys[0].build_default_refund_lock();
+
+    // Simulate adding keys more than the limit of the key list.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &keys[..1], &[]),
+        gen_key_list(&refund_lock, &keys, &[]),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::KeyListNumberIncorrect);
+}
+
+#[test]
+fn should_fail_on_only_reordering() {
+    let mut template = init("update_device_key_list");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate updating the key list without any keys or expirations changed.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone(), key_2.clone()], &[]),
+        gen_key_list(&refund_lock, &[key_2, key_1], &[]),
+    );
+
     challenge_tx(template.as_json(), ErrorCode::UpdateParamsInvalid);
 }
+
+#[test]
+fn should_pass_on_replacing_key_with_expiration() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Replace key_2 with key_3 which expires in 1 day.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone(), key_2], &[]),
+        gen_key_list(&refund_lock, &[key_1, key_3.clone()], &[(key_3, TIMESTAMP + DAY_SEC)]),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn should_pass_on_keeping_expired_key() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // The expiration of key_2 is not changed, so it is allowed even if it is expired.
+    push_webauthn_signature(&mut template, 0);
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(
+            &refund_lock,
+            &[key_1.clone(), key_2.clone()],
+            &[(key_2.clone(), TIMESTAMP)],
+        ),
+        gen_key_list(&refund_lock, &[key_1, key_2.clone(), key_3], &[(key_2, TIMESTAMP)]),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn should_fail_on_signed_by_expired_key() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate the expired key_2 signing the transaction.
+    push_webauthn_signature(&mut template, 1);
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(
+            &refund_lock,
+            &[key_1.clone(), key_2.clone()],
+            &[(key_2.clone(), TIMESTAMP)],
+        ),
+        gen_key_list(&refund_lock, &[key_1, key_2.clone(), key_3], &[(key_2, TIMESTAMP)]),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::SigningKeyExpired);
+}
+
+#[test]
+fn should_fail_on_signed_by_unknown_key() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate the pubkey_index of the signature pointing outside of the key list.
+    push_webauthn_signature(&mut template, 2);
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(
+            &refund_lock,
+            &[key_1.clone(), key_2.clone()],
+            &[(key_2.clone(), TIMESTAMP + DAY_SEC)],
+        ),
+        gen_key_list(
+            &refund_lock,
+            &[key_1, key_2.clone(), key_3],
+            &[(key_2, TIMESTAMP + DAY_SEC)],
+        ),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::WitnessArgsInvalid);
+}
+
+#[test]
+fn should_fail_on_expiration_in_the_past() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate adding a key which is already expired.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone()], &[]),
+        gen_key_list(&refund_lock, &[key_1, key_2.clone()], &[(key_2, TIMESTAMP)]),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::KeyExpirationInvalid);
+}
+
+#[test]
+fn should_fail_on_expiration_of_unknown_key() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2, key_3) = (gen_device_key(1), gen_device_key(2), gen_device_key(3));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate adding an expiration for a key which is not in the key list.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone()], &[]),
+        gen_key_list(&refund_lock, &[key_1, key_2], &[(key_3, TIMESTAMP + DAY_SEC)]),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::KeyExpirationInvalid);
+}
+
+#[test]
+fn should_fail_on_duplicated_expirations() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate adding multiple expirations for the same key.
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone()], &[]),
+        gen_key_list(
+            &refund_lock,
+            &[key_1, key_2.clone()],
+            &[(key_2.clone(), TIMESTAMP + DAY_SEC), (key_2, TIMESTAMP + 2 * DAY_SEC)],
+        ),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::DuplicatedKeys);
+}
+
+#[test]
+fn should_fail_on_all_keys_expired() {
+    let mut template = init("update_device_key_list");
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // Simulate removing the only key which is not expired.
+    push_webauthn_signature(&mut template, 1);
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        gen_key_list(&refund_lock, &[key_1.clone(), key_2], &[(key_1.clone(), TIMESTAMP)]),
+        gen_key_list(&refund_lock, &[key_1.clone()], &[(key_1, TIMESTAMP)]),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::AllKeysExpired);
+}
+
+#[test]
+fn should_pass_on_updating_key_list_v1() {
+    let mut template = init("update_device_key_list");
+    let (key_1, key_2) = (gen_device_key(1), gen_device_key(2));
+    let refund_lock = key_1.build_default_refund_lock();
+
+    // The key list created before expirations are supported has no expirations field.
+    let key_list_v1 = DeviceKeyListCellDataV1::new_builder()
+        .refund_lock(das_types::packed::Script::from_slice(refund_lock.as_slice()).unwrap())
+        .keys(DeviceKeyList::new_builder().push(key_1.clone()).build())
+        .build();
+    push_key_lists(
+        &mut template,
+        &refund_lock,
+        DeviceKeyListCellData::new_unchecked(key_list_v1.as_bytes()),
+        gen_key_list(&refund_lock, &[key_1, key_2], &[]),
+    );
+
+    test_tx(template.as_json());
+}
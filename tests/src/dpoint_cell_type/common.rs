@@ -15,6 +15,10 @@ pub fn init(args: Value) -> TemplateGenerator {
     template.push_contract_cell("balance-cell-type", ContractType::Contract);
     template.push_contract_cell("dpoint-cell-type", ContractType::Contract);
 
+    if let Some(min_cells) = args["oracle_min_cells"].as_u64() {
+        template.oracle_min_cells = min_cells as u8;
+    }
+
     template.push_config_cell(DataType::ConfigCellMain, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellDPoint, Source::CellDep);
 
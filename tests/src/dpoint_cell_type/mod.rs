@@ -2,4 +2,5 @@ mod common;
 
 mod burn_dp;
 mod mint_dp;
+mod schedule;
 mod transfer_dp;
@@ -0,0 +1,254 @@
+use dpoint_cell_type::error::ErrorCode;
+use serde_json::json;
+
+use super::common::*;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+fn before_each(action: &str) -> TemplateGenerator {
+    let mut template = init(json!({ "action": action }));
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+
+    template
+}
+
+#[test]
+fn test_dpoint_transfer_dp_with_expiry() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({ "expired_at": TIMESTAMP + DAY_SEC }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 50 * USD_1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell_with_schedule(
+        &mut template,
+        50 * USD_1,
+        OWNER,
+        json!({ "expired_at": TIMESTAMP + DAY_SEC }),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_dpoint_transfer_dp_expired() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    // Simulate transferring the expired DPoint.
+    push_input_dpoint_cell_with_schedule(&mut template, 100 * USD_1, OWNER, json!({ "expired_at": TIMESTAMP }));
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 100 * USD_1, DP_TRANSFER_WHITELIST_1);
+
+    challenge_tx(template.as_json(), ErrorCode::DPointIsExpired);
+}
+
+#[test]
+fn challenge_dpoint_transfer_dp_extend_expiry() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({ "expired_at": TIMESTAMP + DAY_SEC }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 50 * USD_1, DP_TRANSFER_WHITELIST_1);
+    // Simulate removing the expiry of the change.
+    push_output_dpoint_cell(&mut template, 50 * USD_1, OWNER);
+
+    challenge_tx(template.as_json(), ErrorCode::DPointExpiryCanNotBeExtended);
+}
+
+#[test]
+fn test_dpoint_transfer_dp_vested() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    // Half of the DPoint is vested at TIMESTAMP.
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP - 50 * DAY_SEC,
+            "vesting_end_at": TIMESTAMP + 50 * DAY_SEC
+        }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 50 * USD_1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell_with_schedule(
+        &mut template,
+        50 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP,
+            "vesting_end_at": TIMESTAMP + 50 * DAY_SEC
+        }),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_dpoint_transfer_dp_locked() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP - 50 * DAY_SEC,
+            "vesting_end_at": TIMESTAMP + 50 * DAY_SEC
+        }),
+    );
+
+    // outputs
+    // Simulate spending more DPoint than vested.
+    push_output_dpoint_cell(&mut template, 60 * USD_1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell_with_schedule(
+        &mut template,
+        40 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP,
+            "vesting_end_at": TIMESTAMP + 50 * DAY_SEC
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::DPointIsLocked);
+}
+
+#[test]
+fn challenge_dpoint_transfer_dp_shorten_vesting_period() {
+    let mut template = before_each("transfer_dp");
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP - 50 * DAY_SEC,
+            "vesting_end_at": TIMESTAMP + 50 * DAY_SEC
+        }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 50 * USD_1, DP_TRANSFER_WHITELIST_1);
+    // Simulate releasing the locked DPoint faster than the original vesting period.
+    push_output_dpoint_cell_with_schedule(
+        &mut template,
+        50 * USD_1,
+        OWNER,
+        json!({
+            "vesting_start_at": TIMESTAMP,
+            "vesting_end_at": TIMESTAMP + DAY_SEC
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::DPointIsLocked);
+}
+
+#[test]
+fn test_dpoint_transfer_dp_with_expiry_by_median_of_time_cells() {
+    // The DPoint paid for registration or renewal is transferred with multiple TimeCells in cell_deps.
+    let mut template = init(json!({ "action": "transfer_dp", "oracle_min_cells": 3 }));
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP - 60);
+    template.push_oracle_cell(2, OracleCellType::Time, TIMESTAMP);
+    template.push_oracle_cell(3, OracleCellType::Time, TIMESTAMP + 60);
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({ "expired_at": TIMESTAMP + DAY_SEC }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 100 * USD_1, DP_TRANSFER_WHITELIST_1);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_dpoint_transfer_dp_expired_by_median_of_time_cells() {
+    let mut template = init(json!({ "action": "transfer_dp", "oracle_min_cells": 3 }));
+    // Simulate the DPoint is not expired comparing to the first TimeCell, but it is expired comparing to the median.
+    template.push_oracle_cell(1, OracleCellType::Time, TIMESTAMP);
+    template.push_oracle_cell(2, OracleCellType::Time, TIMESTAMP + 60);
+    template.push_oracle_cell(3, OracleCellType::Time, TIMESTAMP + 60);
+
+    // inputs
+    push_input_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        OWNER,
+        json!({ "expired_at": TIMESTAMP + 60 }),
+    );
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 100 * USD_1, DP_TRANSFER_WHITELIST_1);
+
+    challenge_tx(template.as_json(), ErrorCode::DPointIsExpired);
+}
+
+#[test]
+fn test_dpoint_burn_dp_expired() {
+    let mut template = before_each("burn_dp");
+
+    // inputs
+    // The expired DPoint can be recycled by the whitelist.
+    push_input_dpoint_cell_with_schedule(&mut template, 100 * USD_1, OWNER, json!({ "expired_at": TIMESTAMP }));
+    push_input_dpoint_cell(&mut template, 100 * USD_1, OWNER);
+    push_input_balance_cell(&mut template, 0, DP_RECYCLE_WHITELIST_1);
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 100 * USD_1, OWNER);
+    push_output_balance_cell(
+        &mut template,
+        DPOINT_BASIC_CAPACITY + DPOINT_PREPARED_FEE_CAPACITY,
+        DP_RECYCLE_WHITELIST_1,
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_dpoint_mint_dp_invalid_vesting_period() {
+    let mut template = init(json!({ "action": "mint_dp" }));
+
+    // inputs
+    push_input_normal_cell(&mut template, 0, SUPER_LOCK_ARGS);
+
+    // outputs
+    // Simulate minting DPoint with a vesting period which ends before it starts.
+    push_output_dpoint_cell_with_schedule(
+        &mut template,
+        100 * USD_1,
+        DP_TRANSFER_WHITELIST_1,
+        json!({
+            "vesting_start_at": TIMESTAMP,
+            "vesting_end_at": TIMESTAMP
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::InitialDataError);
+}
@@ -160,6 +160,20 @@ fn challenge_dpoint_transfer_dp_multi_user_3() {
     challenge_tx(template.as_json(), ErrorCode::OnlyOneUserIsAllowed);
 }
 
+#[test]
+fn challenge_dpoint_transfer_dp_payment_from_whitelist() {
+    // Simulate transferring DP from the whitelist to a user in an action of payments.
+    let mut template = init(json!({ "action": "renew_account" }));
+
+    // inputs
+    push_input_dpoint_cell(&mut template, 100 * USD_1, DP_TRANSFER_WHITELIST_1);
+
+    // outputs
+    push_output_dpoint_cell(&mut template, 100 * USD_1, OWNER);
+
+    challenge_tx(template.as_json(), ErrorCode::PaymentShouldBeTransferredToWhitelist);
+}
+
 #[test]
 fn challenge_dpoint_transfer_dp_with_burn_dp() {
     let mut template = before_each();
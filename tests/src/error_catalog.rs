@@ -0,0 +1,145 @@
+#[test]
+fn test_error_catalog_error_sets_are_valid() {
+    for set in error_catalog::ERROR_SETS {
+        let mut codes = std::collections::HashSet::new();
+        let mut names = std::collections::HashSet::new();
+        for info in set.codes {
+            assert!(
+                codes.insert(info.code),
+                "{} has duplicate code {}.",
+                set.path,
+                info.code
+            );
+            assert!(
+                names.insert(info.name),
+                "{} has duplicate name {}.",
+                set.path,
+                info.name
+            );
+        }
+    }
+
+    for contract in error_catalog::CONTRACTS {
+        for path in contract.error_sets {
+            assert!(
+                error_catalog::error_set(path).is_some(),
+                "The error set {} of {} is not in the catalog.",
+                path,
+                contract.name
+            );
+        }
+    }
+
+    for (code, names) in error_catalog::KNOWN_COLLISIONS {
+        assert!(
+            error_catalog::CUSTOMIZED_CODES.contains(code),
+            "The known collision {} is not a customized code.",
+            code
+        );
+        for name in names.iter() {
+            let (path, variant) = name.rsplit_once("::").unwrap();
+            let info = error_catalog::error_set(path).and_then(|set| set.get(*code));
+            assert!(
+                info.map(|info| info.name == variant).unwrap_or(false),
+                "The known collision {} is not the code {} in the catalog.",
+                name,
+                code
+            );
+        }
+    }
+}
+
+#[test]
+fn test_error_catalog_no_collision_in_contracts() {
+    let collisions = error_catalog::CONTRACTS
+        .iter()
+        .flat_map(error_catalog::collisions)
+        .collect::<Vec<_>>();
+
+    assert!(
+        collisions.is_empty(),
+        "Codes should have the same meaning in one contract unless they are customized: {:#?}",
+        collisions
+    );
+}
+
+#[test]
+fn test_error_catalog_collision_in_customized_codes() {
+    // Simulate a contract which returns the errors of dpoint-cell-type and das-core, their overlaps are not known.
+    let contract = error_catalog::Contract {
+        name: "test-cell-type",
+        error_sets: &["dpoint_cell_type::error::ErrorCode", "das_core::error::ErrorCode"],
+    };
+    let collisions = error_catalog::collisions(&contract);
+
+    let collision = collisions
+        .iter()
+        .find(|item| item.code == 50)
+        .expect("The code 50 should be a collision.");
+    assert!(collision
+        .names
+        .contains(&String::from("dpoint_cell_type::error::ErrorCode::SuperLockIsRequired")));
+    assert!(collision
+        .names
+        .contains(&String::from("das_core::error::ErrorCode::WitnessEmpty")));
+}
+
+#[test]
+fn test_error_catalog_consistent_with_contracts() {
+    use device_key_list_cell_type::error::ErrorCode as DeviceKeyListErrorCode;
+    use dpoint_cell_type::error::ErrorCode as DPointErrorCode;
+
+    let cases: &[(&str, i8, &str)] = &[
+        (
+            "dpoint-cell-type",
+            DPointErrorCode::InvalidTransactionStructure as i8,
+            "InvalidTransactionStructure",
+        ),
+        (
+            "dpoint-cell-type",
+            DPointErrorCode::ActionNotSupported as i8,
+            "ActionNotSupported",
+        ),
+        (
+            "dpoint-cell-type",
+            DPointErrorCode::CapacityRecycleError as i8,
+            "CapacityRecycleError",
+        ),
+        (
+            "device-key-list-cell-type",
+            DeviceKeyListErrorCode::FoundKeyListInInput as i8,
+            "FoundKeyListInInput",
+        ),
+        (
+            "device-key-list-cell-type",
+            DeviceKeyListErrorCode::VerificationError as i8,
+            "VerificationError",
+        ),
+    ];
+
+    for (contract, code, name) in cases {
+        let resolved = error_catalog::resolve(contract, *code).expect("The contract should be in the catalog.");
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].info.name, *name);
+    }
+}
+
+#[test]
+fn test_error_catalog_resolve() {
+    // The contract's own error set should take priority.
+    let resolved = error_catalog::resolve("account-cell-type", 50).expect("The contract should be in the catalog.");
+    assert_eq!(resolved[0].error_set, "das_core::error::AccountCellErrorCode");
+    assert_eq!(resolved[0].info.name, "WitnessParsingError");
+    assert!(resolved.iter().any(|item| item.info.name == "WitnessEmpty"));
+
+    // The reserved errors should be listed only once.
+    let resolved = error_catalog::resolve("sub-account-cell-type", -1).expect("The contract should be in the catalog.");
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved[0].info.name, "SystemOff");
+
+    assert!(error_catalog::resolve("income-cell-type", 127).unwrap().is_empty());
+    assert!(error_catalog::resolve("unknown-cell-type", 1).is_none());
+
+    let msg = error_catalog::describe("apply-register-cell-type", 60).unwrap();
+    assert!(msg.contains("ApplyRegisterNeedWaitLonger"));
+}
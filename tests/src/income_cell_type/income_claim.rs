@@ -0,0 +1,323 @@
+use serde_json::json;
+
+use super::common::init;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+const CLAIMER: &str = "0x0000000000000000000000000000000000000010";
+const OTHER: &str = "0x0000000000000000000000000000000000000020";
+
+fn before_each() -> TemplateGenerator {
+    let mut template = init("claim_income");
+
+    // inputs
+    push_input_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 10_000_000_000u64,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": OTHER
+                        },
+                        "capacity": 5_000_000_000u64,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 5_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+
+    template
+}
+
+fn push_output_income_cell_with_other_records(template: &mut TemplateGenerator) {
+    push_output_income_cell(
+        template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": OTHER
+                        },
+                        "capacity": 5_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+}
+
+#[test]
+fn test_income_claim() {
+    let mut template = before_each();
+
+    // The claimer proves it is the owner of the records by putting a cell with its lock in inputs.
+    push_input_normal_cell(&mut template, 100_000_000_000, CLAIMER);
+
+    // outputs
+    push_output_income_cell_with_other_records(&mut template);
+    // The merged records of the claimer are withdrawn.
+    push_output_normal_cell(&mut template, 100_000_000_000 + 15_000_000_000, CLAIMER);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_income_claim_without_owner_lock() {
+    let mut template = before_each();
+
+    // Simulate claiming without any lock of the record owners in inputs.
+    push_input_normal_cell(
+        &mut template,
+        100_000_000_000,
+        "0x0000000000000000000000000000000000000030",
+    );
+
+    // outputs
+    push_output_income_cell_with_other_records(&mut template);
+    push_output_normal_cell(
+        &mut template,
+        100_000_000_000 + 15_000_000_000,
+        "0x0000000000000000000000000000000000000030",
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellClaimError);
+}
+
+#[test]
+fn challenge_income_claim_multiple_owners() {
+    let mut template = before_each();
+
+    // Simulate claiming records of multiple owners at one time.
+    push_input_normal_cell(&mut template, 100_000_000_000, CLAIMER);
+    push_input_normal_cell(&mut template, 100_000_000_000, OTHER);
+
+    // outputs
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell(&mut template, 100_000_000_000 + 15_000_000_000, CLAIMER);
+    push_output_normal_cell(&mut template, 100_000_000_000 + 5_000_000_000, OTHER);
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellClaimError);
+}
+
+#[test]
+fn challenge_income_claim_capacity_not_enough() {
+    let mut template = before_each();
+
+    // Simulate claiming the record whose capacity is less than ConfigCellIncome.min_transfer_capacity.
+    push_input_normal_cell(&mut template, 100_000_000_000, OTHER);
+
+    // outputs
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 15_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell(&mut template, 100_000_000_000 + 5_000_000_000, OTHER);
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellClaimCapacityNotEnough);
+}
+
+#[test]
+fn challenge_income_claim_partially() {
+    let mut template = before_each();
+
+    push_input_normal_cell(&mut template, 100_000_000_000, CLAIMER);
+
+    // outputs
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": OTHER
+                        },
+                        "capacity": 5_000_000_000u64,
+                    },
+                    // Simulate only withdrawing one of the records of the claimer.
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 5_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell(&mut template, 100_000_000_000 + 10_000_000_000, CLAIMER);
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellClaimError);
+}
+
+#[test]
+fn challenge_income_claim_other_records_modified() {
+    let mut template = before_each();
+
+    push_input_normal_cell(&mut template, 100_000_000_000, CLAIMER);
+
+    // outputs
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": OTHER
+                        },
+                        // Simulate taking some capacity from the record of other owner.
+                        "capacity": 4_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell(&mut template, 100_000_000_000 + 16_000_000_000, CLAIMER);
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellClaimError);
+}
+
+#[test]
+fn challenge_income_claim_capacity_less_than_basic() {
+    let mut template = init("claim_income");
+
+    // inputs
+    push_input_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": COMMON_INCOME_CREATOR
+                        },
+                        "capacity": INCOME_BASIC_CAPACITY,
+                    },
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 10_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+    // Simulate the creator claiming its record, then the IncomeCell will not have enough capacity.
+    push_input_normal_cell(&mut template, 100_000_000_000, COMMON_INCOME_CREATOR);
+
+    // outputs
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    {
+                        "belong_to": {
+                            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                            "args": CLAIMER
+                        },
+                        "capacity": 10_000_000_000u64,
+                    },
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell(
+        &mut template,
+        100_000_000_000 + INCOME_BASIC_CAPACITY,
+        COMMON_INCOME_CREATOR,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellCapacityError);
+}
@@ -0,0 +1,240 @@
+use das_types::constants::{DataType, Source};
+use das_types::packed::*;
+use das_types::prelude::*;
+use income_consolidator::{IncomeConfig, Plan, PlanError};
+use serde_json::{json, Value};
+
+use super::common::init;
+use crate::util;
+use crate::util::constants::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+const CREATOR: &str = "0x0000000000000000000000000000000000000001";
+const OWNER_A: &str = "0x0000000000000000000000000000000000000010";
+const OWNER_B: &str = "0x0000000000000000000000000000000000000020";
+const OWNER_C: &str = "0x0000000000000000000000000000000000000030";
+
+fn config() -> IncomeConfig {
+    IncomeConfig {
+        basic_capacity: INCOME_BASIC_CAPACITY,
+        max_records: INCOME_MAX_RECORDS as usize,
+        min_transfer_capacity: INCOME_MIN_TRANSFER_CAPACITY,
+        income_consolidate_profit_rate: CONSOLIDATING_FEE,
+    }
+}
+
+fn lock_json(args: &str) -> Value {
+    json!({
+        "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+        "args": args
+    })
+}
+
+fn script_to_json(script: &Script) -> Value {
+    json!({
+        "code_hash": format!("0x{}", hex::encode(script.code_hash().raw_data())),
+        "hash_type": if script.hash_type().as_slice()[0] == 0 { "data" } else { "type" },
+        "args": format!("0x{}", hex::encode(script.args().raw_data()))
+    })
+}
+
+fn records_to_json(records: &[(Script, u64)]) -> Value {
+    records
+        .iter()
+        .map(|(belong_to, capacity)| {
+            json!({
+                "belong_to": script_to_json(belong_to),
+                "capacity": capacity
+            })
+        })
+        .collect()
+}
+
+/// Generate the IncomeCells in JSON for the template and in IncomeCellData for the planner.
+fn gen_income_cells(cells: &[(&str, &[(&str, u64)])]) -> (Vec<Value>, Vec<IncomeCellData>) {
+    let mut cells_in_json = Vec::new();
+    let mut cells_data = Vec::new();
+    for (creator, records) in cells {
+        let creator = lock_json(creator);
+        let records = records
+            .iter()
+            .map(|(args, capacity)| json!({ "belong_to": lock_json(args), "capacity": capacity }))
+            .collect::<Vec<_>>();
+
+        let mut records_builder = IncomeRecords::new_builder();
+        for record in records.iter() {
+            records_builder = records_builder.push(
+                IncomeRecord::new_builder()
+                    .belong_to(util::parse_json_script_to_mol("belong_to", &record["belong_to"]))
+                    .capacity(Uint64::from(record["capacity"].as_u64().unwrap()))
+                    .build(),
+            );
+        }
+        cells_data.push(
+            IncomeCellData::new_builder()
+                .creator(util::parse_json_script_to_mol("creator", &creator))
+                .records(records_builder.build())
+                .build(),
+        );
+
+        cells_in_json.push(json!({
+            "witness": {
+                "creator": creator,
+                "records": records
+            }
+        }));
+    }
+
+    (cells_in_json, cells_data)
+}
+
+/// Build the consolidate_income transaction from the plan, then verify it with income-cell-type.
+fn verify_plan(cells_in_json: &[Value], plan: &Plan) {
+    let mut template = init("consolidate_income");
+    template.push_config_cell(DataType::ConfigCellProfitRate, Source::CellDep);
+
+    // inputs
+    for &i in plan.inputs.iter() {
+        push_input_income_cell(&mut template, cells_in_json[i].clone());
+    }
+
+    // outputs
+    for records in plan.outputs.iter() {
+        push_output_income_cell(
+            &mut template,
+            json!({
+                "witness": {
+                    "records": records_to_json(records)
+                }
+            }),
+        );
+    }
+    for transfer in plan.transfers.iter() {
+        template.push_output(
+            json!({
+                "capacity": transfer.capacity.to_string(),
+                "lock": script_to_json(&transfer.lock)
+            }),
+            None,
+        );
+    }
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn test_income_consolidate_plan_transfer_all() {
+    let (cells_in_json, cells_data) = gen_income_cells(&[
+        (CREATOR, &[(OWNER_A, 15_000_000_000)]),
+        (CREATOR, &[(OWNER_A, 15_000_000_000)]),
+    ]);
+
+    let plan = income_consolidator::plan(&cells_data, &config()).unwrap();
+
+    assert_eq!(plan.inputs, vec![0, 1]);
+    assert!(plan.outputs.is_empty());
+    assert_eq!(plan.transfers.len(), 1);
+    assert_eq!(plan.transfers[0].capacity, 29_700_000_000);
+    assert_eq!(plan.keeper_fee, 300_000_000);
+
+    verify_plan(&cells_in_json, &plan);
+}
+
+#[test]
+fn test_income_consolidate_plan_pad_with_creator() {
+    let (cells_in_json, cells_data) = gen_income_cells(&[
+        (CREATOR, &[(CREATOR, 20_000_000_000), (OWNER_A, 6_000_000_000)]),
+        (CREATOR, &[(CREATOR, 20_000_000_000), (OWNER_B, 5_000_000_000)]),
+    ]);
+
+    let plan = income_consolidator::plan(&cells_data, &config()).unwrap();
+
+    // The records of the creator are free from fee, so they are used for padding first.
+    assert_eq!(plan.outputs.len(), 1);
+    assert_eq!(
+        plan.outputs[0].iter().map(|record| record.1).sum::<u64>(),
+        INCOME_BASIC_CAPACITY
+    );
+    assert_eq!(plan.transfers.len(), 1);
+    assert_eq!(plan.transfers[0].capacity, 31_000_000_000);
+    assert_eq!(plan.keeper_fee, 0);
+
+    verify_plan(&cells_in_json, &plan);
+}
+
+#[test]
+fn test_income_consolidate_plan_pad_with_others() {
+    let (cells_in_json, cells_data) = gen_income_cells(&[
+        (CREATOR, &[(OWNER_A, 15_000_000_000), (OWNER_B, 3_000_000_000)]),
+        (CREATOR, &[(OWNER_A, 15_000_000_000), (OWNER_C, 3_000_000_000)]),
+    ]);
+
+    let plan = income_consolidator::plan(&cells_data, &config()).unwrap();
+
+    // The record of OWNER_A is transferred partially, the rest of it is used for padding.
+    assert_eq!(plan.outputs.len(), 1);
+    assert_eq!(
+        plan.outputs[0].iter().map(|record| record.1).sum::<u64>(),
+        INCOME_BASIC_CAPACITY
+    );
+    assert_eq!(plan.transfers.len(), 1);
+    assert_eq!(plan.transfers[0].capacity, 15_840_000_000);
+    assert_eq!(plan.keeper_fee, 160_000_000);
+
+    verify_plan(&cells_in_json, &plan);
+}
+
+#[test]
+fn test_income_consolidate_plan_choose_inputs() {
+    let (cells_in_json, cells_data) = gen_income_cells(&[
+        (CREATOR, &[(OWNER_A, 15_000_000_000)]),
+        (CREATOR, &[(OWNER_A, 15_000_000_000)]),
+        // Consolidating this IncomeCell requires padding which reduces the fee.
+        (CREATOR, &[(OWNER_B, 3_000_000_000)]),
+        // The IncomeCell which has only one record belong to the creator can not be consolidated.
+        (CREATOR, &[(CREATOR, 20_000_000_000)]),
+    ]);
+
+    let plan = income_consolidator::plan(&cells_data, &config()).unwrap();
+
+    assert_eq!(plan.inputs, vec![0, 1]);
+    assert_eq!(plan.keeper_fee, 300_000_000);
+
+    verify_plan(&cells_in_json, &plan);
+}
+
+#[test]
+fn test_income_consolidate_plan_not_enough_candidates() {
+    let (_, cells_data) = gen_income_cells(&[
+        (CREATOR, &[(OWNER_A, 15_000_000_000)]),
+        (CREATOR, &[(CREATOR, 20_000_000_000)]),
+    ]);
+
+    assert_eq!(
+        income_consolidator::plan(&cells_data, &config()).unwrap_err(),
+        PlanError::NotEnoughCandidates
+    );
+}
+
+#[test]
+fn test_income_consolidate_plan_too_many_candidates() {
+    let mut cells = vec![
+        // These IncomeCells bring the least fee, so they are not tried when there are too many candidates.
+        (CREATOR, &[(OWNER_B, 3_000_000_000u64)][..]),
+        (CREATOR, &[(OWNER_C, 3_000_000_000u64)][..]),
+    ];
+    for _ in 0..income_consolidator::MAX_CANDIDATES {
+        cells.push((CREATOR, &[(OWNER_A, 15_000_000_000u64)][..]));
+    }
+    let (cells_in_json, cells_data) = gen_income_cells(&cells);
+
+    let plan = income_consolidator::plan(&cells_data, &config()).unwrap();
+
+    assert_eq!(plan.inputs, (2..cells.len()).collect::<Vec<_>>());
+    assert!(plan.outputs.is_empty());
+    assert_eq!(plan.keeper_fee, 1_800_000_000);
+
+    verify_plan(&cells_in_json, &plan);
+}
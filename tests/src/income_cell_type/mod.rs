@@ -1,5 +1,7 @@
 #[macro_use]
 mod common;
 
+mod income_claim;
 mod income_consolidate;
+mod income_consolidate_plan;
 mod income_create;
@@ -10,8 +10,10 @@ mod apply_register_cell_type;
 mod balance_cell_type;
 mod config;
 mod config_cell_type;
+mod das_map;
 mod device_key_list_cell_type;
 mod dpoint_cell_type;
+mod error_catalog;
 mod income_cell_type;
 mod offer_cell_type;
 mod playground;
@@ -20,6 +22,7 @@ mod proposal_cell_type;
 mod reverse_record_cell_type;
 mod reverse_record_root_cell_type;
 mod sub_account_cell_type;
+mod tx_inspector;
 mod uint;
 mod witness_parser;
 
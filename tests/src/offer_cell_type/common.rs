@@ -19,7 +19,7 @@ pub fn init(action: &str) -> TemplateGenerator {
 
     template.push_config_cell(DataType::ConfigCellMain, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellSecondaryMarket, Source::CellDep);
-    template.push_config_cell(DataType::ConfigCellUnAvailableAccount, Source::CellDep);
+    template.push_config_cell(DataType::ConfigCellAccountList, Source::CellDep);
 
     template
 }
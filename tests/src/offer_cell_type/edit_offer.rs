@@ -1,4 +1,3 @@
-use das_types::constants::Source;
 use serde_json::json;
 
 use super::common::*;
@@ -13,7 +12,7 @@ fn before_each() -> TemplateGenerator {
 
     let account_without_suffix = &ACCOUNT_1[0..ACCOUNT_1.len() - 4];
     println!("account_without_suffix = {:?}", account_without_suffix);
-    template.push_config_cell_derived_by_account(account_without_suffix, Source::CellDep);
+    template.push_account_list_proof(account_without_suffix);
 
     template
 }
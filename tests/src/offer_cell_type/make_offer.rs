@@ -1,4 +1,3 @@
-use das_types::constants::Source;
 use serde_json::json;
 
 use super::common::*;
@@ -16,7 +15,7 @@ fn before_each() -> (TemplateGenerator, u64) {
 
     let account_without_suffix = &ACCOUNT_1[0..ACCOUNT_1.len() - 4];
     // println!("account_without_suffix = {:?}", account_without_suffix);
-    template.push_config_cell_derived_by_account(account_without_suffix, Source::CellDep);
+    template.push_account_list_proof(account_without_suffix);
 
     // inputs
     let total_input = 600_000_000_000;
@@ -374,3 +374,83 @@ fn challenge_pre_register_unreleased_pure_en_account_after_20221018() {
 
     challenge_tx(template.as_json(), ErrorCode::AccountStillCanNotBeRegister)
 }
+
+#[test]
+fn test_pre_register_pure_vi_account_released_by_config() {
+    let account = "evwcu.bit";
+    let mut template = init(json!({
+        "account": account,
+        "timestamp": TIMESTAMP_20221018,
+        "release_rules": [
+            { "char_set": RELEASE_RULE_ANY_CHAR_SET, "min_length": 10, "max_length": 0, "effective_at": 0 },
+            // Simulate releasing the pure Vi accounts which have at least 5 chars by config.
+            { "char_set": CharSetType::Vi as u32, "min_length": 5, "max_length": 0, "effective_at": TIMESTAMP_20221018 },
+        ]
+    }));
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 5, false),
+            "witness": {
+                "account": [
+                    { "char": "e", "type": CharSetType::Vi as u32 },
+                    { "char": "v", "type": CharSetType::Vi as u32 },
+                    { "char": "w", "type": CharSetType::Vi as u32 },
+                    { "char": "c", "type": CharSetType::Vi as u32 },
+                    { "char": "u", "type": CharSetType::Vi as u32 },
+                ],
+                "price": {
+                    "length": 5,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "created_at": TIMESTAMP_20221018
+            }
+        }),
+    );
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_pre_register_pure_vi_account_before_release_rule_effective() {
+    let account = "evwcu.bit";
+    let mut template = init(json!({
+        "account": account,
+        "timestamp": TIMESTAMP_20221018,
+        "release_rules": [
+            { "char_set": RELEASE_RULE_ANY_CHAR_SET, "min_length": 10, "max_length": 0, "effective_at": 0 },
+            // Simulate the rule is not effective when the ApplyRegisterCell is created.
+            { "char_set": CharSetType::Vi as u32, "min_length": 5, "max_length": 0, "effective_at": TIMESTAMP_20221018 + 1 },
+        ]
+    }));
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 5, false),
+            "witness": {
+                "account": [
+                    { "char": "e", "type": CharSetType::Vi as u32 },
+                    { "char": "v", "type": CharSetType::Vi as u32 },
+                    { "char": "w", "type": CharSetType::Vi as u32 },
+                    { "char": "c", "type": CharSetType::Vi as u32 },
+                    { "char": "u", "type": CharSetType::Vi as u32 },
+                ],
+                "price": {
+                    "length": 5,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "created_at": TIMESTAMP_20221018
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::AccountStillCanNotBeRegister)
+}
@@ -1,5 +1,6 @@
 use das_types::constants::*;
-use serde_json::json;
+use das_types::util as das_util;
+use serde_json::{json, Value};
 
 use super::common::*;
 use crate::util::constants::*;
@@ -304,3 +305,193 @@ fn test_pre_register_vi() {
 
     test_tx(template.as_json())
 }
+
+#[test]
+fn test_pre_register_ar() {
+    let tx = gen_pre_register_tx("✨سل00.bit", CharSetType::Ar, ["س", "ل"], false);
+    test_tx(tx)
+}
+
+#[test]
+fn test_pre_register_hi() {
+    let tx = gen_pre_register_tx("✨मकी00.bit", CharSetType::Hi, ["म", "की"], false);
+    test_tx(tx)
+}
+
+#[test]
+fn test_pre_register_he() {
+    let tx = gen_pre_register_tx("✨של00.bit", CharSetType::He, ["ש", "ל"], false);
+    test_tx(tx)
+}
+
+#[test]
+fn test_pre_register_el() {
+    let tx = gen_pre_register_tx("✨αβ00.bit", CharSetType::El, ["α", "β"], false);
+    test_tx(tx)
+}
+
+#[test]
+fn test_pre_register_pure_el_released_by_config() {
+    // Simulate registering a 4 chars account which is only released by the release rule of CharSetType::El .
+    let account = "αβγζ.bit";
+    let mut template = init(json!({ "account": account }));
+    template.push_config_cell(DataType::ConfigCellCharSetEl, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 4, false),
+            "witness": {
+                "account": [
+                    { "char": "α", "type": CharSetType::El as u32 },
+                    { "char": "β", "type": CharSetType::El as u32 },
+                    { "char": "γ", "type": CharSetType::El as u32 },
+                    { "char": "ζ", "type": CharSetType::El as u32 },
+                ],
+                "price": {
+                    "length": 4,
+                    "new": ACCOUNT_PRICE_4_CHAR,
+                    "renew": ACCOUNT_PRICE_4_CHAR
+                }
+            }
+        }),
+    );
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_pre_register_ar_and_he() {
+    // Simulate registering an account with chars of two non-global char-sets.
+    let account = "✨سש00.bit";
+    let mut template = init(json!({ "account": account }));
+    template.push_config_cell(DataType::ConfigCellCharSetAr, Source::CellDep);
+    template.push_config_cell(DataType::ConfigCellCharSetHe, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 5, false),
+            "witness": {
+                "account": [
+                    { "char": "✨", "type": CharSetType::Emoji as u32 },
+                    { "char": "س", "type": CharSetType::Ar as u32 },
+                    { "char": "ש", "type": CharSetType::He as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                ],
+                "price": {
+                    "length": 5,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CharSetIsConflict)
+}
+
+#[test]
+fn challenge_pre_register_ru_and_el() {
+    // Simulate registering an account with chars of a new non-global char-set and an old one.
+    let account = "✨лα00.bit";
+    let mut template = init(json!({ "account": account }));
+    template.push_config_cell(DataType::ConfigCellCharSetRu, Source::CellDep);
+    template.push_config_cell(DataType::ConfigCellCharSetEl, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 5, false),
+            "witness": {
+                "account": [
+                    { "char": "✨", "type": CharSetType::Emoji as u32 },
+                    { "char": "л", "type": CharSetType::Ru as u32 },
+                    { "char": "α", "type": CharSetType::El as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                ],
+                "price": {
+                    "length": 5,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::CharSetIsConflict)
+}
+
+fn gen_pre_register_tx(account: &str, char_set_type: CharSetType, chars: [&str; 2], legacy_char_set: bool) -> Value {
+    let mut template = init(json!({ "account": account, "legacy_char_set": legacy_char_set }));
+    template.push_config_cell(das_util::char_set_to_data_type(char_set_type), Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 5, false),
+            "witness": {
+                "account": [
+                    { "char": "✨", "type": CharSetType::Emoji as u32 },
+                    { "char": chars[0], "type": char_set_type as u32 },
+                    { "char": chars[1], "type": char_set_type as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                    { "char": "0", "type": CharSetType::Digit as u32 },
+                ],
+                "price": {
+                    "length": 5,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    template.as_json()
+}
+
+#[test]
+fn test_pre_register_with_legacy_char_set() {
+    let tx = gen_pre_register_tx("✨のロ00.bit", CharSetType::Ja, ["の", "ロ"], true);
+    test_tx(tx)
+}
+
+#[test]
+fn challenge_pre_register_invalid_char_with_legacy_char_set() {
+    // Simulate registering an account with a char which is not in the legacy encoded char-set.
+    let tx = gen_pre_register_tx("✨の지00.bit", CharSetType::Ja, ["の", "지"], true);
+    challenge_tx(tx, ErrorCode::AccountCharIsInvalid)
+}
+
+#[test]
+fn test_pre_register_cycles_of_char_set_encodings() {
+    // The chars at the end of the large char-sets are the worst cases of the legacy encoding.
+    for (account, char_set_type, chars) in [
+        ("✨湾腕00.bit", CharSetType::Ja, ["湾", "腕"]),
+        ("✨힛힝00.bit", CharSetType::Ko, ["힛", "힝"]),
+        ("✨罐矗00.bit", CharSetType::ZhHans, ["罐", "矗"]),
+    ] {
+        let legacy_cycles = perf_tx(gen_pre_register_tx(account, char_set_type, chars, true));
+        let indexed_cycles = perf_tx(gen_pre_register_tx(account, char_set_type, chars, false));
+
+        println!(
+            "CharSet[{:?}] legacy: {} cycles, indexed: {} cycles",
+            char_set_type, legacy_cycles, indexed_cycles
+        );
+        assert!(
+            indexed_cycles < legacy_cycles,
+            "The indexed CharSet[{:?}] should cost less cycles than the legacy one.",
+            char_set_type
+        );
+    }
+}
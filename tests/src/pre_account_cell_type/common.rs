@@ -6,6 +6,7 @@ use crate::util::constants::*;
 use crate::util::since_util::SinceFlag;
 use crate::util::template_common_cell::*;
 use crate::util::template_generator::*;
+use crate::util::{self};
 
 pub const ACCOUNT_SP_1: &str = "✨das🎉001.bit";
 pub const INPUT_CAPACITY_OF_REFUND_LOCK: u64 = 6_100_000_000;
@@ -26,13 +27,20 @@ pub fn init(args: Value) -> TemplateGenerator {
     template.push_contract_cell("pre-account-cell-type", ContractType::Contract);
 
     if action == "pre_register" {
-        if !args["has_super_lock"].as_bool().unwrap_or(false) {
+        if !args["has_super_lock"].as_bool().unwrap_or(false)
+            && !args["has_custom_oracle_cells"].as_bool().unwrap_or(false)
+        {
             template.push_oracle_cell(1, OracleCellType::Height, HEIGHT);
             template.push_oracle_cell(1, OracleCellType::Time, args["timestamp"].as_u64().unwrap_or(TIMESTAMP));
             template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
         }
     }
 
+    template.legacy_char_set = args["legacy_char_set"].as_bool().unwrap_or(false);
+    if let Some(min_cells) = args["oracle_min_cells"].as_u64() {
+        template.oracle_min_cells = min_cells as u8;
+    }
+
     template.push_config_cell(DataType::ConfigCellMain, Source::CellDep);
     if action == "pre_register" {
         template.push_config_cell(DataType::ConfigCellAccount, Source::CellDep);
@@ -42,13 +50,55 @@ pub fn init(args: Value) -> TemplateGenerator {
         template.push_config_cell(DataType::ConfigCellCharSetEn, Source::CellDep);
         template.push_config_cell(DataType::ConfigCellCharSetVi, Source::CellDep);
         template.push_config_cell(DataType::ConfigCellCharSetTr, Source::CellDep);
+        if let Some(tiers) = args["multi_year_discounts"].as_array() {
+            template.multi_year_discounts = tiers
+                .iter()
+                .map(|tier| {
+                    gen_multi_year_discount(
+                        util::parse_json_u32("multi_year_discounts[].years", &tier["years"], None),
+                        util::parse_json_u32("multi_year_discounts[].discount", &tier["discount"], None),
+                    )
+                })
+                .collect();
+        }
+        if let Some(promotions) = args["promotions"].as_array() {
+            template.promotions = promotions
+                .iter()
+                .map(|promotion| {
+                    gen_promotion(
+                        util::parse_json_u64("promotions[].start_at", &promotion["start_at"], None),
+                        util::parse_json_u64("promotions[].end_at", &promotion["end_at"], None),
+                        util::parse_json_u32("promotions[].char_set", &promotion["char_set"], None),
+                        util::parse_json_u32("promotions[].min_length", &promotion["min_length"], Some(0)),
+                        util::parse_json_u32("promotions[].max_length", &promotion["max_length"], Some(0)),
+                        util::parse_json_u32("promotions[].discount", &promotion["discount"], None),
+                    )
+                })
+                .collect();
+        }
         template.push_config_cell(DataType::ConfigCellPrice, Source::CellDep);
+        if let Some(rules) = args["release_rules"].as_array() {
+            template.release_rules = rules
+                .iter()
+                .map(|rule| {
+                    gen_release_rule(
+                        util::parse_json_u32("release_rules[].char_set", &rule["char_set"], None),
+                        util::parse_json_u32("release_rules[].min_length", &rule["min_length"], None),
+                        util::parse_json_u32("release_rules[].max_length", &rule["max_length"], Some(0)),
+                        util::parse_json_u64("release_rules[].effective_at", &rule["effective_at"], Some(0)),
+                    )
+                })
+                .collect();
+        }
         template.push_config_cell(DataType::ConfigCellRelease, Source::CellDep);
-        template.push_config_cell(DataType::ConfigCellUnAvailableAccount, Source::CellDep);
+        template.push_config_cell(DataType::ConfigCellAccountList, Source::CellDep);
+        template.push_config_cell(DataType::ConfigCellConfusables, Source::CellDep);
         template.push_config_cell(DataType::ConfigCellRecordKeyNamespace, Source::CellDep);
 
         if let Some(account) = args["account"].as_str() {
-            template.push_config_cell_derived_by_account(account, Source::CellDep);
+            if !args["skip_account_list_proof"].as_bool().unwrap_or(false) {
+                template.push_account_list_proof(account);
+            }
         }
 
         if !args["has_custom_dep_account_cell"].as_bool().unwrap_or(false) {
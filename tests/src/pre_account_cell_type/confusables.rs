@@ -0,0 +1,107 @@
+use das_types::constants::*;
+use serde_json::{json, Value};
+
+use super::common::*;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::TemplateGenerator;
+use crate::util::template_parser::*;
+use crate::util::{self};
+
+// The account looks the same as the protected account copy2023.bit, but its letters are all in Cyrillic.
+const CONFUSABLE_ACCOUNT: &str = "сору2023.bit";
+
+fn gen_cyrillic_account_chars(letters: &str, digits: &str) -> Value {
+    let mut chars = Vec::new();
+    for char in letters.chars() {
+        chars.push(json!({ "char": char.to_string(), "type": CharSetType::Ru as u32 }));
+    }
+    for char in digits.chars() {
+        chars.push(json!({ "char": char.to_string(), "type": CharSetType::Digit as u32 }));
+    }
+
+    Value::Array(chars)
+}
+
+fn push_output_cyrillic_pre_account_cell(template: &mut TemplateGenerator, account: &str, chars: Value) {
+    push_output_pre_account_cell(
+        template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 8, false),
+            "witness": {
+                "account": chars,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+}
+
+#[test]
+fn test_pre_register_protected_account() {
+    // The protected account contains no confusable chars, so it can be registered as usual.
+    let account = "copy2023.bit";
+    let mut template = init(json!({ "account": account }));
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 8, false),
+            "witness": {
+                "account": account,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn test_pre_register_confusable_account_not_protected() {
+    let account = "сору2024.bit";
+    let mut template = init(json!({ "account": account }));
+    template.push_config_cell(DataType::ConfigCellCharSetRu, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+    push_output_cyrillic_pre_account_cell(&mut template, account, gen_cyrillic_account_chars("сору", "2024"));
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn test_pre_register_confusable_account_with_super_lock() {
+    let account = CONFUSABLE_ACCOUNT;
+    let mut template = init(json!({ "account": account, "has_super_lock": true }));
+    template.push_config_cell(DataType::ConfigCellCharSetRu, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+    push_input_normal_cell(&mut template, 0, SUPER_LOCK_ARGS);
+    push_output_cyrillic_pre_account_cell(&mut template, account, gen_cyrillic_account_chars("сору", "2023"));
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_pre_register_confusable_account() {
+    // Simulate registering an account whose skeleton is the same as a protected account.
+    let account = CONFUSABLE_ACCOUNT;
+    let mut template = init(json!({ "account": account }));
+    template.push_config_cell(DataType::ConfigCellCharSetRu, Source::CellDep);
+
+    push_input_simple_apply_register_cell(&mut template, account);
+    push_output_cyrillic_pre_account_cell(&mut template, account, gen_cyrillic_account_chars("сору", "2023"));
+
+    challenge_tx(template.as_json(), ErrorCode::AccountIsConfusable)
+}
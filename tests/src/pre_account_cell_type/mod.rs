@@ -1,7 +1,12 @@
 mod account_release;
 mod char_set;
 mod common;
+mod confusables;
+mod oracle;
+mod paid_in_dpoint;
+mod paid_in_udt;
 mod preserved_accounts;
+mod price;
 mod refund_pre_register;
 mod simple;
 mod unavailable_accounts;
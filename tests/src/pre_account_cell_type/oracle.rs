@@ -0,0 +1,160 @@
+use serde_json::json;
+
+use super::common::*;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+use crate::util::{self};
+
+fn before_each(min_cells: u8) -> TemplateGenerator {
+    before_each_with_time(min_cells, TIMESTAMP)
+}
+
+fn before_each_with_time(min_cells: u8, timestamp: u64) -> TemplateGenerator {
+    let mut template = init(json!({
+        "account": ACCOUNT_SP_1,
+        "has_custom_oracle_cells": true,
+        "oracle_min_cells": min_cells
+    }));
+
+    for i in 1..=min_cells {
+        template.push_oracle_cell(i, OracleCellType::Height, HEIGHT);
+        template.push_oracle_cell(i, OracleCellType::Time, timestamp);
+    }
+
+    // inputs
+    push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
+
+    // outputs
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(ACCOUNT_SP_1, 8, true),
+            "witness": {
+                "account": ACCOUNT_SP_1,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "inviter_id": "0x0000000000000000000000000000000000000000",
+                "inviter_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(INVITER, None)
+                },
+                "channel_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(CHANNEL, None)
+                },
+                "invited_discount": INVITED_DISCOUNT,
+            }
+        }),
+    );
+
+    template
+}
+
+#[test]
+fn test_pre_register_oracle_median_of_odd_cells() {
+    let mut template = before_each(3);
+
+    // The median of the quotes is the same as the PreAccountCell.quote .
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE - 100);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(3, OracleCellType::Quote, CKB_QUOTE * 5);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn test_pre_register_oracle_median_of_even_cells() {
+    let mut template = before_each(3);
+
+    // The median of even cells is the average of the middle two.
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE - 100);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE - 1);
+    template.push_oracle_cell(3, OracleCellType::Quote, CKB_QUOTE + 1);
+    template.push_oracle_cell(4, OracleCellType::Quote, CKB_QUOTE + 100);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_pre_register_oracle_cells_not_enough() {
+    let mut template = before_each(3);
+
+    // Simulate providing less OracleCells than ConfigCellMain.oracle.min_cells .
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+
+    challenge_tx(template.as_json(), ErrorCode::OracleCellsNotEnough);
+}
+
+#[test]
+fn challenge_pre_register_oracle_cell_duplicated() {
+    let mut template = before_each(3);
+
+    // Simulate providing multiple OracleCells of the same oracle provider.
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+
+    challenge_tx(template.as_json(), ErrorCode::OracleCellIsDuplicated);
+}
+
+#[test]
+fn challenge_pre_register_oracle_cell_provider_invalid() {
+    let mut template = before_each(3);
+
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+    // Simulate providing an OracleCell which is not locked by any oracle provider in ConfigCellMain.oracle .
+    template.push_oracle_cell(ORACLE_PROVIDERS + 1, OracleCellType::Quote, CKB_QUOTE);
+
+    challenge_tx(template.as_json(), ErrorCode::OracleCellProviderInvalid);
+}
+
+#[test]
+fn challenge_pre_register_oracle_cell_stale() {
+    let mut template = before_each(3);
+
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+    // Simulate providing an OracleCell which is not updated for longer than ConfigCellMain.oracle.max_age .
+    template.push_oracle_cell_updated_at(
+        3,
+        OracleCellType::Quote,
+        CKB_QUOTE,
+        ORACLE_UPDATED_AT - (ORACLE_MAX_AGE + 1) * 1000,
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::OracleCellIsStale);
+}
+
+#[test]
+fn challenge_pre_register_oracle_cell_stale_to_time_cells() {
+    // Simulate all the OracleCells are committed long before the time of the TimeCells, their headers are the latest
+    // headers in header_deps, but they should still be treated as stale.
+    let mut template = before_each_with_time(3, TIMESTAMP + ORACLE_MAX_AGE + 1);
+
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(3, OracleCellType::Quote, CKB_QUOTE);
+
+    challenge_tx(template.as_json(), ErrorCode::OracleCellIsStale);
+}
+
+#[test]
+fn challenge_pre_register_oracle_median_mismatch() {
+    let mut template = before_each(3);
+
+    // Simulate the PreAccountCell.quote is not the median of the quotes.
+    template.push_oracle_cell(1, OracleCellType::Quote, CKB_QUOTE);
+    template.push_oracle_cell(2, OracleCellType::Quote, CKB_QUOTE * 2);
+    template.push_oracle_cell(3, OracleCellType::Quote, CKB_QUOTE * 2);
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::QuoteIsInvalid);
+}
@@ -0,0 +1,100 @@
+use das_types::constants::*;
+use serde_json::{json, Value};
+
+use super::common::*;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+// The registration fee of ACCOUNT_SP_1 for 1 year in DPoint, it is the price of 5+ chars with the invited discount.
+const DP_REGISTER_FEE: u64 = ACCOUNT_PRICE_5_CHAR * (10000 - INVITED_DISCOUNT) / 10000;
+
+fn before_each() -> TemplateGenerator {
+    let mut template = init(json!({ "account": ACCOUNT_SP_1 }));
+    template.push_contract_cell("dpoint-cell-type", ContractType::Contract);
+    template.push_config_cell(DataType::ConfigCellDPoint, Source::CellDep);
+
+    // inputs
+    push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
+    push_input_dpoint_cell(&mut template, DP_REGISTER_FEE, OWNER);
+
+    template
+}
+
+fn push_output_dpoint_pre_account_cell(template: &mut TemplateGenerator, quote: u64, amount: u64) {
+    push_output_pre_account_cell(
+        template,
+        json!({
+            // The PreAccountCell only need to contain the capacity for storage when the registration is paid in DPoint.
+            "capacity": ACCOUNT_BASIC_CAPACITY + ACCOUNT_PREPARED_FEE_CAPACITY + (ACCOUNT_SP_1.len() as u64) * ONE_CKB,
+            "witness": {
+                "account": ACCOUNT_SP_1,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "quote": quote,
+                "inviter_id": "0x0000000000000000000000000000000000000000",
+                "inviter_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(INVITER, None)
+                },
+                "channel_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(CHANNEL, None)
+                },
+                "invited_discount": INVITED_DISCOUNT,
+                "paid_udt": gen_paid_dpoint(amount)
+            }
+        }),
+    );
+}
+
+fn gen_paid_dpoint(amount: u64) -> Value {
+    json!({
+        "type_script": {
+            "code_hash": "{{dpoint-cell-type}}"
+        },
+        "amount": amount
+    })
+}
+
+#[test]
+fn test_pre_register_paid_in_dpoint() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_dpoint_pre_account_cell(&mut template, ONE_CKB, DP_REGISTER_FEE);
+    push_output_dpoint_cell(&mut template, DP_REGISTER_FEE, DP_TRANSFER_WHITELIST_1);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_pre_register_paid_in_dpoint_amount_mismatch() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_dpoint_pre_account_cell(&mut template, ONE_CKB, DP_REGISTER_FEE);
+    // Simulate the DPoint paid to the transfer whitelist is less than the PreAccountCell.paid_udt.amount .
+    push_output_dpoint_cell(&mut template, DP_REGISTER_FEE - 1, DP_TRANSFER_WHITELIST_1);
+    push_output_dpoint_cell(&mut template, 1, OWNER);
+
+    challenge_tx(template.as_json(), ErrorCode::DPointPaymentError);
+}
+
+#[test]
+fn challenge_pre_register_paid_in_dpoint_quote_invalid() {
+    let mut template = before_each();
+
+    // outputs
+    // Simulate using the quote of CKB when the registration is paid in DPoint.
+    push_output_dpoint_pre_account_cell(&mut template, CKB_QUOTE, DP_REGISTER_FEE);
+    push_output_dpoint_cell(&mut template, DP_REGISTER_FEE, DP_TRANSFER_WHITELIST_1);
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::QuoteIsInvalid);
+}
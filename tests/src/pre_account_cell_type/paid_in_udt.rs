@@ -0,0 +1,156 @@
+use serde_json::{json, Value};
+
+use super::common::*;
+use crate::util::accounts::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+
+// The registration fee of ACCOUNT_SP_1 for 1 year in UDT, it is the price of 5+ chars with the invited discount.
+const UDT_REGISTER_FEE: u64 = ACCOUNT_PRICE_5_CHAR * (10000 - INVITED_DISCOUNT) / 10000;
+
+fn das_wallet_lock() -> Value {
+    json!({
+        "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+        "args": DAS_WALLET_LOCK_ARGS
+    })
+}
+
+fn before_each() -> TemplateGenerator {
+    let mut template = init(json!({ "account": ACCOUNT_SP_1 }));
+
+    // inputs
+    push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
+    push_input_udt_cell(
+        &mut template,
+        UDT_REGISTER_FEE as u128,
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": OWNER_WITHOUT_TYPE
+        }),
+    );
+
+    template
+}
+
+fn push_output_udt_pre_account_cell(template: &mut TemplateGenerator, quote: u64, paid_udt: Value) {
+    push_output_pre_account_cell(
+        template,
+        json!({
+            // The PreAccountCell only need to contain the capacity for storage when the registration is paid in UDT.
+            "capacity": ACCOUNT_BASIC_CAPACITY + ACCOUNT_PREPARED_FEE_CAPACITY + (ACCOUNT_SP_1.len() as u64) * ONE_CKB,
+            "witness": {
+                "account": ACCOUNT_SP_1,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "quote": quote,
+                "inviter_id": "0x0000000000000000000000000000000000000000",
+                "inviter_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(INVITER, None)
+                },
+                "channel_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(CHANNEL, None)
+                },
+                "invited_discount": INVITED_DISCOUNT,
+                "paid_udt": paid_udt
+            }
+        }),
+    );
+}
+
+fn gen_paid_udt(args: &str, amount: u64) -> Value {
+    json!({
+        "type_script": {
+            "code_hash": "{{always_success}}",
+            "args": args
+        },
+        "amount": amount
+    })
+}
+
+#[test]
+fn test_pre_register_paid_in_udt() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_udt_pre_account_cell(&mut template, UDT_QUOTE, gen_paid_udt(UDT_ARGS, UDT_REGISTER_FEE));
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128, das_wallet_lock());
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_pre_register_paid_in_udt_not_supported() {
+    let mut template = before_each();
+
+    // outputs
+    // Simulate paying with a UDT which is not listed in ConfigCellPrice.udt_payments.
+    push_output_udt_pre_account_cell(
+        &mut template,
+        UDT_QUOTE,
+        gen_paid_udt(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+            UDT_REGISTER_FEE,
+        ),
+    );
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128, das_wallet_lock());
+
+    challenge_tx(template.as_json(), ErrorCode::UdtPaymentNotSupported);
+}
+
+#[test]
+fn challenge_pre_register_paid_in_udt_amount_mismatch() {
+    let mut template = before_each();
+
+    // outputs
+    push_output_udt_pre_account_cell(&mut template, UDT_QUOTE, gen_paid_udt(UDT_ARGS, UDT_REGISTER_FEE));
+    // Simulate the UDT paid to the DAS wallet is less than the PreAccountCell.paid_udt.amount .
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128 - 1, das_wallet_lock());
+
+    challenge_tx(template.as_json(), ErrorCode::UdtPaymentError);
+}
+
+#[test]
+fn challenge_pre_register_paid_in_udt_insufficient() {
+    let mut template = before_each();
+
+    // outputs
+    // Simulate paying less UDT than the registration fee for 1 year.
+    push_output_udt_pre_account_cell(&mut template, UDT_QUOTE, gen_paid_udt(UDT_ARGS, UDT_REGISTER_FEE - 1));
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128 - 1, das_wallet_lock());
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::UdtIsInsufficient);
+}
+
+#[test]
+fn challenge_pre_register_paid_in_udt_quote_invalid() {
+    let mut template = before_each();
+
+    // outputs
+    // Simulate using the quote of CKB when the registration is paid in UDT.
+    push_output_udt_pre_account_cell(&mut template, CKB_QUOTE, gen_paid_udt(UDT_ARGS, UDT_REGISTER_FEE));
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128, das_wallet_lock());
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::QuoteIsInvalid);
+}
+
+#[test]
+fn challenge_pre_register_paid_in_udt_ckb_quote_invalid() {
+    let mut template = before_each();
+
+    // outputs
+    let mut paid_udt = gen_paid_udt(UDT_ARGS, UDT_REGISTER_FEE);
+    // Simulate recording a quote of CKB which is different from the QuoteCell.
+    paid_udt["ckb_quote"] = json!(CKB_QUOTE + 1);
+    push_output_udt_pre_account_cell(&mut template, UDT_QUOTE, paid_udt);
+    push_output_udt_cell(&mut template, UDT_REGISTER_FEE as u128, das_wallet_lock());
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::QuoteIsInvalid);
+}
@@ -60,3 +60,55 @@ fn test_pre_register_preserved_account_with_super_lock() {
 
     test_tx(template.as_json())
 }
+
+#[test]
+fn challenge_pre_register_without_account_list_proof() {
+    let account = "xxxxxxxxxx.bit";
+    let mut template = init(json!({ "account": account, "skip_account_list_proof": true }));
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 10, false),
+            "witness": {
+                "account": account,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::AccountListProofIsRequired)
+}
+
+#[test]
+fn challenge_pre_register_with_account_list_proof_of_other_account() {
+    let account = "xxxxxxxxxx.bit";
+    let mut template = init(json!({ "account": account, "skip_account_list_proof": true }));
+    // Simulate providing the proof of another account.
+    template.push_account_list_proof("yyyyyyyyyy.bit");
+
+    push_input_simple_apply_register_cell(&mut template, account);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(account, 10, false),
+            "witness": {
+                "account": account,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    challenge_tx(template.as_json(), ErrorCode::AccountIdIsInvalid)
+}
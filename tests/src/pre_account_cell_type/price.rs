@@ -0,0 +1,148 @@
+use das_types::constants::*;
+use serde_json::{json, Value};
+
+use super::common::*;
+use crate::util::constants::*;
+use crate::util::error::*;
+use crate::util::template_common_cell::*;
+use crate::util::template_parser::*;
+
+fn gen_capacity(account: &str, price_in_usd: u64, years: u64) -> u64 {
+    price_in_usd * 100_000_000 / CKB_QUOTE * years
+        + ACCOUNT_BASIC_CAPACITY
+        + ACCOUNT_PREPARED_FEE_CAPACITY
+        + (account.as_bytes().len() as u64) * 100_000_000
+}
+
+fn gen_pre_register_tx(args: Value, price_in_usd: u64, capacity: u64) -> Value {
+    let mut template = init(args);
+
+    push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
+
+    push_output_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": capacity,
+            "witness": {
+                "account": ACCOUNT_SP_1,
+                "price": {
+                    "length": 8,
+                    "new": price_in_usd,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                }
+            }
+        }),
+    );
+
+    template.as_json()
+}
+
+fn gen_promotion_args(start_at: u64, end_at: u64, char_set: u32) -> Value {
+    json!({
+        "account": ACCOUNT_SP_1,
+        "promotions": [
+            {
+                "start_at": start_at,
+                "end_at": end_at,
+                "char_set": char_set,
+                "min_length": 8,
+                "discount": 2000
+            }
+        ]
+    })
+}
+
+fn gen_multi_year_discount_args() -> Value {
+    json!({
+        "account": ACCOUNT_SP_1,
+        "multi_year_discounts": [
+            { "years": 3, "discount": 1000 },
+            { "years": 5, "discount": 2000 }
+        ]
+    })
+}
+
+#[test]
+fn test_pre_register_with_promotion() {
+    let price = ACCOUNT_PRICE_5_CHAR * 8 / 10;
+    let tx = gen_pre_register_tx(
+        gen_promotion_args(TIMESTAMP, TIMESTAMP + DAY_SEC, PROMOTION_ANY_CHAR_SET),
+        price,
+        gen_capacity(ACCOUNT_SP_1, price, 1),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn test_pre_register_with_inactive_promotion() {
+    // The promotion has ended just before the current timestamp.
+    let tx = gen_pre_register_tx(
+        gen_promotion_args(TIMESTAMP - DAY_SEC, TIMESTAMP, PROMOTION_ANY_CHAR_SET),
+        ACCOUNT_PRICE_5_CHAR,
+        gen_capacity(ACCOUNT_SP_1, ACCOUNT_PRICE_5_CHAR, 1),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn test_pre_register_with_promotion_of_other_char_set() {
+    // The account contains chars of several char-sets, so the promotion for the Emoji char-set is not applied.
+    let tx = gen_pre_register_tx(
+        gen_promotion_args(TIMESTAMP, TIMESTAMP + DAY_SEC, CharSetType::Emoji as u32),
+        ACCOUNT_PRICE_5_CHAR,
+        gen_capacity(ACCOUNT_SP_1, ACCOUNT_PRICE_5_CHAR, 1),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn challenge_pre_register_without_promotion_applied() {
+    let tx = gen_pre_register_tx(
+        gen_promotion_args(TIMESTAMP, TIMESTAMP + DAY_SEC, PROMOTION_ANY_CHAR_SET),
+        ACCOUNT_PRICE_5_CHAR,
+        gen_capacity(ACCOUNT_SP_1, ACCOUNT_PRICE_5_CHAR, 1),
+    );
+
+    challenge_tx(tx, PreAccountCellErrorCode::PriceIsInvalid);
+}
+
+#[test]
+fn test_pre_register_with_multi_year_discount() {
+    // The capacity is enough for 3 years with the discount of the 3 years tier, but not enough for 5 years.
+    let price = ACCOUNT_PRICE_5_CHAR * 9 / 10;
+    let tx = gen_pre_register_tx(
+        gen_multi_year_discount_args(),
+        price,
+        gen_capacity(ACCOUNT_SP_1, price, 3),
+    );
+
+    test_tx(tx);
+}
+
+#[test]
+fn challenge_pre_register_with_unreached_multi_year_discount() {
+    // The capacity is 1 shannon less than 3 years with the discount of the 3 years tier.
+    let price = ACCOUNT_PRICE_5_CHAR * 9 / 10;
+    let tx = gen_pre_register_tx(
+        gen_multi_year_discount_args(),
+        price,
+        gen_capacity(ACCOUNT_SP_1, price, 3) - 1,
+    );
+
+    challenge_tx(tx, PreAccountCellErrorCode::PriceIsInvalid);
+}
+
+#[test]
+fn test_pre_register_with_promotion_and_multi_year_discount() {
+    // The discounts of the promotion and the tier are accumulated.
+    let mut args = gen_multi_year_discount_args();
+    args["promotions"] =
+        gen_promotion_args(TIMESTAMP, TIMESTAMP + DAY_SEC, PROMOTION_ANY_CHAR_SET)["promotions"].clone();
+    let price = ACCOUNT_PRICE_5_CHAR * 6 / 10;
+    let tx = gen_pre_register_tx(args, price, gen_capacity(ACCOUNT_SP_1, price, 5));
+
+    test_tx(tx);
+}
@@ -1,4 +1,5 @@
-use serde_json::json;
+use das_types::constants::*;
+use serde_json::{json, Value};
 
 use super::common::*;
 use crate::util::accounts::*;
@@ -370,3 +371,100 @@ fn challenge_pre_register_refund_to_das_lock_without_type() {
 
     challenge_tx(template.as_json(), ErrorCode::BalanceCellFoundSomeOutputsLackOfType)
 }
+
+fn push_input_udt_paid_pre_account_cell(template: &mut TemplateGenerator, paid_udt: Value, refund_lock: Value) {
+    push_input_pre_account_cell(
+        template,
+        json!({
+            "capacity": 100_000_000_000u64,
+            "witness": {
+                "account": "xxxxx.bit",
+                "refund_lock": refund_lock,
+                "paid_udt": paid_udt
+            }
+        }),
+        *SINCE_1_D,
+    );
+}
+
+#[test]
+fn test_pre_register_refund_paid_in_udt() {
+    let mut template = init(json!({ "action": ACTION }));
+
+    // inputs
+    push_input_udt_paid_pre_account_cell(
+        &mut template,
+        json!({
+            "type_script": {
+                "code_hash": "{{always_success}}",
+                "args": UDT_ARGS
+            },
+            "amount": 10_000_000u64
+        }),
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": OWNER_WITHOUT_TYPE
+        }),
+    );
+
+    // outputs
+    // The UDT is held by the DAS wallet, so only the capacity of the PreAccountCell is refunded.
+    push_output_normal_cell(&mut template, 100_000_000_000, OWNER_WITHOUT_TYPE);
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_pre_register_refund_paid_in_udt_capacity_not_refunded() {
+    let mut template = init(json!({ "action": ACTION }));
+
+    // inputs
+    push_input_udt_paid_pre_account_cell(
+        &mut template,
+        json!({
+            "type_script": {
+                "code_hash": "{{always_success}}",
+                "args": UDT_ARGS
+            },
+            "amount": 10_000_000u64
+        }),
+        json!({
+            "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+            "args": OWNER_WITHOUT_TYPE
+        }),
+    );
+
+    // outputs
+    // Simulate refunding less capacity than the PreAccountCell when it is paid in UDT.
+    push_output_normal_cell(&mut template, 100_000_000_000 - 20_000, OWNER_WITHOUT_TYPE);
+
+    challenge_tx(template.as_json(), PreAccountCellErrorCode::RefundCapacityError)
+}
+
+#[test]
+fn test_pre_register_refund_paid_in_dpoint() {
+    let mut template = init(json!({ "action": ACTION }));
+    template.push_contract_cell("dpoint-cell-type", ContractType::Contract);
+    template.push_contract_cell("balance-cell-type", ContractType::Contract);
+
+    // inputs
+    push_input_udt_paid_pre_account_cell(
+        &mut template,
+        json!({
+            "type_script": {
+                "code_hash": "{{dpoint-cell-type}}"
+            },
+            "amount": 10_000_000u64
+        }),
+        json!({
+            "code_hash": "{{fake-das-lock}}",
+            "args": gen_das_lock_args(OWNER, None)
+        }),
+    );
+
+    // outputs
+    // The DPoint is held by the transfer whitelist, so only the capacity of the PreAccountCell is refunded.
+    push_output_balance_cell(&mut template, 100_000_000_000, OWNER);
+
+    test_tx(template.as_json())
+}
@@ -116,6 +116,42 @@ fn test_pre_register_simple_v3() {
     // inputs
     push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
 
+    // outputs
+    push_output_pre_account_cell_v3(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2(ACCOUNT_SP_1, 8, true),
+            "witness": {
+                "account": ACCOUNT_SP_1,
+                "price": {
+                    "length": 8,
+                    "new": ACCOUNT_PRICE_5_CHAR,
+                    "renew": ACCOUNT_PRICE_5_CHAR
+                },
+                "inviter_id": "0x0000000000000000000000000000000000000000",
+                "inviter_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(INVITER, None)
+                },
+                "channel_lock": {
+                    "code_hash": "{{fake-das-lock}}",
+                    "args": gen_das_lock_args(CHANNEL, None)
+                },
+                "invited_discount": INVITED_DISCOUNT
+            }
+        }),
+    );
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn test_pre_register_simple_v4() {
+    let mut template = init(json!({ "account": ACCOUNT_SP_1 }));
+
+    // inputs
+    push_input_simple_apply_register_cell(&mut template, ACCOUNT_SP_1);
+
     // outputs
     push_output_simple_pre_account_cell(&mut template);
 
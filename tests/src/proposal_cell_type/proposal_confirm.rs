@@ -919,6 +919,7 @@ fn challenge_proposal_confirm_new_account_cell_approval() {
 #[test]
 fn test_proposal_confirm_new_account_with_cross_chain() {
     let mut template = init_with_confirm();
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
 
     // inputs
     push_input_proposal_cell_with_slices(&mut template);
@@ -962,7 +963,7 @@ fn test_proposal_confirm_new_account_with_cross_chain() {
                 ],
                 "initial_cross_chain": {
                     "checked": 1,
-                    "coin_type": 1,
+                    "coin_type": 60,
                     "chain_id": 1,
                 }
             }
@@ -1006,8 +1007,12 @@ fn test_proposal_confirm_new_account_with_cross_chain() {
             },
             "witness": {
                 "account": "das00005.bit",
-                // Simulate the status of new AccountCell is still Normal.
                 "status": (AccountStatus::LockedForCrossChain as u8),
+                "cross_chain": {
+                    "checked": 1,
+                    "coin_type": 60,
+                    "chain_id": 1
+                },
                 "registered_at": TIMESTAMP,
                 "records": [
                     {
@@ -1028,9 +1033,123 @@ fn test_proposal_confirm_new_account_with_cross_chain() {
     test_tx(template.as_json());
 }
 
+#[test]
+fn challenge_proposal_confirm_new_account_without_cross_chain() {
+    let mut template = init_with_confirm();
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
+
+    // inputs
+    push_input_proposal_cell_with_slices(&mut template);
+
+    let lock_scripts = gen_lock_scripts();
+    push_input_account_cell_v2(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x000000000000000000000000000000000000001111",
+                "manager_lock_args": "0x000000000000000000000000000000000000001111"
+            },
+            "data": {
+                "account": "das00012.bit",
+                "next": "das00002.bit"
+            },
+            "witness": {
+                "account": "das00012.bit",
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+    push_input_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2("das00005.bit", 8, true),
+            "witness": {
+                "account": "das00005.bit",
+                "owner_lock_args": gen_das_lock_args("0x05ffff000000000000000000000000000000000005", None),
+                "inviter_lock": lock_scripts.inviter_1,
+                "channel_lock": lock_scripts.channel_1,
+                "created_at": TIMESTAMP - HOUR_SEC,
+                "initial_records": [
+                    {
+                        "type": "address",
+                        "key": "60",
+                        "label": "Personal",
+                        "value": "0xffff000000000000000000000000000000000005",
+                    }
+                ],
+                "initial_cross_chain": {
+                    "checked": 1,
+                    "coin_type": 60,
+                    "chain_id": 1,
+                }
+            }
+        }),
+        None,
+    );
+
+    push_input_slice_1(&mut template);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x000000000000000000000000000000000000001111",
+                "manager_lock_args": "0x000000000000000000000000000000000000001111"
+            },
+            "data": {
+                "account": "das00012.bit",
+                "next": "das00005.bit"
+            },
+            "witness": {
+                "account": "das00012.bit",
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x05ffff000000000000000000000000000000000005",
+                "manager_lock_args": "0x05ffff000000000000000000000000000000000005"
+            },
+            "data": {
+                "account": "das00005.bit",
+                "next": "das00002.bit",
+                "expired_at": TIMESTAMP + YEAR_SEC
+            },
+            "witness": {
+                "account": "das00005.bit",
+                "status": (AccountStatus::LockedForCrossChain as u8),
+                // Simulate not recording the chain which the new AccountCell is locked to.
+                "registered_at": TIMESTAMP,
+                "records": [
+                    {
+                        "type": "address",
+                        "key": "60",
+                        "label": "Personal",
+                        "value": "0xffff000000000000000000000000000000000005",
+                    }
+                ]
+            }
+        }),
+    );
+
+    push_output_slice_1(&mut template);
+    push_output_income_cell_with_profit(&mut template);
+    push_output_normal_cell_with_refund(&mut template);
+
+    challenge_tx(template.as_json(), ErrorCode::ProposalConfirmNewAccountWitnessError);
+}
+
 #[test]
 fn challenge_proposal_confirm_new_account_with_cross_chain() {
     let mut template = init_with_confirm();
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
 
     // inputs
     push_input_proposal_cell_with_slices(&mut template);
@@ -1074,7 +1193,7 @@ fn challenge_proposal_confirm_new_account_with_cross_chain() {
                 ],
                 "initial_cross_chain": {
                     "checked": 1,
-                    "coin_type": 1,
+                    "coin_type": 60,
                     "chain_id": 1,
                 }
             }
@@ -1139,3 +1258,267 @@ fn challenge_proposal_confirm_new_account_with_cross_chain() {
 
     challenge_tx(template.as_json(), ErrorCode::ProposalConfirmNewAccountWitnessError);
 }
+
+#[test]
+fn challenge_proposal_confirm_new_account_with_unsupported_cross_chain() {
+    let mut template = init_with_confirm();
+    template.push_config_cell(DataType::ConfigCellCrossChain, Source::CellDep);
+
+    // inputs
+    push_input_proposal_cell_with_slices(&mut template);
+
+    let lock_scripts = gen_lock_scripts();
+    push_input_account_cell_v2(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x000000000000000000000000000000000000001111",
+                "manager_lock_args": "0x000000000000000000000000000000000000001111"
+            },
+            "data": {
+                "account": "das00012.bit",
+                "next": "das00002.bit"
+            },
+            "witness": {
+                "account": "das00012.bit",
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+    push_input_pre_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_register_fee_v2("das00005.bit", 8, true),
+            "witness": {
+                "account": "das00005.bit",
+                "owner_lock_args": gen_das_lock_args("0x05ffff000000000000000000000000000000000005", None),
+                "inviter_lock": lock_scripts.inviter_1,
+                "channel_lock": lock_scripts.channel_1,
+                "created_at": TIMESTAMP - HOUR_SEC,
+                "initial_records": [
+                    {
+                        "type": "address",
+                        "key": "60",
+                        "label": "Personal",
+                        "value": "0xffff000000000000000000000000000000000005",
+                    }
+                ],
+                // Simulate the initial_cross_chain is not listed in ConfigCellCrossChain.chains.
+                "initial_cross_chain": {
+                    "checked": 1,
+                    "coin_type": 60,
+                    "chain_id": 2,
+                }
+            }
+        }),
+        None,
+    );
+
+    push_input_slice_1(&mut template);
+
+    // outputs
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x000000000000000000000000000000000000001111",
+                "manager_lock_args": "0x000000000000000000000000000000000000001111"
+            },
+            "data": {
+                "account": "das00012.bit",
+                "next": "das00005.bit"
+            },
+            "witness": {
+                "account": "das00012.bit",
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+    push_output_account_cell(
+        &mut template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x05ffff000000000000000000000000000000000005",
+                "manager_lock_args": "0x05ffff000000000000000000000000000000000005"
+            },
+            "data": {
+                "account": "das00005.bit",
+                "next": "das00002.bit",
+                "expired_at": TIMESTAMP + YEAR_SEC
+            },
+            "witness": {
+                "account": "das00005.bit",
+                "status": (AccountStatus::LockedForCrossChain as u8),
+                "registered_at": TIMESTAMP,
+                "records": [
+                    {
+                        "type": "address",
+                        "key": "60",
+                        "label": "Personal",
+                        "value": "0xffff000000000000000000000000000000000005",
+                    }
+                ]
+            }
+        }),
+    );
+
+    push_output_slice_1(&mut template);
+    push_output_income_cell_with_profit(&mut template);
+    push_output_normal_cell_with_refund(&mut template);
+
+    challenge_tx(template.as_json(), ErrorCode::CrossChainNotSupported);
+}
+
+fn push_input_slice_0_paid_in_udt(template: &mut TemplateGenerator) {
+    let lock_scripts = gen_lock_scripts();
+
+    push_input_account_cell_v2(
+        template,
+        json!({
+            "capacity": util::gen_account_cell_capacity(8),
+            "lock": {
+                "owner_lock_args": "0x000000000000000000000000000000000000001111",
+                "manager_lock_args": "0x000000000000000000000000000000000000001111"
+            },
+            "data": {
+                "account": "das00012.bit",
+                "next": "das00002.bit"
+            },
+            "witness": {
+                "account": "das00012.bit",
+                "status": (AccountStatus::Normal as u8)
+            }
+        }),
+    );
+    push_input_pre_account_cell(
+        template,
+        json!({
+            // The PreAccountCell only contains the capacity for storage when the registration is paid in UDT.
+            "capacity": util::gen_account_cell_capacity(8),
+            "witness": {
+                "account": "das00005.bit",
+                "owner_lock_args": gen_das_lock_args("0x05ffff000000000000000000000000000000000005", None),
+                "inviter_lock": lock_scripts.inviter_1,
+                "channel_lock": lock_scripts.channel_1,
+                "created_at": TIMESTAMP - HOUR_SEC,
+                "quote": UDT_QUOTE,
+                "initial_records": [
+                    {
+                        "type": "address",
+                        "key": "60",
+                        "label": "Personal",
+                        "value": "0xffff000000000000000000000000000000000005",
+                    }
+                ],
+                // The UDT worth the same as the registration fee in CKB, so the profits should be the same too.
+                "paid_udt": {
+                    "type_script": {
+                        "code_hash": "{{always_success}}",
+                        "args": UDT_ARGS
+                    },
+                    "amount": ACCOUNT_PRICE_5_CHAR * (RATE_BASE - INVITED_DISCOUNT) / RATE_BASE
+                }
+            }
+        }),
+        None,
+    );
+}
+
+// The profits of the inviter, the channel and the proposer of the registration paid in UDT.
+const UDT_PAID_OTHERS_PROFIT: u64 = 38000000000 + 38000000000 + 19000000000;
+
+#[test]
+fn test_proposal_confirm_new_account_paid_in_udt() {
+    let mut template = init_with_confirm();
+
+    // inputs
+    push_input_proposal_cell_with_slices(&mut template);
+    push_input_slice_0_paid_in_udt(&mut template);
+    push_input_slice_1(&mut template);
+    // The DAS wallet pays for the profits of the others, because the UDT is received by it in the pre_register transaction.
+    push_input_normal_cell(&mut template, UDT_PAID_OTHERS_PROFIT, DAS_WALLET_LOCK_ARGS);
+
+    // outputs
+    push_output_slice_0(&mut template);
+    push_output_slice_1(&mut template);
+    push_output_income_cell_with_profit(&mut template);
+    push_output_normal_cell_with_refund(&mut template);
+
+    test_tx(template.as_json());
+}
+
+#[test]
+fn challenge_proposal_confirm_new_account_paid_in_udt_without_das_wallet() {
+    let mut template = init_with_confirm();
+
+    // inputs
+    push_input_proposal_cell_with_slices(&mut template);
+    push_input_slice_0_paid_in_udt(&mut template);
+    push_input_slice_1(&mut template);
+    // Simulate the DAS wallet paying less than the profits of the others.
+    push_input_normal_cell(&mut template, UDT_PAID_OTHERS_PROFIT - 1, DAS_WALLET_LOCK_ARGS);
+
+    // outputs
+    push_output_slice_0(&mut template);
+    push_output_slice_1(&mut template);
+    push_output_income_cell_with_profit(&mut template);
+    push_output_normal_cell_with_refund(&mut template);
+
+    challenge_tx(template.as_json(), ErrorCode::UdtPaymentError);
+}
+
+#[test]
+fn challenge_proposal_confirm_new_account_paid_in_udt_profit_mismatch() {
+    let mut template = init_with_confirm();
+    let lock_scripts = gen_lock_scripts();
+
+    // inputs
+    push_input_proposal_cell_with_slices(&mut template);
+    push_input_slice_0_paid_in_udt(&mut template);
+    push_input_slice_1(&mut template);
+    push_input_normal_cell(&mut template, UDT_PAID_OTHERS_PROFIT, DAS_WALLET_LOCK_ARGS);
+
+    // outputs
+    push_output_slice_0(&mut template);
+    push_output_slice_1(&mut template);
+    push_output_income_cell(
+        &mut template,
+        json!({
+            "witness": {
+                "records": [
+                    // Simulate the profit of the inviter is less than expected when the registration is paid in UDT.
+                    {
+                        "belong_to": lock_scripts.inviter_1,
+                        "capacity": 38000000000u64 - 1
+                    },
+                    {
+                        "belong_to": lock_scripts.inviter_2,
+                        "capacity": 38000000000u64 * 2
+                    },
+                    {
+                        "belong_to": lock_scripts.channel_1,
+                        "capacity": 38000000000u64
+                    },
+                    {
+                        "belong_to": lock_scripts.channel_2,
+                        "capacity": 38000000000u64 * 2
+                    },
+                    {
+                        "belong_to": lock_scripts.proposer,
+                        "capacity": 19000000000u64 * 3
+                    },
+                    {
+                        "belong_to": lock_scripts.das_wallet,
+                        "capacity": 380000000000u64 * 3
+                    }
+                ]
+            }
+        }),
+    );
+    push_output_normal_cell_with_refund(&mut template);
+
+    challenge_tx(template.as_json(), ErrorCode::IncomeCellProfitMismatch);
+}
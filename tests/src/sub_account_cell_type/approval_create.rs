@@ -60,7 +60,7 @@ fn push_simple_sub_account_witness(template: &mut TemplateGenerator, sub_account
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "suffix": SUB_ACCOUNT_SUFFIX,
             "registered_at": TIMESTAMP,
@@ -155,7 +155,7 @@ fn test_sub_account_approval_create_edit_records() {
         "sign_role": "0x01",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 2,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -209,7 +209,7 @@ fn challenge_sub_account_approval_create_role_error() {
         "sign_role": "0x01",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -336,7 +336,7 @@ fn challenge_sub_account_approval_create_edit_key_error() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -380,7 +380,7 @@ fn challenge_sub_account_approval_create_edit_value_is_null() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -410,7 +410,7 @@ fn challenge_sub_account_approval_create_action_error() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -455,7 +455,7 @@ fn challenge_sub_account_approval_create_platform_lock_error() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -503,7 +503,7 @@ fn challenge_sub_account_approval_create_protected_until_too_long() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -551,7 +551,7 @@ fn challenge_sub_account_approval_create_sealed_until_too_long() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -599,7 +599,7 @@ fn challenge_sub_account_approval_create_delay_count_invalid() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -647,7 +647,7 @@ fn challenge_sub_account_approval_create_to_lock_invalid() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 1,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
@@ -76,7 +76,7 @@ fn push_simple_sub_account_witness(template: &mut TemplateGenerator, sub_account
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 2,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "suffix": SUB_ACCOUNT_SUFFIX,
             "registered_at": TIMESTAMP,
@@ -236,7 +236,7 @@ fn challenge_sub_account_approval_revoke_protected_approval() {
         "sign_role": "0x00",
         "sign_expired_at": TIMESTAMP,
         "old_sub_account_version": 2,
-        "new_sub_account_version": 2,
+        "new_sub_account_version": 3,
         "sub_account": {
             "lock": {
                 "owner_lock_args": OWNER_1,
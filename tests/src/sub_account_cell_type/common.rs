@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use das_types::constants::*;
 use serde_json::json;
 use sparse_merkle_tree::H256;
@@ -48,7 +50,14 @@ pub fn init_config(action: &str, params_opt: Option<&str>) -> TemplateGenerator
 }
 
 pub fn init_update() -> TemplateGenerator {
+    init_update_with_record_value_validators(HashMap::new())
+}
+
+pub fn init_update_with_record_value_validators(
+    record_value_validators: HashMap<String, RecordValueValidator>,
+) -> TemplateGenerator {
     let mut template = init("update_sub_account", None);
+    template.record_value_validators = record_value_validators;
 
     template.push_contract_cell("ckb_sign.so", ContractType::SharedLib);
     template.push_contract_cell("eth_sign.so", ContractType::SharedLib);
@@ -59,11 +68,11 @@ pub fn init_update() -> TemplateGenerator {
     template.push_config_cell(DataType::ConfigCellCharSetEmoji, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellCharSetDigit, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellCharSetEn, Source::CellDep);
-    template.push_config_cell(DataType::ConfigCellUnAvailableAccount, Source::CellDep);
 
     template.push_config_cell(DataType::ConfigCellAccount, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellSubAccount, Source::CellDep);
     template.push_config_cell(DataType::ConfigCellRecordKeyNamespace, Source::CellDep);
+    template.push_config_cell(DataType::ConfigCellConfusables, Source::CellDep);
 
     template
 }
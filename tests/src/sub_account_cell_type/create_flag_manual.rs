@@ -398,6 +398,52 @@ fn challenge_sub_account_create_flag_manual_undefined_char() {
     challenge_tx(template.as_json(), ErrorCode::ConfigIsPartialMissing);
 }
 
+#[test]
+fn challenge_sub_account_create_flag_manual_confusable_account() {
+    let mut template = before_each();
+    template.push_config_cell(DataType::ConfigCellCharSetRu, Source::CellDep);
+    let account = "сору2023.xxxxx.bit";
+
+    // outputs
+    let smt = template.push_sub_account_mint_sign_witness(
+        DataType::SubAccountMintSign,
+        json!({
+            "version": 1,
+            "expired_at": TIMESTAMP + DAY_SEC,
+            "account_list_smt_root": [
+                [account, gen_das_lock_args(OWNER_1, Some(MANAGER_1))],
+            ]
+        }),
+    );
+    template.push_sub_account_witness_v2(json!({
+        "action": SubAccountAction::Create.to_string(),
+        "sub_account": {
+            "lock": {
+                "owner_lock_args": OWNER_1,
+                "manager_lock_args": MANAGER_1
+            },
+            // Simulate the sub-account looks the same as the protected account copy2023 .
+            "account": [
+                { "char": "с", "type": CharSetType::Ru as u32 },
+                { "char": "о", "type": CharSetType::Ru as u32 },
+                { "char": "р", "type": CharSetType::Ru as u32 },
+                { "char": "у", "type": CharSetType::Ru as u32 },
+                { "char": "2", "type": CharSetType::Digit as u32 },
+                { "char": "0", "type": CharSetType::Digit as u32 },
+                { "char": "2", "type": CharSetType::Digit as u32 },
+                { "char": "3", "type": CharSetType::Digit as u32 },
+            ],
+            "suffix": SUB_ACCOUNT_SUFFIX,
+            "registered_at": TIMESTAMP,
+            "expired_at": TIMESTAMP + YEAR_SEC,
+        },
+        "edit_value": get_compiled_proof(&smt, account)
+    }));
+    push_common_output_cells(&mut template, 1, SubAccountConfigFlag::Manual);
+
+    challenge_tx(template.as_json(), ErrorCode::AccountIsConfusable);
+}
+
 #[test]
 fn challenge_sub_account_create_flag_manual_too_long() {
     let mut template = before_each();
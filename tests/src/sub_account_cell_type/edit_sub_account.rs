@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use das_types::constants::*;
 use serde_json::{json, Value};
 
@@ -11,7 +13,13 @@ use crate::util::template_parser::*;
 use crate::util::{self};
 
 fn before_each() -> TemplateGenerator {
-    let mut template = init_update();
+    before_each_with_record_value_validators(HashMap::new())
+}
+
+fn before_each_with_record_value_validators(
+    record_value_validators: HashMap<String, RecordValueValidator>,
+) -> TemplateGenerator {
+    let mut template = init_update_with_record_value_validators(record_value_validators);
 
     // cell_deps
     push_simple_dep_account_cell(&mut template);
@@ -463,6 +471,53 @@ fn challenge_sub_account_edit_records_invalid_key() {
     challenge_tx(template.as_json(), AccountCellErrorCode::AccountCellRecordKeyInvalid);
 }
 
+fn gen_edit_records_tx_with_evm_validator(value: &str) -> Value {
+    let mut template = before_each_with_record_value_validators(HashMap::from([(
+        String::from("address.eth"),
+        RecordValueValidator::EvmAddress,
+    )]));
+
+    // outputs
+    push_simple_sub_account_witness(
+        &mut template,
+        json!({
+            "sign_role": "0x01",
+            "sub_account": {
+                "lock": {
+                    "owner_lock_args": OWNER_1,
+                    "manager_lock_args": MANAGER_1
+                },
+                "account": SUB_ACCOUNT_1,
+            },
+            "edit_key": "records",
+            "edit_value": [
+                {
+                    "type": "address",
+                    "key": "eth",
+                    "label": "Personal",
+                    "value": util::bytes_to_hex(value.as_bytes()),
+                }
+            ]
+        }),
+    );
+    push_simple_output_sub_account_cell(&mut template, 0, 0, SubAccountConfigFlag::CustomRule);
+
+    template.as_json()
+}
+
+#[test]
+fn test_sub_account_edit_records_with_validated_value() {
+    let tx = gen_edit_records_tx_with_evm_validator("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    test_tx(tx);
+}
+
+#[test]
+fn challenge_sub_account_edit_records_invalid_value() {
+    // Simulate the EIP-55 checksum of the address is broken.
+    let tx = gen_edit_records_tx_with_evm_validator("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordValueInvalid);
+}
+
 #[test]
 fn challenge_sub_account_edit_records_invalid_role() {
     let mut template = before_each();
@@ -582,3 +637,175 @@ fn challenge_sub_account_edit_has_expired() {
 
     challenge_tx(template.as_json(), SubAccountCellErrorCode::AccountHasInGracePeriod);
 }
+
+fn gen_edit_records_tx_by_delegate(sign_role: &str, records: Value) -> Value {
+    let mut template = init_update();
+
+    // cell_deps
+    push_simple_dep_account_cell(&mut template);
+
+    // inputs
+    let sub_account = json!({
+        "lock": {
+            "owner_lock_args": OWNER_1,
+            "manager_lock_args": MANAGER_1
+        },
+        "account": SUB_ACCOUNT_1,
+        "suffix": SUB_ACCOUNT_SUFFIX,
+        "registered_at": TIMESTAMP,
+        "expired_at": TIMESTAMP + YEAR_SEC,
+        "records": [
+            {
+                "type": "address",
+                "key": "eth",
+                "label": "Personal",
+                "value": "0x0000000000000000000000000000000000000000",
+            },
+        ],
+        "records_delegates": [
+            {
+                "lock_args": OWNER_2,
+                "key_prefixes": ["profile."]
+            }
+        ]
+    });
+    template.restore_sub_account_v3(vec![sub_account.clone()]);
+    push_simple_input_sub_account_cell(&mut template, 0, 0, SubAccountConfigFlag::CustomRule);
+
+    // outputs
+    push_simple_sub_account_witness(
+        &mut template,
+        json!({
+            "sign_role": sign_role,
+            "old_sub_account_version": 3,
+            "new_sub_account_version": 3,
+            "sub_account": sub_account,
+            "edit_key": "records",
+            "edit_value": records
+        }),
+    );
+    push_simple_output_sub_account_cell(&mut template, 0, 0, SubAccountConfigFlag::CustomRule);
+
+    template.as_json()
+}
+
+#[test]
+fn test_sub_account_edit_records_by_delegate() {
+    let tx = gen_edit_records_tx_by_delegate(
+        "0x0200",
+        json!([
+            {
+                "type": "address",
+                "key": "eth",
+                "label": "Personal",
+                "value": "0x0000000000000000000000000000000000000000",
+            },
+            {
+                "type": "profile",
+                "key": "twitter",
+                "label": "",
+                "value": "0x00",
+            }
+        ]),
+    );
+
+    test_tx(tx)
+}
+
+#[test]
+fn challenge_sub_account_edit_records_by_delegate_not_found() {
+    // Simulate the index of the delegate is out of SubAccount.records_delegates.
+    let tx = gen_edit_records_tx_by_delegate(
+        "0x0201",
+        json!([
+            {
+                "type": "profile",
+                "key": "twitter",
+                "label": "",
+                "value": "0x00",
+            }
+        ]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellPermissionDenied)
+}
+
+#[test]
+fn challenge_sub_account_edit_records_by_delegate_key_not_delegated() {
+    // Simulate the delegate removes a record out of its key prefixes.
+    let tx = gen_edit_records_tx_by_delegate(
+        "0x0200",
+        json!([
+            {
+                "type": "profile",
+                "key": "twitter",
+                "label": "",
+                "value": "0x00",
+            }
+        ]),
+    );
+
+    challenge_tx(tx, AccountCellErrorCode::AccountCellRecordKeyNotDelegated)
+}
+
+#[test]
+fn test_sub_account_edit_records_delegates() {
+    let mut template = before_each();
+
+    // outputs
+    push_simple_sub_account_witness(
+        &mut template,
+        json!({
+            "sub_account": {
+                "lock": {
+                    "owner_lock_args": OWNER_1,
+                    "manager_lock_args": MANAGER_1
+                },
+                "account": SUB_ACCOUNT_1,
+            },
+            "edit_key": "records_delegates",
+            "edit_value": [
+                {
+                    "lock_args": OWNER_2,
+                    "key_prefixes": ["profile.", "dweb."]
+                }
+            ]
+        }),
+    );
+    push_simple_output_sub_account_cell(&mut template, 0, 0, SubAccountConfigFlag::CustomRule);
+
+    test_tx(template.as_json())
+}
+
+#[test]
+fn challenge_sub_account_edit_records_delegates_empty_key_prefixes() {
+    let mut template = before_each();
+
+    // outputs
+    push_simple_sub_account_witness(
+        &mut template,
+        json!({
+            "sub_account": {
+                "lock": {
+                    "owner_lock_args": OWNER_1,
+                    "manager_lock_args": MANAGER_1
+                },
+                "account": SUB_ACCOUNT_1,
+            },
+            "edit_key": "records_delegates",
+            "edit_value": [
+                {
+                    "lock_args": OWNER_2,
+                    // Simulate delegating nothing to the delegate.
+                    "key_prefixes": []
+                }
+            ]
+        }),
+    );
+    push_simple_output_sub_account_cell(&mut template, 0, 0, SubAccountConfigFlag::CustomRule);
+
+    challenge_tx(
+        template.as_json(),
+        AccountCellErrorCode::AccountCellRecordsDelegateInvalid,
+    )
+}
@@ -0,0 +1,31 @@
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+use serde_json::Value;
+
+const MOCK_TX: &str = include_str!("../data/tx_inspector_mock_tx.json");
+
+#[test]
+fn test_tx_inspector_round_trip() {
+    let raw: Value = serde_json::from_str(MOCK_TX).unwrap();
+    let repr_tx: ReprMockTransaction = serde_json::from_value(raw.clone()).unwrap();
+    let mock_tx = MockTransaction::from(repr_tx);
+
+    // The mock transaction should be encoded back to the same JSON.
+    let encoded = serde_json::to_value(ReprMockTransaction::from(mock_tx.clone())).unwrap();
+    assert_eq!(encoded, raw);
+
+    let report = tx_inspector::inspect(mock_tx).expect("The mock transaction should be inspected.");
+
+    let lines = report.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "Transaction: 0x15c3b432db3e4234c385eb2a021cb284eceee135e438ca9da111fbe75338927f"
+    );
+    assert!(lines.contains(&"  action: transfer"));
+    assert!(lines.contains(&"  role: Owner"));
+    assert!(lines.contains(&"Input:"));
+    assert!(lines.contains(&"  [0] normal cell capacity: 20000000000"));
+    assert!(lines.contains(&"Output:"));
+    assert!(lines.contains(&"  [0] normal cell capacity: 19999990000"));
+    assert!(lines.contains(&"Witnesses:"));
+    assert!(lines.iter().any(|line| line.starts_with("  [1] ActionData")));
+}
@@ -45,6 +45,9 @@ pub const ACCOUNT_2: &str = "yyyyy.bit";
 pub const ACCOUNT_3: &str = "zzzzz.bit";
 
 pub const CROSS_CHAIN_BLACK_ACCOUNT: &str = "0x030000000000000000000000000000000000000000";
+pub const RELAYER_1: &str = "0x05EEEE000000000000000000000000000000000001";
+pub const RELAYER_2: &str = "0x05EEEE000000000000000000000000000000000002";
+pub const RELAYER_3: &str = "0x05EEEE000000000000000000000000000000000003";
 
 pub const SUB_ACCOUNT_1: &str = "00000.xxxxx.bit";
 pub const SUB_ACCOUNT_1_ID: &str = "0xb5e8f063fe55e67fe87310ddde7d05fea4dbe282";
@@ -14,6 +14,16 @@ pub const USD_20: u64 = 20 * USD_1;
 
 pub const ONE_CKB: u64 = 100_000_000;
 pub const CKB_QUOTE: u64 = 1000;
+pub const ORACLE_MIN_CELLS: u8 = 1;
+pub const ORACLE_MAX_AGE: u64 = HOUR_SEC;
+// The oracle providers are indexed from 1, and the lock of each provider is always_success with its index as args.
+pub const ORACLE_PROVIDERS: u8 = 5;
+// The timestamp of the block which OracleCells are committed in, it is in milliseconds like the headers on-chain.
+pub const ORACLE_UPDATED_AT: u64 = TIMESTAMP * 1000;
+// The UDT for payments is a USD stablecoin with 6 decimals, so its equivalent quote is 1 USD per 1 UDT.
+pub const UDT_ARGS: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+pub const UDT_DECIMALS: u8 = 6;
+pub const UDT_QUOTE: u64 = 100_000_000;
 
 pub const APPLY_MIN_WAITING_BLOCK: u64 = 1;
 pub const APPLY_MAX_WAITING_BLOCK: u64 = 5760;
@@ -27,6 +37,8 @@ pub const ACCOUNT_EXPIRATION_AUCTION_PERIOD: u64 = 2_588_400;
 pub const ACCOUNT_EXPIRATION_DELIVER_PERIOD: u64 = 3600;
 pub const ACCOUNT_EXPIRATION_AUCTION_START_PREMIUMS: u64 = 100_000_000;
 
+pub const CROSS_CHAIN_LOCK_FEE: u64 = 100_000;
+
 pub const ACCOUNT_PRICE_1_CHAR: u64 = 0;
 pub const ACCOUNT_PRICE_2_CHAR: u64 = 1000_000_000;
 pub const ACCOUNT_PRICE_3_CHAR: u64 = 700_000_000;
@@ -43,6 +55,8 @@ pub const PRE_ACCOUNT_SHORT_TIMEOUT_LIMIT: u64 = HOUR_SEC;
 pub const PRE_ACCOUNT_REFUND_AVAILABLE_FEE: u64 = DAY_SEC;
 
 pub const INCOME_BASIC_CAPACITY: u64 = 20_000_000_000;
+pub const INCOME_MAX_RECORDS: u32 = 50;
+pub const INCOME_MIN_TRANSFER_CAPACITY: u64 = 10_000_000_000;
 
 pub const SALE_BUYER_INVITER_PROFIT_RATE: u64 = 100;
 pub const SALE_BUYER_CHANNEL_PROFIT_RATE: u64 = 100;
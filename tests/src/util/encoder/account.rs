@@ -58,6 +58,36 @@ pub fn to_v3(path: &str, value: &Value) -> AccountCellDataV3 {
         .build()
 }
 
+pub fn to_v4(path: &str, value: &Value) -> AccountCellDataV4 {
+    let (
+        _account,
+        account_chars,
+        account_id,
+        registered_at,
+        last_transfer_account_at,
+        last_edit_manager_at,
+        last_edit_records_at,
+        status,
+        records,
+    ) = encode_common_fields(path, value);
+    let (enable_sub_account, renew_sub_account_price) = encode_v3_fields(path, value);
+    let approval = encode_v4_fields(path, value);
+
+    AccountCellDataV4::new_builder()
+        .id(account_id)
+        .account(account_chars)
+        .registered_at(registered_at)
+        .last_transfer_account_at(last_transfer_account_at)
+        .last_edit_manager_at(last_edit_manager_at)
+        .last_edit_records_at(last_edit_records_at)
+        .status(status)
+        .records(records)
+        .enable_sub_account(enable_sub_account)
+        .renew_sub_account_price(renew_sub_account_price)
+        .approval(approval)
+        .build()
+}
+
 pub fn to_latest(path: &str, value: &Value) -> AccountCellData {
     let (
         _account,
@@ -72,6 +102,11 @@ pub fn to_latest(path: &str, value: &Value) -> AccountCellData {
     ) = encode_common_fields(path, value);
     let (enable_sub_account, renew_sub_account_price) = encode_v3_fields(path, value);
     let approval = encode_v4_fields(path, value);
+    let cross_chain = if value["cross_chain"].is_null() {
+        ChainId::default()
+    } else {
+        util::parse_json_to_chain_id_mol(&format!("{}.{}", path, "cross_chain"), &value["cross_chain"])
+    };
 
     AccountCellData::new_builder()
         .id(account_id)
@@ -85,6 +120,7 @@ pub fn to_latest(path: &str, value: &Value) -> AccountCellData {
         .enable_sub_account(enable_sub_account)
         .renew_sub_account_price(renew_sub_account_price)
         .approval(approval)
+        .cross_chain(cross_chain)
         .build()
 }
 
@@ -1,3 +1,2 @@
 pub mod account;
 pub mod sub_account;
-pub mod util;
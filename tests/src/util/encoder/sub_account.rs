@@ -1,7 +1,9 @@
 use std::convert::TryFrom;
 use std::str::FromStr;
 
+use das_lv::LvEncode;
 use das_types::constants::*;
+use das_types::lv_witness::RawSubAccountWitness;
 use das_types::mixer::SubAccountMixer;
 use das_types::packed::*;
 use das_types::prelude::*;
@@ -10,7 +12,6 @@ use serde_json::Value;
 
 use super::super::smt::SMTWithHistory;
 use super::super::util;
-use super::util as encoder_util;
 
 pub fn to_v1(path: &str, value: &Value) -> SubAccountV1 {
     let (
@@ -42,6 +43,38 @@ pub fn to_v1(path: &str, value: &Value) -> SubAccountV1 {
         .build()
 }
 
+pub fn to_v2(path: &str, value: &Value) -> SubAccountV2 {
+    let (
+        suffix,
+        lock,
+        account_id,
+        account_chars,
+        registered_at,
+        expired_at,
+        status,
+        records,
+        nonce,
+        enable_sub_account,
+        renew_sub_account_price,
+    ) = encode_v1_fields(path, value);
+    let approval = encode_v2_fields(&format!("{}.approval", path), &value["approval"]);
+
+    SubAccountV2::new_builder()
+        .lock(lock)
+        .id(account_id)
+        .account(account_chars)
+        .suffix(Bytes::from(suffix.as_bytes()))
+        .registered_at(registered_at)
+        .expired_at(expired_at)
+        .status(status)
+        .records(records)
+        .nonce(nonce)
+        .enable_sub_account(enable_sub_account)
+        .renew_sub_account_price(renew_sub_account_price)
+        .approval(approval)
+        .build()
+}
+
 pub fn to_latest(path: &str, value: &Value) -> SubAccount {
     let (
         suffix,
@@ -57,6 +90,8 @@ pub fn to_latest(path: &str, value: &Value) -> SubAccount {
         renew_sub_account_price,
     ) = encode_v1_fields(path, value);
     let approval = encode_v2_fields(&format!("{}.approval", path), &value["approval"]);
+    let records_delegates =
+        util::parse_json_to_records_delegates_mol(&format!("{}.records_delegates", path), &value["records_delegates"]);
 
     let entity = SubAccount::new_builder()
         .lock(lock)
@@ -71,6 +106,7 @@ pub fn to_latest(path: &str, value: &Value) -> SubAccount {
         .enable_sub_account(enable_sub_account)
         .renew_sub_account_price(renew_sub_account_price)
         .approval(approval)
+        .records_delegates(records_delegates)
         .build();
     // println!("entity = {}", entity.as_prettier());
 
@@ -206,22 +242,33 @@ pub fn to_raw_witness_v2(smt_with_history: &mut SMTWithHistory, path: &str, valu
         panic!("witness.sub_account is missing");
     }
 
-    let (action, mut witness_bytes) = encode_raw_witness_common_fields(path, value, Some(2));
+    let common_fields = parse_raw_witness_common_fields(path, value, Some(2));
+    let action = common_fields.action;
 
     let key = get_smt_key_from_json(&format!("{}.sub_account", path), &value["sub_account"]);
     let entity = to_v1(&format!("{}.sub_account", path), &value["sub_account"]);
     let entity_bytes = Entity::as_slice(&entity).to_vec();
-    let (new_root, compiled_proof) =
-        get_smt_new_root_and_proof(&action, smt_with_history, path, key, value, Box::new(entity));
-
-    encode_smt_fields(&mut witness_bytes, new_root, compiled_proof);
-
-    witness_bytes.extend(encoder_util::length_of(&entity_bytes));
-    witness_bytes.extend(entity_bytes);
-
-    encode_edit_fields(&action, path, &mut witness_bytes, &value);
+    let (new_root, proof) = get_smt_new_root_and_proof(&action, smt_with_history, path, key, value, Box::new(entity));
+
+    let action_str = action.to_string();
+    let edit_key = parse_edit_key(path, value);
+    let edit_value = parse_edit_value(&action, path, value);
+    let raw_witness = RawSubAccountWitness {
+        version: common_fields.version,
+        action: &action_str,
+        signature: &common_fields.signature,
+        sign_role: &common_fields.sign_role,
+        sign_expired_at: &common_fields.sign_expired_at,
+        new_root: &new_root,
+        proof: &proof,
+        old_sub_account_version: None,
+        new_sub_account_version: None,
+        sub_account: &entity_bytes,
+        edit_key: edit_key.as_bytes(),
+        edit_value: &edit_value,
+    };
 
-    das_util::wrap_raw_witness_v2(DataType::SubAccount, witness_bytes)
+    das_util::wrap_raw_witness_v2(DataType::SubAccount, raw_witness.encode_lv())
 }
 
 /// v3
@@ -230,7 +277,19 @@ pub fn to_raw_witness_latest(smt_with_history: &mut SMTWithHistory, path: &str,
         panic!("{}.sub_account is missing", path);
     }
 
-    let (action, mut witness_bytes) = encode_raw_witness_common_fields(path, value, Some(3));
+    let common_fields = parse_raw_witness_common_fields(path, value, Some(3));
+    let action = common_fields.action;
+    let old_sub_account_version = util::parse_json_u32(
+        &format!("{}.old_sub_account_version", path),
+        &value["old_sub_account_version"],
+        None,
+    );
+    let new_sub_account_version = util::parse_json_u32(
+        &format!("{}.new_sub_account_version", path),
+        &value["new_sub_account_version"],
+        None,
+    );
+
     let (entity, entity_bytes) = match action {
         SubAccountAction::Create => {
             let entity = to_latest(&format!("{}.sub_account", path), &value["sub_account"]);
@@ -239,16 +298,16 @@ pub fn to_raw_witness_latest(smt_with_history: &mut SMTWithHistory, path: &str,
             (entity, entity_bytes)
         }
         _ => {
-            let old_sub_account_version = util::parse_json_u32(
-                &format!("{}.old_sub_account_version", path),
-                &value["old_sub_account_version"],
-                None,
-            );
             if old_sub_account_version == 1 {
                 let entity = to_v1(&format!("{}.sub_account", path), &value["sub_account"]);
                 let entity_bytes = Entity::as_slice(&entity).to_vec();
                 let entity: Box<dyn SubAccountMixer> = Box::new(entity);
                 (entity, entity_bytes)
+            } else if old_sub_account_version == 2 {
+                let entity = to_v2(&format!("{}.sub_account", path), &value["sub_account"]);
+                let entity_bytes = Entity::as_slice(&entity).to_vec();
+                let entity: Box<dyn SubAccountMixer> = Box::new(entity);
+                (entity, entity_bytes)
             } else {
                 let entity = to_latest(&format!("{}.sub_account", path), &value["sub_account"]);
                 let entity_bytes = Entity::as_slice(&entity).to_vec();
@@ -259,73 +318,54 @@ pub fn to_raw_witness_latest(smt_with_history: &mut SMTWithHistory, path: &str,
     };
 
     let key = get_smt_key_from_json(&format!("{}.sub_account", path), &value["sub_account"]);
-    let (new_root, compiled_proof) = get_smt_new_root_and_proof(&action, smt_with_history, path, key, value, entity);
-
-    encode_smt_fields(&mut witness_bytes, new_root, compiled_proof);
-    encode_v3_fields(&mut witness_bytes, path, value);
-
-    witness_bytes.extend(encoder_util::length_of(&entity_bytes));
-    witness_bytes.extend(entity_bytes);
-
-    encode_edit_fields(&action, path, &mut witness_bytes, &value);
+    let (new_root, proof) = get_smt_new_root_and_proof(&action, smt_with_history, path, key, value, entity);
+
+    let action_str = action.to_string();
+    let edit_key = parse_edit_key(path, value);
+    let edit_value = parse_edit_value(&action, path, value);
+    let raw_witness = RawSubAccountWitness {
+        version: common_fields.version,
+        action: &action_str,
+        signature: &common_fields.signature,
+        sign_role: &common_fields.sign_role,
+        sign_expired_at: &common_fields.sign_expired_at,
+        new_root: &new_root,
+        proof: &proof,
+        old_sub_account_version: Some(old_sub_account_version),
+        new_sub_account_version: Some(new_sub_account_version),
+        sub_account: &entity_bytes,
+        edit_key: edit_key.as_bytes(),
+        edit_value: &edit_value,
+    };
 
-    das_util::wrap_raw_witness_v2(DataType::SubAccount, witness_bytes)
+    das_util::wrap_raw_witness_v2(DataType::SubAccount, raw_witness.encode_lv())
 }
 
-fn encode_raw_witness_common_fields(
-    path: &str,
-    value: &Value,
-    default_version: Option<u32>,
-) -> (SubAccountAction, Vec<u8>) {
-    let mut witness_bytes = Vec::new();
-
-    let field_value =
-        util::parse_json_u32(&format!("{}.version", path), &value["version"], default_version).to_le_bytes();
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
+struct RawWitnessCommonFields {
+    version: u32,
+    action: SubAccountAction,
+    signature: Vec<u8>,
+    sign_role: Vec<u8>,
+    sign_expired_at: [u8; 8],
+}
 
+fn parse_raw_witness_common_fields(path: &str, value: &Value, default_version: Option<u32>) -> RawWitnessCommonFields {
+    let version = util::parse_json_u32(&format!("{}.version", path), &value["version"], default_version);
     let action = SubAccountAction::from_str(value["action"].as_str().expect("witness.action should be a valid str."))
         .expect("witness.action should be a valid SubAccountAction.");
-
-    let action_str = action.clone().to_string();
-    witness_bytes.extend(encoder_util::length_of(action_str.as_bytes()));
-    witness_bytes.extend(action_str.as_bytes());
-
-    let field_value =
+    let signature =
         util::parse_json_hex_with_default(&format!("{}.signature", path), &value["signature"], vec![255u8; 65]);
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
-
-    let field_value = util::parse_json_hex_with_default(&format!("{}.sign_role", path), &value["sign_role"], vec![0]);
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
-
-    let field_value =
+    let sign_role = util::parse_json_hex_with_default(&format!("{}.sign_role", path), &value["sign_role"], vec![0]);
+    let sign_expired_at =
         util::parse_json_u64(&format!("{}.sign_expired_at", path), &value["sign_expired_at"], Some(0)).to_le_bytes();
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
-
-    (action, witness_bytes)
-}
-
-fn encode_v3_fields(witness_bytes: &mut Vec<u8>, path: &str, value: &Value) {
-    let old_sub_account_version = util::parse_json_u32(
-        &format!("{}.old_sub_account_version", path),
-        &value["old_sub_account_version"],
-        None,
-    );
-    let field_value = old_sub_account_version.to_le_bytes();
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
 
-    let field_value = util::parse_json_u32(
-        &format!("{}.new_sub_account_version", path),
-        &value["new_sub_account_version"],
-        None,
-    )
-    .to_le_bytes();
-    witness_bytes.extend(encoder_util::length_of(&field_value));
-    witness_bytes.extend(field_value);
+    RawWitnessCommonFields {
+        version,
+        action,
+        signature,
+        sign_role,
+        sign_expired_at,
+    }
 }
 
 fn get_smt_key_from_json(path: &str, sub_account_value: &Value) -> [u8; 32] {
@@ -365,6 +405,25 @@ fn get_smt_new_root_and_proof(
             .enable_sub_account(sub_account.enable_sub_account().clone())
             .renew_sub_account_price(sub_account.renew_sub_account_price().clone())
             .build()
+    } else if sub_account.version() == 2 {
+        let sub_account = sub_account
+            .try_into_v2()
+            .expect("The SubAccount should be the latest version.");
+
+        SubAccount::new_builder()
+            .lock(sub_account.lock().clone())
+            .id(sub_account.id().clone())
+            .account(sub_account.account().clone())
+            .suffix(sub_account.suffix().clone())
+            .registered_at(sub_account.registered_at().clone())
+            .expired_at(sub_account.expired_at().clone())
+            .status(sub_account.status().clone())
+            .records(sub_account.records().clone())
+            .nonce(sub_account.nonce().clone())
+            .enable_sub_account(sub_account.enable_sub_account().clone())
+            .renew_sub_account_price(sub_account.renew_sub_account_price().clone())
+            .approval(sub_account.approval().clone())
+            .build()
     } else {
         sub_account
             .try_into_latest()
@@ -396,6 +455,13 @@ fn get_smt_new_root_and_proof(
                     let mol = util::parse_json_to_records_mol(&format!("{}.edit_value", path), &value["edit_value"]);
                     builder = builder.records(mol)
                 }
+                "records_delegates" => {
+                    let mol = util::parse_json_to_records_delegates_mol(
+                        &format!("{}.edit_value", path),
+                        &value["edit_value"],
+                    );
+                    builder = builder.records_delegates(mol)
+                }
                 "expired_at" => {
                     let expired_at = util::parse_json_u64(&format!("{}.edit_value", path), &value["edit_value"], None);
                     builder = builder.expired_at(Uint64::from(expired_at));
@@ -466,77 +532,72 @@ fn get_smt_new_root_and_proof(
     (new_root, compiled_proof)
 }
 
-fn encode_smt_fields(witness_bytes: &mut Vec<u8>, new_root: [u8; 32], proof: Vec<u8>) {
-    witness_bytes.extend(encoder_util::length_of(&new_root));
-    witness_bytes.extend(new_root);
-
-    witness_bytes.extend(encoder_util::length_of(&proof));
-    witness_bytes.extend(proof);
+fn parse_edit_key(path: &str, value: &Value) -> String {
+    if value["edit_key"].is_null() {
+        String::new()
+    } else {
+        util::parse_json_str(&format!("{}.edit_key", path), &value["edit_key"]).to_string()
+    }
 }
 
-fn encode_edit_fields(action: &SubAccountAction, path: &str, witness_bytes: &mut Vec<u8>, value: &Value) {
+fn parse_edit_value(action: &SubAccountAction, path: &str, value: &Value) -> Vec<u8> {
     // println!("edit_key: {:?}", value["edit_key"]);
     // println!("edit_value: {:?}", value["edit_value"]);
-    if value["edit_key"].is_null() {
-        witness_bytes.extend(encoder_util::length_of(&[]));
-    } else {
-        let edit_key = util::parse_json_str(&format!("{}.edit_key", path), &value["edit_key"]);
-        witness_bytes.extend(encoder_util::length_of(edit_key.as_bytes()));
-        witness_bytes.extend(edit_key.as_bytes().to_vec());
+    if value["edit_value"].is_null() {
+        return vec![];
     }
 
-    if value["edit_value"].is_null() {
-        witness_bytes.extend(encoder_util::length_of(&[]));
-    } else {
-        let edit_value = match action {
-            SubAccountAction::Renew => {
-                let expired_at = Uint64::from(util::parse_json_u64(
-                    "witness.edit_value.expired_at",
-                    &value["edit_value"]["expired_at"],
-                    None,
-                ));
-                let mut ret = expired_at.as_slice().to_vec();
-                let rest =
-                    util::parse_json_hex_with_default("witness.edit_value.rest", &value["edit_value"]["rest"], vec![]);
-                ret.extend(rest);
-                ret
-            }
-            SubAccountAction::Edit => {
-                // Allow the edit_key field to be an invalid value.
-                let edit_key = util::parse_json_str_with_default(&format!("{}.edit_key", path), &value["edit_key"], "");
-                match edit_key {
-                    "owner" => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
-                    "manager" => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
-                    "records" => {
-                        let mol =
-                            util::parse_json_to_records_mol(&format!("{}.edit_value", path), &value["edit_value"]);
-                        mol.as_slice().to_vec()
-                    }
-                    "expired_at" => {
-                        let ret = util::parse_json_u64(&format!("{}.edit_value", path), &value["edit_value"], None);
-                        ret.to_le_bytes().to_vec()
-                    }
-                    // If the edit_key field is invalid just parse edit_value field as hex string.
-                    _ => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
+    match action {
+        SubAccountAction::Renew => {
+            let expired_at = Uint64::from(util::parse_json_u64(
+                "witness.edit_value.expired_at",
+                &value["edit_value"]["expired_at"],
+                None,
+            ));
+            let mut ret = expired_at.as_slice().to_vec();
+            let rest =
+                util::parse_json_hex_with_default("witness.edit_value.rest", &value["edit_value"]["rest"], vec![]);
+            ret.extend(rest);
+            ret
+        }
+        SubAccountAction::Edit => {
+            // Allow the edit_key field to be an invalid value.
+            let edit_key = util::parse_json_str_with_default(&format!("{}.edit_key", path), &value["edit_key"], "");
+            match edit_key {
+                "owner" => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
+                "manager" => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
+                "records" => {
+                    let mol = util::parse_json_to_records_mol(&format!("{}.edit_value", path), &value["edit_value"]);
+                    mol.as_slice().to_vec()
+                }
+                "records_delegates" => {
+                    let mol = util::parse_json_to_records_delegates_mol(
+                        &format!("{}.edit_value", path),
+                        &value["edit_value"],
+                    );
+                    mol.as_slice().to_vec()
                 }
+                "expired_at" => {
+                    let ret = util::parse_json_u64(&format!("{}.edit_value", path), &value["edit_value"], None);
+                    ret.to_le_bytes().to_vec()
+                }
+                // If the edit_key field is invalid just parse edit_value field as hex string.
+                _ => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
             }
-            SubAccountAction::CreateApproval | SubAccountAction::DelayApproval => {
+        }
+        SubAccountAction::CreateApproval | SubAccountAction::DelayApproval => {
+            let approval = encode_v2_fields(&format!("{}.edit_value", path), &value["edit_value"]);
+            approval.as_slice().to_vec()
+        }
+        SubAccountAction::RevokeApproval | SubAccountAction::FulfillApproval => {
+            if !value["edit_value"].is_null() {
+                // This should not happen, but we still need to build the transaction with the error to test it.
                 let approval = encode_v2_fields(&format!("{}.edit_value", path), &value["edit_value"]);
                 approval.as_slice().to_vec()
+            } else {
+                vec![]
             }
-            SubAccountAction::RevokeApproval | SubAccountAction::FulfillApproval => {
-                if !value["edit_value"].is_null() {
-                    // This should not happen, but we still need to build the transaction with the error to test it.
-                    let approval = encode_v2_fields(&format!("{}.edit_value", path), &value["edit_value"]);
-                    approval.as_slice().to_vec()
-                } else {
-                    vec![]
-                }
-            }
-            _ => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
-        };
-
-        witness_bytes.extend(encoder_util::length_of(&edit_value));
-        witness_bytes.extend(edit_value);
+        }
+        _ => util::parse_json_hex(&format!("{}.edit_value", path), &value["edit_value"]),
     }
 }
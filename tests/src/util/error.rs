@@ -72,6 +72,8 @@ pub enum ErrorCode {
     AccountCharIsInvalid,
     AccountIsTooShort,
     AccountIsTooLong,
+    AccountListProofIsRequired = 70,
+    AccountIsConfusable,
     ProposalSliceIsNotSorted = 90,
     ProposalSliceIsDiscontinuity,
     ProposalSliceRelatedCellNotFound,
@@ -103,6 +105,8 @@ pub enum ErrorCode {
     IncomeCellTransferError,
     IncomeCellCapacityError,
     IncomeCellProfitMismatch,
+    IncomeCellClaimError,
+    IncomeCellClaimCapacityNotEnough,
     EIP712SerializationError = -90,
     EIP712SematicError,
     EIP712DecodingWitnessArgsError,
@@ -130,6 +134,18 @@ pub enum ErrorCode {
     UpgradeDefaultValueOfNewFieldIsError,
     CrossChainLockError,
     CrossChainUnlockError,
+    CrossChainUnlockAttestationIsRequired,
+    CrossChainUnlockAttestationInvalid,
+    CrossChainUnlockThresholdNotReached,
+    CrossChainNotSupported,
+    CrossChainDisabled,
+    UdtPaymentNotSupported,
+    UdtPaymentError,
+    OracleCellsNotEnough,
+    OracleCellIsDuplicated,
+    OracleCellIsStale,
+    DPointPaymentError,
+    OracleCellProviderInvalid,
     UnittestError = -2,
     SystemOff = -1,
 }
@@ -172,6 +188,7 @@ pub enum PreAccountCellErrorCode {
     InviterIdIsInvalid,
     InviteeDiscountShouldBeEmpty,
     InviteeDiscountIsInvalid,
+    UdtIsInsufficient,
 }
 
 impl Into<i8> for PreAccountCellErrorCode {
@@ -235,6 +252,11 @@ pub enum AccountCellErrorCode {
     ApprovalNotRevoked,
     ApprovalInProtectionPeriod,
     ApprovalFulfillError,
+    AccountCellBidPriceTooLow,
+    AccountCellRecordValueInvalid,
+    AccountCellRecordTtlTooShort,
+    AccountCellRecordsDelegateInvalid,
+    AccountCellRecordKeyNotDelegated,
 }
 
 impl Into<i8> for AccountCellErrorCode {
@@ -388,6 +410,10 @@ pub enum DPointCellErrorCode {
     CanNotSpendAnyFee,
     ThereShouldBeSomeDPointCellInInput,
     CapacityRecycleError,
+    PaymentShouldBeTransferredToWhitelist,
+    DPointIsExpired,
+    DPointIsLocked,
+    DPointExpiryCanNotBeExtended,
 }
 
 impl Into<i8> for DPointCellErrorCode {
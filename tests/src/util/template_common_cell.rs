@@ -116,12 +116,13 @@ pub fn push_input_pre_account_cell(template: &mut TemplateGenerator, cell_partia
                 "coin_type": 0,
                 "chain_id": 0,
                 "checked": 0
-            }
+            },
+            "paid_udt": Value::Null
         }
     });
     util::merge_json(&mut cell, cell_partial);
 
-    template.push_input(cell, since, Some(3));
+    template.push_input(cell, since, Some(4));
 }
 
 pub fn push_output_pre_account_cell_v1(template: &mut TemplateGenerator, cell_partial: Value) {
@@ -200,7 +201,7 @@ pub fn push_output_pre_account_cell_v2(template: &mut TemplateGenerator, cell_pa
     template.push_output(cell, Some(2));
 }
 
-pub fn push_output_pre_account_cell(template: &mut TemplateGenerator, cell_partial: Value) {
+pub fn push_output_pre_account_cell_v3(template: &mut TemplateGenerator, cell_partial: Value) {
     let mut cell = json!({
         "capacity": util::gen_register_fee_v2(ACCOUNT_1, 5, false),
         "lock": {
@@ -247,6 +248,54 @@ pub fn push_output_pre_account_cell(template: &mut TemplateGenerator, cell_parti
     template.push_output(cell, Some(3));
 }
 
+pub fn push_output_pre_account_cell(template: &mut TemplateGenerator, cell_partial: Value) {
+    let mut cell = json!({
+        "capacity": util::gen_register_fee_v2(ACCOUNT_1, 5, false),
+        "lock": {
+            "code_hash": "{{always_success}}"
+        },
+        "type": {
+            "code_hash": "{{pre-account-cell-type}}"
+        },
+        "witness": {
+            "account": ACCOUNT_1,
+            "refund_lock": {
+                "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                "args": OWNER_WITHOUT_TYPE
+            },
+            "owner_lock_args": gen_das_lock_args(OWNER, None),
+            "inviter_id": Value::Null,
+            "inviter_lock": Value::Null,
+            "channel_lock": Value::Null,
+            "price": {
+                "length": 5,
+                "new": ACCOUNT_PRICE_5_CHAR,
+                "renew": ACCOUNT_PRICE_5_CHAR
+            },
+            "quote": CKB_QUOTE,
+            "invited_discount": 0,
+            "created_at": TIMESTAMP,
+            "initial_records": [
+                {
+                    "type": "address",
+                    "key": "60",
+                    "label": "Personal",
+                    "value": OWNER_WITHOUT_TYPE,
+                }
+            ],
+            "initial_cross_chain": {
+                "coin_type": 0,
+                "chain_id": 0,
+                "checked": 0
+            },
+            "paid_udt": Value::Null
+        }
+    });
+    util::merge_json(&mut cell, cell_partial);
+
+    template.push_output(cell, Some(4));
+}
+
 pub fn push_dep_account_cell(template: &mut TemplateGenerator, cell_partial: Value) {
     let mut cell = json!({
         "capacity": util::gen_account_cell_capacity(5),
@@ -367,7 +416,7 @@ pub fn push_output_account_cell(template: &mut TemplateGenerator, cell_partial:
     });
     util::merge_json(&mut cell, cell_partial);
 
-    template.push_output(cell, Some(4));
+    template.push_output(cell, Some(5));
 }
 
 pub fn push_input_account_cell_v2(template: &mut TemplateGenerator, cell_partial: Value) {
@@ -610,6 +659,38 @@ pub fn push_output_normal_cell(template: &mut TemplateGenerator, capacity: u64,
     );
 }
 
+pub fn push_input_udt_cell(template: &mut TemplateGenerator, amount: u128, lock: Value) {
+    template.push_input(
+        json!({
+            "capacity": 142 * ONE_CKB,
+            "lock": lock,
+            "type": {
+                "code_hash": "{{always_success}}",
+                "args": UDT_ARGS
+            },
+            "data": util::bytes_to_hex(&amount.to_le_bytes())
+        }),
+        None,
+        None,
+    );
+    template.push_empty_witness();
+}
+
+pub fn push_output_udt_cell(template: &mut TemplateGenerator, amount: u128, lock: Value) {
+    template.push_output(
+        json!({
+            "capacity": 142 * ONE_CKB,
+            "lock": lock,
+            "type": {
+                "code_hash": "{{always_success}}",
+                "args": UDT_ARGS
+            },
+            "data": util::bytes_to_hex(&amount.to_le_bytes())
+        }),
+        None,
+    );
+}
+
 pub fn push_input_test_env_cell(template: &mut TemplateGenerator) {
     template.push_input(
         json!({
@@ -684,6 +765,64 @@ pub fn push_output_dpoint_cell(template: &mut TemplateGenerator, dpoint: u64, ow
     );
 }
 
+/// Push a DPointCell with expiry or vesting period, the schedule is an object like
+/// `{ "expired_at": u64, "vesting_start_at": u64, "vesting_end_at": u64 }` and all fields of it are optional.
+pub fn push_input_dpoint_cell_with_schedule(
+    template: &mut TemplateGenerator,
+    dpoint: u64,
+    owner: &str,
+    schedule: Value,
+) {
+    template.push_input(
+        json!({
+            "capacity": DPOINT_BASIC_CAPACITY + DPOINT_PREPARED_FEE_CAPACITY,
+            "lock": {
+                "owner_lock_args": owner,
+                "manager_lock_args": owner,
+            },
+            "type": {
+                "code_hash": "{{dpoint-cell-type}}"
+            },
+            "data": {
+                "value": dpoint,
+                "expired_at": schedule["expired_at"],
+                "vesting_start_at": schedule["vesting_start_at"],
+                "vesting_end_at": schedule["vesting_end_at"]
+            }
+        }),
+        None,
+        None,
+    );
+    template.push_das_lock_witness("0000000000000000000000000000000000000000000000000000000000000000");
+}
+
+pub fn push_output_dpoint_cell_with_schedule(
+    template: &mut TemplateGenerator,
+    dpoint: u64,
+    owner: &str,
+    schedule: Value,
+) {
+    template.push_output(
+        json!({
+            "capacity": DPOINT_BASIC_CAPACITY + DPOINT_PREPARED_FEE_CAPACITY,
+            "lock": {
+                "owner_lock_args": owner,
+                "manager_lock_args": owner,
+            },
+            "type": {
+                "code_hash": "{{dpoint-cell-type}}"
+            },
+            "data": {
+                "value": dpoint,
+                "expired_at": schedule["expired_at"],
+                "vesting_start_at": schedule["vesting_start_at"],
+                "vesting_end_at": schedule["vesting_end_at"]
+            }
+        }),
+        None,
+    );
+}
+
 pub fn push_input_dpoint_cell_float(template: &mut TemplateGenerator, dpoint: u64, owner: &str) {
     template.push_input(
         json!({
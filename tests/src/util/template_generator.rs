@@ -5,12 +5,16 @@ use std::io::Write;
 use std::str::FromStr;
 use std::{env, str};
 
+use account_list_smt::AccountList;
 use ckb_hash::blake2b_256;
+use das_lv::LvEncode;
 use das_types::constants::*;
+use das_types::lv_witness::RawReverseRecordWitness;
 use das_types::packed::*;
 use das_types::prelude::*;
-use das_types::util as das_util;
 use das_types::util::EntityWrapper;
+use das_types::{char_set as das_char_set, record_key_namespace as das_record_key_namespace, util as das_util};
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use simple_ast::util as ast_util;
@@ -23,6 +27,18 @@ use super::since_util::SinceFlag;
 use super::smt::*;
 use super::{encoder, since_util, util};
 
+lazy_static! {
+    // Building the SMTs of the account lists is slow, so they are built only once and shared by all tests.
+    static ref ACCOUNT_LISTS: (AccountList, AccountList) = {
+        let preserved = AccountList::from_accounts_file(util::data_file_path("preserved_accounts.txt"))
+            .expect("Expect file ./tests/data/preserved_accounts.txt exist.");
+        let unavailable = AccountList::from_account_hashes_file(util::data_file_path("unavailable_account_hashes.txt"))
+            .expect("Expect file ./tests/data/unavailable_account_hashes.txt exist.");
+
+        (preserved, unavailable)
+    };
+}
+
 pub enum ContractType {
     DeployedContract,
     Contract,
@@ -114,6 +130,40 @@ fn gen_price_config(length: u8, new_price: u64, renew_price: u64) -> PriceConfig
         .build()
 }
 
+pub fn gen_release_rule(char_set: u32, min_length: u32, max_length: u32, effective_at: u64) -> ReleaseRule {
+    ReleaseRule::new_builder()
+        .char_set(Uint32::from(char_set))
+        .min_length(Uint32::from(min_length))
+        .max_length(Uint32::from(max_length))
+        .effective_at(Uint64::from(effective_at))
+        .build()
+}
+
+pub fn gen_multi_year_discount(years: u32, discount: u32) -> MultiYearDiscount {
+    MultiYearDiscount::new_builder()
+        .years(Uint32::from(years))
+        .discount(Uint32::from(discount))
+        .build()
+}
+
+pub fn gen_promotion(
+    start_at: u64,
+    end_at: u64,
+    char_set: u32,
+    min_length: u32,
+    max_length: u32,
+    discount: u32,
+) -> Promotion {
+    Promotion::new_builder()
+        .start_at(Uint64::from(start_at))
+        .end_at(Uint64::from(end_at))
+        .char_set(Uint32::from(char_set))
+        .min_length(Uint32::from(min_length))
+        .max_length(Uint32::from(max_length))
+        .discount(Uint32::from(discount))
+        .build()
+}
+
 fn gen_account_char(char: &str, char_set_type: CharSetType) -> AccountChar {
     AccountChar::new_builder()
         .char_set_name(Uint32::from(char_set_type as u32))
@@ -414,30 +464,6 @@ fn parse_json_to_account_chars(field_name: &str, field: &Value, suffix_opt: Opti
     (account, account_chars)
 }
 
-fn parse_json_to_chain_id_mol(field_name: &str, field: &Value) -> ChainId {
-    let coin_type = Uint64::from(util::parse_json_u64(
-        &format!("{}.coin_type", field_name),
-        &field["coin_type"],
-        None,
-    ));
-    let chain_id = Uint64::from(util::parse_json_u64(
-        &format!("{}.chain_id", field_name),
-        &field["chain_id"],
-        None,
-    ));
-    let checked = Uint8::from(util::parse_json_u8(
-        &format!("{}.checked", field_name),
-        &field["checked"],
-        None,
-    ));
-
-    ChainId::new_builder()
-        .coin_type(coin_type)
-        .chain_id(chain_id)
-        .checked(checked)
-        .build()
-}
-
 fn length_of(data: &[u8]) -> Vec<u8> {
     (data.len() as u32).to_le_bytes().to_vec()
 }
@@ -471,8 +497,18 @@ pub struct TemplateGenerator {
     pub sub_account_price_rules_bytes: Vec<u8>,
     pub sub_account_preserved_rules_bytes: Vec<u8>,
     pub prices: HashMap<u8, PriceConfig>,
-    pub preserved_account_groups: HashMap<u32, (Vec<u8>, Vec<u8>)>,
+    pub release_rules: Vec<ReleaseRule>,
+    pub multi_year_discounts: Vec<MultiYearDiscount>,
+    pub promotions: Vec<Promotion>,
+    // The minimum number of OracleCells of each type in ConfigCellMain.oracle .
+    pub oracle_min_cells: u8,
     pub charsets: HashMap<u32, (Bytes, Vec<u8>)>,
+    // Encode ConfigCellCharSet* in the legacy format instead of the indexed format.
+    pub legacy_char_set: bool,
+    // The validators of record keys in ConfigCellRecordKeyNamespace, the keys not listed here accept any values.
+    pub record_value_validators: HashMap<String, RecordValueValidator>,
+    // Encode ConfigCellRecordKeyNamespace in the legacy format instead of the v2 format.
+    pub legacy_record_key_namespace: bool,
     pub smt_with_history: SMTWithHistory,
     pub new_sub_account_smt: SMTWithHistory,
 }
@@ -491,6 +527,23 @@ impl TemplateGenerator {
         prices.insert(7u8, gen_price_config(7, ACCOUNT_PRICE_5_CHAR, ACCOUNT_PRICE_5_CHAR));
         prices.insert(8u8, gen_price_config(8, ACCOUNT_PRICE_5_CHAR, ACCOUNT_PRICE_5_CHAR));
 
+        // The accounts with 10 or more characters are always released, and the accounts with 4 or more characters in the
+        // fully released char-sets are released since 2022-10-18, the char-sets added later are fully released since they
+        // are supported.
+        let mut release_rules = vec![gen_release_rule(RELEASE_RULE_ANY_CHAR_SET, 10, 0, 0)];
+        for char_set in [
+            CharSetType::Emoji,
+            CharSetType::Digit,
+            CharSetType::Ko,
+            CharSetType::Th,
+            CharSetType::Ar,
+            CharSetType::Hi,
+            CharSetType::He,
+            CharSetType::El,
+        ] {
+            release_rules.push(gen_release_rule(char_set as u32, 4, 0, TIMESTAMP_20221018));
+        }
+
         TemplateGenerator {
             loaded_contracts: vec![],
             header_deps: Vec::new(),
@@ -504,8 +557,14 @@ impl TemplateGenerator {
             sub_account_price_rules_bytes: Vec::new(),
             sub_account_preserved_rules_bytes: Vec::new(),
             prices,
-            preserved_account_groups: HashMap::new(),
+            release_rules,
+            multi_year_discounts: Vec::new(),
+            oracle_min_cells: ORACLE_MIN_CELLS,
+            promotions: Vec::new(),
             charsets: HashMap::new(),
+            legacy_char_set: false,
+            record_value_validators: HashMap::new(),
+            legacy_record_key_namespace: false,
             smt_with_history: SMTWithHistory::new(),
             new_sub_account_smt: SMTWithHistory::new(),
         }
@@ -609,6 +668,12 @@ impl TemplateGenerator {
     }
 
     pub fn push_oracle_cell(&mut self, index: u8, type_: OracleCellType, data: u64) {
+        self.push_oracle_cell_updated_at(index, type_, data, ORACLE_UPDATED_AT);
+    }
+
+    /// Push an OracleCell of the oracle provider with the given index, the updated_at is the timestamp in milliseconds of
+    /// the block which the OracleCell is committed in, its header will be pushed into header_deps too.
+    pub fn push_oracle_cell_updated_at(&mut self, index: u8, type_: OracleCellType, data: u64, updated_at: u64) {
         let mut cell_raw_data: Vec<u8> = Vec::new();
         cell_raw_data.extend(index.to_be_bytes().iter());
         cell_raw_data.extend(&[type_ as u8]);
@@ -616,7 +681,8 @@ impl TemplateGenerator {
         let cell_data = cell_raw_data;
 
         let lock_script = json!({
-            "code_hash": "{{always_success}}"
+            "code_hash": "{{always_success}}",
+            "args": util::bytes_to_hex(&index.to_be_bytes())
         });
         let type_script = json!({
             "code_hash": "0x0100000000000000000000000000000000000000000000000000000000000000",
@@ -624,13 +690,17 @@ impl TemplateGenerator {
             "args": format!("0x{}", hex::encode(&[type_ as u8]))
         });
 
-        self.push_cell(
-            40_000_000_000,
-            lock_script,
-            type_script,
-            Some(cell_data),
-            Source::CellDep,
-        );
+        self.cell_deps.push(json!({
+            "tmp_header": {
+                "height": HEIGHT,
+                "timestamp": updated_at
+            },
+            "tmp_type": "full",
+            "capacity": 40_000_000_000u64,
+            "lock": lock_script,
+            "type": type_script,
+            "tmp_data": util::bytes_to_hex(&cell_data),
+        }));
     }
 
     fn gen_config_cell_account(&mut self) -> (Vec<u8>, EntityWrapper) {
@@ -674,8 +744,8 @@ impl TemplateGenerator {
     fn gen_config_cell_income(&mut self) -> (Vec<u8>, EntityWrapper) {
         let entity = ConfigCellIncome::new_builder()
             .basic_capacity(Uint64::from(INCOME_BASIC_CAPACITY))
-            .max_records(Uint32::from(50))
-            .min_transfer_capacity(Uint64::from(10_000_000_000))
+            .max_records(Uint32::from(INCOME_MAX_RECORDS))
+            .min_transfer_capacity(Uint64::from(INCOME_MIN_TRANSFER_CAPACITY))
             .build();
 
         let cell_data = blake2b_256(entity.as_slice()).to_vec();
@@ -712,11 +782,30 @@ impl TemplateGenerator {
             .web_authn(Hash::try_from(util::get_type_id_bytes("webauthn_sign.so")).unwrap())
             .build();
 
+        let mut provider_locks_builder = Scripts::new_builder();
+        for index in 1..=ORACLE_PROVIDERS {
+            provider_locks_builder = provider_locks_builder.push(
+                Script::new_builder()
+                    .code_hash(Hash::try_from(util::get_type_id_bytes("always_success")).unwrap())
+                    .hash_type(Byte::new(1))
+                    .args(Bytes::from(vec![index]))
+                    .build(),
+            );
+        }
+        let provider_locks = provider_locks_builder.build();
+
         let entity = ConfigCellMain::new_builder()
             .status(Uint8::from(1))
             .type_id_table(type_id_table)
             .das_lock_out_point_table(DasLockOutPointTable::default())
             .das_lock_type_id_table(das_lock_type_id_table)
+            .oracle(
+                OracleConfig::new_builder()
+                    .min_cells(Uint8::from(self.oracle_min_cells))
+                    .max_age(Uint32::from(ORACLE_MAX_AGE as u32))
+                    .provider_locks(provider_locks)
+                    .build(),
+            )
             .build();
 
         let cell_data = blake2b_256(entity.as_slice()).to_vec();
@@ -737,6 +826,28 @@ impl TemplateGenerator {
         let entity = ConfigCellPrice::new_builder()
             .discount(discount_config)
             .prices(prices.build())
+            .multi_year_discounts(
+                MultiYearDiscounts::new_builder()
+                    .set(self.multi_year_discounts.clone())
+                    .build(),
+            )
+            .promotions(Promotions::new_builder().set(self.promotions.clone()).build())
+            .udt_payments(
+                UdtPayments::new_builder()
+                    .push(
+                        UdtPayment::new_builder()
+                            .type_script(parse_json_script_to_mol(
+                                "udt_payments[0].type_script",
+                                &json!({
+                                    "code_hash": "{{always_success}}",
+                                    "args": UDT_ARGS
+                                }),
+                            ))
+                            .decimals(Uint8::from(UDT_DECIMALS))
+                            .build(),
+                    )
+                    .build(),
+            )
             .build();
 
         let cell_data = blake2b_256(entity.as_slice()).to_vec();
@@ -782,6 +893,7 @@ impl TemplateGenerator {
     fn gen_config_cell_release(&mut self) -> (Vec<u8>, EntityWrapper) {
         let entity = ConfigCellRelease::new_builder()
             .lucky_number(Uint32::from(3435973836))
+            .release_rules(ReleaseRules::new_builder().set(self.release_rules.clone()).build())
             .build();
         let cell_data = blake2b_256(entity.as_slice()).to_vec();
 
@@ -894,12 +1006,23 @@ impl TemplateGenerator {
         }
         record_key_namespace.sort();
 
-        // Join all record keys with 0x00 byte as entity.
-        let mut raw = Vec::new();
-        for key in record_key_namespace {
-            raw.extend(key.as_bytes());
-            raw.extend(&[0u8]);
-        }
+        let raw = if self.legacy_record_key_namespace {
+            // Join all record keys with 0x00 byte as entity.
+            das_record_key_namespace::encode_legacy(&record_key_namespace)
+        } else {
+            let keys = record_key_namespace
+                .into_iter()
+                .map(|key| {
+                    let validator = self
+                        .record_value_validators
+                        .get(&key)
+                        .copied()
+                        .unwrap_or(RecordValueValidator::Any);
+                    (key, validator)
+                })
+                .collect::<Vec<_>>();
+            das_record_key_namespace::encode_v2(&keys)
+        };
         let raw = util::prepend_molecule_like_length(raw);
 
         let cell_data = blake2b_256(raw.as_slice()).to_vec();
@@ -907,68 +1030,96 @@ impl TemplateGenerator {
         (cell_data, raw)
     }
 
-    fn gen_config_cell_preserved_account(&mut self, data_type: DataType) -> Option<(Vec<u8>, Vec<u8>)> {
-        if self.preserved_account_groups.is_empty() {
-            // Load and group preserved accounts
-            let mut preserved_accounts_groups: Vec<Vec<Vec<u8>>> =
-                vec![Vec::new(); PRESERVED_ACCOUNT_CELL_COUNT as usize];
-            let lines =
-                util::read_lines("preserved_accounts.txt").expect("Expect file ./data/preserved_accounts.txt exist.");
-            for line in lines {
-                if let Ok(account) = line {
-                    let account_hash = blake2b_256(account.as_bytes())
-                        .get(..ACCOUNT_ID_LENGTH)
-                        .unwrap()
-                        .to_vec();
-                    let index = (account_hash[0] % PRESERVED_ACCOUNT_CELL_COUNT) as usize;
-
-                    preserved_accounts_groups[index].push(account_hash);
-                }
-            }
+    fn gen_config_cell_account_list(&mut self) -> (Vec<u8>, EntityWrapper) {
+        let (preserved, unavailable) = &*ACCOUNT_LISTS;
+        let entity = account_list_smt::gen_config_cell_account_list(preserved, unavailable);
+        let cell_data = blake2b_256(entity.as_slice()).to_vec();
 
-            // Store grouped preserved accounts into self.preserved_account_groups
-            for (_i, mut group) in preserved_accounts_groups.into_iter().enumerate() {
-                // println!("Preserved account group[{}] count: {}", _i, group.len());
-                group.sort();
-                let mut raw = group.into_iter().flatten().collect::<Vec<u8>>();
-                raw = util::prepend_molecule_like_length(raw);
+        (cell_data, EntityWrapper::ConfigCellAccountList(entity))
+    }
 
-                let data_type = das_util::preserved_accounts_group_to_data_type(_i);
-                let cell_data = blake2b_256(raw.as_slice()).to_vec();
-                self.preserved_account_groups.insert(data_type as u32, (cell_data, raw));
-            }
+    fn gen_config_cell_cross_chain(&mut self) -> (Vec<u8>, EntityWrapper) {
+        let mut relayers_builder = BytesVec::new_builder();
+        for relayer in [RELAYER_1, RELAYER_2, RELAYER_3] {
+            relayers_builder = relayers_builder.push(Bytes::from(util::hex_to_bytes(relayer)));
         }
 
-        self.preserved_account_groups
-            .get(&(data_type as u32))
-            .map(|item| item.to_owned())
-    }
+        // (coin_type, chain_id, name, fee, enabled)
+        let chains = [
+            (60u64, 1u64, "Ethereum", CROSS_CHAIN_LOCK_FEE, 1u8),
+            (60, 56, "BNB Smart Chain", CROSS_CHAIN_LOCK_FEE, 1),
+            (966, 137, "Polygon", CROSS_CHAIN_LOCK_FEE, 0),
+        ];
+        let mut chains_builder = CrossChainList::new_builder();
+        for (coin_type, chain_id, name, fee, enabled) in chains {
+            chains_builder = chains_builder.push(
+                CrossChain::new_builder()
+                    .coin_type(Uint64::from(coin_type))
+                    .chain_id(Uint64::from(chain_id))
+                    .name(Bytes::from(name.as_bytes()))
+                    .fee(Uint64::from(fee))
+                    .enabled(Uint8::from(enabled))
+                    .build(),
+            );
+        }
 
-    fn gen_config_cell_unavailable_account(&mut self) -> (Vec<u8>, Vec<u8>) {
-        // Load and group unavailable accounts
-        let mut unavailable_account_hashes = Vec::new();
-        let lines = util::read_lines("unavailable_account_hashes.txt")
-            .expect("Expect file ./tests/data/unavailable_account_hashes.txt exist.");
+        let entity = ConfigCellCrossChain::new_builder()
+            .threshold(Uint8::from(2))
+            .relayers(relayers_builder.build())
+            .chains(chains_builder.build())
+            .build();
+        let cell_data = blake2b_256(entity.as_slice()).to_vec();
+
+        (cell_data, EntityWrapper::ConfigCellCrossChain(entity))
+    }
 
+    fn gen_config_cell_confusables(&mut self) -> (Vec<u8>, EntityWrapper) {
+        // Every line is a confusable char and its prototype separated by a space.
+        let mut confusables = Vec::new();
+        let lines = util::read_lines("confusables.txt").expect("Expect file ./tests/data/confusables.txt exist.");
         for line in lines {
-            if let Ok(account_hash_string) = line {
-                let account_hash: Vec<u8> = hex::decode(account_hash_string).unwrap();
-                unavailable_account_hashes.push(account_hash.get(..ACCOUNT_ID_LENGTH).unwrap().to_vec());
+            if let Ok(line) = line {
+                let (char, prototype) = line
+                    .split_once(' ')
+                    .expect("Expect every line to be \"char prototype\".");
+                confusables.push((char.to_string(), prototype.to_string()));
             }
         }
+        confusables.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+
+        let mut chars_builder = ConfusableChars::new_builder();
+        for (char, prototype) in confusables {
+            chars_builder = chars_builder.push(
+                ConfusableChar::new_builder()
+                    .char(Bytes::from(char.as_bytes()))
+                    .prototype(Bytes::from(prototype.as_bytes()))
+                    .build(),
+            );
+        }
 
-        unavailable_account_hashes.sort(); // todo: maybe we don't need to sort, traverse is just enough
-
-        let mut raw = Vec::new();
+        // The skeletons of the protected accounts are themselves, because they contain no confusable chars.
+        let mut protected_skeletons = Vec::new();
+        let lines = util::read_lines("confusable_protected_accounts.txt")
+            .expect("Expect file ./tests/data/confusable_protected_accounts.txt exist.");
+        for line in lines {
+            if let Ok(account) = line {
+                protected_skeletons.push(blake2b_256(account.as_bytes()));
+            }
+        }
+        protected_skeletons.sort();
 
-        for account_hash in unavailable_account_hashes {
-            raw.extend(account_hash);
+        let mut protected_skeletons_builder = HashList::new_builder();
+        for hash in protected_skeletons {
+            protected_skeletons_builder = protected_skeletons_builder.push(Hash::from(hash));
         }
-        let raw = util::prepend_molecule_like_length(raw);
 
-        let cell_data = blake2b_256(raw.as_slice()).to_vec();
+        let entity = ConfigCellConfusables::new_builder()
+            .chars(chars_builder.build())
+            .protected_skeletons(protected_skeletons_builder.build())
+            .build();
+        let cell_data = blake2b_256(entity.as_slice()).to_vec();
 
-        (cell_data, raw)
+        (cell_data, EntityWrapper::ConfigCellConfusables(entity))
     }
 
     fn gen_config_cell_char_set(&mut self, file_name: &str, is_global: u8) -> (Vec<u8>, Vec<u8>) {
@@ -981,13 +1132,12 @@ impl TemplateGenerator {
             }
         }
 
-        // Join all record keys with 0x00 byte as entity.
-        let mut raw = Vec::new();
-        raw.push(is_global); // global status
-        for key in charsets {
-            raw.extend(key.as_bytes());
-            raw.extend(&[0u8]);
-        }
+        let mut raw = if self.legacy_char_set {
+            // Join all chars with 0x00 byte as entity.
+            das_char_set::encode_legacy(is_global == 1, &charsets)
+        } else {
+            das_char_set::encode_indexed(is_global == 1, &charsets)
+        };
         raw = util::prepend_molecule_like_length(raw);
 
         let cell_data = blake2b_256(raw.as_slice()).to_vec();
@@ -1129,19 +1279,6 @@ impl TemplateGenerator {
                 };
                 self.outer_witnesses.push(util::bytes_to_hex(&witness));
             }};
-            (@preserved_account $fn:ident, $config_type:expr) => {{
-                if let Some((outputs_data, raw)) = self.$fn($config_type) {
-                    push_cell(self, config_type, outputs_data, source);
-                    let witness = match source {
-                        Source::Input => das_util::wrap_raw_witness_v2(config_type, raw),
-                        Source::Output => das_util::wrap_raw_witness_v2(config_type, raw),
-                        _ => das_util::wrap_raw_witness_v2(config_type, raw),
-                    };
-                    self.outer_witnesses.push(util::bytes_to_hex(&witness));
-                } else {
-                    panic!("Load preserved_account failed.");
-                }
-            }};
         }
 
         match config_type {
@@ -1158,9 +1295,11 @@ impl TemplateGenerator {
             DataType::ConfigCellReverseResolution => push_cell!(@entity gen_config_cell_reverse_resolution),
             DataType::ConfigCellSubAccount => push_cell!(@entity gen_config_cell_sub_account),
             DataType::ConfigCellDPoint => push_cell!(@entity gen_config_cell_dpoint),
+            DataType::ConfigCellAccountList => push_cell!(@entity gen_config_cell_account_list),
+            DataType::ConfigCellConfusables => push_cell!(@entity gen_config_cell_confusables),
+            DataType::ConfigCellCrossChain => push_cell!(@entity gen_config_cell_cross_chain),
             // ConfigCells with raw binary data.
             DataType::ConfigCellRecordKeyNamespace => push_cell!(@raw gen_config_cell_record_key_namespace),
-            DataType::ConfigCellUnAvailableAccount => push_cell!(@raw gen_config_cell_unavailable_account),
             DataType::ConfigCellCharSetEmoji => push_cell!(@char_set gen_config_cell_char_set, "char_set_emoji.txt", 1),
             DataType::ConfigCellCharSetDigit => {
                 push_cell!(@char_set gen_config_cell_char_set, "char_set_digit_and_symbol.txt", 1)
@@ -1172,6 +1311,10 @@ impl TemplateGenerator {
             DataType::ConfigCellCharSetTh => push_cell!(@char_set gen_config_cell_char_set, "char_set_th.txt", 0),
             DataType::ConfigCellCharSetTr => push_cell!(@char_set gen_config_cell_char_set, "char_set_tr.txt", 0),
             DataType::ConfigCellCharSetVi => push_cell!(@char_set gen_config_cell_char_set, "char_set_vi.txt", 0),
+            DataType::ConfigCellCharSetAr => push_cell!(@char_set gen_config_cell_char_set, "char_set_ar.txt", 0),
+            DataType::ConfigCellCharSetHi => push_cell!(@char_set gen_config_cell_char_set, "char_set_hi.txt", 0),
+            DataType::ConfigCellCharSetHe => push_cell!(@char_set gen_config_cell_char_set, "char_set_he.txt", 0),
+            DataType::ConfigCellCharSetEl => push_cell!(@char_set gen_config_cell_char_set, "char_set_el.txt", 0),
             DataType::ConfigCellSubAccountBetaList => push_cell!(@raw gen_config_cell_sub_account_beta_list),
             DataType::ConfigCellSMTNodeWhitelist => push_cell!(@raw gen_config_cell_smt_node_white_list),
             DataType::ConfigCellCharSetZhHans => {
@@ -1180,84 +1323,61 @@ impl TemplateGenerator {
             DataType::ConfigCellCharSetZhHant => {
                 push_cell!(@char_set gen_config_cell_char_set, "char_set_zh_hant.txt", 0)
             }
-            DataType::ConfigCellPreservedAccount00 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount00)
-            }
-            DataType::ConfigCellPreservedAccount01 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount01)
-            }
-            DataType::ConfigCellPreservedAccount02 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount02)
-            }
-            DataType::ConfigCellPreservedAccount03 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount03)
-            }
-            DataType::ConfigCellPreservedAccount04 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount04)
-            }
-            DataType::ConfigCellPreservedAccount05 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount05)
-            }
-            DataType::ConfigCellPreservedAccount06 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount06)
-            }
-            DataType::ConfigCellPreservedAccount07 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount07)
-            }
-            DataType::ConfigCellPreservedAccount08 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount08)
-            }
-            DataType::ConfigCellPreservedAccount09 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount09)
-            }
-            DataType::ConfigCellPreservedAccount10 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount10)
-            }
-            DataType::ConfigCellPreservedAccount11 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount11)
-            }
-            DataType::ConfigCellPreservedAccount12 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount12)
-            }
-            DataType::ConfigCellPreservedAccount13 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount13)
-            }
-            DataType::ConfigCellPreservedAccount14 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount14)
-            }
-            DataType::ConfigCellPreservedAccount15 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount15)
-            }
-            DataType::ConfigCellPreservedAccount16 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount16)
-            }
-            DataType::ConfigCellPreservedAccount17 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount17)
-            }
-            DataType::ConfigCellPreservedAccount18 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount18)
-            }
-            DataType::ConfigCellPreservedAccount19 => {
-                push_cell!(@preserved_account gen_config_cell_preserved_account, DataType::ConfigCellPreservedAccount19)
-            }
             _ => panic!("Undefined config cell type."),
         }
     }
 
-    pub fn push_config_cell_derived_by_account(&mut self, account: &str, source: Source) {
-        let account_without_suffix = match account.strip_suffix(".bit") {
-            Some(val) => val,
-            _ => account,
-        };
-        let first_byte_of_account_hash = blake2b_256(account_without_suffix.as_bytes())[0];
-        let index = (first_byte_of_account_hash % PRESERVED_ACCOUNT_CELL_COUNT) as usize;
-        let config_type = das_util::preserved_accounts_group_to_data_type(index);
+    /// Push the AccountListProof witness of the account, the `.bit` suffix is optional.
+    pub fn push_account_list_proof(&mut self, account: &str) {
+        let (preserved, unavailable) = &*ACCOUNT_LISTS;
+        let account_id = account_list_smt::account_to_id(account);
+        let entity = account_list_smt::gen_account_list_proof(preserved, unavailable, &account_id);
+        let witness = das_util::wrap_entity_witness_v2(DataType::AccountListProof, entity);
+
+        self.outer_witnesses.push(util::bytes_to_hex(&witness));
+    }
+
+    /// Push the CrossChainUnlockAttestation witness, the value should be an object like below:
+    ///
+    /// ```json
+    /// {
+    ///     "account": "xxxxx.bit",
+    ///     "owner_lock_args": "0x...",
+    ///     "coin_type": u64,
+    ///     "chain_id": u64,
+    ///     "relayer_indexes": [u8]
+    /// }
+    /// ```
+    pub fn push_cross_chain_unlock_attestation(&mut self, value: Value) {
+        let account_id = util::parse_json_str_to_account_id_mol("attestation.account", &value["account"]);
+        let owner_lock_args = util::parse_json_hex("attestation.owner_lock_args", &value["owner_lock_args"]);
+        let coin_type = util::parse_json_u64("attestation.coin_type", &value["coin_type"], Some(60));
+        let chain_id = util::parse_json_u64("attestation.chain_id", &value["chain_id"], Some(1));
+
+        let mut signatures_builder = RelayerSignatures::new_builder();
+        for (i, item) in util::parse_json_array("attestation.relayer_indexes", &value["relayer_indexes"])
+            .iter()
+            .enumerate()
+        {
+            let relayer_index = util::parse_json_u8(&format!("attestation.relayer_indexes[{}]", i), item, None);
+            signatures_builder = signatures_builder.push(
+                RelayerSignature::new_builder()
+                    .relayer_index(Uint8::from(relayer_index))
+                    .signature(Bytes::from(vec![255u8; 65]))
+                    .build(),
+            );
+        }
+
+        let entity = CrossChainUnlockAttestation::new_builder()
+            .account_id(account_id)
+            .owner_lock_args(Bytes::from(owner_lock_args))
+            .coin_type(Uint64::from(coin_type))
+            .chain_id(Uint64::from(chain_id))
+            .signatures(signatures_builder.build())
+            .build();
+        let witness = das_util::wrap_entity_witness_v2(DataType::CrossChainUnlockAttestation, entity);
 
-        // println!(
-        //     "The first byte of account hash is {:?}, so {:?} will be chosen.",
-        //     first_byte_of_account_hash, config_type
-        // );
-        self.push_config_cell(config_type, source);
+        self.outer_witnesses.push(util::bytes_to_hex(&witness));
     }
 
     // ======
@@ -1405,6 +1525,7 @@ impl TemplateGenerator {
                     "sub-account-cell-type" => push_cell!(gen_sub_account_cell, cell),
                     "reverse-record-cell-type" => push_cell!(gen_reverse_record_cell, cell),
                     "reverse-record-root-cell-type" => push_cell!(gen_reverse_record_root_cell, cell),
+                    "always_success" => push_cell!(gen_custom_cell, cell),
                     "test-env" => push_cell!(gen_custom_cell, cell),
                     "playground" => push_cell!(gen_custom_cell, cell),
                     _ => panic!("Unknown type ID {}", type_id),
@@ -1691,11 +1812,81 @@ impl TemplateGenerator {
                         Some(EntityWrapper::PreAccountCellDataV2(entity)),
                     )
                 }
+                3 => {
+                    let initial_records =
+                        parse_json_to_records_mol("cell.witness.initial_records", &witness["initial_records"]);
+                    let initial_cross_chain = util::parse_json_to_chain_id_mol(
+                        "cell.witness.initial_cross_chain",
+                        &witness["initial_cross_chain"],
+                    );
+                    let entity = PreAccountCellDataV3::new_builder()
+                        .account(account_chars)
+                        .refund_lock(refund_lock)
+                        .owner_lock_args(Bytes::from(owner_lock_args))
+                        .inviter_id(inviter_id)
+                        .inviter_lock(inviter_lock)
+                        .channel_lock(channel_lock)
+                        .price(price)
+                        .quote(Uint64::from(quote))
+                        .invited_discount(Uint32::from(invited_discount))
+                        .created_at(Uint64::from(created_at))
+                        .initial_records(initial_records)
+                        .initial_cross_chain(initial_cross_chain)
+                        .build();
+
+                    let data = &cell["data"];
+                    let hash = util::parse_json_hex_with_default(
+                        "cell.data.hash",
+                        &data["hash"],
+                        blake2b_256(entity.as_slice()).to_vec(),
+                    );
+                    let account_id =
+                        util::parse_json_hex_with_default("cell.data.id", &data["id"], util::account_to_id(&account));
+                    let outputs_data = [hash, account_id].concat();
+
+                    (
+                        json!({
+                            "tmp_header": cell["header"],
+                            "tmp_type": "full",
+                            "capacity": capacity,
+                            "lock": lock_script,
+                            "type": type_script,
+                            "tmp_data": util::bytes_to_hex(&outputs_data)
+                        }),
+                        Some(EntityWrapper::PreAccountCellDataV3(entity)),
+                    )
+                }
                 _ => {
                     let initial_records =
                         parse_json_to_records_mol("cell.witness.initial_records", &witness["initial_records"]);
-                    let initial_cross_chain =
-                        parse_json_to_chain_id_mol("cell.witness.initial_cross_chain", &witness["initial_cross_chain"]);
+                    let initial_cross_chain = util::parse_json_to_chain_id_mol(
+                        "cell.witness.initial_cross_chain",
+                        &witness["initial_cross_chain"],
+                    );
+                    let paid_udt = if !witness["paid_udt"].is_null() {
+                        PaidUdtOpt::new_builder()
+                            .set(Some(
+                                PaidUdt::new_builder()
+                                    .type_script(parse_json_script_to_mol(
+                                        "cell.witness.paid_udt.type_script",
+                                        &witness["paid_udt"]["type_script"],
+                                    ))
+                                    .amount(Uint64::from(util::parse_json_u64(
+                                        "cell.witness.paid_udt.amount",
+                                        &witness["paid_udt"]["amount"],
+                                        None,
+                                    )))
+                                    .ckb_quote(Uint64::from(util::parse_json_u64(
+                                        "cell.witness.paid_udt.ckb_quote",
+                                        &witness["paid_udt"]["ckb_quote"],
+                                        Some(CKB_QUOTE),
+                                    )))
+                                    .build(),
+                            ))
+                            .build()
+                    } else {
+                        PaidUdtOpt::default()
+                    };
                     let entity = PreAccountCellData::new_builder()
                         .account(account_chars)
                         .refund_lock(refund_lock)
@@ -1709,6 +1900,7 @@ impl TemplateGenerator {
                         .created_at(Uint64::from(created_at))
                         .initial_records(initial_records)
                         .initial_cross_chain(initial_cross_chain)
+                        .paid_udt(paid_udt)
                         .build();
 
                     let data = &cell["data"];
@@ -1976,6 +2168,22 @@ impl TemplateGenerator {
                         Some(EntityWrapper::AccountCellDataV3(entity)),
                     )
                 }
+                4 => {
+                    let entity = encoder::account::to_v4("cell.witness", &witness);
+                    let outputs_data = gen_outputs_data(&cell, Some(&entity));
+
+                    (
+                        json!({
+                            "tmp_header": cell["header"],
+                            "tmp_type": "full",
+                            "capacity": capacity,
+                            "lock": lock_script,
+                            "type": type_script,
+                            "tmp_data": util::bytes_to_hex(&outputs_data)
+                        }),
+                        Some(EntityWrapper::AccountCellDataV4(entity)),
+                    )
+                }
                 _ => {
                     let entity = encoder::account::to_latest("cell.witness", &witness);
                     let outputs_data = gen_outputs_data(&cell, Some(&entity));
@@ -2602,7 +2810,11 @@ impl TemplateGenerator {
     ///         "code_hash": "{{dpoint-cell-type}}"
     ///     },
     ///     "data": {
-    ///         "value": u64
+    ///         "value": u64,
+    ///         // The following fields are optional, the data will be v2 if any of them exists.
+    ///         "expired_at": null | u64,
+    ///         "vesting_start_at": null | u64,
+    ///         "vesting_end_at": null | u64
     ///     }
     /// })
     /// ```
@@ -2614,10 +2826,31 @@ impl TemplateGenerator {
         let outputs_data = if cell["data"].is_null() {
             String::from("0x")
         } else {
-            let value = util::parse_json_u64("cell.data.value", &cell["data"]["value"], None);
-            let value_bytes = value.to_le_bytes().to_vec();
-            let value_length = (value_bytes.len() as u32).to_le_bytes().to_vec();
-            let outputs_data = [value_length, value_bytes].concat();
+            let data = &cell["data"];
+            let mut fields = vec![util::parse_json_u64("cell.data.value", &data["value"], None)];
+            if !data["expired_at"].is_null() || !data["vesting_start_at"].is_null() || !data["vesting_end_at"].is_null()
+            {
+                fields.push(util::parse_json_u64(
+                    "cell.data.expired_at",
+                    &data["expired_at"],
+                    Some(0),
+                ));
+                fields.push(util::parse_json_u64(
+                    "cell.data.vesting_start_at",
+                    &data["vesting_start_at"],
+                    Some(0),
+                ));
+                fields.push(util::parse_json_u64(
+                    "cell.data.vesting_end_at",
+                    &data["vesting_end_at"],
+                    Some(0),
+                ));
+            }
+
+            let outputs_data = fields
+                .iter()
+                .flat_map(|field| [8u32.to_le_bytes().to_vec(), field.to_le_bytes().to_vec()].concat())
+                .collect::<Vec<u8>>();
 
             util::bytes_to_hex(&outputs_data)
         };
@@ -2696,8 +2929,8 @@ impl TemplateGenerator {
         for sub_account_json in sub_account_jsons {
             let account = parse_json_str("", &sub_account_json["account"]);
             let key = util::gen_smt_key_from_account(account);
-            // Be aware that the sub_account used here is SubAccountV1
-            let sub_account_2 = encoder::sub_account::to_latest("", &sub_account_json);
+            // Be aware that the sub_account used here is SubAccountV2
+            let sub_account_2 = encoder::sub_account::to_v2("", &sub_account_json);
             let value = util::blake2b_smt(sub_account_2.as_slice());
             leaves.push((key.into(), value.into()));
         }
@@ -2705,6 +2938,22 @@ impl TemplateGenerator {
         self.smt_with_history.restore_state(leaves);
     }
 
+    /// Insert some leaves into the sparse-merkle-tree without pushing any witness
+    pub fn restore_sub_account_v3(&mut self, sub_account_jsons: Vec<Value>) {
+        let mut leaves: Vec<(H256, H256)> = Vec::new();
+
+        for sub_account_json in sub_account_jsons {
+            let account = parse_json_str("", &sub_account_json["account"]);
+            let key = util::gen_smt_key_from_account(account);
+            // Be aware that the sub_account used here is SubAccount
+            let sub_account_3 = encoder::sub_account::to_latest("", &sub_account_json);
+            let value = util::blake2b_smt(sub_account_3.as_slice());
+            leaves.push((key.into(), value.into()));
+        }
+
+        self.smt_with_history.restore_state(leaves);
+    }
+
     /// Push SubAccountMintSign witness
     ///
     /// Witness structure:
@@ -2897,14 +3146,14 @@ impl TemplateGenerator {
         let mut default_witness = match action {
             SubAccountAction::Create => {
                 json!({
-                    "old_sub_account_version": 2,
-                    "new_sub_account_version": 2,
+                    "old_sub_account_version": 3,
+                    "new_sub_account_version": 3,
                 })
             }
             _ => {
                 json!({
                     "old_sub_account_version": 1,
-                    "new_sub_account_version": 2,
+                    "new_sub_account_version": 3,
                 })
             }
         };
@@ -2955,45 +3204,27 @@ impl TemplateGenerator {
     /// })
     /// ```
     pub fn push_reverse_record(&mut self, witness: Value, ignore_smt_check: bool) {
-        let mut witness_bytes = Vec::new();
-
-        let field_value = util::parse_json_u32("witness.version", &witness["version"], Some(1)).to_le_bytes();
-        witness_bytes.extend(length_of(&field_value));
-        witness_bytes.extend(field_value);
-
+        let version = util::parse_json_u32("witness.version", &witness["version"], Some(1));
         let action = ReverseRecordAction::from_str(
             witness["action"]
                 .as_str()
                 .expect("witness.action should be a valid str."),
         )
         .expect("witness.action should be a valid ReverseRecordAction.");
-        let action_str = action.clone().to_string();
-        witness_bytes.extend(length_of(action_str.as_bytes()));
-        witness_bytes.extend(action_str.as_bytes());
-
-        let field_value =
-            util::parse_json_hex_with_default("witness.signature", &witness["signature"], vec![255u8; 65]);
-        witness_bytes.extend(length_of(&field_value));
-        witness_bytes.extend(field_value);
-
-        let field_value = util::parse_json_u8("witness.sign_type", &witness["sign_type"], Some(1)).to_le_bytes();
-        witness_bytes.extend(length_of(&field_value));
-        witness_bytes.extend(field_value);
-
+        let action_str = action.to_string();
+        let signature = util::parse_json_hex_with_default("witness.signature", &witness["signature"], vec![255u8; 65]);
+        let sign_type = util::parse_json_u8("witness.sign_type", &witness["sign_type"], Some(1));
         let address_payload = util::parse_json_hex("witness.address_payload", &witness["address_payload"]);
-        witness_bytes.extend(length_of(&address_payload));
-        witness_bytes.extend(address_payload.clone());
 
         let key = H256::from(blake2b_256(&address_payload));
 
         // println!("key: {:?}", util::bytes_to_hex(key.as_slice()));
 
         // fields of previous status
-        let (prev_nonce, prev_nonce_bytes) = if witness["prev_nonce"].is_null() {
-            (None, vec![])
+        let prev_nonce = if witness["prev_nonce"].is_null() {
+            None
         } else {
-            let prev_nonce = util::parse_json_u32("witness.prev_nonce", &witness["prev_nonce"], None);
-            (Some(prev_nonce), prev_nonce.to_le_bytes().to_vec())
+            Some(util::parse_json_u32("witness.prev_nonce", &witness["prev_nonce"], None))
         };
         let prev_account = parse_json_str_with_default("witness.prev_account", &witness["prev_account"], "").as_bytes();
         let proof = if witness["proof"].is_null() {
@@ -3029,13 +3260,6 @@ impl TemplateGenerator {
         } else {
             util::parse_json_hex_with_default("witness.prev_proof", &witness["prev_proof"], vec![])
         };
-        witness_bytes.extend(length_of(&proof));
-        witness_bytes.extend(proof.clone());
-        witness_bytes.extend(length_of(&prev_nonce_bytes));
-        witness_bytes.extend(prev_nonce_bytes);
-        witness_bytes.extend(length_of(&prev_account));
-        witness_bytes.extend(prev_account);
-
         // fields of next status
         let next_nonce = if prev_nonce.is_none() {
             1
@@ -3066,14 +3290,22 @@ impl TemplateGenerator {
         } else {
             util::parse_json_hex_with_default("witness.next_root", &witness["next_root"], vec![])
         };
-        witness_bytes.extend(length_of(&next_root));
-        witness_bytes.extend(next_root);
-        witness_bytes.extend(length_of(&next_account));
-        witness_bytes.extend(next_account);
 
         // println!("  proof: {:?}", util::bytes_to_hex(&proof));
 
-        witness_bytes = das_util::wrap_raw_witness_v2(DataType::ReverseRecord, witness_bytes);
+        let raw_witness = RawReverseRecordWitness {
+            version,
+            action: &action_str,
+            signature: &signature,
+            sign_type,
+            address_payload: &address_payload,
+            proof: &proof,
+            prev_nonce,
+            prev_account,
+            next_root: next_root.try_into().expect("witness.next_root should be 32 bytes."),
+            next_account: std::str::from_utf8(next_account).unwrap(),
+        };
+        let witness_bytes = das_util::wrap_raw_witness_v2(DataType::ReverseRecord, raw_witness.encode_lv());
         self.reverse_record_outer_witnesses
             .push(util::bytes_to_hex(&witness_bytes));
     }
@@ -46,7 +46,7 @@ impl FromStr for BinaryVersion {
     }
 }
 
-pub fn perf_tx(tx: Value) {
+pub fn perf_tx(tx: Value) -> Cycle {
     // println!("Transaction template: {}", serde_json::to_string_pretty(&tx).unwrap());
     let mut parser = TemplateParser::from_data(tx, u64::MAX);
     match parser.try_parse() {
@@ -58,6 +58,7 @@ Cycles: {}
 ========================================"#,
                     cycles
                 );
+                cycles
             }
             Err(e) => {
                 panic!(
@@ -227,7 +228,7 @@ impl TemplateParser {
         Ok(())
     }
 
-    pub fn execute_tx(&mut self) -> Result<(Cycle, TransactionView), String> {
+    pub fn build_mock_tx(&mut self) -> MockTransaction {
         let mut builder = self.tx_builder.take();
         // The block hash of headers must be put into the header_deps field, then it will be readable later in the script.
         let mut header_hashes = Vec::new();
@@ -243,10 +244,16 @@ impl TemplateParser {
             cell_deps: self.mock_cell_deps.drain(0..).collect(),
             inputs: self.mock_inputs.drain(0..).collect(),
         };
-        let mock_tx = MockTransaction {
+
+        MockTransaction {
             mock_info,
             tx: tx.data(),
-        };
+        }
+    }
+
+    pub fn execute_tx(&mut self) -> Result<(Cycle, TransactionView), String> {
+        let mock_tx = self.build_mock_tx();
+        let tx = mock_tx.core_transaction();
 
         let resource = Resource::from_both(&mock_tx, DummyResourceLoader {})?;
         let rtx: ResolvedTransaction = {
@@ -351,11 +358,20 @@ impl TemplateParser {
                         .type_(ScriptOpt::new_builder().set(type_script).build())
                         .build();
 
+                    let header_hash_opt = if !item["tmp_header"].is_null() {
+                        let header = self.mock_block_header(&format!("cell_deps[{}]", i), &item["tmp_header"])?;
+                        let hash = header.hash();
+                        self.mock_header_deps.push(header);
+                        Some(hash)
+                    } else {
+                        None
+                    };
+
                     let mock_cell_dep = MockCellDep {
                         cell_dep: cell_dep.clone(),
                         output: cell_output,
                         data: cell_data,
-                        header: None,
+                        header: header_hash_opt,
                     };
                     self.mock_cell_deps.push(mock_cell_dep);
 
@@ -144,15 +144,19 @@ pub fn prepend_molecule_like_length(raw: Vec<u8>) -> Vec<u8> {
     entity
 }
 
-pub fn read_lines(file_name: &str) -> io::Result<Lines<BufReader<File>>> {
+pub fn data_file_path(file_name: &str) -> PathBuf {
     let dir = env::current_dir().unwrap();
     let mut file_path = PathBuf::new();
     file_path.push(dir);
     file_path.push("data");
     file_path.push(file_name);
 
+    file_path
+}
+
+pub fn read_lines(file_name: &str) -> io::Result<Lines<BufReader<File>>> {
     // Read record keys from file, then sort them.
-    let file = File::open(file_path)?;
+    let file = File::open(data_file_path(file_name))?;
     Ok(io::BufReader::new(file).lines())
 }
 
@@ -453,6 +457,55 @@ pub fn parse_json_to_records_mol(field_name: &str, field: &Value) -> Records {
     records_builder.build()
 }
 
+pub fn parse_json_to_chain_id_mol(field_name: &str, field: &Value) -> ChainId {
+    let coin_type = Uint64::from(parse_json_u64(
+        &format!("{}.coin_type", field_name),
+        &field["coin_type"],
+        None,
+    ));
+    let chain_id = Uint64::from(parse_json_u64(
+        &format!("{}.chain_id", field_name),
+        &field["chain_id"],
+        None,
+    ));
+    let checked = Uint8::from(parse_json_u8(
+        &format!("{}.checked", field_name),
+        &field["checked"],
+        None,
+    ));
+
+    ChainId::new_builder()
+        .coin_type(coin_type)
+        .chain_id(chain_id)
+        .checked(checked)
+        .build()
+}
+
+pub fn parse_json_to_records_delegates_mol(field_name: &str, field: &Value) -> RecordsDelegates {
+    if field.is_null() {
+        return RecordsDelegates::default();
+    };
+
+    let delegates = parse_json_array(field_name, field);
+    let mut delegates_builder = RecordsDelegates::new_builder();
+    for delegate in delegates.iter() {
+        let key_prefixes = parse_json_array(&format!("{}[].key_prefixes", field_name), &delegate["key_prefixes"])
+            .iter()
+            .map(|prefix| Bytes::from(parse_json_str(&format!("{}[].key_prefixes[]", field_name), prefix).as_bytes()))
+            .collect::<Vec<_>>();
+        let delegate = RecordsDelegate::new_builder()
+            .lock_args(Bytes::from(parse_json_hex(
+                &format!("{}[].lock_args", field_name),
+                &delegate["lock_args"],
+            )))
+            .key_prefixes(BytesVec::new_builder().set(key_prefixes).build())
+            .build();
+        delegates_builder = delegates_builder.push(delegate);
+    }
+
+    delegates_builder.build()
+}
+
 pub fn parse_json_to_account_chars(
     field_name: &str,
     field: &Value,
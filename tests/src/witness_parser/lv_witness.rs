@@ -0,0 +1,108 @@
+use das_lv::{LvDecode, LvEncode, LvError};
+use das_types::lv_witness::{RawReverseRecordWitness, RawSubAccountWitness};
+
+fn gen_sub_account_witness(version: u32) -> RawSubAccountWitness<'static> {
+    let (old_sub_account_version, new_sub_account_version) =
+        if version >= 3 { (Some(1), Some(2)) } else { (None, None) };
+
+    RawSubAccountWitness {
+        version,
+        action: "edit",
+        signature: &[255; 65],
+        sign_role: &[0],
+        sign_expired_at: &[0; 8],
+        new_root: &[1; 32],
+        proof: &[2; 10],
+        old_sub_account_version,
+        new_sub_account_version,
+        sub_account: &[3; 20],
+        edit_key: b"owner",
+        edit_value: &[4; 42],
+    }
+}
+
+#[test]
+fn test_lv_witness_reverse_record_round_trip() {
+    let witness = RawReverseRecordWitness {
+        version: 1,
+        action: "update",
+        signature: &[255; 65],
+        sign_type: 3,
+        address_payload: &[1; 20],
+        proof: &[2; 10],
+        prev_nonce: None,
+        prev_account: &[],
+        next_root: [3; 32],
+        next_account: "xxxxx.bit",
+    };
+
+    let bytes = witness.encode_lv();
+    // The None should be encoded as an empty field.
+    assert_eq!(&bytes[..4], &4u32.to_le_bytes());
+    assert_eq!(RawReverseRecordWitness::decode_lv(&bytes), Ok(witness.clone()));
+
+    let witness = RawReverseRecordWitness {
+        prev_nonce: Some(1),
+        prev_account: b"yyyyy.bit",
+        ..witness
+    };
+    assert_eq!(RawReverseRecordWitness::decode_lv(&witness.encode_lv()), Ok(witness));
+}
+
+#[test]
+fn test_lv_witness_sub_account_since_version() {
+    let witness = gen_sub_account_witness(2);
+    let bytes_v2 = witness.encode_lv();
+    assert_eq!(RawSubAccountWitness::decode_lv(&bytes_v2), Ok(witness));
+
+    // The fields since version 3 should take two more fields which are both 4 bytes.
+    let witness = gen_sub_account_witness(3);
+    let bytes_v3 = witness.encode_lv();
+    assert_eq!(bytes_v3.len(), bytes_v2.len() + 2 * (4 + 4));
+    assert_eq!(RawSubAccountWitness::decode_lv(&bytes_v3), Ok(witness));
+}
+
+#[test]
+fn test_lv_witness_trailing_bytes_ignored() {
+    let witness = gen_sub_account_witness(3);
+    let mut bytes = witness.encode_lv();
+    bytes.extend([0; 8]);
+
+    assert_eq!(RawSubAccountWitness::decode_lv(&bytes), Ok(witness));
+}
+
+#[test]
+fn challenge_lv_witness_data_missing() {
+    let bytes = gen_sub_account_witness(3).encode_lv();
+
+    let ret = RawSubAccountWitness::decode_lv(&bytes[..(bytes.len() - 1)]);
+    assert!(matches!(
+        ret,
+        Err(LvError::DataMissing {
+            field: "edit_value",
+            ..
+        })
+    ));
+
+    let ret = RawSubAccountWitness::decode_lv(&bytes[..2]);
+    assert!(matches!(ret, Err(LvError::LengthMissing { field: "version", .. })));
+}
+
+#[test]
+fn challenge_lv_witness_invalid_value() {
+    let mut witness = gen_sub_account_witness(3);
+    let version = 3u32.to_le_bytes();
+    let mut bytes = vec![];
+    // Encode the version as 2 bytes.
+    das_lv::push_field(&mut bytes, &version[..2]);
+    bytes.extend(witness.encode_lv()[8..].to_vec());
+    let ret = RawSubAccountWitness::decode_lv(&bytes);
+    assert!(matches!(ret, Err(LvError::InvalidValue { field: "version", .. })));
+
+    witness.action = "";
+    let mut bytes = witness.encode_lv();
+    // Replace the empty action with an invalid utf-8 string.
+    bytes.splice(8..12, [1u32.to_le_bytes().to_vec(), vec![0xff]].concat());
+    let ret = RawSubAccountWitness::decode_lv(&bytes);
+    assert!(matches!(ret, Err(LvError::InvalidValue { field: "action", .. })));
+}
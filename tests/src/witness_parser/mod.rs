@@ -1,3 +1,5 @@
+mod lv_witness;
 mod reverse_record;
 mod sub_account;
+mod tx_resolver;
 mod witness_parser;
@@ -0,0 +1,135 @@
+use std::convert::TryFrom;
+
+use ckb_hash::blake2b_256;
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_types::prelude::*;
+use das_types::constants::*;
+use das_types::packed::*;
+use das_types::util::EntityWrapper;
+use serde_json::{json, Value};
+use witness_parser::error::WitnessParserError;
+use witness_parser::traits::WitnessQueryable;
+use witness_parser::types::CellMeta;
+use witness_parser::{TxResolver, WitnessesParserV1};
+
+use crate::util::accounts::*;
+use crate::util::template_generator::*;
+use crate::util::template_parser::*;
+use crate::util::{self};
+
+fn init(name: &str) -> TemplateGenerator {
+    let mut template = TemplateGenerator::new("unit_test", Some(name.as_bytes().to_vec()));
+
+    template.push_config_cell(DataType::ConfigCellMain, Source::CellDep);
+
+    template
+}
+
+fn gen_account_cell(outputs_data_opt: Option<String>) -> (Value, EntityWrapper, AccountCellData) {
+    let entity = AccountCellData::new_builder()
+        .id(AccountId::try_from(vec![1; 20]).unwrap())
+        .build();
+
+    let lock = parse_json_script_das_lock(
+        "",
+        &json!({
+            "owner_lock_args": OWNER,
+            "manager_lock_args": MANAGER
+        }),
+    );
+    let outputs_data = if let Some(val) = outputs_data_opt {
+        val
+    } else {
+        util::bytes_to_hex(&blake2b_256(entity.as_slice()))
+    };
+    let cell = json!({
+        "tmp_type": "full",
+        "capacity": util::gen_account_cell_capacity(5),
+        "lock": lock,
+        "type": {
+            "code_hash": "{{account-cell-type}}"
+        },
+        "tmp_data": outputs_data
+    });
+
+    (cell, EntityWrapper::AccountCellData(entity.clone()), entity)
+}
+
+fn init_parser(template: &TemplateGenerator) -> Result<WitnessesParserV1, WitnessParserError> {
+    let mut template_parser = TemplateParser::from_data(template.as_json(), u64::MAX);
+    template_parser.try_parse().expect("The template should be valid.");
+    let mock_tx = template_parser.build_mock_tx();
+
+    let inputs = mock_tx
+        .mock_info
+        .inputs
+        .iter()
+        .map(|input| (input.output.clone().into(), JsonBytes::from_bytes(input.data.clone())))
+        .collect();
+    let cell_deps = mock_tx
+        .mock_info
+        .cell_deps
+        .iter()
+        .map(|dep| (dep.output.clone().into(), JsonBytes::from_bytes(dep.data.clone())))
+        .collect();
+    let tx = ckb_jsonrpc_types::TransactionView::from(mock_tx.core_transaction());
+
+    let mut parser = WitnessesParserV1::new(TxResolver::new(tx, inputs, cell_deps)?);
+    parser.init()?;
+
+    Ok(parser)
+}
+
+#[test]
+fn test_tx_resolver_parse_action() {
+    let template = init("test_tx_resolver_parse_action");
+
+    let parser = init_parser(&template).expect("The parser should be inited.");
+
+    assert_eq!(parser.action, Action::UnitTest);
+    assert_eq!(
+        parser.action_params,
+        ActionParams::TestName(String::from("test_tx_resolver_parse_action"))
+    );
+}
+
+#[test]
+fn test_tx_resolver_get_entity() {
+    let mut template = init("test_tx_resolver_get_entity");
+
+    let (cell, wrapper, expected) = gen_account_cell(None);
+    template.push_cell_json_with_entity(cell, Source::CellDep, DataType::AccountCellData, 3, Some(wrapper), None);
+
+    let mut parser = init_parser(&template).expect("The parser should be inited.");
+
+    let entity = parser
+        .get_entity_by_cell_meta::<AccountCellData>(CellMeta::new(1, Source::CellDep))
+        .expect("The AccountCellData should be found.");
+    assert_eq!(entity.as_slice(), expected.as_slice());
+
+    parser
+        .get_entity_by_data_type::<ConfigCellMain>(DataType::ConfigCellMain)
+        .expect("The ConfigCellMain should be found.");
+    let type_id = parser
+        .get_type_id(TypeScript::AccountCellType)
+        .expect("The type ID should be found.");
+    assert_eq!(
+        util::bytes_to_hex(&type_id),
+        util::bytes_to_hex(&util::get_type_id_bytes("account-cell-type"))
+    );
+}
+
+#[test]
+fn test_tx_resolver_hash_mismatched() {
+    let mut template = init("test_tx_resolver_hash_mismatched");
+
+    let (cell, wrapper, _) = gen_account_cell(Some(String::from(
+        "0x0000000000000000000000000000000000000000000000000000000000000000",
+    )));
+    template.push_cell_json_with_entity(cell, Source::CellDep, DataType::AccountCellData, 3, Some(wrapper), None);
+
+    let mut parser = init_parser(&template).expect("The parser should be inited.");
+
+    let ret = parser.get_entity_by_cell_meta::<AccountCellData>(CellMeta::new(1, Source::CellDep));
+    assert!(matches!(ret, Err(WitnessParserError::WitnessHashMismatched { .. })));
+}
@@ -0,0 +1,161 @@
+//! Build the SMTs of ConfigCellAccountList from the account list files, and generate the AccountListProof witnesses which
+//! are required when registering accounts.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use ckb_hash::{Blake2b, Blake2bBuilder};
+use das_types::constants::{ACCOUNT_ID_LENGTH, CKB_HASH_PERSONALIZATION};
+use das_types::packed::*;
+use das_types::prelude::*;
+use das_types::util as das_util;
+use sparse_merkle_tree::default_store::DefaultStore;
+use sparse_merkle_tree::traits::Hasher;
+use sparse_merkle_tree::{SparseMerkleTree, H256};
+
+type Smt = SparseMerkleTree<Blake2bHasher, H256, DefaultStore<H256>>;
+
+struct Blake2bHasher(Blake2b);
+
+impl Default for Blake2bHasher {
+    fn default() -> Self {
+        // The ckb_smt in contracts uses "ckb-default-hash" as personalization, so the hasher must be the same.
+        let blake2b = Blake2bBuilder::new(32)
+            .personal(CKB_HASH_PERSONALIZATION)
+            .key(&[])
+            .build();
+        Blake2bHasher(blake2b)
+    }
+}
+
+impl Hasher for Blake2bHasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.update(h.as_slice());
+    }
+
+    fn write_byte(&mut self, b: u8) {
+        self.0.update(&[b][..]);
+    }
+
+    fn finish(self) -> H256 {
+        let mut hash = [0u8; 32];
+        self.0.finalize(&mut hash);
+        hash.into()
+    }
+}
+
+/// Calculate the account ID of an account, the `.bit` suffix is optional.
+pub fn account_to_id(account: &str) -> [u8; ACCOUNT_ID_LENGTH] {
+    let account = account.strip_suffix(".bit").unwrap_or(account);
+    let mut account_id = [0u8; ACCOUNT_ID_LENGTH];
+    account_id.copy_from_slice(&das_util::blake2b_256(account.as_bytes())[..ACCOUNT_ID_LENGTH]);
+    account_id
+}
+
+/// An account list stored in a sparse-merkle-tree, only its root is saved in the ConfigCellAccountList.
+pub struct AccountList {
+    smt: Smt,
+}
+
+impl AccountList {
+    pub fn new<I: IntoIterator<Item = [u8; ACCOUNT_ID_LENGTH]>>(account_ids: I) -> Self {
+        let leaves = account_ids
+            .into_iter()
+            .map(|account_id| {
+                (
+                    H256::from(das_util::gen_account_list_smt_key(&account_id)),
+                    H256::from(das_util::gen_account_list_smt_value(&account_id)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut smt = Smt::default();
+        smt.update_all(leaves).expect("Should build the SMT successfully");
+
+        AccountList { smt }
+    }
+
+    /// Load the list from a file of accounts without suffix, one account per line, like `tests/data/preserved_accounts.txt`.
+    pub fn from_accounts_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let account_ids = read_lines(path)?
+            .iter()
+            .map(|account| account_to_id(account))
+            .collect::<Vec<_>>();
+
+        Ok(Self::new(account_ids))
+    }
+
+    /// Load the list from a file of hex encoded account hashes, one hash per line, like
+    /// `tests/data/unavailable_account_hashes.txt`.
+    pub fn from_account_hashes_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut account_ids = Vec::new();
+        for line in read_lines(path)? {
+            let account_hash = hex::decode(line.trim_start_matches("0x"))
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", line, err)))?;
+            let account_id = account_hash
+                .get(..ACCOUNT_ID_LENGTH)
+                .and_then(|val| val.try_into().ok())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("{}: the hash is too short", line))
+                })?;
+            account_ids.push(account_id);
+        }
+
+        Ok(Self::new(account_ids))
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.smt.root().to_owned().into()
+    }
+
+    pub fn contains(&self, account_id: &[u8; ACCOUNT_ID_LENGTH]) -> bool {
+        let key = H256::from(das_util::gen_account_list_smt_key(account_id));
+        !self.smt.get(&key).expect("Should get the leaf successfully").is_zero()
+    }
+
+    /// Generate the compiled proof of an account, it is a membership proof if the account is in the list, otherwise it is
+    /// a non-membership proof.
+    pub fn proof(&self, account_id: &[u8; ACCOUNT_ID_LENGTH]) -> Vec<u8> {
+        let keys = vec![H256::from(das_util::gen_account_list_smt_key(account_id))];
+        self.smt
+            .merkle_proof(keys.clone())
+            .expect("Should generate proof successfully")
+            .compile(keys)
+            .expect("Proof should be compiled successfully")
+            .into()
+    }
+}
+
+pub fn gen_config_cell_account_list(preserved: &AccountList, unavailable: &AccountList) -> ConfigCellAccountList {
+    ConfigCellAccountList::new_builder()
+        .preserved_account_root(Hash::from(preserved.root()))
+        .unavailable_account_root(Hash::from(unavailable.root()))
+        .build()
+}
+
+pub fn gen_account_list_proof(
+    preserved: &AccountList,
+    unavailable: &AccountList,
+    account_id: &[u8; ACCOUNT_ID_LENGTH],
+) -> AccountListProof {
+    AccountListProof::new_builder()
+        .account_id(AccountId::try_from(&account_id[..]).unwrap())
+        .preserved_account_proof(Bytes::from(preserved.proof(account_id)))
+        .unavailable_account_proof(Bytes::from(unavailable.proof(account_id)))
+        .build()
+}
+
+fn read_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    let file = File::open(path)?;
+    let mut lines = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+
+    Ok(lines)
+}
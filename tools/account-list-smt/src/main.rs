@@ -0,0 +1,66 @@
+use std::{env, process};
+
+use account_list_smt::{account_to_id, gen_account_list_proof, gen_config_cell_account_list, AccountList};
+use das_types::constants::DataType;
+use das_types::prelude::*;
+use das_types::prettier::Prettier;
+use das_types::util as das_util;
+
+const USAGE: &str = "Usage:
+  account-list-smt config <PRESERVED_ACCOUNTS_FILE> <UNAVAILABLE_ACCOUNT_HASHES_FILE>
+      Print the ConfigCellAccountList built from the account list files.
+  account-list-smt proof <PRESERVED_ACCOUNTS_FILE> <UNAVAILABLE_ACCOUNT_HASHES_FILE> <ACCOUNT>
+      Print the AccountListProof witness of the account.
+
+The PRESERVED_ACCOUNTS_FILE contains one account without suffix per line, and the UNAVAILABLE_ACCOUNT_HASHES_FILE contains
+one hex encoded account hash per line.";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match args.as_slice() {
+        [cmd, preserved_file, unavailable_file] if cmd == "config" => {
+            let (preserved, unavailable) = load_account_lists(preserved_file, unavailable_file);
+            let config = gen_config_cell_account_list(&preserved, &unavailable);
+
+            println!("{}", config.as_prettier());
+            println!(
+                "witness: 0x{}",
+                hex::encode(das_util::wrap_entity_witness_v2(
+                    DataType::ConfigCellAccountList,
+                    config.clone()
+                ))
+            );
+            println!("cell data: 0x{}", hex::encode(das_util::blake2b_256(config.as_slice())));
+        }
+        [cmd, preserved_file, unavailable_file, account] if cmd == "proof" => {
+            let (preserved, unavailable) = load_account_lists(preserved_file, unavailable_file);
+            let account_id = account_to_id(account);
+            let proof = gen_account_list_proof(&preserved, &unavailable, &account_id);
+
+            println!("preserved: {}", preserved.contains(&account_id));
+            println!("unavailable: {}", unavailable.contains(&account_id));
+            println!("{}", proof.as_prettier());
+            println!(
+                "witness: 0x{}",
+                hex::encode(das_util::wrap_entity_witness_v2(DataType::AccountListProof, proof))
+            );
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+fn load_account_lists(preserved_file: &str, unavailable_file: &str) -> (AccountList, AccountList) {
+    let preserved = AccountList::from_accounts_file(preserved_file).unwrap_or_else(|err| {
+        eprintln!("Load {} failed: {}", preserved_file, err);
+        process::exit(1);
+    });
+    let unavailable = AccountList::from_account_hashes_file(unavailable_file).unwrap_or_else(|err| {
+        eprintln!("Load {} failed: {}", unavailable_file, err);
+        process::exit(1);
+    });
+
+    (preserved, unavailable)
+}
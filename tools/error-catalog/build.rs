@@ -0,0 +1,160 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use syn::{Expr, ExprLit, ExprUnary, Item, Lit, Meta, UnOp};
+
+/// All the source files which contain error code enums, the paths are relative to the root of the workspace.
+const SOURCES: &[(&str, &str)] = &[
+    ("das_core::error", "libs/das-core/src/error.rs"),
+    ("dpoint_cell_type::error", "contracts/dpoint-cell-type/src/error.rs"),
+    (
+        "device_key_list_cell_type::error",
+        "contracts/device-key-list-cell-type/src/error.rs",
+    ),
+    ("always_success::error", "contracts/always-success/src/error.rs"),
+];
+
+struct Variant {
+    code: i8,
+    name: String,
+    description: String,
+}
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../..");
+
+    let mut code = String::from("pub static ERROR_SETS: &[ErrorSet] = &[\n");
+    for (module, path) in SOURCES {
+        let path = root.join(path);
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        for (name, variants) in parse_enums(&path) {
+            writeln!(code, "    ErrorSet {{").unwrap();
+            writeln!(code, "        path: \"{}::{}\",", module, name).unwrap();
+            writeln!(code, "        codes: &[").unwrap();
+            for variant in variants {
+                writeln!(
+                    code,
+                    "            ErrorInfo {{ code: {}, name: \"{}\", description: {:?} }},",
+                    variant.code, variant.name, variant.description
+                )
+                .unwrap();
+            }
+            writeln!(code, "        ],").unwrap();
+            writeln!(code, "    }},").unwrap();
+        }
+    }
+    code.push_str("];\n");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("error_sets.rs");
+    fs::write(out, code).unwrap();
+}
+
+/// Parse all `#[repr(i8)]` enums in the file, the discriminants are calculated in the same way as rustc does.
+fn parse_enums(path: &Path) -> Vec<(String, Vec<Variant>)> {
+    let content = fs::read_to_string(path).unwrap_or_else(|err| panic!("Read {} failed: {}", path.display(), err));
+    let file = syn::parse_file(&content).unwrap_or_else(|err| panic!("Parse {} failed: {}", path.display(), err));
+
+    let mut ret = vec![];
+    for item in file.items {
+        let item = match item {
+            Item::Enum(item) if is_repr_i8(&item.attrs) => item,
+            _ => continue,
+        };
+
+        let mut next = 0i8;
+        let mut variants = vec![];
+        for variant in item.variants {
+            let code = match &variant.discriminant {
+                Some((_, expr)) => eval_discriminant(expr)
+                    .unwrap_or_else(|| panic!("Unsupported discriminant of {}::{}", item.ident, variant.ident)),
+                None => next,
+            };
+            next = code.wrapping_add(1);
+
+            let name = variant.ident.to_string();
+            let description = doc_of(&variant.attrs).unwrap_or_else(|| humanize(&name));
+            variants.push(Variant {
+                code,
+                name,
+                description,
+            });
+        }
+
+        ret.push((item.ident.to_string(), variants));
+    }
+
+    ret
+}
+
+fn is_repr_i8(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("repr") => list.tokens.to_string() == "i8",
+        _ => false,
+    })
+}
+
+fn eval_discriminant(expr: &Expr) -> Option<i8> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<i8>().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_), expr, ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<i16>().ok().map(|v| -v as i8),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn doc_of(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(lit.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// Turn a variant name like `AccountCellIdNotMatch` into `Account cell id not match`, it is used when there is no doc
+/// comment on the variant.
+fn humanize(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut ret = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev_is_lower = chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit();
+            let next_is_lower = chars.get(i + 1).map(|c| c.is_lowercase()).unwrap_or(false);
+            // Keep acronyms like SMT or EIP712 together.
+            if prev_is_lower || (chars[i - 1].is_uppercase() && next_is_lower) {
+                ret.push(' ');
+            }
+        }
+
+        if i > 0 && !is_acronym_at(&chars, i) {
+            ret.extend(c.to_lowercase());
+        } else {
+            ret.push(*c);
+        }
+    }
+
+    ret
+}
+
+fn is_acronym_at(chars: &[char], i: usize) -> bool {
+    let is_upper = |idx: usize| chars.get(idx).map(|c| c.is_uppercase()).unwrap_or(false);
+    chars[i].is_uppercase()
+        && (is_upper(i + 1)
+            || (i > 0 && is_upper(i - 1) && !chars.get(i + 1).map(|c| c.is_lowercase()).unwrap_or(false)))
+}
@@ -0,0 +1,508 @@
+//! The known overlaps of the customized error codes, every item is a code and the names of the errors which share it
+//! in at least one contract. The list is maintained by hand, so a new overlap must be reviewed before being added here.
+
+/// The codes in `CUSTOMIZED_CODES` which have different meanings in the error sets used by the same contract, the
+/// names are in the format of `<error set path>::<variant>`.
+pub static KNOWN_COLLISIONS: &[(i8, &[&str])] = &[
+    (
+        50,
+        &[
+            "das_core::error::ErrorCode::WitnessEmpty",
+            "das_core::error::AccountCellErrorCode::WitnessParsingError",
+            "das_core::error::PreAccountCellErrorCode::ApplyHashMismatch",
+            "das_core::error::ReverseRecordRootCellErrorCode::InitialCapacityError",
+            "das_core::error::SubAccountCellErrorCode::SubAccountFeatureNotEnabled",
+        ],
+    ),
+    (
+        51,
+        &[
+            "das_core::error::ErrorCode::WitnessArgsInvalid",
+            "das_core::error::AccountCellErrorCode::AccountCellNextUpdateError",
+            "das_core::error::PreAccountCellErrorCode::ApplySinceMismatch",
+            "das_core::error::ReverseRecordRootCellErrorCode::InitialOutputsDataError",
+            "das_core::error::SubAccountCellErrorCode::ConfigManualInvalid",
+        ],
+    ),
+    (
+        52,
+        &[
+            "das_core::error::ErrorCode::WitnessArgsDecodingError",
+            "das_core::error::AccountCellErrorCode::AccountCellIdNotMatch",
+            "das_core::error::PreAccountCellErrorCode::AccountIdIsInvalid",
+            "das_core::error::ReverseRecordRootCellErrorCode::SignatureVerifyError",
+            "das_core::error::SubAccountCellErrorCode::ConfigCustomRuleInvalid",
+        ],
+    ),
+    (
+        53,
+        &[
+            "das_core::error::ErrorCode::WitnessVersionOrTypeInvalid",
+            "das_core::error::AccountCellErrorCode::AccountCellPermissionDenied",
+            "das_core::error::PreAccountCellErrorCode::AccountAlreadyExistOrProofInvalid",
+            "das_core::error::SubAccountCellErrorCode::ConfigFlagInvalid",
+        ],
+    ),
+    (
+        54,
+        &[
+            "das_core::error::ErrorCode::WitnessVersionUndefined",
+            "das_core::error::AccountCellErrorCode::AccountCellOwnerLockShouldNotBeModified",
+            "das_core::error::PreAccountCellErrorCode::CreateAtIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::ConfigRulesHashMismatch",
+        ],
+    ),
+    (
+        55,
+        &[
+            "das_core::error::ErrorCode::SMTWhiteListTheLockIsNotFound",
+            "das_core::error::AccountCellErrorCode::AccountCellOwnerLockShouldBeModified",
+            "das_core::error::PreAccountCellErrorCode::PriceIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::ConfigRulesHasSyntaxError",
+        ],
+    ),
+    (
+        56,
+        &[
+            "das_core::error::ErrorCode::SMTNewRootMismatch",
+            "das_core::error::AccountCellErrorCode::AccountCellManagerLockShouldBeModified",
+            "das_core::error::PreAccountCellErrorCode::CharSetIsUndefined",
+            "das_core::error::SubAccountCellErrorCode::ConfigRulesPriceError",
+        ],
+    ),
+    (
+        57,
+        &[
+            "das_core::error::ErrorCode::SMTProofVerifyFailed",
+            "das_core::error::AccountCellErrorCode::AccountCellDataNotConsistent",
+            "das_core::error::PreAccountCellErrorCode::CKBIsInsufficient",
+            "das_core::error::SubAccountCellErrorCode::WitnessParsingError",
+        ],
+    ),
+    (
+        58,
+        &[
+            "das_core::error::ErrorCode::SignMethodUnsupported",
+            "das_core::error::AccountCellErrorCode::AccountCellProtectFieldIsModified",
+            "das_core::error::PreAccountCellErrorCode::QuoteIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::WitnessEditKeyInvalid",
+        ],
+    ),
+    (
+        59,
+        &[
+            "das_core::error::ErrorCode::WitnessCannotBeVerified",
+            "das_core::error::AccountCellErrorCode::AccountCellNoMoreFee",
+            "das_core::error::PreAccountCellErrorCode::OwnerLockArgsIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::WitnessEditValueError",
+        ],
+    ),
+    (
+        60,
+        &[
+            "das_core::error::ErrorCode::ApplyRegisterNeedWaitLonger",
+            "das_core::error::AccountCellErrorCode::AccountCellRenewDurationMustLongerThanYear",
+            "das_core::error::PreAccountCellErrorCode::RefundLockMustBeUnique",
+            "das_core::error::SubAccountCellErrorCode::WitnessSignMintIsRequired",
+        ],
+    ),
+    (
+        61,
+        &[
+            "das_core::error::ErrorCode::ApplyRegisterHasTimeout",
+            "das_core::error::AccountCellErrorCode::AccountCellRenewDurationBiggerThanPayed",
+            "das_core::error::PreAccountCellErrorCode::RefundCapacityError",
+            "das_core::error::SubAccountCellErrorCode::WitnessVersionMismatched",
+        ],
+    ),
+    (
+        62,
+        &[
+            "das_core::error::ErrorCode::ApplyLockMustBeUnique",
+            "das_core::error::AccountCellErrorCode::AccountCellRecycleCapacityError",
+            "das_core::error::PreAccountCellErrorCode::SinceMismatch",
+            "das_core::error::SubAccountCellErrorCode::WitnessUpgradeNeeded",
+        ],
+    ),
+    (
+        63,
+        &[
+            "das_core::error::ErrorCode::ApplyRegisterSinceMismatch",
+            "das_core::error::AccountCellErrorCode::AccountCellChangeCapacityError",
+            "das_core::error::PreAccountCellErrorCode::InviterIdShouldBeEmpty",
+            "das_core::error::SubAccountCellErrorCode::CanNotMint",
+        ],
+    ),
+    (
+        64,
+        &[
+            "das_core::error::ErrorCode::ApplyRegisterRefundCapacityError",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordKeyInvalid",
+            "das_core::error::PreAccountCellErrorCode::InviterIdIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::ProofInManualSignRenewListMissing",
+        ],
+    ),
+    (
+        65,
+        &[
+            "das_core::error::ErrorCode::CharSetIsConflict",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordSizeTooLarge",
+            "das_core::error::PreAccountCellErrorCode::InviteeDiscountShouldBeEmpty",
+            "das_core::error::SubAccountCellErrorCode::AccountIsPreserved",
+        ],
+    ),
+    (
+        66,
+        &[
+            "das_core::error::ErrorCode::CharSetIsUndefined",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordNotEmpty",
+            "das_core::error::PreAccountCellErrorCode::InviteeDiscountIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::AccountHasNoPrice",
+        ],
+    ),
+    (
+        67,
+        &[
+            "das_core::error::ErrorCode::AccountCharIsInvalid",
+            "das_core::error::AccountCellErrorCode::AccountCellStatusLocked",
+            "das_core::error::PreAccountCellErrorCode::UdtIsInsufficient",
+            "das_core::error::SubAccountCellErrorCode::BytesToStringFailed",
+        ],
+    ),
+    (
+        68,
+        &[
+            "das_core::error::ErrorCode::AccountIsTooShort",
+            "das_core::error::AccountCellErrorCode::AccountCellIsNotExpired",
+            "das_core::error::SubAccountCellErrorCode::MinimalProfitToDASNotReached",
+        ],
+    ),
+    (
+        69,
+        &[
+            "das_core::error::ErrorCode::AccountIsTooLong",
+            "das_core::error::AccountCellErrorCode::AccountCellInExpirationAuctionConfirmationPeriod",
+            "das_core::error::SubAccountCellErrorCode::ExpirationYearsTooShort",
+        ],
+    ),
+    (
+        70,
+        &[
+            "das_core::error::ErrorCode::AccountListProofIsRequired",
+            "das_core::error::AccountCellErrorCode::AccountCellInExpirationAuctionPeriod",
+            "das_core::error::SubAccountCellErrorCode::ExpirationToleranceReached",
+        ],
+    ),
+    (
+        71,
+        &[
+            "das_core::error::ErrorCode::AccountIsConfusable",
+            "das_core::error::AccountCellErrorCode::AccountCellHasExpired",
+            "das_core::error::SubAccountCellErrorCode::SenderCapacityOverCost",
+        ],
+    ),
+    (
+        72,
+        &[
+            "das_core::error::AccountCellErrorCode::AccountCellStillCanNotRecycle",
+            "das_core::error::SubAccountCellErrorCode::ProfitManagerLockIsRequired",
+        ],
+    ),
+    (
+        73,
+        &[
+            "das_core::error::AccountCellErrorCode::AccountHasNearGracePeriod",
+            "das_core::error::SubAccountCellErrorCode::ProfitMustBeCollected",
+        ],
+    ),
+    (
+        74,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalExist",
+            "das_core::error::SubAccountCellErrorCode::ProfitIsEmpty",
+        ],
+    ),
+    (
+        75,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalActionUndefined",
+            "das_core::error::SubAccountCellErrorCode::CustomRuleIsOff",
+        ],
+    ),
+    (
+        76,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsPlatformLockInvalid",
+            "das_core::error::SubAccountCellErrorCode::NewExpiredAtIsRequired",
+        ],
+    ),
+    (
+        77,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsProtectedUntilInvalid",
+            "das_core::error::SubAccountCellErrorCode::AccountHasNearGracePeriod",
+        ],
+    ),
+    (
+        78,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsSealedUntilInvalid",
+            "das_core::error::SubAccountCellErrorCode::AccountHasInGracePeriod",
+        ],
+    ),
+    (
+        79,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsDelayCountRemainInvalid",
+            "das_core::error::SubAccountCellErrorCode::AccountHasExpired",
+        ],
+    ),
+    (
+        80,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsToLockInvalid",
+            "das_core::error::SubAccountCellErrorCode::AccountStillCanNotBeRecycled",
+        ],
+    ),
+    (
+        81,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsCanNotBeChanged",
+            "das_core::error::SubAccountCellErrorCode::SomeCellWithDasLockMayBeAbused",
+        ],
+    ),
+    (
+        82,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsDelayCountNotEnough",
+            "das_core::error::SubAccountCellErrorCode::MultipleSignRolesIsNotAllowed",
+        ],
+    ),
+    (
+        83,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsDelayCountDecrementError",
+            "das_core::error::SubAccountCellErrorCode::ManualRenewListIsRequired",
+        ],
+    ),
+    (
+        84,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalParamsSealedUntilIncrementError",
+            "das_core::error::SubAccountCellErrorCode::ManualRenewProofIsRequired",
+        ],
+    ),
+    (
+        85,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalNotRevoked",
+            "das_core::error::SubAccountCellErrorCode::ManualRenewProofIsInvalid",
+        ],
+    ),
+    (
+        86,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalInProtectionPeriod",
+            "das_core::error::SubAccountCellErrorCode::EditKeyMismatch",
+        ],
+    ),
+    (
+        87,
+        &[
+            "das_core::error::AccountCellErrorCode::ApprovalFulfillError",
+            "das_core::error::SubAccountCellErrorCode::ApprovalExist",
+        ],
+    ),
+    (
+        88,
+        &[
+            "das_core::error::AccountCellErrorCode::AccountCellBidPriceTooLow",
+            "das_core::error::SubAccountCellErrorCode::ApprovalActionUndefined",
+        ],
+    ),
+    (
+        89,
+        &[
+            "das_core::error::AccountCellErrorCode::AccountCellRecordValueInvalid",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsPlatformLockInvalid",
+        ],
+    ),
+    (
+        90,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceIsNotSorted",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordTtlTooShort",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsProtectedUntilInvalid",
+        ],
+    ),
+    (
+        91,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceIsDiscontinuity",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordsDelegateInvalid",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsSealedUntilInvalid",
+        ],
+    ),
+    (
+        92,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceRelatedCellNotFound",
+            "das_core::error::AccountCellErrorCode::AccountCellRecordKeyNotDelegated",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsDelayCountRemainInvalid",
+        ],
+    ),
+    (
+        93,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceRelatedCellMissing",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsToLockInvalid",
+        ],
+    ),
+    (
+        94,
+        &[
+            "das_core::error::ErrorCode::ProposalCellTypeError",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsCanNotBeChanged",
+        ],
+    ),
+    (
+        95,
+        &[
+            "das_core::error::ErrorCode::ProposalCellAccountIdError",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsDelayCountNotEnough",
+        ],
+    ),
+    (
+        96,
+        &[
+            "das_core::error::ErrorCode::ProposalCellNextError",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsDelayCountDecrementError",
+        ],
+    ),
+    (
+        97,
+        &[
+            "das_core::error::ErrorCode::ProposalFieldCanNotBeModified",
+            "das_core::error::SubAccountCellErrorCode::ApprovalParamsSealedUntilIncrementError",
+        ],
+    ),
+    (
+        98,
+        &[
+            "das_core::error::ErrorCode::ProposalWitnessCanNotBeModified",
+            "das_core::error::SubAccountCellErrorCode::ApprovalNotRevoked",
+        ],
+    ),
+    (
+        100,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmNewAccountCellDataError",
+            "das_core::error::SubAccountCellErrorCode::ApprovalFulfillError",
+        ],
+    ),
+    (
+        101,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmNewAccountCellCapacityError",
+            "das_core::error::SubAccountCellErrorCode::AccountStatusError",
+        ],
+    ),
+    (
+        102,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmNewAccountWitnessError",
+            "das_core::error::SubAccountCellErrorCode::SignExpiredAtTooLarge",
+        ],
+    ),
+    (
+        103,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmPreAccountCellExpired",
+            "das_core::error::SubAccountCellErrorCode::SignExpiredAtReached",
+        ],
+    ),
+    (
+        104,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmNeedWaitLonger",
+            "das_core::error::SubAccountCellErrorCode::SignError",
+        ],
+    ),
+    (
+        105,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmInitialRecordsMismatch",
+            "das_core::error::SubAccountCellErrorCode::SubAccountRenewSignIsNotAllowed",
+        ],
+    ),
+    (
+        110,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmAccountLockArgsIsInvalid",
+            "das_core::error::SubAccountCellErrorCode::SubAccountSignMintSignatureRequired",
+        ],
+    ),
+    (
+        111,
+        &[
+            "das_core::error::ErrorCode::ProposalConfirmRefundError",
+            "das_core::error::SubAccountCellErrorCode::SubAccountCellCapacityError",
+        ],
+    ),
+    (
+        112,
+        &[
+            "das_core::error::ErrorCode::ProposalSlicesCanNotBeEmpty",
+            "das_core::error::SubAccountCellErrorCode::SubAccountCellAccountIdError",
+        ],
+    ),
+    (
+        113,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceNotEndCorrectly",
+            "das_core::error::SubAccountCellErrorCode::SubAccountCellConsistencyError",
+        ],
+    ),
+    (
+        114,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceMustStartWithAccountCell",
+            "das_core::error::SubAccountCellErrorCode::SubAccountInitialValueError",
+        ],
+    ),
+    (
+        115,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceMustContainMoreThanOneElement",
+            "das_core::error::SubAccountCellErrorCode::SubAccountSigVerifyError",
+        ],
+    ),
+    (
+        116,
+        &[
+            "das_core::error::ErrorCode::ProposalSliceItemMustBeUniqueAccount",
+            "das_core::error::SubAccountCellErrorCode::SubAccountFieldNotEditable",
+        ],
+    ),
+    (
+        117,
+        &[
+            "das_core::error::ErrorCode::ProposalRecycleNeedWaitLonger",
+            "das_core::error::SubAccountCellErrorCode::SubAccountEditLockError",
+        ],
+    ),
+    (
+        118,
+        &[
+            "das_core::error::ErrorCode::ProposalRecycleRefundAmountError",
+            "das_core::error::SubAccountCellErrorCode::SubAccountJoinBetaError",
+        ],
+    ),
+    (
+        119,
+        &[
+            "das_core::error::ErrorCode::PrevProposalItemNotFound",
+            "das_core::error::SubAccountCellErrorCode::SubAccountProfitError",
+        ],
+    ),
+];
@@ -0,0 +1,229 @@
+//! A catalog of all the error codes which may be returned by the contracts.
+//!
+//! The error codes are parsed from the source code of the contracts when building, so the catalog is always
+//! consistent with the contracts in the same commit.
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+pub use known_collisions::KNOWN_COLLISIONS;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+mod known_collisions;
+
+/// The customized error codes, every type script can define its own meanings of them, the rest codes should have
+/// the same meanings in all error sets.
+pub const CUSTOMIZED_CODES: RangeInclusive<i8> = 50..=126;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorInfo {
+    pub code: i8,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorSet {
+    pub path: &'static str,
+    pub codes: &'static [ErrorInfo],
+}
+
+impl ErrorSet {
+    pub fn get(&self, code: i8) -> Option<&'static ErrorInfo> {
+        self.codes.iter().find(|info| info.code == code)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/error_sets.rs"));
+
+const ERROR_CODE: &str = "das_core::error::ErrorCode";
+const ACCOUNT_CELL_ERROR_CODE: &str = "das_core::error::AccountCellErrorCode";
+const PRE_ACCOUNT_CELL_ERROR_CODE: &str = "das_core::error::PreAccountCellErrorCode";
+const REVERSE_RECORD_ROOT_CELL_ERROR_CODE: &str = "das_core::error::ReverseRecordRootCellErrorCode";
+const SUB_ACCOUNT_CELL_ERROR_CODE: &str = "das_core::error::SubAccountCellErrorCode";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Contract {
+    pub name: &'static str,
+    /// The error sets which may be returned by the contract, the first one is the contract's own error set and it
+    /// takes priority when the same code is defined in more than one set.
+    pub error_sets: &'static [&'static str],
+}
+
+/// The error sets used by each contract, including the ones returned by the shared verifiers in das-core.
+pub static CONTRACTS: &[Contract] = &[
+    Contract {
+        name: "always-success",
+        error_sets: &["always_success::error::Error"],
+    },
+    Contract {
+        name: "account-cell-type",
+        error_sets: &[ACCOUNT_CELL_ERROR_CODE, ERROR_CODE, SUB_ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "account-sale-cell-type",
+        error_sets: &[ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "apply-register-cell-type",
+        error_sets: &[ERROR_CODE],
+    },
+    Contract {
+        name: "balance-cell-type",
+        error_sets: &[ERROR_CODE],
+    },
+    Contract {
+        name: "config-cell-type",
+        error_sets: &[ERROR_CODE],
+    },
+    Contract {
+        name: "income-cell-type",
+        error_sets: &[ERROR_CODE],
+    },
+    Contract {
+        name: "offer-cell-type",
+        error_sets: &[ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "pre-account-cell-type",
+        error_sets: &[PRE_ACCOUNT_CELL_ERROR_CODE, ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "proposal-cell-type",
+        error_sets: &[ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "reverse-record-cell-type",
+        error_sets: &[ERROR_CODE],
+    },
+    Contract {
+        name: "reverse-record-root-cell-type",
+        error_sets: &[REVERSE_RECORD_ROOT_CELL_ERROR_CODE, ERROR_CODE],
+    },
+    Contract {
+        name: "sub-account-cell-type",
+        error_sets: &[SUB_ACCOUNT_CELL_ERROR_CODE, ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "eip712-lib",
+        error_sets: &[ERROR_CODE, ACCOUNT_CELL_ERROR_CODE],
+    },
+    Contract {
+        name: "device-key-list-cell-type",
+        error_sets: &["device_key_list_cell_type::error::ErrorCode"],
+    },
+    Contract {
+        name: "dpoint-cell-type",
+        error_sets: &["dpoint_cell_type::error::ErrorCode"],
+    },
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolved {
+    pub error_set: &'static str,
+    pub info: &'static ErrorInfo,
+}
+
+/// Two error sets of the same contract give different meanings to one code, and the overlap is not listed in
+/// `KNOWN_COLLISIONS`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collision {
+    pub contract: &'static str,
+    pub code: i8,
+    pub names: Vec<String>,
+}
+
+pub fn error_set(path: &str) -> Option<&'static ErrorSet> {
+    ERROR_SETS.iter().find(|set| set.path == path)
+}
+
+pub fn contract(name: &str) -> Option<&'static Contract> {
+    CONTRACTS.iter().find(|contract| contract.name == name)
+}
+
+/// Resolve an exit code of the contract to all the possible errors, the one from the contract's own error set comes
+/// first. Return None if the contract is unknown.
+pub fn resolve(contract_name: &str, code: i8) -> Option<Vec<Resolved>> {
+    let contract = contract(contract_name)?;
+
+    let mut ret: Vec<Resolved> = vec![];
+    for path in contract.error_sets {
+        let set = error_set(path).unwrap_or_else(|| panic!("The error set {} is not in the catalog.", path));
+        if let Some(info) = set.get(code) {
+            // The reserved errors are copied into every error set, no need to list them repeatedly.
+            if ret.iter().all(|item| item.info.name != info.name) {
+                ret.push(Resolved {
+                    error_set: set.path,
+                    info,
+                });
+            }
+        }
+    }
+
+    Some(ret)
+}
+
+/// Resolve an exit code of the contract to a human readable message.
+pub fn describe(contract_name: &str, code: i8) -> Option<String> {
+    let resolved = resolve(contract_name, code)?;
+
+    let msg = match resolved.as_slice() {
+        [] => format!("{} exited with unknown error code {}.", contract_name, code),
+        [first, rest @ ..] => {
+            let mut msg = format!(
+                "{} exited with error code {}: {} ({}), {}.",
+                contract_name, code, first.info.name, first.error_set, first.info.description
+            );
+            for item in rest {
+                msg.push_str(&format!(
+                    "\n  It may also be {} ({}), {}.",
+                    item.info.name, item.error_set, item.info.description
+                ));
+            }
+            msg
+        }
+    };
+
+    Some(msg)
+}
+
+/// Find all the codes which have different meanings in the error sets of the contract, only the overlaps listed in
+/// `KNOWN_COLLISIONS` are allowed.
+pub fn collisions(contract: &Contract) -> Vec<Collision> {
+    let mut names_by_code: BTreeMap<i8, Vec<String>> = BTreeMap::new();
+    for path in contract.error_sets {
+        let set = error_set(path).unwrap_or_else(|| panic!("The error set {} is not in the catalog.", path));
+        for info in set.codes.iter() {
+            let names = names_by_code.entry(info.code).or_default();
+            // The reserved errors are copied into every error set, they are not collisions.
+            if names.iter().all(|item| !item.ends_with(&format!("::{}", info.name))) {
+                names.push(format!("{}::{}", set.path, info.name));
+            }
+        }
+    }
+
+    names_by_code
+        .into_iter()
+        .filter(|(code, names)| names.len() > 1 && !is_known_collision(*code, names))
+        .map(|(code, names)| Collision {
+            contract: contract.name,
+            code,
+            names,
+        })
+        .collect()
+}
+
+fn is_known_collision(code: i8, names: &[String]) -> bool {
+    KNOWN_COLLISIONS.iter().any(|(known_code, known_names)| {
+        *known_code == code && names.iter().all(|name| known_names.contains(&name.as_str()))
+    })
+}
+
+/// Export the whole catalog as JSON.
+pub fn to_json() -> Value {
+    json!({
+        "error_sets": ERROR_SETS,
+        "contracts": CONTRACTS,
+    })
+}
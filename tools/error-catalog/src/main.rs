@@ -0,0 +1,52 @@
+use std::{env, process};
+
+const USAGE: &str = "Usage:
+  error-catalog json                    Print the catalog of all error codes in JSON.
+  error-catalog <CONTRACT> <EXIT_CODE>  Print the meaning of the exit code returned by the contract.";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match args.as_slice() {
+        [cmd] if cmd == "json" => {
+            println!("{}", serde_json::to_string_pretty(&error_catalog::to_json()).unwrap());
+        }
+        [contract, code] => {
+            let code = match parse_exit_code(code) {
+                Some(code) => code,
+                None => {
+                    eprintln!("The exit code should be an integer in -128..=255 .");
+                    process::exit(1);
+                }
+            };
+
+            match error_catalog::describe(contract, code) {
+                Some(msg) => println!("{}", msg),
+                None => {
+                    let contracts = error_catalog::CONTRACTS
+                        .iter()
+                        .map(|item| item.name)
+                        .collect::<Vec<_>>();
+                    eprintln!(
+                        "Unknown contract {}, available contracts: {}",
+                        contract,
+                        contracts.join(", ")
+                    );
+                    process::exit(1);
+                }
+            }
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+/// The exit code may be printed as i8 by ckb or as u8 by shells, both of them are accepted.
+fn parse_exit_code(code: &str) -> Option<i8> {
+    match code.parse::<i16>().ok()? {
+        val @ -128..=127 => Some(val as i8),
+        val @ 128..=255 => Some(val as u8 as i8),
+        _ => None,
+    }
+}
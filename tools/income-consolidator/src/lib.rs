@@ -0,0 +1,299 @@
+//! Plan the consolidate_income transactions for keepers. The planner follows the same rules as the ConsolidateIncome action of
+//! income-cell-type, so the plans it produces can be turned into transactions which pass the verification directly.
+
+use das_types::constants::wallet_lock;
+use das_types::packed::*;
+use das_types::prelude::*;
+
+/// The same as `das_core::constants::RATE_BASE`, the profit rates in ConfigCellProfitRate are based on it.
+pub const RATE_BASE: u64 = 10_000;
+/// The planner tries every combination of the candidates, so when there are more IncomeCells than this, only the ones
+/// which bring the most fee are tried.
+pub const MAX_CANDIDATES: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncomeConfig {
+    pub basic_capacity: u64,
+    pub max_records: usize,
+    pub min_transfer_capacity: u64,
+    pub income_consolidate_profit_rate: u64,
+}
+
+impl IncomeConfig {
+    pub fn new(config_income: ConfigCellIncomeReader, config_profit_rate: ConfigCellProfitRateReader) -> Self {
+        IncomeConfig {
+            basic_capacity: u64::from(config_income.basic_capacity()),
+            max_records: u32::from(config_income.max_records()) as usize,
+            min_transfer_capacity: u64::from(config_income.min_transfer_capacity()),
+            income_consolidate_profit_rate: u32::from(config_profit_rate.income_consolidate()) as u64,
+        }
+    }
+}
+
+/// A cell which should be created in outputs for the owner of a record.
+///
+/// The lock of every transfer should be used by only one cell in outputs, and the cells with das-lock of ETHTypedData
+/// type should have balance-cell-type in their type field.
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    pub lock: Script,
+    pub capacity: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Plan {
+    /// The indexes of the IncomeCells which should be put in inputs.
+    pub inputs: Vec<usize>,
+    /// The records of every IncomeCell in outputs, the capacity of each IncomeCell should be the total capacity of its
+    /// records.
+    pub outputs: Vec<Vec<(Script, u64)>>,
+    pub transfers: Vec<Transfer>,
+    /// The consolidating fee which the keeper can take from the transaction.
+    pub keeper_fee: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanError {
+    NotEnoughCandidates,
+    NoFeasiblePlan,
+}
+
+/// Find the plan which brings the keeper the most fee from the IncomeCells, if some plans bring the same fee, the one
+/// which consolidates more IncomeCells is preferred.
+pub fn plan(cells: &[IncomeCellData], config: &IncomeConfig) -> Result<Plan, PlanError> {
+    let mut candidates = cells
+        .iter()
+        .enumerate()
+        .filter(|(_, cell)| is_consolidatable(cell))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    if candidates.len() < 2 {
+        return Err(PlanError::NotEnoughCandidates);
+    }
+    if candidates.len() > MAX_CANDIDATES {
+        // Pick the candidates greedily by the fee each of them brings alone, the order of inputs is kept.
+        candidates.sort_by_key(|&i| core::cmp::Reverse(estimate_fee(&cells[i], config)));
+        candidates.truncate(MAX_CANDIDATES);
+        candidates.sort();
+    }
+
+    let mut best: Option<Plan> = None;
+    for mask in 1u32..(1 << candidates.len()) {
+        if mask.count_ones() < 2 {
+            continue;
+        }
+
+        let inputs = candidates
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| mask & (1 << bit) != 0)
+            .map(|(_, &i)| i)
+            .collect::<Vec<_>>();
+        if let Some(plan) = plan_inputs(cells, config, inputs) {
+            if best.as_ref().map(|best| is_better(&plan, best)).unwrap_or(true) {
+                best = Some(plan);
+            }
+        }
+    }
+
+    best.ok_or(PlanError::NoFeasiblePlan)
+}
+
+/// Plan the consolidation of the given IncomeCells, None is returned if they can not be consolidated together.
+pub fn plan_inputs(cells: &[IncomeCellData], config: &IncomeConfig, inputs: Vec<usize>) -> Option<Plan> {
+    if inputs.len() < 2 || inputs.iter().any(|&i| !is_consolidatable(&cells[i])) {
+        return None;
+    }
+
+    // Always include DAS in the members which is free from consolidating fee.
+    let mut creators = inputs.iter().map(|&i| cells[i].creator()).collect::<Vec<_>>();
+    creators.push(wallet_lock().clone());
+
+    let mut records: Vec<(Script, u64)> = Vec::new();
+    for &i in inputs.iter() {
+        for record in cells[i].records().into_iter() {
+            merge_record(&mut records, record.belong_to(), u64::from(record.capacity()));
+        }
+    }
+
+    let rate = config.income_consolidate_profit_rate;
+    let (records_should_transfer, records_should_keep): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .partition(|record| capacity_after_fee(record.1, rate) >= config.min_transfer_capacity);
+
+    let remain_capacity = records_should_keep.iter().map(|record| record.1).sum::<u64>();
+    let need_pad = remain_capacity != 0
+        && remain_capacity < config.basic_capacity * count_income_cells(records_should_keep.len(), config) as u64;
+
+    let mut output_records = records_should_keep;
+    let mut total_capacity = remain_capacity;
+    let mut transfers = Vec::new();
+    let mut keeper_fee = 0;
+
+    let mut records_should_transfer = records_should_transfer
+        .into_iter()
+        .map(|record| {
+            let is_creator = creators.iter().any(|creator| creator.as_slice() == record.0.as_slice());
+            (record.0, record.1, is_creator)
+        })
+        .collect::<Vec<_>>();
+    if need_pad {
+        // Pad with the records of creators first because they are free from fee, then the larger records which are more
+        // likely to be transferred partially.
+        records_should_transfer.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1)));
+    }
+
+    for (lock, capacity, is_creator) in records_should_transfer {
+        let required_capacity = config.basic_capacity * count_income_cells(output_records.len(), config) as u64;
+        if !need_pad || total_capacity >= required_capacity {
+            let capacity_transferred = if is_creator {
+                capacity
+            } else {
+                capacity_after_fee(capacity, rate)
+            };
+            keeper_fee += capacity - capacity_transferred;
+            transfers.push(Transfer {
+                lock,
+                capacity: capacity_transferred,
+            });
+            continue;
+        }
+
+        let deficit =
+            config.basic_capacity * count_income_cells(output_records.len() + 1, config) as u64 - total_capacity;
+        match split_for_pad(capacity, deficit, is_creator, config) {
+            Some((capacity_transferred, capacity_for_pad)) => {
+                keeper_fee += capacity - capacity_for_pad - capacity_transferred;
+                transfers.push(Transfer {
+                    lock: lock.clone(),
+                    capacity: capacity_transferred,
+                });
+                output_records.push((lock, capacity_for_pad));
+                total_capacity += capacity_for_pad;
+            }
+            None => {
+                // The whole record is used for padding, so nothing is transferred.
+                output_records.push((lock, capacity));
+                total_capacity += capacity;
+            }
+        }
+    }
+
+    let outputs = distribute_records(output_records, config)?;
+
+    Some(Plan {
+        inputs,
+        outputs,
+        transfers,
+        keeper_fee,
+    })
+}
+
+fn is_consolidatable(cell: &IncomeCellData) -> bool {
+    // The IncomeCell which has only one record belong to the creator can not be consolidated.
+    let records = cell.records();
+    !(records.len() == 1 && records.get(0).unwrap().belong_to().as_slice() == cell.creator().as_slice())
+}
+
+/// Estimate the consolidating fee which the keeper can take from an IncomeCell alone, the records of the creator are
+/// free from fee and the records which are too small to be transferred are ignored.
+fn estimate_fee(cell: &IncomeCellData, config: &IncomeConfig) -> u64 {
+    let rate = config.income_consolidate_profit_rate;
+    let creator = cell.creator();
+    let mut records: Vec<(Script, u64)> = Vec::new();
+    for record in cell.records().into_iter() {
+        merge_record(&mut records, record.belong_to(), u64::from(record.capacity()));
+    }
+
+    records
+        .into_iter()
+        .filter(|record| {
+            record.0.as_slice() != creator.as_slice()
+                && record.0.as_slice() != wallet_lock().as_slice()
+                && capacity_after_fee(record.1, rate) >= config.min_transfer_capacity
+        })
+        .map(|record| record.1 - capacity_after_fee(record.1, rate))
+        .sum()
+}
+
+fn is_better(a: &Plan, b: &Plan) -> bool {
+    let score = |plan: &Plan| {
+        (
+            plan.keeper_fee,
+            plan.inputs.len() as i64 - plan.outputs.len() as i64,
+            -((plan.outputs.len() + plan.transfers.len()) as i64),
+        )
+    };
+
+    score(a) > score(b)
+}
+
+fn merge_record(records: &mut Vec<(Script, u64)>, belong_to: Script, capacity: u64) {
+    match records
+        .iter_mut()
+        .find(|record| record.0.as_slice() == belong_to.as_slice())
+    {
+        Some(record) => record.1 += capacity,
+        None => records.push((belong_to, capacity)),
+    }
+}
+
+fn capacity_after_fee(capacity: u64, rate: u64) -> u64 {
+    capacity / RATE_BASE * (RATE_BASE - rate)
+}
+
+fn count_income_cells(records_count: usize, config: &IncomeConfig) -> usize {
+    records_count.div_ceil(config.max_records)
+}
+
+/// Split a record into the capacity transferred and the capacity used for padding which is at least the deficit, None is
+/// returned if the whole record should be used for padding.
+fn split_for_pad(capacity: u64, deficit: u64, is_creator: bool, config: &IncomeConfig) -> Option<(u64, u64)> {
+    if deficit >= capacity {
+        return None;
+    }
+
+    let rate = config.income_consolidate_profit_rate;
+    let (capacity_transferred, capacity_for_pad, capacity_should_be_transferred) = if is_creator {
+        (capacity - deficit, deficit, capacity)
+    } else {
+        // The contract recalculates the capacity with fee by `capacity_transferred / (RATE_BASE - rate) * RATE_BASE`, so
+        // keep it a multiple of RATE_BASE to make the capacity used for padding predictable.
+        let capacity_with_fee = (capacity - deficit) / RATE_BASE * RATE_BASE;
+        (
+            capacity_with_fee / RATE_BASE * (RATE_BASE - rate),
+            capacity - capacity_with_fee,
+            capacity_after_fee(capacity, rate),
+        )
+    };
+
+    if capacity_transferred < config.min_transfer_capacity || capacity_transferred >= capacity_should_be_transferred {
+        return None;
+    }
+
+    Some((capacity_transferred, capacity_for_pad))
+}
+
+/// Distribute the records into as few IncomeCells as possible, every IncomeCell should have enough capacity.
+fn distribute_records(mut records: Vec<(Script, u64)>, config: &IncomeConfig) -> Option<Vec<Vec<(Script, u64)>>> {
+    let count = count_income_cells(records.len(), config);
+    let mut outputs: Vec<(Vec<(Script, u64)>, u64)> = vec![(Vec::new(), 0); count];
+
+    // Always put the largest record into the IncomeCell with the least capacity.
+    records.sort_by_key(|record| core::cmp::Reverse(record.1));
+    for record in records {
+        let (output_records, total) = outputs
+            .iter_mut()
+            .filter(|(output_records, _)| output_records.len() < config.max_records)
+            .min_by_key(|(_, total)| *total)?;
+        *total += record.1;
+        output_records.push(record);
+    }
+
+    if outputs.iter().any(|(_, total)| *total < config.basic_capacity) {
+        return None;
+    }
+
+    Some(outputs.into_iter().map(|(output_records, _)| output_records).collect())
+}
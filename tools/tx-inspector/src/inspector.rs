@@ -0,0 +1,492 @@
+use std::str::FromStr;
+
+use ckb_jsonrpc_types::JsonBytes;
+use ckb_mock_tx_types::MockTransaction;
+use ckb_types::core::DepType;
+use ckb_types::prelude::*;
+use ckb_types::{bytes, packed as ckb_packed};
+use das_lv::LvDecode;
+use das_types::constants::*;
+use das_types::data_parser;
+use das_types::lv_witness::{RawReverseRecordWitness, RawSubAccountWitness};
+use das_types::packed::*;
+use das_types::prettier::Prettier;
+use witness_parser::error::WitnessParserError;
+use witness_parser::traits::WitnessQueryable;
+use witness_parser::types::{CellMeta, Hash};
+use witness_parser::{TxResolver, WitnessesParserV1};
+
+use crate::lv_parser::*;
+
+const TYPE_SCRIPTS: [TypeScript; 15] = [
+    TypeScript::AccountCellType,
+    TypeScript::AccountSaleCellType,
+    TypeScript::AccountAuctionCellType,
+    TypeScript::ApplyRegisterCellType,
+    TypeScript::BalanceCellType,
+    TypeScript::IncomeCellType,
+    TypeScript::OfferCellType,
+    TypeScript::PreAccountCellType,
+    TypeScript::ProposalCellType,
+    TypeScript::ReverseRecordCellType,
+    TypeScript::SubAccountCellType,
+    TypeScript::ReverseRecordRootCellType,
+    TypeScript::DPointCellType,
+    TypeScript::EIP712Lib,
+    TypeScript::DeviceKeyListCellType,
+];
+
+type ResolvedCells = Vec<(ckb_packed::CellOutput, bytes::Bytes)>;
+
+/// The cells of the transaction in the same order as ckb-vm sees them.
+struct Cells {
+    cell_deps: ResolvedCells,
+    inputs: ResolvedCells,
+    outputs: ResolvedCells,
+}
+
+impl Cells {
+    fn get(&self, source: Source) -> &ResolvedCells {
+        match source {
+            Source::CellDep => &self.cell_deps,
+            Source::Input => &self.inputs,
+            Source::Output => &self.outputs,
+        }
+    }
+}
+
+pub fn inspect(mock_tx: MockTransaction) -> Result<String, String> {
+    let tx = mock_tx.core_transaction();
+    let cells = resolve_cells(&mock_tx)?;
+
+    let to_json = |cells: &ResolvedCells| {
+        cells
+            .iter()
+            .map(|(output, data)| (output.to_owned().into(), JsonBytes::from_bytes(data.to_owned())))
+            .collect::<Vec<_>>()
+    };
+    let resolver = TxResolver::new(tx.clone().into(), to_json(&cells.inputs), to_json(&cells.cell_deps))
+        .map_err(|err| err.to_string())?;
+    let mut parser = WitnessesParserV1::new(resolver.clone());
+    parser.init().map_err(|err| err.to_string())?;
+
+    let mut report = vec![
+        format!("Transaction: 0x{}", hex::encode(tx.hash().raw_data())),
+        format!("Action: {}", parser.action_data.as_prettier()),
+        format!("  action: {}", parser.action),
+        format!("  params: {:?}", parser.action_params),
+        format!(
+            "  role: {}",
+            parser
+                .action_params
+                .get_role()
+                .map(|role| format!("{:?}", role))
+                .unwrap_or(String::from("None"))
+        ),
+    ];
+
+    let type_ids = load_type_ids(&mut parser);
+    if type_ids.is_empty() {
+        report.push(String::from(
+            "⚠️ The ConfigCellMain is missing, the DAS cells can only be identified by their witnesses.",
+        ));
+    }
+
+    for source in [Source::CellDep, Source::Input, Source::Output] {
+        report.push(format!("{:?}:", source));
+        for (index, (output, data)) in cells.get(source).iter().enumerate() {
+            report.extend(inspect_cell(&mut parser, &type_ids, source, index, output, data));
+        }
+    }
+
+    report.push(String::from("Witnesses:"));
+    let mut index = 0;
+    while let Some(witness) = resolver.witness(index) {
+        if let Some(line) = inspect_witness(index, witness) {
+            report.push(line);
+        }
+        index += 1;
+    }
+
+    Ok(report.join("\n"))
+}
+
+fn resolve_cells(mock_tx: &MockTransaction) -> Result<Cells, String> {
+    let tx = mock_tx.core_transaction();
+    let find_dep = |out_point: &ckb_packed::OutPoint| {
+        mock_tx
+            .mock_info
+            .cell_deps
+            .iter()
+            .find(|dep| dep.cell_dep.out_point().as_slice() == out_point.as_slice())
+            .ok_or(format!("The cell_dep {} is not resolved.", out_point))
+    };
+
+    let mut cell_deps = vec![];
+    for cell_dep in tx.cell_deps_iter() {
+        let dep = find_dep(&cell_dep.out_point())?;
+        if cell_dep.dep_type() == DepType::DepGroup.into() {
+            let out_points = ckb_packed::OutPointVec::from_slice(&dep.data)
+                .map_err(|err| format!("The dep group {} is invalid: {}", cell_dep.out_point(), err))?;
+            for out_point in out_points.into_iter() {
+                let dep = find_dep(&out_point)?;
+                cell_deps.push((dep.output.clone(), dep.data.clone()));
+            }
+        } else {
+            cell_deps.push((dep.output.clone(), dep.data.clone()));
+        }
+    }
+
+    let mut inputs = vec![];
+    for input in tx.inputs() {
+        let mock_input = mock_tx
+            .mock_info
+            .inputs
+            .iter()
+            .find(|mock_input| mock_input.input.previous_output().as_slice() == input.previous_output().as_slice())
+            .ok_or(format!("The input {} is not resolved.", input.previous_output()))?;
+        inputs.push((mock_input.output.clone(), mock_input.data.clone()));
+    }
+
+    let outputs = tx.outputs_with_data_iter().collect();
+
+    Ok(Cells {
+        cell_deps,
+        inputs,
+        outputs,
+    })
+}
+
+fn load_type_ids(parser: &mut WitnessesParserV1) -> Vec<(Hash, TypeScript)> {
+    TYPE_SCRIPTS
+        .iter()
+        .filter_map(|&type_script| {
+            parser
+                .get_type_id(type_script)
+                .ok()
+                .map(|type_id| (type_id, type_script))
+        })
+        .collect()
+}
+
+fn inspect_cell(
+    parser: &mut WitnessesParserV1,
+    type_ids: &[(Hash, TypeScript)],
+    source: Source,
+    index: usize,
+    output: &ckb_packed::CellOutput,
+    data: &bytes::Bytes,
+) -> Vec<String> {
+    let capacity: u64 = output.capacity().unpack();
+    let lock = output.lock();
+    let mut lines = vec![];
+
+    let type_script = match output.type_().to_opt() {
+        Some(script) if script.code_hash().raw_data() == config_cell_type().code_hash().raw_data() => {
+            let data_type = script
+                .args()
+                .raw_data()
+                .get(..4)
+                .map(|v| u32::from_le_bytes(v.try_into().unwrap()))
+                .and_then(|v| DataType::try_from(v).ok());
+            match data_type {
+                Some(data_type) => {
+                    lines.push(format!(
+                        "  [{}] ConfigCell({:?}) capacity: {}",
+                        index, data_type, capacity
+                    ));
+                    lines.push(format!("    witness: {}", inspect_config(parser, data_type)));
+                }
+                None => lines.push(format!("  [{}] ConfigCell(unknown) capacity: {}", index, capacity)),
+            }
+            return lines;
+        }
+        Some(script) => type_ids
+            .iter()
+            .find(|(type_id, _)| type_id == script.code_hash().raw_data().as_ref())
+            .map(|(_, type_script)| type_script.to_owned()),
+        None => None,
+    };
+
+    match type_script {
+        Some(type_script) => lines.push(format!("  [{}] {} capacity: {}", index, type_script, capacity)),
+        None => lines.push(format!(
+            "  [{}] {} capacity: {}",
+            index,
+            if output.type_().is_none() {
+                "normal cell"
+            } else {
+                "unknown cell"
+            },
+            capacity
+        )),
+    }
+
+    if lock.code_hash().raw_data() == das_lock().code_hash().raw_data() {
+        let args = lock.args().raw_data();
+        lines.push(format!(
+            "    lock: das-lock {{ owner: ({:?}, 0x{}), manager: ({:?}, 0x{}) }}",
+            data_parser::das_lock_args::get_owner_type(&args).and_then(|v| DasLockType::try_from(v).ok()),
+            hex::encode(data_parser::das_lock_args::get_owner_lock_args(&args).unwrap_or_default()),
+            data_parser::das_lock_args::get_manager_type(&args).and_then(|v| DasLockType::try_from(v).ok()),
+            hex::encode(data_parser::das_lock_args::get_manager_lock_args(&args).unwrap_or_default()),
+        ));
+    } else {
+        lines.push(format!(
+            "    lock: {{ code_hash: 0x{}, args: 0x{} }}",
+            hex::encode(lock.code_hash().raw_data()),
+            hex::encode(lock.args().raw_data())
+        ));
+    }
+
+    if let Some(type_script) = type_script {
+        if let Some(line) = inspect_cell_data(type_script, data) {
+            lines.push(format!("    data: {}", line));
+        }
+    }
+
+    let cell_meta = CellMeta::new(index, source);
+    if let Ok(witness_meta) = parser.get_witness_meta_by_cell_meta(cell_meta) {
+        let entity = inspect_entity(parser, cell_meta, witness_meta.data_type, witness_meta.version)
+            .unwrap_or_else(|err| format!("❌ {}", err));
+        lines.push(format!(
+            "    witness: witnesses[{}] {:?} v{} {}",
+            witness_meta.index, witness_meta.data_type, witness_meta.version, entity
+        ));
+    }
+
+    lines
+}
+
+fn inspect_cell_data(type_script: TypeScript, data: &[u8]) -> Option<String> {
+    let hash = data.get(..32).map(hex::encode).unwrap_or_default();
+    let ret = match type_script {
+        TypeScript::AccountCellType => {
+            let account = data_parser::account_cell::get_account(data)
+                .and_then(|v| String::from_utf8(v.to_vec()).ok())
+                .unwrap_or_default();
+            format!(
+                "{{ hash: 0x{}, id: 0x{}, next: 0x{}, expired_at: {:?}, account: {} }}",
+                hash,
+                hex::encode(data_parser::account_cell::get_id(data).unwrap_or_default()),
+                hex::encode(data_parser::account_cell::get_next(data).unwrap_or_default()),
+                data_parser::account_cell::get_expired_at(data),
+                account
+            )
+        }
+        TypeScript::ApplyRegisterCellType => format!(
+            "{{ account_hash: 0x{}, height: {:?}, timestamp: {:?} }}",
+            hex::encode(data_parser::apply_register_cell::get_account_hash(data).unwrap_or_default()),
+            data_parser::apply_register_cell::get_height(data),
+            data_parser::apply_register_cell::get_timestamp(data)
+        ),
+        TypeScript::PreAccountCellType => format!(
+            "{{ hash: 0x{}, id: 0x{} }}",
+            hash,
+            hex::encode(data_parser::pre_account_cell::get_id(data).unwrap_or_default())
+        ),
+        TypeScript::SubAccountCellType => format!(
+            "{{ smt_root: 0x{}, das_profit: {:?}, owner_profit: {:?}, flag: {:?}, custom_script: 0x{} }}",
+            hex::encode(data_parser::sub_account_cell::get_smt_root(data).unwrap_or_default()),
+            data_parser::sub_account_cell::get_das_profit(data),
+            data_parser::sub_account_cell::get_owner_profit(data),
+            data_parser::sub_account_cell::get_flag(data),
+            hex::encode(data_parser::sub_account_cell::get_custom_script(data).unwrap_or_default())
+        ),
+        TypeScript::DPointCellType => format!("{{ value: {:?} }}", data_parser::dpoint_cell::get_value(data)),
+        TypeScript::BalanceCellType | TypeScript::EIP712Lib => return None,
+        _ => format!("{{ hash: 0x{} }}", hash),
+    };
+
+    Some(ret)
+}
+
+fn inspect_entity(
+    parser: &mut WitnessesParserV1,
+    cell_meta: CellMeta,
+    data_type: DataType,
+    version: u32,
+) -> Result<String, WitnessParserError> {
+    macro_rules! prettier {
+        ($entity_type:ty) => {
+            parser
+                .get_entity_by_cell_meta::<$entity_type>(cell_meta)?
+                .as_prettier()
+        };
+    }
+
+    let ret = match (data_type, version) {
+        (DataType::AccountCellData, 2) => prettier!(AccountCellDataV2),
+        (DataType::AccountCellData, 3) => prettier!(AccountCellDataV3),
+        (DataType::AccountCellData, 4) => prettier!(AccountCellDataV4),
+        (DataType::AccountCellData, _) => prettier!(AccountCellData),
+        (DataType::PreAccountCellData, 1) => prettier!(PreAccountCellDataV1),
+        (DataType::PreAccountCellData, 2) => prettier!(PreAccountCellDataV2),
+        (DataType::PreAccountCellData, 3) => prettier!(PreAccountCellDataV3),
+        (DataType::PreAccountCellData, _) => prettier!(PreAccountCellData),
+        (DataType::AccountSaleCellData, 1) => prettier!(AccountSaleCellDataV1),
+        (DataType::AccountSaleCellData, _) => prettier!(AccountSaleCellData),
+        (DataType::OfferCellData, _) => prettier!(OfferCellData),
+        (DataType::IncomeCellData, _) => prettier!(IncomeCellData),
+        (DataType::ProposalCellData, _) => prettier!(ProposalCellData),
+        _ => String::from("(no prettier available)"),
+    };
+
+    Ok(ret)
+}
+
+fn inspect_config(parser: &mut WitnessesParserV1, data_type: DataType) -> String {
+    macro_rules! prettier {
+        ($entity_type:ty) => {
+            parser
+                .get_entity_by_data_type::<$entity_type>(data_type)
+                .map(|entity| entity.as_prettier())
+        };
+    }
+
+    let ret = match data_type {
+        DataType::ConfigCellMain => prettier!(ConfigCellMain),
+        DataType::ConfigCellAccount => prettier!(ConfigCellAccount),
+        DataType::ConfigCellApply => prettier!(ConfigCellApply),
+        DataType::ConfigCellIncome => prettier!(ConfigCellIncome),
+        DataType::ConfigCellPrice => prettier!(ConfigCellPrice),
+        DataType::ConfigCellProposal => prettier!(ConfigCellProposal),
+        DataType::ConfigCellProfitRate => prettier!(ConfigCellProfitRate),
+        DataType::ConfigCellRelease => prettier!(ConfigCellRelease),
+        DataType::ConfigCellSecondaryMarket => prettier!(ConfigCellSecondaryMarket),
+        DataType::ConfigCellReverseResolution => prettier!(ConfigCellReverseResolution),
+        DataType::ConfigCellSubAccount => prettier!(ConfigCellSubAccount),
+        DataType::ConfigCellSystemStatus => prettier!(ConfigCellSystemStatus),
+        DataType::ConfigCellDPoint => prettier!(ConfigCellDPoint),
+        DataType::ConfigCellAccountList => prettier!(ConfigCellAccountList),
+        DataType::ConfigCellConfusables => prettier!(ConfigCellConfusables),
+        DataType::ConfigCellCrossChain => prettier!(ConfigCellCrossChain),
+        _ => parser
+            .get_raw_by_data_type(data_type)
+            .map(|raw| format!("(raw data in {} bytes)", raw.len())),
+    };
+
+    ret.unwrap_or_else(|err| format!("❌ {}", err))
+}
+
+fn inspect_witness(index: usize, witness: &[u8]) -> Option<String> {
+    if witness.len() <= WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES || witness[..WITNESS_HEADER_BYTES] != WITNESS_HEADER {
+        return None;
+    }
+
+    let data_type_in_int = u32::from_le_bytes(
+        witness[WITNESS_HEADER_BYTES..(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)]
+            .try_into()
+            .unwrap(),
+    );
+    let data_type = match DataType::try_from(data_type_in_int) {
+        Ok(data_type) => data_type,
+        Err(_) => return Some(format!("  [{}] unknown DataType {}", index, data_type_in_int)),
+    };
+
+    let ret = match data_type {
+        DataType::SubAccount => inspect_sub_account_witness(witness),
+        DataType::SubAccountMintSign | DataType::SubAccountRenewSign => inspect_sub_account_sign_witness(witness),
+        DataType::ReverseRecord => inspect_reverse_record_witness(witness),
+        DataType::AccountListProof => {
+            AccountListProof::from_compatible_slice(&witness[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])
+                .map(|v| v.as_prettier())
+                .map_err(|err| format!("decoding failed: {}", err))
+        }
+        DataType::CrossChainUnlockAttestation => {
+            CrossChainUnlockAttestation::from_compatible_slice(&witness[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])
+                .map(|v| v.as_prettier())
+                .map_err(|err| format!("decoding failed: {}", err))
+        }
+        _ => Ok(format!("({} bytes)", witness.len())),
+    };
+
+    Some(format!(
+        "  [{}] {:?} {}",
+        index,
+        data_type,
+        ret.unwrap_or_else(|err| format!("❌ {}", err))
+    ))
+}
+
+fn inspect_sub_account_witness(raw: &[u8]) -> Result<String, String> {
+    let witness = RawSubAccountWitness::decode_lv(&raw[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])
+        .map_err(|err| err.to_string())?;
+    let old_sub_account_version = witness.old_sub_account_version.unwrap_or(1);
+    let new_sub_account_version = witness.new_sub_account_version.unwrap_or(1);
+
+    let is_create = SubAccountAction::from_str(witness.action) == Ok(SubAccountAction::Create);
+    let sub_account = if !is_create && old_sub_account_version == 1 {
+        SubAccountV1::from_compatible_slice(witness.sub_account).map(|v| v.as_prettier())
+    } else if !is_create && old_sub_account_version == 2 {
+        SubAccountV2::from_compatible_slice(witness.sub_account).map(|v| v.as_prettier())
+    } else {
+        SubAccount::from_compatible_slice(witness.sub_account).map(|v| v.as_prettier())
+    }
+    .map_err(|err| format!("[sub_account] decoding failed: {}", err))?;
+
+    let sign_expired_at = if witness.sign_expired_at.is_empty() {
+        String::from("None")
+    } else {
+        parse_u64("sign_expired_at", witness.sign_expired_at)?.to_string()
+    };
+    let edit_key = parse_string("edit_key", witness.edit_key)?;
+
+    Ok(format!(
+        "{{ version: {}, action: {}, signature: 0x{}, sign_role: 0x{}, sign_expired_at: {}, new_root: 0x{}, proof: 0x{}, old_sub_account_version: {}, new_sub_account_version: {}, sub_account: {}, edit_key: {}, edit_value: 0x{} }}",
+        witness.version,
+        witness.action,
+        hex::encode(witness.signature),
+        hex::encode(witness.sign_role),
+        sign_expired_at,
+        hex::encode(witness.new_root),
+        hex::encode(witness.proof),
+        old_sub_account_version,
+        new_sub_account_version,
+        sub_account,
+        edit_key,
+        hex::encode(witness.edit_value)
+    ))
+}
+
+fn inspect_sub_account_sign_witness(raw: &[u8]) -> Result<String, String> {
+    let start = WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES;
+
+    let (start, version) = das_lv::parse_field("version", raw, start).map_err(|err| err.to_string())?;
+    let (start, signature) = das_lv::parse_field("signature", raw, start).map_err(|err| err.to_string())?;
+    let (start, sign_role) = das_lv::parse_field("sign_role", raw, start).map_err(|err| err.to_string())?;
+    let (start, expired_at) = das_lv::parse_field("expired_at", raw, start).map_err(|err| err.to_string())?;
+    let (_, account_list_smt_root) =
+        das_lv::parse_field("account_list_smt_root", raw, start).map_err(|err| err.to_string())?;
+
+    Ok(format!(
+        "{{ version: {}, signature: 0x{}, sign_role: 0x{}, expired_at: {}, account_list_smt_root: 0x{} }}",
+        parse_u32("version", version)?,
+        hex::encode(signature),
+        hex::encode(sign_role),
+        parse_u64("expired_at", expired_at)?,
+        hex::encode(account_list_smt_root)
+    ))
+}
+
+fn inspect_reverse_record_witness(raw: &[u8]) -> Result<String, String> {
+    let witness = RawReverseRecordWitness::decode_lv(&raw[(WITNESS_HEADER_BYTES + WITNESS_TYPE_BYTES)..])
+        .map_err(|err| err.to_string())?;
+
+    let sign_type = DasLockType::try_from(witness.sign_type)
+        .map_err(|_| String::from("[sign_type] should be a valid DasLockType."))?;
+
+    Ok(format!(
+        "{{ version: {}, action: {}, signature: 0x{}, sign_type: {:?}, address_payload: 0x{}, proof: 0x{}, prev_nonce: {:?}, prev_account: {}, next_root: 0x{}, next_account: {} }}",
+        witness.version,
+        witness.action,
+        hex::encode(witness.signature),
+        sign_type,
+        hex::encode(witness.address_payload),
+        hex::encode(witness.proof),
+        witness.prev_nonce,
+        parse_string("prev_account", witness.prev_account)?,
+        hex::encode(witness.next_root),
+        witness.next_account
+    ))
+}
@@ -0,0 +1,6 @@
+//! Decode a DAS transaction in the mock-tx format and generate a readable report of its cells and witnesses.
+
+mod inspector;
+mod lv_parser;
+
+pub use inspector::inspect;
@@ -0,0 +1,17 @@
+pub fn parse_u32(field_name: &str, bytes: &[u8]) -> Result<u32, String> {
+    bytes
+        .try_into()
+        .map(u32::from_le_bytes)
+        .map_err(|_| format!("[{}] should be 4 bytes.", field_name))
+}
+
+pub fn parse_u64(field_name: &str, bytes: &[u8]) -> Result<u64, String> {
+    bytes
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| format!("[{}] should be 8 bytes.", field_name))
+}
+
+pub fn parse_string(field_name: &str, bytes: &[u8]) -> Result<String, String> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| format!("[{}] should be a valid utf-8 string.", field_name))
+}
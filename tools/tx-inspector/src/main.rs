@@ -0,0 +1,47 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::{env, process};
+
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+
+const USAGE: &str = "Usage: tx-inspector <MOCK_TX_JSON_FILE | ->
+
+Decode a DAS transaction in the mock-tx format and print a readable report, use - to read from stdin.";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let filepath = match args.as_slice() {
+        [filepath] if filepath != "-h" && filepath != "--help" => filepath.to_owned(),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let mut raw_json = String::new();
+    let ret = if filepath == "-" {
+        io::stdin().read_to_string(&mut raw_json)
+    } else {
+        File::open(&filepath).and_then(|mut file| file.read_to_string(&mut raw_json))
+    };
+    if let Err(err) = ret {
+        eprintln!("Read {} failed: {}", filepath, err);
+        process::exit(1);
+    }
+
+    let repr_tx: ReprMockTransaction = match serde_json::from_str(&raw_json) {
+        Ok(tx) => tx,
+        Err(err) => {
+            eprintln!("Parse the mock transaction failed: {}", err);
+            process::exit(1);
+        }
+    };
+
+    match tx_inspector::inspect(MockTransaction::from(repr_tx)) {
+        Ok(report) => println!("{}", report),
+        Err(err) => {
+            eprintln!("Inspect the transaction failed: {}", err);
+            process::exit(1);
+        }
+    }
+}